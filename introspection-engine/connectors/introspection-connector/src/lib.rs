@@ -11,7 +11,23 @@ pub trait IntrospectionConnector: Send + Sync + 'static {
 
     fn get_metadata(&self, database: &str) -> ConnectorResult<DatabaseMetadata>;
 
-    fn introspect(&self, database: &str) -> ConnectorResult<Datamodel>;
+    fn introspect(&self, database: &str) -> ConnectorResult<IntrospectionResult> {
+        self.introspect_schemas(database, &[])
+    }
+
+    /// Introspects `database` along with other schemas of the same database. The models of the
+    /// tables in these schemas get a `@@schema` directive.
+    fn introspect_schemas(&self, database: &str, schemas: &[String]) -> ConnectorResult<IntrospectionResult>;
+
+    /// Introspects the database again and merges the result into `previous`, so that the models,
+    /// fields and enums that still exist keep the names, directives and documentation the user
+    /// gave them.
+    fn re_introspect(
+        &self,
+        database: &str,
+        schemas: &[String],
+        previous: &Datamodel,
+    ) -> ConnectorResult<ReIntrospectionResult>;
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -19,3 +35,80 @@ pub struct DatabaseMetadata {
     pub table_count: usize,
     pub size_in_bytes: usize,
 }
+
+/// The introspected datamodel, with what could not be represented in it faithfully.
+#[derive(Debug)]
+pub struct IntrospectionResult {
+    pub datamodel: Datamodel,
+    pub warnings: Vec<IntrospectionWarning>,
+}
+
+/// The merged datamodel of a re-introspection, with what changed compared to the previous one.
+#[derive(Debug)]
+pub struct ReIntrospectionResult {
+    pub datamodel: Datamodel,
+    pub changes: Vec<IntrospectionChange>,
+    pub warnings: Vec<IntrospectionWarning>,
+}
+
+/// A construct of the database that introspection does not support. The warnings name tables
+/// and columns as they are in the database.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(tag = "code", rename_all = "camelCase")]
+pub enum IntrospectionWarning {
+    /// A table with neither a primary key nor a unique index, whose rows can't be told apart.
+    ModelWithoutIdentifier { table: String },
+    /// A view without a unique index. Its model is commented out, as it needs an id to be valid.
+    ViewWithoutIdentifier { view: String },
+    /// A column whose type has no equivalent in the datamodel. Its field is a `String`.
+    UnsupportedType {
+        table: String,
+        column: String,
+        #[serde(rename = "type")]
+        tpe: String,
+    },
+    /// A column whose name is not a valid identifier. Its field got a valid name and maps to
+    /// the column.
+    FieldRenamed {
+        table: String,
+        column: String,
+        field: String,
+    },
+    /// An enum value that is not a valid identifier. It is left out of the enum.
+    InvalidEnumValue { name: String, value: String },
+    /// A primary key column with a default expression that has no datamodel equivalent. The id
+    /// field is left without a default.
+    GeneratedIdDefault {
+        table: String,
+        column: String,
+        expression: String,
+    },
+}
+
+/// A model, field or enum that only exists in the previous datamodel or in the database.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct IntrospectionChange {
+    pub kind: IntrospectionChangeKind,
+    pub object: IntrospectionObject,
+    /// The model the field belongs to, or the changed model or enum itself.
+    pub name: String,
+    /// The name of the changed field. `None` for models and enums.
+    pub field: Option<String>,
+}
+
+/// Whether the object was added to the database or removed from it.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Copy)]
+#[serde(rename_all = "camelCase")]
+pub enum IntrospectionChangeKind {
+    Added,
+    Removed,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Copy)]
+#[serde(rename_all = "camelCase")]
+pub enum IntrospectionObject {
+    Model,
+    Field,
+    Enum,
+}
@@ -1,12 +1,15 @@
 use crate::SqlIntrospectionResult;
 use datamodel::{
-    common::{names::NameNormalizer, PrismaType, PrismaValue},
-    dml, Datamodel, Field, FieldArity, FieldType, IdInfo, IdStrategy, IndexDefinition, Model, OnDeleteStrategy,
+    common::{names::NameNormalizer, Decimal, PrismaType, PrismaValue},
+    dml, Datamodel, Field, FieldArity, FieldType, IdInfo, IdStrategy, IndexDefinition, Model, ReferentialAction,
     RelationInfo, ScalarListStrategy, WithDatabaseName,
 };
+use introspection_connector::IntrospectionWarning;
+use lazy_static::lazy_static;
 use log::debug;
 use prisma_inflector;
 use regex::Regex;
+use sql_connection::SqlFamily;
 use sql_schema_describer::*;
 
 fn is_migration_table(table: &Table) -> bool {
@@ -41,17 +44,29 @@ fn is_prisma_scalar_list_table(table: &Table) -> bool {
         && table.columns.iter().find(|column| column.name == "value").is_some()
 }
 
-fn create_many_to_many_field(foreign_key: &ForeignKey, relation_name: String, is_self_relation: bool) -> Field {
+fn create_many_to_many_field(
+    schema: &SqlSchema,
+    foreign_key: &ForeignKey,
+    relation_name: String,
+    is_self_relation: bool,
+) -> Field {
     let inflector = prisma_inflector::default();
 
+    let to = referenced_model_name(schema, foreign_key);
+
     let field_type = FieldType::Relation(RelationInfo {
         name: relation_name,
-        to: foreign_key.referenced_table.clone(),
-        to_fields: foreign_key.referenced_columns.clone(),
-        on_delete: OnDeleteStrategy::None,
+        to: to.clone(),
+        to_fields: foreign_key
+            .referenced_columns
+            .iter()
+            .map(|column| referenced_field_name(schema, foreign_key, column))
+            .collect(),
+        on_delete: ReferentialAction::None,
+        on_update: ReferentialAction::None,
     });
 
-    let basename = inflector.pluralize(&foreign_key.referenced_table).camel_case();
+    let basename = inflector.pluralize(&to).camel_case();
 
     let name = match is_self_relation {
         true => format!("{}_{}", basename, foreign_key.columns[0]),
@@ -70,26 +85,225 @@ fn create_many_to_many_field(foreign_key: &ForeignKey, relation_name: String, is
         documentation: None,
         is_generated: false,
         is_updated_at: false,
+        validators: vec![],
+    }
+}
+
+/// Valid identifiers for the given names, in the same order. Names that are not valid identifiers
+/// get a sanitized one, with a numeric suffix when another name already has it.
+fn identifiers(names: &[String]) -> Vec<String> {
+    let mut taken: Vec<String> = names
+        .iter()
+        .filter(|name| name.is_valid_identifier())
+        .cloned()
+        .collect();
+
+    names
+        .iter()
+        .map(|name| {
+            if name.is_valid_identifier() {
+                return name.clone();
+            }
+
+            let sanitized = name.sanitize();
+            let identifier = if taken.contains(&sanitized) {
+                (1..)
+                    .map(|suffix| format!("{}_{}", sanitized, suffix))
+                    .find(|candidate| !taken.contains(candidate))
+                    .expect("find a free suffix")
+            } else {
+                sanitized
+            };
+
+            taken.push(identifier.clone());
+            identifier
+        })
+        .collect()
+}
+
+/// The names of the fields for the columns of a table, in column order.
+fn field_names(table: &Table) -> Vec<String> {
+    let columns: Vec<String> = table.columns.iter().map(|column| column.name.clone()).collect();
+
+    identifiers(&columns)
+}
+
+/// The name of the field for a column of the table.
+fn field_name(table: &Table, column: &str) -> String {
+    match table
+        .columns
+        .iter()
+        .position(|table_column| table_column.name == column)
+    {
+        Some(position) => field_names(table).swap_remove(position),
+        None => column.to_owned(),
+    }
+}
+
+/// The name of the field for a column of the referenced table of a foreign key.
+fn referenced_field_name(schema: &SqlSchema, foreign_key: &ForeignKey, column: &str) -> String {
+    match schema.get_table_in_schema(
+        foreign_key.referenced_schema.as_ref().map(String::as_str),
+        &foreign_key.referenced_table,
+    ) {
+        Some(table) => field_name(table, column),
+        None => column.to_owned(),
+    }
+}
+
+/// The name of the model for a table or view. When tables or views of several schemas share a name,
+/// the ones outside the default schema get their schema as a prefix and map to their table.
+fn model_name(schema: &SqlSchema, table_schema: Option<&str>, table_name: &str) -> String {
+    let table_schemas = schema
+        .tables
+        .iter()
+        .map(|table| (table.schema.as_ref(), &table.name))
+        .chain(schema.views.iter().map(|view| (view.schema.as_ref(), &view.name)));
+    let name_is_shared = table_schemas
+        .filter(|(_, name)| name.as_str() == table_name)
+        .any(|(other_schema, _)| other_schema.map(String::as_str) != table_schema);
+
+    match table_schema {
+        Some(table_schema) if name_is_shared => {
+            let name = format!("{}_{}", table_schema, table_name);
+
+            if name.is_valid_identifier() {
+                name
+            } else {
+                name.sanitize()
+            }
+        }
+        _ => table_name.to_owned(),
     }
 }
 
+/// The name of the model for the referenced table of a foreign key.
+fn referenced_model_name(schema: &SqlSchema, foreign_key: &ForeignKey) -> String {
+    model_name(
+        schema,
+        foreign_key.referenced_schema.as_ref().map(String::as_str),
+        &foreign_key.referenced_table,
+    )
+}
+
+/// Column types the datamodel has no type for. Their fields are introspected as `String`.
+fn is_unsupported_type(family: &ColumnTypeFamily) -> bool {
+    match family {
+        ColumnTypeFamily::Binary
+        | ColumnTypeFamily::Geometric
+        | ColumnTypeFamily::LogSequenceNumber
+        | ColumnTypeFamily::TextSearch
+        | ColumnTypeFamily::TransactionId
+        | ColumnTypeFamily::Unknown => true,
+        _ => false,
+    }
+}
+
+fn has_unique_identifier(table: &Table) -> bool {
+    table.primary_key.is_some() || table.indices.iter().any(|index| index.tpe == IndexType::Unique)
+}
+
+/// Views have no primary key, the first unique index of a view identifies its rows instead. The
+/// database reports the columns of views as nullable whatever they hold, so the columns of that
+/// index are made required like those of any id.
+fn view_as_table(view: &View) -> Table {
+    let mut table = view.as_table();
+
+    if let Some(position) = table.indices.iter().position(|index| index.tpe == IndexType::Unique) {
+        let index = table.indices.remove(position);
+        for column in table.columns.iter_mut() {
+            if index.columns.contains(&column.name) {
+                column.arity = ColumnArity::Required;
+            }
+        }
+        table.primary_key = Some(PrimaryKey {
+            columns: index.columns,
+            sequence: None,
+        });
+    }
+
+    table
+}
+
 /// Calculate a data model from a database schema.
 pub fn calculate_model(schema: &SqlSchema) -> SqlIntrospectionResult<Datamodel> {
+    calculate_model_internal(schema, None).map(|(data_model, _)| data_model)
+}
+
+/// Calculate a data model from a database schema, pinning fields to the native type of their
+/// column whenever it is not the type the field would be migrated to anyway.
+pub fn calculate_model_with_native_types(
+    schema: &SqlSchema,
+    sql_family: SqlFamily,
+) -> SqlIntrospectionResult<Datamodel> {
+    calculate_model_with_warnings(schema, sql_family).map(|(data_model, _)| data_model)
+}
+
+/// Calculate a data model from a database schema like `calculate_model_with_native_types`, along
+/// with warnings about everything the data model can't represent faithfully.
+pub fn calculate_model_with_warnings(
+    schema: &SqlSchema,
+    sql_family: SqlFamily,
+) -> SqlIntrospectionResult<(Datamodel, Vec<IntrospectionWarning>)> {
+    calculate_model_internal(schema, Some(sql_family))
+}
+
+fn calculate_model_internal(
+    schema: &SqlSchema,
+    sql_family: Option<SqlFamily>,
+) -> SqlIntrospectionResult<(Datamodel, Vec<IntrospectionWarning>)> {
     debug!("Calculating data model");
 
     let mut data_model = Datamodel::new();
-    for table in schema
+    let mut warnings = Vec::new();
+    // Views become read only models. They have no foreign keys, so they can't take part in relations.
+    let views: Vec<Table> = schema.views.iter().map(view_as_table).collect();
+    let tables = schema
         .tables
         .iter()
         .filter(|table| !is_migration_table(&table))
         .filter(|table| !is_prisma_join_table(&table))
         .filter(|table| !is_prisma_scalar_list_table(&table))
-    {
-        let mut model = Model::new(&table.name);
+        .map(|table| (table, false))
+        .chain(views.iter().map(|view| (view, true)));
+
+    for (table, is_view) in tables {
+        let mut model = Model::new(&model_name(
+            schema,
+            table.schema.as_ref().map(String::as_str),
+            &table.name,
+        ));
+        if model.name != table.name {
+            model.database_name = Some(table.name.clone());
+        }
+        model.schema = table.schema.clone();
+        model.is_view = is_view;
+        model.documentation = table.comment.clone();
+
+        if is_view && table.primary_key.is_none() {
+            // A model needs an id, the view stays commented out until one is added by hand.
+            model.is_commented_out = true;
+            warnings.push(IntrospectionWarning::ViewWithoutIdentifier {
+                view: table.name.clone(),
+            });
+        } else if !has_unique_identifier(table) {
+            warnings.push(IntrospectionWarning::ModelWithoutIdentifier {
+                table: table.name.clone(),
+            });
+        }
+
         //Todo: This needs to filter out composite Foreign Key columns, they are merged into one new field
-        for column in table.columns.iter() {
+        for (column, name) in table.columns.iter().zip(field_names(table)) {
             debug!("Handling column {:?}", column);
-            let field_type = calculate_field_type(&schema, &column, &table);
+            let mut field_type = calculate_field_type(&schema, &column, &table);
+            if let (Some(sql_family), FieldType::Base(base_type)) = (sql_family, &field_type) {
+                if let Some(native_type) = native_type_for_column(sql_family, &column.tpe) {
+                    field_type = FieldType::ConnectorSpecific {
+                        base_type: *base_type,
+                        connector_type: Some(native_type),
+                    };
+                }
+            }
             let arity = match column.arity {
                 ColumnArity::Required => FieldArity::Required,
                 ColumnArity::Nullable => FieldArity::Optional,
@@ -100,10 +314,26 @@ pub fn calculate_model(schema: &SqlSchema) -> SqlIntrospectionResult<Datamodel>
                 FieldArity::List => Some(ScalarListStrategy::Embedded),
                 _ => None,
             };
-            let default_value = column
-                .default
-                .as_ref()
-                .and_then(|default| calculate_default(default, &column.tpe.family));
+            // Auto-incrementing ids are generated by the id strategy, not by a default.
+            let default_value = match id_info {
+                Some(IdInfo {
+                    strategy: IdStrategy::Auto,
+                    ..
+                }) => None,
+                // Ids can't be generated by the database, their value must be known to read the record back.
+                Some(_) => match calculate_default(&column, &field_type) {
+                    Some(PrismaValue::Expression(name, _, _)) if name == "dbgenerated" => {
+                        warnings.push(IntrospectionWarning::GeneratedIdDefault {
+                            table: table.name.clone(),
+                            column: column.name.clone(),
+                            expression: column.default.clone().unwrap_or_default(),
+                        });
+                        None
+                    }
+                    default_value => default_value,
+                },
+                None => calculate_default(&column, &field_type),
+            };
 
             let is_unique = match field_type {
                 datamodel::dml::FieldType::Relation(..) => false,
@@ -116,18 +346,38 @@ pub fn calculate_model(schema: &SqlSchema) -> SqlIntrospectionResult<Datamodel>
                 }
             };
 
+            if is_unsupported_type(&column.tpe.family) && field_type.scalar_type().is_some() {
+                warnings.push(IntrospectionWarning::UnsupportedType {
+                    table: table.name.clone(),
+                    column: column.name.clone(),
+                    tpe: column.tpe.raw.clone(),
+                });
+            }
+
+            let database_name = if name == column.name {
+                None
+            } else {
+                warnings.push(IntrospectionWarning::FieldRenamed {
+                    table: table.name.clone(),
+                    column: column.name.clone(),
+                    field: name.clone(),
+                });
+                Some(column.name.clone())
+            };
+
             let field = Field {
-                name: column.name.clone(),
+                name,
                 arity,
                 field_type,
-                database_name: None,
+                database_name,
                 default_value,
                 is_unique,
                 id_info,
                 scalar_list_strategy,
-                documentation: None,
+                documentation: column.comment.clone(),
                 is_generated: false,
-                is_updated_at: false,
+                is_updated_at: column.on_update_current_timestamp,
+                validators: vec![],
             };
             model.add_field(field);
         }
@@ -142,7 +392,7 @@ pub fn calculate_model(schema: &SqlSchema) -> SqlIntrospectionResult<Datamodel>
 
                 let index_definition: IndexDefinition = IndexDefinition {
                     name: Some(index.name.clone()),
-                    fields: index.columns.clone(),
+                    fields: index.columns.iter().map(|column| field_name(table, column)).collect(),
                     tpe,
                 };
                 model.add_index(index_definition)
@@ -150,7 +400,7 @@ pub fn calculate_model(schema: &SqlSchema) -> SqlIntrospectionResult<Datamodel>
             if index.columns.len() == 1 && index.tpe != IndexType::Unique {
                 let index_definition: IndexDefinition = IndexDefinition {
                     name: Some(index.name.clone()),
-                    fields: index.columns.clone(),
+                    fields: index.columns.iter().map(|column| field_name(table, column)).collect(),
                     tpe: datamodel::dml::IndexType::Normal,
                 };
                 model.add_index(index_definition)
@@ -158,15 +408,36 @@ pub fn calculate_model(schema: &SqlSchema) -> SqlIntrospectionResult<Datamodel>
         }
 
         if table.primary_key_columns().len() > 1 {
-            model.id_fields = table.primary_key_columns();
+            model.id_fields = table
+                .primary_key_columns()
+                .iter()
+                .map(|column| field_name(table, column))
+                .collect();
         }
 
+        model.check_constraints = table
+            .check_constraints
+            .iter()
+            .map(|check| dml::CheckConstraint {
+                name: Some(check.name.clone()),
+                expression: check.expression.clone(),
+            })
+            .collect();
+
         data_model.add_model(model);
     }
 
     for e in schema.enums.iter() {
-        let mut values: Vec<String> = e.values.iter().cloned().collect();
+        let (mut values, invalid_values): (Vec<String>, Vec<String>) =
+            e.values.iter().cloned().partition(|value| value.is_valid_identifier());
+        for value in invalid_values {
+            warnings.push(IntrospectionWarning::InvalidEnumValue {
+                name: e.name.clone(),
+                value,
+            });
+        }
         values.sort_unstable();
+
         data_model.add_enum(dml::Enum {
             name: e.name.clone(),
             values,
@@ -197,14 +468,21 @@ pub fn calculate_model(schema: &SqlSchema) -> SqlIntrospectionResult<Datamodel>
                             name: relation_info.name.clone(),
                             to: model.name.clone(),
                             to_fields: vec![relation_field.name.clone()],
-                            on_delete: OnDeleteStrategy::None,
+                            on_delete: ReferentialAction::None,
+                            on_update: ReferentialAction::None,
                         });
 
                         let arity = match relation_field.arity {
                             FieldArity::Required | FieldArity::Optional
-                                if schema.table_bang(&model.name).is_column_unique(
-                                    &relation_field.database_name().as_ref().unwrap_or(&relation_field.name),
-                                ) =>
+                                if schema
+                                    .get_table_in_schema(
+                                        model.schema.as_ref().map(String::as_str),
+                                        model.database_name.as_ref().unwrap_or(&model.name),
+                                    )
+                                    .expect("find the table of the model")
+                                    .is_column_unique(
+                                        &relation_field.database_name().as_ref().unwrap_or(&relation_field.name),
+                                    ) =>
                             {
                                 FieldArity::Optional
                             }
@@ -232,6 +510,7 @@ pub fn calculate_model(schema: &SqlSchema) -> SqlIntrospectionResult<Datamodel>
                             documentation: None,
                             is_generated: false,
                             is_updated_at: false,
+                            validators: vec![],
                         };
 
                         fields_to_be_added.push((other_model.name.clone(), field));
@@ -249,15 +528,15 @@ pub fn calculate_model(schema: &SqlSchema) -> SqlIntrospectionResult<Datamodel>
 
         match (first, second) {
             (Some(f), Some(s)) => {
-                let is_self_relation = f.referenced_table == s.referenced_table;
+                let is_self_relation = references_same_table(f, s);
 
                 fields_to_be_added.push((
-                    s.referenced_table.clone(),
-                    create_many_to_many_field(f, table.name[1..].to_string(), is_self_relation),
+                    referenced_model_name(schema, s),
+                    create_many_to_many_field(schema, f, table.name[1..].to_string(), is_self_relation),
                 ));
                 fields_to_be_added.push((
-                    f.referenced_table.clone(),
-                    create_many_to_many_field(s, table.name[1..].to_string(), is_self_relation),
+                    referenced_model_name(schema, f),
+                    create_many_to_many_field(schema, s, table.name[1..].to_string(), is_self_relation),
                 ));
             }
             (_, _) => (),
@@ -287,6 +566,7 @@ pub fn calculate_model(schema: &SqlSchema) -> SqlIntrospectionResult<Datamodel>
             documentation: None,
             is_generated: false,
             is_updated_at: false,
+            validators: vec![],
         };
 
         fields_to_be_added.push((model.to_owned(), field));
@@ -325,13 +605,25 @@ pub fn calculate_model(schema: &SqlSchema) -> SqlIntrospectionResult<Datamodel>
         model.add_field(field);
     }
 
-    Ok(data_model)
+    Ok((data_model, warnings))
+}
+
+lazy_static! {
+    /// An integer, quoted or not.
+    static ref RE_INT: Regex = Regex::new(r"^'?(\d+)'?$").expect("compile regex");
+    /// Anything, quoted or not. The value is parsed as a number afterwards.
+    static ref RE_NUMBER: Regex = Regex::new(r"^'?([^']+)'?$").expect("compile regex");
+    /// Expressions meaning `now()`.
+    static ref RE_NOW: Regex =
+        Regex::new(r"(?i)^(now\(\)|(current_timestamp|localtimestamp)(\(\d*\))?|transaction_timestamp\(\))$")
+            .expect("compile regex");
+    /// Expressions meaning `uuid()`.
+    static ref RE_UUID: Regex = Regex::new(r"(?i)^(gen_random_uuid|uuid_generate_v4|uuid)\(\)$").expect("compile regex");
 }
 
 fn parse_int(value: &str) -> Option<i32> {
     debug!("Parsing int '{}'", value);
-    let re_num = Regex::new(r"^'?(\d+)'?$").expect("compile regex");
-    let rslt = re_num.captures(value);
+    let rslt = RE_INT.captures(value);
     if rslt.is_none() {
         debug!("Couldn't parse int");
         return None;
@@ -349,6 +641,26 @@ fn parse_int(value: &str) -> Option<i32> {
     }
 }
 
+fn parse_big_int(value: &str) -> Option<i64> {
+    debug!("Parsing big int '{}'", value);
+    let rslt = RE_INT.captures(value);
+    if rslt.is_none() {
+        debug!("Couldn't parse big int");
+        return None;
+    }
+
+    let captures = rslt.expect("get captures");
+    let num_str = captures.get(1).expect("get capture").as_str();
+    let num_rslt = num_str.parse::<i64>();
+    match num_rslt {
+        Ok(num) => Some(num),
+        Err(_) => {
+            debug!("Couldn't parse big int '{}'", num_str);
+            None
+        }
+    }
+}
+
 fn parse_bool(value: &str) -> Option<bool> {
     debug!("Parsing bool '{}'", value);
     value.to_lowercase().parse().ok()
@@ -356,8 +668,7 @@ fn parse_bool(value: &str) -> Option<bool> {
 
 fn parse_float(value: &str) -> Option<f32> {
     debug!("Parsing float '{}'", value);
-    let re_num = Regex::new(r"^'?([^']+)'?$").expect("compile regex");
-    let rslt = re_num.captures(value);
+    let rslt = RE_NUMBER.captures(value);
     if rslt.is_none() {
         debug!("Couldn't parse float");
         return None;
@@ -375,23 +686,88 @@ fn parse_float(value: &str) -> Option<f32> {
     }
 }
 
-fn calculate_default(default: &str, tpe: &ColumnTypeFamily) -> Option<PrismaValue> {
-    match tpe {
+fn parse_decimal(value: &str) -> Option<Decimal> {
+    debug!("Parsing decimal '{}'", value);
+    let rslt = RE_NUMBER.captures(value);
+    if rslt.is_none() {
+        debug!("Couldn't parse decimal");
+        return None;
+    }
+
+    let captures = rslt.expect("get captures");
+    let num_str = captures.get(1).expect("get capture").as_str();
+    let num_rslt = num_str.parse::<Decimal>();
+    match num_rslt {
+        Ok(num) => Some(num),
+        Err(_) => {
+            debug!("Couldn't parse decimal '{}'", num_str);
+            None
+        }
+    }
+}
+
+fn calculate_default(column: &Column, field_type: &FieldType) -> Option<PrismaValue> {
+    let default = column.default.as_ref()?;
+
+    if column.default_is_expression {
+        return field_type
+            .scalar_type()
+            .map(|scalar_type| calculate_expression_default(default, scalar_type));
+    }
+
+    match &column.tpe.family {
         ColumnTypeFamily::Boolean => match parse_int(default) {
             Some(x) => Some(PrismaValue::Boolean(x != 0)),
             None => parse_bool(default).map(|b| PrismaValue::Boolean(b)),
         },
         ColumnTypeFamily::Int => parse_int(default).map(|x| PrismaValue::Int(x)),
+        ColumnTypeFamily::BigInt => parse_big_int(default).map(|x| PrismaValue::BigInt(x)),
         ColumnTypeFamily::Float => parse_float(default).map(|x| PrismaValue::Float(x)),
+        ColumnTypeFamily::Decimal if is_float_column(&column.tpe) => {
+            parse_float(default).map(|x| PrismaValue::Float(x))
+        }
+        ColumnTypeFamily::Decimal => parse_decimal(default).map(|x| PrismaValue::Decimal(x)),
         ColumnTypeFamily::String => Some(PrismaValue::String(default.to_string())),
         _ => None,
     }
 }
 
+/// Maps database expressions to the datamodel function with the same meaning, e.g. `CURRENT_TIMESTAMP` to
+/// `now()`. Expressions without an equivalent are kept verbatim in `dbgenerated("...")`.
+fn calculate_expression_default(expression: &str, scalar_type: PrismaType) -> PrismaValue {
+    match scalar_type {
+        PrismaType::DateTime if RE_NOW.is_match(expression) => {
+            PrismaValue::Expression(String::from("now"), PrismaType::DateTime, vec![])
+        }
+        PrismaType::String if RE_UUID.is_match(expression) => {
+            PrismaValue::Expression(String::from("uuid"), PrismaType::String, vec![])
+        }
+        _ => PrismaValue::Expression(
+            String::from("dbgenerated"),
+            scalar_type,
+            vec![PrismaValue::String(expression.to_string())],
+        ),
+    }
+}
+
+fn is_sequence_default(column: &Column) -> bool {
+    column.default_is_expression
+        && column
+            .default
+            .as_ref()
+            .map(|default| default.starts_with("nextval("))
+            .unwrap_or(false)
+}
+
 fn calc_id_info(column: &Column, table: &Table) -> Option<IdInfo> {
     table.primary_key.as_ref().and_then(|pk| {
         if pk.is_single_primary_key(&column.name) {
-            let strategy = match column.auto_increment {
+            let is_integer = match column.tpe.family {
+                ColumnTypeFamily::Int | ColumnTypeFamily::BigInt => true,
+                _ => false,
+            };
+            let is_auto_increment = column.auto_increment || (is_integer && is_sequence_default(column));
+            let strategy = match is_auto_increment {
                 true => IdStrategy::Auto,
                 false => IdStrategy::None,
             };
@@ -409,23 +785,28 @@ fn calc_id_info(column: &Column, table: &Table) -> Option<IdInfo> {
     })
 }
 
+fn references_same_table(first: &ForeignKey, second: &ForeignKey) -> bool {
+    first.referenced_table == second.referenced_table && first.referenced_schema == second.referenced_schema
+}
+
 fn calculate_relation_name(schema: &SqlSchema, fk: &ForeignKey, table: &Table) -> String {
     //this is not called for prisma many to many relations. for them the name is just the name of the join table.
-    let referenced_model = &fk.referenced_table;
-    let model_with_fk = &table.name;
+    let referenced_model = &referenced_model_name(schema, fk);
+    let model_with_fk = &model_name(schema, table.schema.as_ref().map(String::as_str), &table.name);
     let fk_column_name = fk.columns.get(0).unwrap();
 
     let fk_to_same_model: Vec<&ForeignKey> = table
         .foreign_keys
         .iter()
-        .filter(|fk| fk.referenced_table == referenced_model.clone())
+        .filter(|other_fk| references_same_table(other_fk, fk))
         .collect();
 
     let fk_from_other_model_to_this: Vec<&ForeignKey> = schema
-        .table_bang(referenced_model)
+        .get_table_in_schema(fk.referenced_schema.as_ref().map(String::as_str), &fk.referenced_table)
+        .expect("find the referenced table")
         .foreign_keys
         .iter()
-        .filter(|fk| fk.referenced_table == model_with_fk.clone())
+        .filter(|other_fk| other_fk.referenced_table == table.name && other_fk.referenced_schema == table.schema)
         .collect();
 
     //unambiguous
@@ -445,6 +826,63 @@ fn calculate_relation_name(schema: &SqlSchema, fk: &ForeignKey, table: &Table) -
     }
 }
 
+/// The native type of a column, if its raw type differs from the one Prisma would use for the field.
+/// The lengths of character types and the precisions of exact numeric types are kept as the arguments
+/// of the native type.
+fn native_type_for_column(sql_family: SqlFamily, tpe: &ColumnType) -> Option<String> {
+    let native_type = match (sql_family, tpe.raw.as_str()) {
+        (SqlFamily::Postgres, "int2") => "SmallInt",
+        (SqlFamily::Postgres, "float4") => "Real",
+        (SqlFamily::Postgres, "varchar") => "VarChar",
+        (SqlFamily::Postgres, "bpchar") => "Char",
+        (SqlFamily::Postgres, "uuid") => "Uuid",
+        (SqlFamily::Postgres, "json") => "Json",
+        (SqlFamily::Postgres, "jsonb") => "JsonB",
+        (SqlFamily::Postgres, "timestamptz") => "Timestamptz",
+        (SqlFamily::Postgres, "date") => "Date",
+        (SqlFamily::Postgres, "time") => "Time",
+        // Prisma uses `numeric` without a precision for decimals on Postgres.
+        (SqlFamily::Postgres, "numeric") if tpe.numeric_precision.is_some() && !is_float_column(tpe) => "Decimal",
+        (SqlFamily::Mysql, "smallint") => "SmallInt",
+        (SqlFamily::Mysql, "mediumint") => "MediumInt",
+        (SqlFamily::Mysql, "float") => "Float",
+        // Prisma uses `varchar(191)` for strings on MySQL.
+        (SqlFamily::Mysql, "varchar") if tpe.character_maximum_length != Some(191) => "VarChar",
+        (SqlFamily::Mysql, "char") => "Char",
+        (SqlFamily::Mysql, "text") => "Text",
+        (SqlFamily::Mysql, "mediumtext") => "MediumText",
+        (SqlFamily::Mysql, "longtext") => "LongText",
+        (SqlFamily::Mysql, "json") => "Json",
+        (SqlFamily::Mysql, "timestamp") => "Timestamp",
+        (SqlFamily::Mysql, "date") => "Date",
+        (SqlFamily::Mysql, "time") => "Time",
+        // Prisma uses `Decimal(64,30)` for decimals on MySQL.
+        (SqlFamily::Mysql, "decimal") if !is_default_mysql_decimal(tpe) && !is_float_column(tpe) => "Decimal",
+        _ => return None,
+    };
+
+    match (tpe.character_maximum_length, tpe.numeric_precision) {
+        (Some(length), _) => Some(format!("{}({})", native_type, length)),
+        (None, Some(precision)) => Some(format!(
+            "{}({},{})",
+            native_type,
+            precision,
+            tpe.numeric_scale.unwrap_or(0)
+        )),
+        (None, None) => Some(native_type.to_owned()),
+    }
+}
+
+fn is_default_mysql_decimal(tpe: &ColumnType) -> bool {
+    tpe.numeric_precision == Some(64) && tpe.numeric_scale == Some(30)
+}
+
+/// Float fields used to be stored as `Decimal(65,30)` on MySQL and Postgres. These columns are
+/// introspected as floats, like the migration engine keeps them for Float fields.
+fn is_float_column(tpe: &ColumnType) -> bool {
+    tpe.family == ColumnTypeFamily::Decimal && tpe.numeric_precision == Some(65) && tpe.numeric_scale == Some(30)
+}
+
 fn calculate_field_type(schema: &SqlSchema, column: &Column, table: &Table) -> FieldType {
     debug!("Calculating field type for '{}'", column.name);
     // Look for a foreign key referencing this column
@@ -460,11 +898,22 @@ fn calculate_field_type(schema: &SqlSchema, column: &Column, table: &Table) -> F
 
             FieldType::Relation(RelationInfo {
                 name: calculate_relation_name(schema, fk, table),
-                to: fk.referenced_table.clone(),
-                to_fields: vec![referenced_col.clone()],
+                to: referenced_model_name(schema, fk),
+                to_fields: vec![referenced_field_name(schema, fk, referenced_col)],
+                // The actions the migration engine uses by default (SET NULL on delete, NO ACTION on update)
+                // are not rendered, so that migrated databases introspect to the same datamodel.
                 on_delete: match fk.on_delete_action {
-                    ForeignKeyAction::Cascade => OnDeleteStrategy::Cascade,
-                    _ => OnDeleteStrategy::None,
+                    ForeignKeyAction::Cascade => ReferentialAction::Cascade,
+                    ForeignKeyAction::Restrict => ReferentialAction::Restrict,
+                    ForeignKeyAction::SetDefault => ReferentialAction::SetDefault,
+                    ForeignKeyAction::NoAction | ForeignKeyAction::SetNull => ReferentialAction::None,
+                },
+                on_update: match fk.on_update_action {
+                    ForeignKeyAction::Cascade => ReferentialAction::Cascade,
+                    ForeignKeyAction::Restrict => ReferentialAction::Restrict,
+                    ForeignKeyAction::SetNull if column.arity == ColumnArity::Nullable => ReferentialAction::SetNull,
+                    ForeignKeyAction::SetDefault => ReferentialAction::SetDefault,
+                    _ => ReferentialAction::None,
                 },
             })
         }
@@ -474,7 +923,10 @@ fn calculate_field_type(schema: &SqlSchema, column: &Column, table: &Table) -> F
                 ColumnTypeFamily::Boolean => FieldType::Base(PrismaType::Boolean),
                 ColumnTypeFamily::DateTime => FieldType::Base(PrismaType::DateTime),
                 ColumnTypeFamily::Float => FieldType::Base(PrismaType::Float),
+                ColumnTypeFamily::Decimal if is_float_column(&column.tpe) => FieldType::Base(PrismaType::Float),
+                ColumnTypeFamily::Decimal => FieldType::Base(PrismaType::Decimal),
                 ColumnTypeFamily::Int => FieldType::Base(PrismaType::Int),
+                ColumnTypeFamily::BigInt => FieldType::Base(PrismaType::BigInt),
                 ColumnTypeFamily::String => FieldType::Base(PrismaType::String),
                 // XXX: We made a conscious decision to punt on mapping of ColumnTypeFamily
                 // variants that don't yet have corresponding PrismaType variants
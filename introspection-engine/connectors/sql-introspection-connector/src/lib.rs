@@ -1,10 +1,15 @@
 pub mod calculate_datamodel; // only exported to be able to unit test it
+pub mod re_introspection; // only exported to be able to unit test it
 
 mod error;
 mod schema_describer_loading;
 
 use datamodel::Datamodel;
-use introspection_connector::{ConnectorResult, DatabaseMetadata, IntrospectionConnector};
+use introspection_connector::{
+    ConnectorResult, DatabaseMetadata, IntrospectionConnector, IntrospectionResult, IntrospectionWarning,
+    ReIntrospectionResult,
+};
+use sql_connection::SqlFamily;
 use sql_schema_describer::{SqlSchema, SqlSchemaDescriberBackend};
 
 pub use error::*;
@@ -12,13 +17,14 @@ pub use error::*;
 pub type SqlIntrospectionResult<T> = core::result::Result<T, SqlIntrospectionError>;
 
 pub struct SqlIntrospectionConnector {
+    sql_family: SqlFamily,
     describer: Box<dyn SqlSchemaDescriberBackend>,
 }
 
 impl SqlIntrospectionConnector {
     pub fn new(url: &str) -> ConnectorResult<SqlIntrospectionConnector> {
-        let describer = schema_describer_loading::load_describer(&url)?;
-        Ok(SqlIntrospectionConnector { describer })
+        let (sql_family, describer) = schema_describer_loading::load_describer(&url)?;
+        Ok(SqlIntrospectionConnector { sql_family, describer })
     }
 
     fn list_databases_internal(&self) -> SqlIntrospectionResult<Vec<String>> {
@@ -34,8 +40,8 @@ impl SqlIntrospectionConnector {
         Ok(db_metadate)
     }
 
-    fn describe(&self, database: &str) -> SqlIntrospectionResult<SqlSchema> {
-        Ok(self.describer.describe(&database)?)
+    fn describe(&self, database: &str, schemas: &[String]) -> SqlIntrospectionResult<SqlSchema> {
+        Ok(self.describer.describe_schemas(&database, schemas)?)
     }
 }
 
@@ -48,9 +54,38 @@ impl IntrospectionConnector for SqlIntrospectionConnector {
         Ok(self.get_metadata_internal(&database)?)
     }
 
-    fn introspect(&self, database: &str) -> ConnectorResult<Datamodel> {
-        let sql_schema = self.describe(database)?;
-        let data_model = calculate_datamodel::calculate_model(&sql_schema).unwrap();
-        Ok(data_model)
+    fn introspect_schemas(&self, database: &str, schemas: &[String]) -> ConnectorResult<IntrospectionResult> {
+        let sql_schema = self.describe(database, schemas)?;
+        let (datamodel, warnings) = calculate_datamodel::calculate_model_with_warnings(&sql_schema, self.sql_family)?;
+
+        Ok(IntrospectionResult { datamodel, warnings })
+    }
+
+    fn re_introspect(
+        &self,
+        database: &str,
+        schemas: &[String],
+        previous: &Datamodel,
+    ) -> ConnectorResult<ReIntrospectionResult> {
+        let sql_schema = self.describe(database, schemas)?;
+        let (introspected, warnings) =
+            calculate_datamodel::calculate_model_with_warnings(&sql_schema, self.sql_family)?;
+        let (datamodel, changes) = re_introspection::merge_datamodels(previous, &introspected, &sql_schema);
+        // Views that got their id from the previous datamodel are not commented out anymore.
+        let warnings = warnings
+            .into_iter()
+            .filter(|warning| match warning {
+                IntrospectionWarning::ViewWithoutIdentifier { view } => datamodel
+                    .models()
+                    .any(|model| model.is_commented_out && model.database_name.as_ref().unwrap_or(&model.name) == view),
+                _ => true,
+            })
+            .collect();
+
+        Ok(ReIntrospectionResult {
+            datamodel,
+            changes,
+            warnings,
+        })
     }
 }
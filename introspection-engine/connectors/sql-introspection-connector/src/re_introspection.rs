@@ -0,0 +1,361 @@
+use datamodel::{Datamodel, Enum, Field, FieldArity, FieldType, Model};
+use introspection_connector::{IntrospectionChange, IntrospectionChangeKind, IntrospectionObject};
+use sql_schema_describer::SqlSchema;
+use std::collections::HashMap;
+
+/// What a field is recognized by in both datamodels.
+#[derive(Debug, PartialEq)]
+enum FieldKey<'a> {
+    /// A scalar field or the relation field holding the foreign key, by the name of its column.
+    Column(&'a str),
+    /// The back relation field of a foreign key, by the referencing table and column.
+    BackRelation { table: &'a str, column: &'a str },
+    /// A side of a many to many relation, by the related table and the name of the relation.
+    ManyToMany { table: &'a str, relation: &'a str },
+}
+
+/// Merges a freshly introspected datamodel into the `previous` one. Models, fields and enums whose
+/// table, column or enum still exists keep their names, `@map`s, the documentation when the
+/// database has no comment and the defaults introspection can't see. Relations keep their names.
+/// Everything else is taken from the database, and reported as added or removed.
+pub fn merge_datamodels(
+    previous: &Datamodel,
+    introspected: &Datamodel,
+    schema: &SqlSchema,
+) -> (Datamodel, Vec<IntrospectionChange>) {
+    let mut changes = Vec::new();
+    let mut merged = Datamodel::new();
+    // Keyed by the names in the introspected datamodel.
+    let mut model_names: HashMap<&str, String> = HashMap::new();
+    let mut field_names: HashMap<(&str, &str), String> = HashMap::new();
+    let mut relation_names: HashMap<&str, String> = HashMap::new();
+    let mut enum_names: HashMap<&str, String> = HashMap::new();
+
+    for model in introspected.models() {
+        let mut merged_model = model.clone();
+
+        let previous_model = previous
+            .models()
+            .find(|previous_model| db_name(previous_model) == db_name(model) && previous_model.schema == model.schema);
+
+        let previous_model = match previous_model {
+            Some(previous_model) => previous_model,
+            None => {
+                changes.push(change(
+                    IntrospectionChangeKind::Added,
+                    IntrospectionObject::Model,
+                    &model.name,
+                    None,
+                ));
+                merged.add_model(merged_model);
+                continue;
+            }
+        };
+
+        merged_model.name = previous_model.name.clone();
+        merged_model.database_name = previous_model.database_name.clone();
+        if merged_model.documentation.is_none() {
+            merged_model.documentation = previous_model.documentation.clone();
+        }
+        model_names.insert(model.name.as_str(), previous_model.name.clone());
+
+        let mut matched_fields = Vec::new();
+        // Views have no primary key in the database, their id is the one set in the datamodel. A view
+        // without an id there keeps the one introspection took from a unique index, if any.
+        let keep_view_id = model.is_view && has_id(previous_model);
+        let mut view_id_fields = Vec::new();
+
+        for (merged_field, field) in merged_model.fields.iter_mut().zip(model.fields()) {
+            let key = introspected_key(introspected, schema, model, field);
+            let previous_field = previous_model
+                .fields()
+                .find(|previous_field| previous_key(previous, previous_model, previous_field) == key);
+
+            match previous_field {
+                Some(previous_field) => {
+                    merge_field(merged_field, previous_field);
+                    if model.is_view {
+                        // The nullability of view columns isn't reliable either.
+                        if previous_field.arity == FieldArity::Required && field.arity == FieldArity::Optional {
+                            merged_field.arity = FieldArity::Required;
+                        }
+                        if keep_view_id {
+                            merged_field.id_info = previous_field.id_info.clone();
+                        }
+                        if let Some(position) = previous_model.id_fields.iter().position(|f| f == &previous_field.name)
+                        {
+                            view_id_fields.push((position, field.name.clone()));
+                        }
+                    }
+                    field_names.insert((model.name.as_str(), field.name.as_str()), previous_field.name.clone());
+                    matched_fields.push(&previous_field.name);
+
+                    if let (FieldKey::Column(_), FieldType::Relation(info), FieldType::Relation(previous_info)) =
+                        (&key, &field.field_type, &previous_field.field_type)
+                    {
+                        relation_names.insert(info.name.as_str(), previous_info.name.clone());
+                    }
+                }
+                None => {
+                    if keep_view_id {
+                        merged_field.id_info = None;
+                    }
+                    changes.push(change(
+                        IntrospectionChangeKind::Added,
+                        IntrospectionObject::Field,
+                        &previous_model.name,
+                        Some(field.name.as_str()),
+                    ))
+                }
+            }
+        }
+
+        if keep_view_id {
+            view_id_fields.sort_unstable();
+            merged_model.id_fields = view_id_fields.into_iter().map(|(_, name)| name).collect();
+        }
+
+        if model.is_view {
+            merged_model.is_commented_out = !has_id(&merged_model);
+        }
+
+        for previous_field in previous_model.fields() {
+            if !matched_fields.contains(&&previous_field.name) {
+                changes.push(change(
+                    IntrospectionChangeKind::Removed,
+                    IntrospectionObject::Field,
+                    &previous_model.name,
+                    Some(previous_field.name.as_str()),
+                ));
+            }
+        }
+
+        merged.add_model(merged_model);
+    }
+
+    for previous_model in previous.models() {
+        let still_exists = introspected
+            .models()
+            .any(|model| db_name(previous_model) == db_name(model) && previous_model.schema == model.schema);
+
+        if !still_exists {
+            changes.push(change(
+                IntrospectionChangeKind::Removed,
+                IntrospectionObject::Model,
+                &previous_model.name,
+                None,
+            ));
+        }
+    }
+
+    for enm in introspected.enums() {
+        let mut merged_enum = enm.clone();
+
+        match previous
+            .enums()
+            .find(|previous_enum| enum_db_name(previous_enum) == enm.name)
+        {
+            Some(previous_enum) => {
+                merged_enum.name = previous_enum.name.clone();
+                merged_enum.database_name = previous_enum.database_name.clone();
+                merged_enum.documentation = previous_enum.documentation.clone();
+                enum_names.insert(enm.name.as_str(), previous_enum.name.clone());
+            }
+            None => changes.push(change(
+                IntrospectionChangeKind::Added,
+                IntrospectionObject::Enum,
+                &enm.name,
+                None,
+            )),
+        }
+
+        merged.add_enum(merged_enum);
+    }
+
+    for previous_enum in previous.enums() {
+        if !introspected.enums().any(|enm| enum_db_name(previous_enum) == enm.name) {
+            changes.push(change(
+                IntrospectionChangeKind::Removed,
+                IntrospectionObject::Enum,
+                &previous_enum.name,
+                None,
+            ));
+        }
+    }
+
+    // The references between models still use the introspected names.
+    let field_name = |model: &str, field: &str| -> String {
+        field_names
+            .get(&(model, field))
+            .cloned()
+            .unwrap_or_else(|| field.to_owned())
+    };
+
+    for (merged_model, model) in merged.models_mut().zip(introspected.models()) {
+        for field in merged_model.fields_mut() {
+            match &mut field.field_type {
+                FieldType::Relation(info) => {
+                    info.to_fields = info
+                        .to_fields
+                        .iter()
+                        .map(|to_field| field_name(&info.to, to_field))
+                        .collect();
+                    if let Some(name) = relation_names.get(info.name.as_str()) {
+                        info.name = name.clone();
+                    }
+                    if let Some(to) = model_names.get(info.to.as_str()) {
+                        info.to = to.clone();
+                    }
+                }
+                FieldType::Enum(enum_name) => {
+                    if let Some(name) = enum_names.get(enum_name.as_str()) {
+                        *enum_name = name.clone();
+                    }
+                }
+                _ => (),
+            }
+        }
+
+        for id_field in merged_model.id_fields.iter_mut() {
+            *id_field = field_name(&model.name, id_field);
+        }
+
+        for index in merged_model.indexes.iter_mut() {
+            index.fields = index
+                .fields
+                .iter()
+                .map(|field| field_name(&model.name, field))
+                .collect();
+        }
+    }
+
+    (merged, changes)
+}
+
+fn merge_field(field: &mut Field, previous_field: &Field) {
+    field.name = previous_field.name.clone();
+    field.database_name = previous_field.database_name.clone();
+    // Comments in the database take precedence, SQLite has none.
+    if field.documentation.is_none() {
+        field.documentation = previous_field.documentation.clone();
+    }
+    // `@updatedAt` is only visible in the database on MySQL, as `ON UPDATE CURRENT_TIMESTAMP`.
+    field.is_updated_at = field.is_updated_at || previous_field.is_updated_at;
+
+    // Defaults like `cuid()` and `now()` are generated by Prisma and not stored in the database.
+    if field.default_value.is_none() {
+        field.default_value = previous_field.default_value.clone();
+    }
+}
+
+fn introspected_key<'a>(
+    datamodel: &'a Datamodel,
+    schema: &SqlSchema,
+    model: &'a Model,
+    field: &'a Field,
+) -> FieldKey<'a> {
+    let info = match &field.field_type {
+        FieldType::Relation(info) => info,
+        _ => return FieldKey::Column(db_field_name(field)),
+    };
+
+    let has_column = |model: &Model, field: &Field| {
+        schema
+            .get_table_in_schema(model.schema.as_ref().map(String::as_str), db_name(model))
+            .map(|table| table.has_column(db_field_name(field)))
+            .unwrap_or(false)
+    };
+
+    if has_column(model, field) {
+        return FieldKey::Column(db_field_name(field));
+    }
+
+    let related_model = datamodel.find_model(&info.to);
+    let related_field =
+        related_model.and_then(|related_model| related_model.related_field(&model.name, &info.name, &field.name));
+
+    match (related_model, related_field) {
+        (Some(related_model), Some(related_field)) if has_column(related_model, related_field) => {
+            FieldKey::BackRelation {
+                table: db_name(related_model),
+                column: db_field_name(related_field),
+            }
+        }
+        _ => FieldKey::ManyToMany {
+            table: related_model.map(db_name).unwrap_or(&info.to),
+            relation: &info.name,
+        },
+    }
+}
+
+fn previous_key<'a>(datamodel: &'a Datamodel, model: &'a Model, field: &'a Field) -> FieldKey<'a> {
+    let info = match &field.field_type {
+        FieldType::Relation(info) => info,
+        _ => return FieldKey::Column(db_field_name(field)),
+    };
+
+    // After standardisation, only the side of the relation holding the foreign key has `to_fields`.
+    let has_column = |field: &Field| field.arity != FieldArity::List && !is_back_relation(field);
+
+    if has_column(field) {
+        return FieldKey::Column(db_field_name(field));
+    }
+
+    let related_model = match datamodel.find_model(&info.to) {
+        Some(related_model) => related_model,
+        None => {
+            return FieldKey::ManyToMany {
+                table: &info.to,
+                relation: &info.name,
+            }
+        }
+    };
+
+    match related_model.related_field(&model.name, &info.name, &field.name) {
+        Some(related_field) if has_column(related_field) => FieldKey::BackRelation {
+            table: db_name(related_model),
+            column: db_field_name(related_field),
+        },
+        _ => FieldKey::ManyToMany {
+            table: db_name(related_model),
+            relation: &info.name,
+        },
+    }
+}
+
+fn is_back_relation(field: &Field) -> bool {
+    match &field.field_type {
+        FieldType::Relation(info) => info.to_fields.is_empty(),
+        _ => false,
+    }
+}
+
+fn has_id(model: &Model) -> bool {
+    model.singular_id_fields().next().is_some() || !model.id_fields.is_empty()
+}
+
+fn db_name(model: &Model) -> &str {
+    model.database_name.as_ref().unwrap_or(&model.name)
+}
+
+fn db_field_name(field: &Field) -> &str {
+    field.database_name.as_ref().unwrap_or(&field.name)
+}
+
+fn enum_db_name(enm: &Enum) -> &str {
+    enm.database_name.as_ref().unwrap_or(&enm.name)
+}
+
+fn change(
+    kind: IntrospectionChangeKind,
+    object: IntrospectionObject,
+    name: &str,
+    field: Option<&str>,
+) -> IntrospectionChange {
+    IntrospectionChange {
+        kind,
+        object,
+        name: name.to_owned(),
+        field: field.map(String::from),
+    }
+}
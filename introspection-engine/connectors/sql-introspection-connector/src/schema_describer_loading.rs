@@ -1,23 +1,29 @@
 use crate::{SqlIntrospectionError, SqlIntrospectionResult};
+use sql_connection::SqlFamily;
 use sql_schema_describer::SqlSchemaDescriberBackend;
 use std::sync::Arc;
 
-pub fn load_describer(url_str: &str) -> SqlIntrospectionResult<Box<dyn SqlSchemaDescriberBackend>> {
+pub fn load_describer(url_str: &str) -> SqlIntrospectionResult<(SqlFamily, Box<dyn SqlSchemaDescriberBackend>)> {
     if url_str.starts_with("postgresql://") {
         let wrapper = sql_connection::Postgresql::new(url_str.parse()?)?;
-        Ok(Box::new(sql_schema_describer::postgres::SqlSchemaDescriber::new(
-            Arc::new(wrapper),
-        )))
+        Ok((
+            SqlFamily::Postgres,
+            Box::new(sql_schema_describer::postgres::SqlSchemaDescriber::new(Arc::new(
+                wrapper,
+            ))),
+        ))
     } else if url_str.starts_with("mysql://") {
         let wrapper = sql_connection::Mysql::new(url_str.parse()?)?;
-        Ok(Box::new(sql_schema_describer::mysql::SqlSchemaDescriber::new(
-            Arc::new(wrapper),
-        )))
+        Ok((
+            SqlFamily::Mysql,
+            Box::new(sql_schema_describer::mysql::SqlSchemaDescriber::new(Arc::new(wrapper))),
+        ))
     } else if url_str.starts_with("file:") {
         let wrapper = sql_connection::Sqlite::new(url_str, "introspection-engine")?;
-        Ok(Box::new(sql_schema_describer::sqlite::SqlSchemaDescriber::new(
-            Arc::new(wrapper),
-        )))
+        Ok((
+            SqlFamily::Sqlite,
+            Box::new(sql_schema_describer::sqlite::SqlSchemaDescriber::new(Arc::new(wrapper))),
+        ))
     } else {
         Err(SqlIntrospectionError::InvalidUrl {
             message: format!("Could not load connector for the provided url: {}", url_str),
@@ -1,4 +1,3 @@
-use std::collections::HashSet;
 use std::sync::atomic::{AtomicBool, Ordering};
 
 use log::LevelFilter;
@@ -6,10 +5,11 @@ use pretty_assertions::assert_eq;
 
 use datamodel::{
     common::{PrismaType, PrismaValue},
-    dml, Datamodel, Field, FieldArity, FieldType, IdInfo, IdStrategy, Model, OnDeleteStrategy, RelationInfo,
+    dml, Datamodel, Field, FieldArity, FieldType, IdInfo, IdStrategy, Model, ReferentialAction, RelationInfo,
     ScalarListStrategy,
 };
-use sql_introspection_connector::calculate_datamodel::calculate_model;
+use sql_connection::SqlFamily;
+use sql_introspection_connector::calculate_datamodel::{calculate_model, calculate_model_with_native_types};
 use sql_schema_describer::*;
 
 static IS_SETUP: AtomicBool = AtomicBool::new(false);
@@ -50,7 +50,9 @@ fn a_data_model_can_be_generated_from_a_schema() {
 
     let col_types = vec![
         ColumnTypeFamily::Int,
+        ColumnTypeFamily::BigInt,
         ColumnTypeFamily::Float,
+        ColumnTypeFamily::Decimal,
         ColumnTypeFamily::Boolean,
         ColumnTypeFamily::String,
         ColumnTypeFamily::DateTime,
@@ -69,9 +71,13 @@ fn a_data_model_can_be_generated_from_a_schema() {
             name: "Table1".to_string(),
             documentation: None,
             is_embedded: false,
+            is_view: false,
+            is_commented_out: false,
+            schema: None,
             is_generated: false,
             indexes: vec![],
             id_fields: vec![],
+            check_constraints: vec![],
             fields: col_types
                 .iter()
                 .map(|col_type| {
@@ -79,7 +85,9 @@ fn a_data_model_can_be_generated_from_a_schema() {
                         ColumnTypeFamily::Boolean => FieldType::Base(PrismaType::Boolean),
                         ColumnTypeFamily::DateTime => FieldType::Base(PrismaType::DateTime),
                         ColumnTypeFamily::Float => FieldType::Base(PrismaType::Float),
+                        ColumnTypeFamily::Decimal => FieldType::Base(PrismaType::Decimal),
                         ColumnTypeFamily::Int => FieldType::Base(PrismaType::Int),
+                        ColumnTypeFamily::BigInt => FieldType::Base(PrismaType::BigInt),
                         ColumnTypeFamily::String => FieldType::Base(PrismaType::String),
                         // XXX: We made a conscious decision to punt on mapping of ColumnTypeFamily
                         // variants that don't yet have corresponding PrismaType variants
@@ -97,6 +105,7 @@ fn a_data_model_can_be_generated_from_a_schema() {
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        validators: vec![],
                     }
                 })
                 .collect(),
@@ -107,6 +116,7 @@ fn a_data_model_can_be_generated_from_a_schema() {
     let schema = SqlSchema {
         tables: vec![Table {
             name: "Table1".to_string(),
+            schema: None,
             columns: col_types
                 .iter()
                 .map(|family| Column {
@@ -114,10 +124,16 @@ fn a_data_model_can_be_generated_from_a_schema() {
                     tpe: ColumnType {
                         raw: "raw".to_string(),
                         family: family.to_owned(),
+                        character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
                     },
                     arity: ColumnArity::Nullable,
                     default: None,
+                    default_is_expression: false,
                     auto_increment: false,
+                    on_update_current_timestamp: false,
+                    comment: None,
                 })
                 .collect(),
             indices: vec![],
@@ -125,10 +141,13 @@ fn a_data_model_can_be_generated_from_a_schema() {
                 columns: vec!["primary_col".to_string()],
                 sequence: None,
             }),
+            check_constraints: vec![],
+            comment: None,
             foreign_keys: vec![],
         }],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let data_model = calculate_model(&schema).expect("calculate data model");
 
@@ -145,6 +164,9 @@ fn arity_is_preserved_when_generating_data_model_from_a_schema() {
             name: "Table1".to_string(),
             documentation: None,
             is_embedded: false,
+            is_view: false,
+            is_commented_out: false,
+            schema: None,
             fields: vec![
                 Field {
                     name: "optional".to_string(),
@@ -158,6 +180,7 @@ fn arity_is_preserved_when_generating_data_model_from_a_schema() {
                     documentation: None,
                     is_generated: false,
                     is_updated_at: false,
+                    validators: vec![],
                 },
                 Field {
                     name: "required".to_string(),
@@ -171,6 +194,7 @@ fn arity_is_preserved_when_generating_data_model_from_a_schema() {
                     documentation: None,
                     is_generated: false,
                     is_updated_at: false,
+                    validators: vec![],
                 },
                 Field {
                     name: "list".to_string(),
@@ -184,11 +208,13 @@ fn arity_is_preserved_when_generating_data_model_from_a_schema() {
                     documentation: None,
                     is_generated: false,
                     is_updated_at: false,
+                    validators: vec![],
                 },
             ],
             is_generated: false,
             indexes: vec![],
             id_fields: vec![],
+            check_constraints: vec![],
         }],
         enums: vec![],
     };
@@ -196,36 +222,55 @@ fn arity_is_preserved_when_generating_data_model_from_a_schema() {
     let schema = SqlSchema {
         tables: vec![Table {
             name: "Table1".to_string(),
+            schema: None,
             columns: vec![
                 Column {
                     name: "optional".to_string(),
                     tpe: ColumnType {
                         raw: "raw".to_string(),
                         family: ColumnTypeFamily::Int,
+                        character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
                     },
                     arity: ColumnArity::Nullable,
                     default: None,
+                    default_is_expression: false,
                     auto_increment: false,
+                    on_update_current_timestamp: false,
+                    comment: None,
                 },
                 Column {
                     name: "required".to_string(),
                     tpe: ColumnType {
                         raw: "raw".to_string(),
                         family: ColumnTypeFamily::Int,
+                        character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
                     },
                     arity: ColumnArity::Required,
                     default: None,
+                    default_is_expression: false,
                     auto_increment: false,
+                    on_update_current_timestamp: false,
+                    comment: None,
                 },
                 Column {
                     name: "list".to_string(),
                     tpe: ColumnType {
                         raw: "raw".to_string(),
                         family: ColumnTypeFamily::Int,
+                        character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
                     },
                     arity: ColumnArity::List,
                     default: None,
+                    default_is_expression: false,
                     auto_increment: false,
+                    on_update_current_timestamp: false,
+                    comment: None,
                 },
             ],
             indices: vec![],
@@ -233,10 +278,13 @@ fn arity_is_preserved_when_generating_data_model_from_a_schema() {
                 columns: vec!["primary_col".to_string()],
                 sequence: None,
             }),
+            check_constraints: vec![],
+            comment: None,
             foreign_keys: vec![],
         }],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let data_model = calculate_model(&schema).expect("calculate data model");
 
@@ -253,6 +301,9 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
             name: "Table1".to_string(),
             documentation: None,
             is_embedded: false,
+            is_view: false,
+            is_commented_out: false,
+            schema: None,
             fields: vec![
                 Field {
                     name: "no-default".to_string(),
@@ -266,6 +317,7 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                     documentation: None,
                     is_generated: false,
                     is_updated_at: false,
+                    validators: vec![],
                 },
                 Field {
                     name: "int-default".to_string(),
@@ -279,6 +331,7 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                     documentation: None,
                     is_generated: false,
                     is_updated_at: false,
+                    validators: vec![],
                 },
                 Field {
                     name: "bool-default".to_string(),
@@ -292,6 +345,7 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                     documentation: None,
                     is_generated: false,
                     is_updated_at: false,
+                    validators: vec![],
                 },
                 Field {
                     name: "float-default".to_string(),
@@ -305,6 +359,7 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                     documentation: None,
                     is_generated: false,
                     is_updated_at: false,
+                    validators: vec![],
                 },
                 Field {
                     name: "string-default".to_string(),
@@ -318,11 +373,13 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                     documentation: None,
                     is_generated: false,
                     is_updated_at: false,
+                    validators: vec![],
                 },
             ],
             is_generated: false,
             indexes: vec![],
             id_fields: vec![],
+            check_constraints: vec![],
         }],
         enums: vec![],
     };
@@ -330,64 +387,382 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
     let schema = SqlSchema {
         tables: vec![Table {
             name: "Table1".to_string(),
+            schema: None,
             columns: vec![
                 Column {
                     name: "no-default".to_string(),
                     tpe: ColumnType {
                         raw: "raw".to_string(),
                         family: ColumnTypeFamily::Int,
+                        character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
                     },
                     arity: ColumnArity::Nullable,
                     default: None,
+                    default_is_expression: false,
                     auto_increment: false,
+                    on_update_current_timestamp: false,
+                    comment: None,
                 },
                 Column {
                     name: "int-default".to_string(),
                     tpe: ColumnType {
                         raw: "raw".to_string(),
                         family: ColumnTypeFamily::Int,
+                        character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
                     },
                     arity: ColumnArity::Nullable,
                     default: Some("'1'".to_string()),
+                    default_is_expression: false,
                     auto_increment: false,
+                    on_update_current_timestamp: false,
+                    comment: None,
                 },
                 Column {
                     name: "bool-default".to_string(),
                     tpe: ColumnType {
                         raw: "raw".to_string(),
                         family: ColumnTypeFamily::Boolean,
+                        character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
                     },
                     arity: ColumnArity::Nullable,
                     default: Some("'1'".to_string()),
+                    default_is_expression: false,
                     auto_increment: false,
+                    on_update_current_timestamp: false,
+                    comment: None,
                 },
                 Column {
                     name: "float-default".to_string(),
                     tpe: ColumnType {
                         raw: "raw".to_string(),
                         family: ColumnTypeFamily::Float,
+                        character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
                     },
                     arity: ColumnArity::Nullable,
                     default: Some("'1.0'".to_string()),
+                    default_is_expression: false,
                     auto_increment: false,
+                    on_update_current_timestamp: false,
+                    comment: None,
                 },
                 Column {
                     name: "string-default".to_string(),
                     tpe: ColumnType {
                         raw: "raw".to_string(),
                         family: ColumnTypeFamily::String,
+                        character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
                     },
                     arity: ColumnArity::Nullable,
                     default: Some("default".to_string()),
+                    default_is_expression: false,
                     auto_increment: false,
+                    on_update_current_timestamp: false,
+                    comment: None,
                 },
             ],
             indices: vec![],
             primary_key: None,
+            check_constraints: vec![],
+            comment: None,
             foreign_keys: vec![],
         }],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
+    };
+    let data_model = calculate_model(&schema).expect("calculate data model");
+
+    assert_eq!(data_model, ref_data_model);
+}
+
+#[test]
+fn database_expression_defaults_are_mapped_to_datamodel_functions() {
+    setup();
+
+    let ref_data_model = Datamodel {
+        models: vec![Model {
+            database_name: None,
+            name: "Table1".to_string(),
+            documentation: None,
+            is_embedded: false,
+            is_view: false,
+            is_commented_out: false,
+            schema: None,
+            fields: vec![
+                Field {
+                    name: "id".to_string(),
+                    arity: FieldArity::Required,
+                    field_type: FieldType::Base(PrismaType::Int),
+                    database_name: None,
+                    default_value: None,
+                    is_unique: false,
+                    id_info: Some(IdInfo {
+                        strategy: IdStrategy::Auto,
+                        sequence: None,
+                    }),
+                    scalar_list_strategy: None,
+                    documentation: None,
+                    is_generated: false,
+                    is_updated_at: false,
+                    validators: vec![],
+                },
+                Field {
+                    name: "created".to_string(),
+                    arity: FieldArity::Required,
+                    field_type: FieldType::Base(PrismaType::DateTime),
+                    database_name: None,
+                    default_value: Some(PrismaValue::Expression("now".to_string(), PrismaType::DateTime, vec![])),
+                    is_unique: false,
+                    id_info: None,
+                    scalar_list_strategy: None,
+                    documentation: None,
+                    is_generated: false,
+                    is_updated_at: true,
+                    validators: vec![],
+                },
+                Field {
+                    name: "token".to_string(),
+                    arity: FieldArity::Required,
+                    field_type: FieldType::Base(PrismaType::String),
+                    database_name: None,
+                    default_value: Some(PrismaValue::Expression("uuid".to_string(), PrismaType::String, vec![])),
+                    is_unique: false,
+                    id_info: None,
+                    scalar_list_strategy: None,
+                    documentation: None,
+                    is_generated: false,
+                    is_updated_at: false,
+                    validators: vec![],
+                },
+                Field {
+                    name: "checksum".to_string(),
+                    arity: FieldArity::Required,
+                    field_type: FieldType::Base(PrismaType::String),
+                    database_name: None,
+                    default_value: Some(PrismaValue::Expression(
+                        "dbgenerated".to_string(),
+                        PrismaType::String,
+                        vec![PrismaValue::String("md5(random()::text)".to_string())],
+                    )),
+                    is_unique: false,
+                    id_info: None,
+                    scalar_list_strategy: None,
+                    documentation: None,
+                    is_generated: false,
+                    is_updated_at: false,
+                    validators: vec![],
+                },
+            ],
+            is_generated: false,
+            indexes: vec![],
+            id_fields: vec![],
+            check_constraints: vec![],
+        }],
+        enums: vec![],
+    };
+
+    let schema = SqlSchema {
+        tables: vec![Table {
+            name: "Table1".to_string(),
+            schema: None,
+            columns: vec![
+                Column {
+                    name: "id".to_string(),
+                    tpe: ColumnType {
+                        raw: "raw".to_string(),
+                        family: ColumnTypeFamily::Int,
+                        character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
+                    },
+                    arity: ColumnArity::Required,
+                    default: Some("nextval('\"Table1_id_seq\"'::regclass)".to_string()),
+                    default_is_expression: true,
+                    auto_increment: false,
+                    on_update_current_timestamp: false,
+                    comment: None,
+                },
+                Column {
+                    name: "created".to_string(),
+                    tpe: ColumnType {
+                        raw: "raw".to_string(),
+                        family: ColumnTypeFamily::DateTime,
+                        character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
+                    },
+                    arity: ColumnArity::Required,
+                    default: Some("CURRENT_TIMESTAMP".to_string()),
+                    default_is_expression: true,
+                    auto_increment: false,
+                    on_update_current_timestamp: true,
+                    comment: None,
+                },
+                Column {
+                    name: "token".to_string(),
+                    tpe: ColumnType {
+                        raw: "raw".to_string(),
+                        family: ColumnTypeFamily::String,
+                        character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
+                    },
+                    arity: ColumnArity::Required,
+                    default: Some("gen_random_uuid()".to_string()),
+                    default_is_expression: true,
+                    auto_increment: false,
+                    on_update_current_timestamp: false,
+                    comment: None,
+                },
+                Column {
+                    name: "checksum".to_string(),
+                    tpe: ColumnType {
+                        raw: "raw".to_string(),
+                        family: ColumnTypeFamily::String,
+                        character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
+                    },
+                    arity: ColumnArity::Required,
+                    default: Some("md5(random()::text)".to_string()),
+                    default_is_expression: true,
+                    auto_increment: false,
+                    on_update_current_timestamp: false,
+                    comment: None,
+                },
+            ],
+            indices: vec![],
+            primary_key: Some(PrimaryKey {
+                columns: vec!["id".to_string()],
+                sequence: None,
+            }),
+            check_constraints: vec![],
+            comment: None,
+            foreign_keys: vec![],
+        }],
+        enums: vec![],
+        sequences: vec![],
+        views: vec![],
+    };
+    let data_model = calculate_model(&schema).expect("calculate data model");
+
+    assert_eq!(data_model, ref_data_model);
+}
+
+#[test]
+fn table_and_column_comments_are_mapped_to_documentation() {
+    setup();
+
+    let ref_data_model = Datamodel {
+        models: vec![Model {
+            database_name: None,
+            name: "Table1".to_string(),
+            documentation: Some("The first table".to_string()),
+            is_embedded: false,
+            is_view: false,
+            is_commented_out: false,
+            schema: None,
+            fields: vec![
+                Field {
+                    name: "id".to_string(),
+                    arity: FieldArity::Required,
+                    field_type: FieldType::Base(PrismaType::Int),
+                    database_name: None,
+                    default_value: None,
+                    is_unique: false,
+                    id_info: Some(IdInfo {
+                        strategy: IdStrategy::Auto,
+                        sequence: None,
+                    }),
+                    scalar_list_strategy: None,
+                    documentation: None,
+                    is_generated: false,
+                    is_updated_at: false,
+                    validators: vec![],
+                },
+                Field {
+                    name: "name".to_string(),
+                    arity: FieldArity::Optional,
+                    field_type: FieldType::Base(PrismaType::String),
+                    database_name: None,
+                    default_value: None,
+                    is_unique: false,
+                    id_info: None,
+                    scalar_list_strategy: None,
+                    documentation: Some("The display name".to_string()),
+                    is_generated: false,
+                    is_updated_at: false,
+                    validators: vec![],
+                },
+            ],
+            is_generated: false,
+            indexes: vec![],
+            id_fields: vec![],
+            check_constraints: vec![],
+        }],
+        enums: vec![],
+    };
+
+    let schema = SqlSchema {
+        tables: vec![Table {
+            name: "Table1".to_string(),
+            schema: None,
+            columns: vec![
+                Column {
+                    name: "id".to_string(),
+                    tpe: ColumnType {
+                        raw: "raw".to_string(),
+                        family: ColumnTypeFamily::Int,
+                        character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
+                    },
+                    arity: ColumnArity::Required,
+                    default: None,
+                    default_is_expression: false,
+                    auto_increment: true,
+                    on_update_current_timestamp: false,
+                    comment: None,
+                },
+                Column {
+                    name: "name".to_string(),
+                    tpe: ColumnType {
+                        raw: "raw".to_string(),
+                        family: ColumnTypeFamily::String,
+                        character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
+                    },
+                    arity: ColumnArity::Nullable,
+                    default: None,
+                    default_is_expression: false,
+                    auto_increment: false,
+                    on_update_current_timestamp: false,
+                    comment: Some("The display name".to_string()),
+                },
+            ],
+            indices: vec![],
+            primary_key: Some(PrimaryKey {
+                columns: vec!["id".to_string()],
+                sequence: None,
+            }),
+            check_constraints: vec![],
+            comment: Some("The first table".to_string()),
+            foreign_keys: vec![],
+        }],
+        enums: vec![],
+        sequences: vec![],
+        views: vec![],
     };
     let data_model = calculate_model(&schema).expect("calculate data model");
 
@@ -406,6 +781,9 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                 name: "Table1".to_string(),
                 documentation: None,
                 is_embedded: false,
+                is_view: false,
+                is_commented_out: false,
+                schema: None,
                 fields: vec![Field {
                     name: "primary".to_string(),
                     arity: FieldArity::Required,
@@ -421,10 +799,12 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                     documentation: None,
                     is_generated: false,
                     is_updated_at: false,
+                    validators: vec![],
                 }],
                 is_generated: false,
                 indexes: vec![],
                 id_fields: vec![],
+                check_constraints: vec![],
             },
             // Model with non-auto-incrementing primary key
             Model {
@@ -432,6 +812,9 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                 name: "Table2".to_string(),
                 documentation: None,
                 is_embedded: false,
+                is_view: false,
+                is_commented_out: false,
+                schema: None,
                 fields: vec![Field {
                     name: "primary".to_string(),
                     arity: FieldArity::Required,
@@ -447,10 +830,12 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                     documentation: None,
                     is_generated: false,
                     is_updated_at: false,
+                    validators: vec![],
                 }],
                 is_generated: false,
                 indexes: vec![],
                 id_fields: vec![],
+                check_constraints: vec![],
             },
             // Model with primary key seeded by sequence
             Model {
@@ -458,6 +843,9 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                 name: "Table3".to_string(),
                 documentation: None,
                 is_embedded: false,
+                is_view: false,
+                is_commented_out: false,
+                schema: None,
                 fields: vec![Field {
                     name: "primary".to_string(),
                     arity: FieldArity::Required,
@@ -477,10 +865,43 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                     documentation: None,
                     is_generated: false,
                     is_updated_at: false,
+                    validators: vec![],
                 }],
                 is_generated: false,
                 indexes: vec![],
                 id_fields: vec![],
+                check_constraints: vec![],
+            },
+            // Model with a big integer primary key seeded by a Postgres `bigserial` sequence
+            Model {
+                database_name: None,
+                name: "Table4".to_string(),
+                documentation: None,
+                is_embedded: false,
+                is_view: false,
+                is_commented_out: false,
+                schema: None,
+                fields: vec![Field {
+                    name: "primary".to_string(),
+                    arity: FieldArity::Required,
+                    field_type: FieldType::Base(PrismaType::BigInt),
+                    database_name: None,
+                    default_value: None,
+                    is_unique: false,
+                    id_info: Some(IdInfo {
+                        strategy: IdStrategy::Auto,
+                        sequence: None,
+                    }),
+                    scalar_list_strategy: None,
+                    documentation: None,
+                    is_generated: false,
+                    is_updated_at: false,
+                    validators: vec![],
+                }],
+                is_generated: false,
+                indexes: vec![],
+                id_fields: vec![],
+                check_constraints: vec![],
             },
         ],
         enums: vec![],
@@ -490,53 +911,78 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
         tables: vec![
             Table {
                 name: "Table1".to_string(),
+                schema: None,
                 columns: vec![Column {
                     name: "primary".to_string(),
                     tpe: ColumnType {
                         raw: "integer".to_string(),
                         family: ColumnTypeFamily::Int,
+                        character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
                     },
                     arity: ColumnArity::Required,
                     default: None,
+                    default_is_expression: false,
                     auto_increment: true,
+                    on_update_current_timestamp: false,
+                    comment: None,
                 }],
                 indices: vec![],
                 primary_key: Some(PrimaryKey {
                     columns: vec!["primary".to_string()],
                     sequence: None,
                 }),
+                check_constraints: vec![],
+                comment: None,
                 foreign_keys: vec![],
             },
             Table {
                 name: "Table2".to_string(),
+                schema: None,
                 columns: vec![Column {
                     name: "primary".to_string(),
                     tpe: ColumnType {
                         raw: "integer".to_string(),
                         family: ColumnTypeFamily::Int,
+                        character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
                     },
                     arity: ColumnArity::Required,
                     default: None,
+                    default_is_expression: false,
                     auto_increment: false,
+                    on_update_current_timestamp: false,
+                    comment: None,
                 }],
                 indices: vec![],
                 primary_key: Some(PrimaryKey {
                     columns: vec!["primary".to_string()],
                     sequence: None,
                 }),
+                check_constraints: vec![],
+                comment: None,
                 foreign_keys: vec![],
             },
             Table {
                 name: "Table3".to_string(),
+                schema: None,
                 columns: vec![Column {
                     name: "primary".to_string(),
                     tpe: ColumnType {
                         raw: "integer".to_string(),
                         family: ColumnTypeFamily::Int,
+                        character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
                     },
                     arity: ColumnArity::Required,
                     default: None,
+                    default_is_expression: false,
                     auto_increment: true,
+                    on_update_current_timestamp: false,
+                    comment: None,
                 }],
                 indices: vec![],
                 primary_key: Some(PrimaryKey {
@@ -547,11 +993,42 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                         allocation_size: 1,
                     }),
                 }),
+                check_constraints: vec![],
+                comment: None,
+                foreign_keys: vec![],
+            },
+            Table {
+                name: "Table4".to_string(),
+                schema: None,
+                columns: vec![Column {
+                    name: "primary".to_string(),
+                    tpe: ColumnType {
+                        raw: "int8".to_string(),
+                        family: ColumnTypeFamily::BigInt,
+                        character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
+                    },
+                    arity: ColumnArity::Required,
+                    default: Some("nextval('\"Table4_primary_seq\"'::regclass)".to_string()),
+                    default_is_expression: true,
+                    auto_increment: false,
+                    on_update_current_timestamp: false,
+                    comment: None,
+                }],
+                indices: vec![],
+                primary_key: Some(PrimaryKey {
+                    columns: vec!["primary".to_string()],
+                    sequence: None,
+                }),
+                check_constraints: vec![],
+                comment: None,
                 foreign_keys: vec![],
             },
         ],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let data_model = calculate_model(&schema).expect("calculate data model");
 
@@ -568,6 +1045,9 @@ fn uniqueness_is_preserved_when_generating_data_model_from_a_schema() {
             name: "Table1".to_string(),
             documentation: None,
             is_embedded: false,
+            is_view: false,
+            is_commented_out: false,
+            schema: None,
             fields: vec![
                 Field {
                     name: "non-unique".to_string(),
@@ -581,6 +1061,7 @@ fn uniqueness_is_preserved_when_generating_data_model_from_a_schema() {
                     documentation: None,
                     is_generated: false,
                     is_updated_at: false,
+                    validators: vec![],
                 },
                 Field {
                     name: "unique".to_string(),
@@ -594,11 +1075,13 @@ fn uniqueness_is_preserved_when_generating_data_model_from_a_schema() {
                     documentation: None,
                     is_generated: false,
                     is_updated_at: false,
+                    validators: vec![],
                 },
             ],
             is_generated: false,
             indexes: vec![],
             id_fields: vec![],
+            check_constraints: vec![],
         }],
         enums: vec![],
     };
@@ -606,26 +1089,39 @@ fn uniqueness_is_preserved_when_generating_data_model_from_a_schema() {
     let schema = SqlSchema {
         tables: vec![Table {
             name: "Table1".to_string(),
+            schema: None,
             columns: vec![
                 Column {
                     name: "non-unique".to_string(),
                     tpe: ColumnType {
                         raw: "raw".to_string(),
                         family: ColumnTypeFamily::Int,
+                        character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
                     },
                     arity: ColumnArity::Nullable,
                     default: None,
+                    default_is_expression: false,
                     auto_increment: false,
+                    on_update_current_timestamp: false,
+                    comment: None,
                 },
                 Column {
                     name: "unique".to_string(),
                     tpe: ColumnType {
                         raw: "raw".to_string(),
                         family: ColumnTypeFamily::Int,
+                        character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
                     },
                     arity: ColumnArity::Required,
                     default: None,
+                    default_is_expression: false,
                     auto_increment: false,
+                    on_update_current_timestamp: false,
+                    comment: None,
                 },
             ],
             indices: vec![Index {
@@ -634,10 +1130,13 @@ fn uniqueness_is_preserved_when_generating_data_model_from_a_schema() {
                 tpe: IndexType::Unique,
             }],
             primary_key: None,
+            check_constraints: vec![],
+            comment: None,
             foreign_keys: vec![],
         }],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let data_model = calculate_model(&schema).expect("calculate data model");
 
@@ -657,6 +1156,9 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                 name: "City".to_string(),
                 documentation: None,
                 is_embedded: false,
+                is_view: false,
+                is_commented_out: false,
+                schema: None,
                 fields: vec![
                     Field {
                         name: "id".to_string(),
@@ -673,6 +1175,7 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        validators: vec![],
                     },
                     Field {
                         name: "name".to_string(),
@@ -686,17 +1189,22 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        validators: vec![],
                     },
                 ],
                 is_generated: false,
                 indexes: vec![],
                 id_fields: vec![],
+                check_constraints: vec![],
             },
             Model {
                 database_name: None,
                 name: "User".to_string(),
                 documentation: None,
                 is_embedded: false,
+                is_view: false,
+                is_commented_out: false,
+                schema: None,
                 fields: vec![
                     Field {
                         name: "id".to_string(),
@@ -710,6 +1218,7 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        validators: vec![],
                     },
                     Field {
                         name: "city-id".to_string(),
@@ -718,7 +1227,8 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                             name: "".to_string(),
                             to: "City".to_string(),
                             to_fields: vec!["id".to_string()],
-                            on_delete: OnDeleteStrategy::None,
+                            on_delete: ReferentialAction::None,
+                            on_update: ReferentialAction::None,
                         }),
                         database_name: None,
                         default_value: None,
@@ -728,6 +1238,7 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        validators: vec![],
                     },
                     Field {
                         name: "city-name".to_string(),
@@ -736,7 +1247,8 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                             name: "".to_string(),
                             to: "City".to_string(),
                             to_fields: vec!["name".to_string()],
-                            on_delete: OnDeleteStrategy::None,
+                            on_delete: ReferentialAction::None,
+                            on_update: ReferentialAction::None,
                         }),
                         database_name: None,
                         default_value: None,
@@ -746,11 +1258,13 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        validators: vec![],
                     },
                 ],
                 is_generated: false,
                 indexes: vec![],
                 id_fields: vec![],
+                check_constraints: vec![],
             },
         ],
         enums: vec![],
@@ -760,26 +1274,39 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
         tables: vec![
             Table {
                 name: "City".to_string(),
+                schema: None,
                 columns: vec![
                     Column {
                         name: "id".to_string(),
                         tpe: ColumnType {
                             raw: "integer".to_string(),
                             family: ColumnTypeFamily::Int,
+                            character_maximum_length: None,
+                            numeric_precision: None,
+                            numeric_scale: None,
                         },
                         arity: ColumnArity::Required,
                         default: None,
+                        default_is_expression: false,
                         auto_increment: true,
+                        on_update_current_timestamp: false,
+                        comment: None,
                     },
                     Column {
                         name: "name".to_string(),
                         tpe: ColumnType {
                             raw: "text".to_string(),
                             family: ColumnTypeFamily::String,
+                            character_maximum_length: None,
+                            numeric_precision: None,
+                            numeric_scale: None,
                         },
                         arity: ColumnArity::Required,
                         default: None,
+                        default_is_expression: false,
                         auto_increment: false,
+                        on_update_current_timestamp: false,
+                        comment: None,
                     },
                 ],
                 indices: vec![],
@@ -787,56 +1314,82 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                     columns: vec!["id".to_string()],
                     sequence: None,
                 }),
+                check_constraints: vec![],
+                comment: None,
                 foreign_keys: vec![],
             },
             Table {
                 name: "User".to_string(),
+                schema: None,
                 columns: vec![
                     Column {
                         name: "id".to_string(),
                         tpe: ColumnType {
                             raw: "integer".to_string(),
                             family: ColumnTypeFamily::Int,
+                            character_maximum_length: None,
+                            numeric_precision: None,
+                            numeric_scale: None,
                         },
                         arity: ColumnArity::Required,
                         default: None,
+                        default_is_expression: false,
                         auto_increment: true,
+                        on_update_current_timestamp: false,
+                        comment: None,
                     },
                     Column {
                         name: "city-id".to_string(),
                         tpe: ColumnType {
                             raw: "integer".to_string(),
                             family: ColumnTypeFamily::Int,
+                            character_maximum_length: None,
+                            numeric_precision: None,
+                            numeric_scale: None,
                         },
                         arity: ColumnArity::Required,
                         default: None,
+                        default_is_expression: false,
                         auto_increment: false,
+                        on_update_current_timestamp: false,
+                        comment: None,
                     },
                     Column {
                         name: "city-name".to_string(),
                         tpe: ColumnType {
                             raw: "text".to_string(),
                             family: ColumnTypeFamily::String,
+                            character_maximum_length: None,
+                            numeric_precision: None,
+                            numeric_scale: None,
                         },
                         arity: ColumnArity::Required,
                         default: None,
+                        default_is_expression: false,
                         auto_increment: false,
+                        on_update_current_timestamp: false,
+                        comment: None,
                     },
                 ],
                 indices: vec![],
                 primary_key: None,
+                check_constraints: vec![],
+                comment: None,
                 foreign_keys: vec![ForeignKey {
                     // what does this mean? the from columns are not targeting a specific to column?
                     constraint_name: None,
                     columns: vec!["city-id".to_string(), "city-name".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::NoAction,
+                    on_update_action: ForeignKeyAction::NoAction,
                     referenced_columns: vec!["id".to_string(), "name".to_string()],
                 }],
             },
         ],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let data_model = calculate_model(&schema).expect("calculate data model");
 
@@ -853,6 +1406,9 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
             name: "User".to_string(),
             documentation: None,
             is_embedded: false,
+            is_view: false,
+            is_commented_out: false,
+            schema: None,
             fields: vec![
                 Field {
                     name: "id".to_string(),
@@ -869,6 +1425,7 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
                     documentation: None,
                     is_generated: false,
                     is_updated_at: false,
+                    validators: vec![],
                 },
                 Field {
                     name: "name".to_string(),
@@ -882,6 +1439,7 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
                     documentation: None,
                     is_generated: false,
                     is_updated_at: false,
+                    validators: vec![],
                 },
                 Field {
                     name: "lastname".to_string(),
@@ -895,6 +1453,7 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
                     documentation: None,
                     is_generated: false,
                     is_updated_at: false,
+                    validators: vec![],
                 },
             ],
             is_generated: false,
@@ -904,6 +1463,7 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
                 tpe: datamodel::dml::IndexType::Unique,
             }],
             id_fields: vec![],
+            check_constraints: vec![],
         }],
         enums: vec![],
     };
@@ -911,36 +1471,55 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
     let schema = SqlSchema {
         tables: vec![Table {
             name: "User".to_string(),
+            schema: None,
             columns: vec![
                 Column {
                     name: "id".to_string(),
                     tpe: ColumnType {
                         raw: "integer".to_string(),
                         family: ColumnTypeFamily::Int,
+                        character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
                     },
                     arity: ColumnArity::Required,
                     default: None,
+                    default_is_expression: false,
                     auto_increment: true,
+                    on_update_current_timestamp: false,
+                    comment: None,
                 },
                 Column {
                     name: "name".to_string(),
                     tpe: ColumnType {
                         raw: "text".to_string(),
                         family: ColumnTypeFamily::String,
+                        character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
                     },
                     arity: ColumnArity::Required,
                     default: None,
+                    default_is_expression: false,
                     auto_increment: false,
+                    on_update_current_timestamp: false,
+                    comment: None,
                 },
                 Column {
                     name: "lastname".to_string(),
                     tpe: ColumnType {
                         raw: "text".to_string(),
                         family: ColumnTypeFamily::String,
+                        character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
                     },
                     arity: ColumnArity::Required,
                     default: None,
+                    default_is_expression: false,
                     auto_increment: false,
+                    on_update_current_timestamp: false,
+                    comment: None,
                 },
             ],
             indices: vec![Index {
@@ -952,10 +1531,13 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
                 columns: vec!["id".to_string()],
                 sequence: None,
             }),
+            check_constraints: vec![],
+            comment: None,
             foreign_keys: vec![],
         }],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let data_model = calculate_model(&schema).expect("calculate data model");
 
@@ -973,6 +1555,9 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                 name: "City".to_string(),
                 documentation: None,
                 is_embedded: false,
+                is_view: false,
+                is_commented_out: false,
+                schema: None,
                 fields: vec![
                     Field {
                         name: "id".to_string(),
@@ -989,6 +1574,7 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        validators: vec![],
                     },
                     Field {
                         name: "name".to_string(),
@@ -1002,6 +1588,7 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        validators: vec![],
                     },
                     Field {
                         name: "users".to_string(),
@@ -1010,7 +1597,8 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                             to: "User".to_string(),
                             to_fields: vec!["city-id".to_string()],
                             name: "CityToUser".to_string(),
-                            on_delete: OnDeleteStrategy::None,
+                            on_delete: ReferentialAction::None,
+                            on_update: ReferentialAction::None,
                         }),
                         database_name: None,
                         default_value: None,
@@ -1020,17 +1608,22 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        validators: vec![],
                     },
                 ],
                 is_generated: false,
                 indexes: vec![],
                 id_fields: vec![],
+                check_constraints: vec![],
             },
             Model {
                 database_name: None,
                 name: "User".to_string(),
                 documentation: None,
                 is_embedded: false,
+                is_view: false,
+                is_commented_out: false,
+                schema: None,
                 fields: vec![
                     Field {
                         name: "id".to_string(),
@@ -1044,6 +1637,7 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        validators: vec![],
                     },
                     Field {
                         name: "city-id".to_string(),
@@ -1052,7 +1646,8 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                             name: "CityToUser".to_string(),
                             to: "City".to_string(),
                             to_fields: vec!["id".to_string()],
-                            on_delete: OnDeleteStrategy::None,
+                            on_delete: ReferentialAction::None,
+                            on_update: ReferentialAction::None,
                         }),
                         database_name: None,
                         default_value: None,
@@ -1062,11 +1657,13 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        validators: vec![],
                     },
                 ],
                 is_generated: false,
                 indexes: vec![],
                 id_fields: vec![],
+                check_constraints: vec![],
             },
         ],
         enums: vec![],
@@ -1076,26 +1673,39 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
         tables: vec![
             Table {
                 name: "City".to_string(),
+                schema: None,
                 columns: vec![
                     Column {
                         name: "id".to_string(),
                         tpe: ColumnType {
                             raw: "integer".to_string(),
                             family: ColumnTypeFamily::Int,
+                            character_maximum_length: None,
+                            numeric_precision: None,
+                            numeric_scale: None,
                         },
                         arity: ColumnArity::Required,
                         default: None,
+                        default_is_expression: false,
                         auto_increment: true,
+                        on_update_current_timestamp: false,
+                        comment: None,
                     },
                     Column {
                         name: "name".to_string(),
                         tpe: ColumnType {
                             raw: "text".to_string(),
                             family: ColumnTypeFamily::String,
+                            character_maximum_length: None,
+                            numeric_precision: None,
+                            numeric_scale: None,
                         },
                         arity: ColumnArity::Required,
                         default: None,
+                        default_is_expression: false,
                         auto_increment: false,
+                        on_update_current_timestamp: false,
+                        comment: None,
                     },
                 ],
                 indices: vec![],
@@ -1103,51 +1713,272 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                     columns: vec!["id".to_string()],
                     sequence: None,
                 }),
+                check_constraints: vec![],
+                comment: None,
                 foreign_keys: vec![],
             },
             Table {
                 name: "User".to_string(),
+                schema: None,
                 columns: vec![
                     Column {
                         name: "id".to_string(),
                         tpe: ColumnType {
                             raw: "integer".to_string(),
                             family: ColumnTypeFamily::Int,
+                            character_maximum_length: None,
+                            numeric_precision: None,
+                            numeric_scale: None,
                         },
                         arity: ColumnArity::Required,
                         default: None,
+                        default_is_expression: false,
                         auto_increment: true,
+                        on_update_current_timestamp: false,
+                        comment: None,
                     },
                     Column {
                         name: "city-id".to_string(),
                         tpe: ColumnType {
                             raw: "integer".to_string(),
                             family: ColumnTypeFamily::Int,
+                            character_maximum_length: None,
+                            numeric_precision: None,
+                            numeric_scale: None,
                         },
                         arity: ColumnArity::Required,
                         default: None,
+                        default_is_expression: false,
                         auto_increment: false,
+                        on_update_current_timestamp: false,
+                        comment: None,
                     },
                 ],
                 indices: vec![],
                 primary_key: None,
+                check_constraints: vec![],
+                comment: None,
                 foreign_keys: vec![ForeignKey {
                     constraint_name: None,
                     columns: vec!["city-id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::NoAction,
+                    on_update_action: ForeignKeyAction::NoAction,
                     referenced_columns: vec!["id".to_string()],
                 }],
             },
         ],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let data_model = calculate_model(&schema).expect("calculate data model");
 
     assert_eq!(data_model, ref_data_model);
 }
 
+#[test]
+fn referential_actions_are_preserved_when_generating_data_model_from_a_schema() {
+    setup();
+
+    let id_column = Column {
+        name: "id".to_string(),
+        tpe: ColumnType {
+            raw: "integer".to_string(),
+            family: ColumnTypeFamily::Int,
+            character_maximum_length: None,
+            numeric_precision: None,
+            numeric_scale: None,
+        },
+        arity: ColumnArity::Required,
+        default: None,
+        default_is_expression: false,
+        auto_increment: true,
+        on_update_current_timestamp: false,
+        comment: None,
+    };
+    let schema = SqlSchema {
+        tables: vec![
+            Table {
+                name: "City".to_string(),
+                schema: None,
+                columns: vec![id_column.clone()],
+                indices: vec![],
+                primary_key: Some(PrimaryKey {
+                    columns: vec!["id".to_string()],
+                    sequence: None,
+                }),
+                check_constraints: vec![],
+                comment: None,
+                foreign_keys: vec![],
+            },
+            Table {
+                name: "User".to_string(),
+                schema: None,
+                columns: vec![
+                    id_column.clone(),
+                    Column {
+                        name: "city".to_string(),
+                        tpe: ColumnType {
+                            raw: "integer".to_string(),
+                            family: ColumnTypeFamily::Int,
+                            character_maximum_length: None,
+                            numeric_precision: None,
+                            numeric_scale: None,
+                        },
+                        arity: ColumnArity::Required,
+                        default: None,
+                        default_is_expression: false,
+                        auto_increment: false,
+                        on_update_current_timestamp: false,
+                        comment: None,
+                    },
+                ],
+                indices: vec![],
+                primary_key: Some(PrimaryKey {
+                    columns: vec!["id".to_string()],
+                    sequence: None,
+                }),
+                check_constraints: vec![],
+                comment: None,
+                foreign_keys: vec![ForeignKey {
+                    constraint_name: None,
+                    columns: vec!["city".to_string()],
+                    referenced_table: "City".to_string(),
+                    referenced_schema: None,
+                    on_delete_action: ForeignKeyAction::Cascade,
+                    on_update_action: ForeignKeyAction::Restrict,
+                    referenced_columns: vec!["id".to_string()],
+                }],
+            },
+        ],
+        enums: vec![],
+        sequences: vec![],
+        views: vec![],
+    };
+    let data_model = calculate_model(&schema).expect("calculate data model");
+    let city_field = data_model
+        .find_model("User")
+        .and_then(|model| model.find_field("city"))
+        .expect("find city field");
+
+    match &city_field.field_type {
+        FieldType::Relation(relation_info) => {
+            assert_eq!(relation_info.on_delete, ReferentialAction::Cascade);
+            assert_eq!(relation_info.on_update, ReferentialAction::Restrict);
+        }
+        field_type => panic!("Expected a relation field, found {:?}", field_type),
+    }
+}
+
+#[test]
+fn tables_sharing_a_name_across_schemas_must_get_distinct_models() {
+    setup();
+
+    let id_column = Column {
+        name: "id".to_string(),
+        tpe: ColumnType {
+            raw: "integer".to_string(),
+            family: ColumnTypeFamily::Int,
+            character_maximum_length: None,
+            numeric_precision: None,
+            numeric_scale: None,
+        },
+        arity: ColumnArity::Required,
+        default: None,
+        default_is_expression: false,
+        auto_increment: true,
+        on_update_current_timestamp: false,
+        comment: None,
+    };
+    let user_table = |schema: Option<&str>| Table {
+        name: "User".to_string(),
+        schema: schema.map(String::from),
+        columns: vec![id_column.clone()],
+        indices: vec![],
+        primary_key: Some(PrimaryKey {
+            columns: vec!["id".to_string()],
+            sequence: None,
+        }),
+        check_constraints: vec![],
+        comment: None,
+        foreign_keys: vec![],
+    };
+    let schema = SqlSchema {
+        tables: vec![
+            user_table(None),
+            user_table(Some("archive")),
+            Table {
+                name: "Post".to_string(),
+                schema: Some("archive".to_string()),
+                columns: vec![
+                    id_column.clone(),
+                    Column {
+                        name: "author".to_string(),
+                        tpe: ColumnType {
+                            raw: "integer".to_string(),
+                            family: ColumnTypeFamily::Int,
+                            character_maximum_length: None,
+                            numeric_precision: None,
+                            numeric_scale: None,
+                        },
+                        arity: ColumnArity::Required,
+                        default: None,
+                        default_is_expression: false,
+                        auto_increment: false,
+                        on_update_current_timestamp: false,
+                        comment: None,
+                    },
+                ],
+                indices: vec![],
+                primary_key: Some(PrimaryKey {
+                    columns: vec!["id".to_string()],
+                    sequence: None,
+                }),
+                check_constraints: vec![],
+                comment: None,
+                foreign_keys: vec![ForeignKey {
+                    constraint_name: None,
+                    columns: vec!["author".to_string()],
+                    referenced_table: "User".to_string(),
+                    referenced_schema: Some("archive".to_string()),
+                    on_delete_action: ForeignKeyAction::NoAction,
+                    on_update_action: ForeignKeyAction::NoAction,
+                    referenced_columns: vec!["id".to_string()],
+                }],
+            },
+        ],
+        enums: vec![],
+        sequences: vec![],
+        views: vec![],
+    };
+    let data_model = calculate_model(&schema).expect("calculate data model");
+
+    let user_model = data_model.find_model("User").expect("find User model");
+    assert_eq!(user_model.database_name, None);
+    assert_eq!(user_model.schema, None);
+
+    let archived_user_model = data_model.find_model("archive_User").expect("find archive_User model");
+    assert_eq!(archived_user_model.database_name, Some("User".to_string()));
+    assert_eq!(archived_user_model.schema, Some("archive".to_string()));
+    assert!(archived_user_model.find_field("posts").is_some());
+    assert!(user_model.find_field("posts").is_none());
+
+    let author_field = data_model
+        .find_model("Post")
+        .and_then(|model| model.find_field("author"))
+        .expect("find author field");
+
+    match &author_field.field_type {
+        FieldType::Relation(relation_info) => {
+            assert_eq!(relation_info.to, "archive_User");
+            assert_eq!(relation_info.name, "PostToarchive_User");
+        }
+        field_type => panic!("Expected a relation field, found {:?}", field_type),
+    }
+}
+
 #[test]
 fn enums_are_preserved_when_generating_data_model_from_a_schema() {
     setup();
@@ -1162,9 +1993,7 @@ fn enums_are_preserved_when_generating_data_model_from_a_schema() {
         }],
     };
 
-    let mut enum_values = HashSet::new();
-    enum_values.insert("a".to_string());
-    enum_values.insert("b".to_string());
+    let enum_values = vec!["a".to_string(), "b".to_string()];
     let schema = SqlSchema {
         tables: vec![],
         enums: vec![Enum {
@@ -1172,8 +2001,200 @@ fn enums_are_preserved_when_generating_data_model_from_a_schema() {
             values: enum_values,
         }],
         sequences: vec![],
+        views: vec![],
     };
     let data_model = calculate_model(&schema).expect("calculate data model");
 
     assert_eq!(data_model, ref_data_model);
 }
+
+#[test]
+fn native_types_are_preserved_when_generating_data_model_from_a_schema() {
+    setup();
+
+    let columns = vec![
+        ("int", "int4", ColumnTypeFamily::Int, FieldType::Base(PrismaType::Int)),
+        (
+            "small",
+            "int2",
+            ColumnTypeFamily::Int,
+            FieldType::ConnectorSpecific {
+                base_type: PrismaType::Int,
+                connector_type: Some("SmallInt".to_string()),
+            },
+        ),
+        (
+            "name",
+            "varchar",
+            ColumnTypeFamily::String,
+            FieldType::ConnectorSpecific {
+                base_type: PrismaType::String,
+                connector_type: Some("VarChar(100)".to_string()),
+            },
+        ),
+        (
+            "bio",
+            "text",
+            ColumnTypeFamily::String,
+            FieldType::Base(PrismaType::String),
+        ),
+        (
+            "createdAt",
+            "timestamptz",
+            ColumnTypeFamily::DateTime,
+            FieldType::ConnectorSpecific {
+                base_type: PrismaType::DateTime,
+                connector_type: Some("Timestamptz".to_string()),
+            },
+        ),
+        (
+            "price",
+            "numeric",
+            ColumnTypeFamily::Decimal,
+            FieldType::ConnectorSpecific {
+                base_type: PrismaType::Decimal,
+                connector_type: Some("Decimal(10,2)".to_string()),
+            },
+        ),
+        (
+            "balance",
+            "numeric",
+            ColumnTypeFamily::Decimal,
+            FieldType::Base(PrismaType::Decimal),
+        ),
+        // Float fields used to be stored as `Decimal(65,30)`.
+        (
+            "rating",
+            "numeric",
+            ColumnTypeFamily::Decimal,
+            FieldType::Base(PrismaType::Float),
+        ),
+    ];
+
+    let ref_data_model = Datamodel {
+        models: vec![Model {
+            database_name: None,
+            name: "Table1".to_string(),
+            documentation: None,
+            is_embedded: false,
+            is_view: false,
+            is_commented_out: false,
+            schema: None,
+            is_generated: false,
+            indexes: vec![],
+            id_fields: vec![],
+            check_constraints: vec![],
+            fields: columns
+                .iter()
+                .map(|(name, _, _, field_type)| Field {
+                    name: name.to_string(),
+                    arity: FieldArity::Required,
+                    field_type: field_type.clone(),
+                    database_name: None,
+                    default_value: None,
+                    is_unique: false,
+                    id_info: None,
+                    scalar_list_strategy: None,
+                    documentation: None,
+                    is_generated: false,
+                    is_updated_at: false,
+                    validators: vec![],
+                })
+                .collect(),
+        }],
+        enums: vec![],
+    };
+
+    let schema = SqlSchema {
+        tables: vec![Table {
+            name: "Table1".to_string(),
+            schema: None,
+            columns: columns
+                .iter()
+                .map(|(name, raw, family, _)| Column {
+                    name: name.to_string(),
+                    tpe: ColumnType {
+                        raw: raw.to_string(),
+                        family: family.clone(),
+                        character_maximum_length: Some(100).filter(|_| *raw == "varchar"),
+                        numeric_precision: match *name {
+                            "price" => Some(10),
+                            "rating" => Some(65),
+                            _ => None,
+                        },
+                        numeric_scale: match *name {
+                            "price" => Some(2),
+                            "rating" => Some(30),
+                            _ => None,
+                        },
+                    },
+                    arity: ColumnArity::Required,
+                    default: None,
+                    default_is_expression: false,
+                    auto_increment: false,
+                    on_update_current_timestamp: false,
+                    comment: None,
+                })
+                .collect(),
+            indices: vec![],
+            primary_key: None,
+            check_constraints: vec![],
+            comment: None,
+            foreign_keys: vec![],
+        }],
+        enums: vec![],
+        sequences: vec![],
+        views: vec![],
+    };
+    let data_model = calculate_model_with_native_types(&schema, SqlFamily::Postgres).expect("calculate data model");
+
+    assert_eq!(data_model, ref_data_model);
+}
+
+#[test]
+fn check_constraints_are_preserved_when_generating_data_model_from_a_schema() {
+    setup();
+
+    let schema = SqlSchema {
+        tables: vec![Table {
+            name: "Product".to_string(),
+            schema: None,
+            columns: vec![Column {
+                name: "price".to_string(),
+                tpe: ColumnType {
+                    raw: "numeric".to_string(),
+                    family: ColumnTypeFamily::Float,
+                    character_maximum_length: None,
+                    numeric_precision: None,
+                    numeric_scale: None,
+                },
+                arity: ColumnArity::Required,
+                default: None,
+                default_is_expression: false,
+                auto_increment: false,
+                on_update_current_timestamp: false,
+                comment: None,
+            }],
+            indices: vec![],
+            primary_key: None,
+            check_constraints: vec![CheckConstraint {
+                name: "positive_price".to_string(),
+                expression: "(price > (0)::numeric)".to_string(),
+            }],
+            comment: None,
+            foreign_keys: vec![],
+        }],
+        enums: vec![],
+        sequences: vec![],
+        views: vec![],
+    };
+    let data_model = calculate_model(&schema).expect("calculate data model");
+
+    assert_eq!(
+        data_model.find_model("Product").unwrap().check_constraints,
+        vec![dml::CheckConstraint {
+            name: Some("positive_price".to_string()),
+            expression: "(price > (0)::numeric)".to_string(),
+        }]
+    );
+}
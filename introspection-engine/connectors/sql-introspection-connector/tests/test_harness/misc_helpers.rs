@@ -1,6 +1,6 @@
 use crate::test_harness::{Mysql, Postgresql, Sqlite, SyncSqlConnection};
 use barrel::{Migration, SqlVariant};
-use introspection_connector::{DatabaseMetadata, IntrospectionConnector};
+use introspection_connector::{DatabaseMetadata, IntrospectionChange, IntrospectionConnector, IntrospectionWarning};
 use pretty_assertions::assert_eq;
 use sql_connection::SqlFamily;
 use sql_introspection_connector::*;
@@ -21,8 +21,23 @@ pub struct BarrelMigrationExecutor {
 // test execution
 
 pub(crate) fn introspect(test_setup: &TestSetup) -> String {
-    let datamodel = test_setup.introspection_connector.introspect(SCHEMA_NAME).unwrap();
-    datamodel::render_datamodel_to_string(&datamodel).expect("Datamodel rendering failed")
+    let result = test_setup.introspection_connector.introspect(SCHEMA_NAME).unwrap();
+    datamodel::render_datamodel_to_string(&result.datamodel).expect("Datamodel rendering failed")
+}
+
+pub(crate) fn introspection_warnings(test_setup: &TestSetup) -> Vec<IntrospectionWarning> {
+    let result = test_setup.introspection_connector.introspect(SCHEMA_NAME).unwrap();
+    result.warnings
+}
+
+pub(crate) fn re_introspect(test_setup: &TestSetup, previous: &str) -> (String, Vec<IntrospectionChange>) {
+    let previous = datamodel::parse_datamodel(previous).unwrap();
+    let result = test_setup
+        .introspection_connector
+        .re_introspect(SCHEMA_NAME, &[], &previous)
+        .unwrap();
+    let rendered = datamodel::render_datamodel_to_string(&result.datamodel).expect("Datamodel rendering failed");
+    (rendered, result.changes)
 }
 
 pub(crate) fn get_metadata(test_setup: &TestSetup) -> DatabaseMetadata {
@@ -4,4 +4,7 @@ mod get_metadata_command_tests;
 mod list_databases_command_tests;
 mod mysql;
 mod postgres;
+mod re_introspection_tests;
 mod sqlite;
+mod views_tests;
+mod warnings_tests;
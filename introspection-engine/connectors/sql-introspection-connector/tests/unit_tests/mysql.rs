@@ -181,6 +181,29 @@ fn introspecting_a_table_with_default_values_should_work() {
     });
 }
 
+#[test]
+fn introspecting_a_table_with_on_update_current_timestamp_should_work() {
+    test_each_backend_with_ignores(vec![SqlFamily::Sqlite, SqlFamily::Postgres], |test_setup, barrel| {
+        let _setup_schema = barrel.execute(|migration| {
+            migration.create_table("User", |t| {
+                t.add_column("id", types::primary());
+                t.inject_custom("`created` TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP");
+                t.inject_custom("`updated` TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP");
+            });
+        });
+
+        let dm = r#"
+            model User {
+                created DateTime @default(now())
+                id      Int @id
+                updated DateTime @default(now()) @updatedAt
+            }
+        "#;
+        let result = dbg!(introspect(test_setup));
+        custom_assert(&result, dm);
+    });
+}
+
 #[test]
 fn introspecting_a_table_with_a_non_unique_index_should_work() {
     test_each_backend_with_ignores(vec![SqlFamily::Sqlite, SqlFamily::Postgres], |test_setup, barrel| {
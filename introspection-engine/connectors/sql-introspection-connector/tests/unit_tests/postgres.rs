@@ -22,7 +22,7 @@ fn introspecting_a_simple_table_with_gql_types_must_work() {
                 bool    Boolean
                 date    DateTime
                 float   Float
-                id      Int @id(strategy: NONE) @sequence(name: "Blog_id_seq", allocationSize: 1, initialValue: 1)
+                id      Int @id @sequence(name: "Blog_id_seq", allocationSize: 1, initialValue: 1)
                 int     Int 
                 string  String
             }
@@ -69,7 +69,7 @@ fn introspecting_a_table_with_unique_index_must_work() {
         let dm = r#"
             model Blog {
                 authorId String @unique
-                id      Int @id(strategy: NONE) @sequence(name: "Blog_id_seq", allocationSize: 1, initialValue: 1)
+                id      Int @id @sequence(name: "Blog_id_seq", allocationSize: 1, initialValue: 1)
             }
         "#;
         let result = dbg!(introspect(test_setup));
@@ -94,7 +94,7 @@ fn introspecting_a_table_with_multi_column_unique_index_must_work() {
         let dm = r#"
             model User {
                 firstname String
-                id      Int @id(strategy: NONE) @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
+                id      Int @id @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
                 lastname String
                 @@unique([firstname, lastname], name: "test")
             }
@@ -117,7 +117,7 @@ fn introspecting_a_table_with_required_and_optional_columns_must_work() {
 
         let dm = r#"
             model User {
-                id      Int @id(strategy: NONE) @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
+                id      Int @id @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
                 optionalname String?
                 requiredname String
             }
@@ -141,7 +141,7 @@ fn introspecting_a_table_with_datetime_default_values_should_work() {
 
         let dm = r#"
             model User {
-                id      Int @id(strategy: NONE) @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
+                id      Int @id @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
                 joined DateTime? @default(now())
                 name String
             }
@@ -172,7 +172,7 @@ fn introspecting_a_table_with_default_values_should_work() {
                 bool Boolean @default(false)
                 bool2 Boolean @default(false)
                 float Float @default(5.3)
-                id      Int @id(strategy: NONE) @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
+                id      Int @id @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
                 int Int @default(5)
                 string String @default("Test")
             }
@@ -182,6 +182,29 @@ fn introspecting_a_table_with_default_values_should_work() {
     });
 }
 
+#[test]
+fn introspecting_a_table_with_expression_default_values_should_work() {
+    test_each_backend_with_ignores(vec![SqlFamily::Sqlite, SqlFamily::Mysql], |test_setup, barrel| {
+        let _setup_schema = barrel.execute(|migration| {
+            migration.create_table("User", |t| {
+                t.add_column("id", types::primary());
+                t.inject_custom("\"checksum\" TEXT NOT NULL DEFAULT md5(random()::text)");
+                t.inject_custom("\"created\" timestamp NOT NULL DEFAULT now()");
+            });
+        });
+
+        let dm = r#"
+            model User {
+                checksum String @default(dbgenerated("md5((random())::text)"))
+                created DateTime @default(now())
+                id      Int @id @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
+            }
+        "#;
+        let result = dbg!(introspect(test_setup));
+        custom_assert(&result, dm);
+    });
+}
+
 #[test]
 fn introspecting_a_table_with_a_non_unique_index_should_work() {
     test_each_backend_with_ignores(vec![SqlFamily::Sqlite, SqlFamily::Mysql], |test_setup, barrel| {
@@ -196,7 +219,7 @@ fn introspecting_a_table_with_a_non_unique_index_should_work() {
         let dm = r#"
             model User {
                 a String
-                id      Int @id(strategy: NONE) @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
+                id      Int @id @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
                 @@index([a], name: "test")
             }
         "#;
@@ -221,7 +244,7 @@ fn introspecting_a_table_with_a_multi_column_non_unique_index_should_work() {
             model User {
                 a String
                 b String
-                id      Int @id(strategy: NONE) @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
+                id      Int @id @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
                 @@index([a,b], name: "test")
             }
         "#;
@@ -246,12 +269,12 @@ fn introspecting_a_one_to_one_req_relation_should_work() {
 
         let dm = r#"
               model Post {
-               id      Int @id(strategy: NONE) @sequence(name: "Post_id_seq", allocationSize: 1, initialValue: 1)
+               id      Int @id @sequence(name: "Post_id_seq", allocationSize: 1, initialValue: 1)
                user_id User
             }
           
             model User {
-               id      Int @id(strategy: NONE) @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
+               id      Int @id @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
                post Post? 
             }
         "#;
@@ -276,13 +299,13 @@ fn introspecting_two_one_to_one_relations_between_the_same_models_should_work()
 
         let dm = r#"
             model Post {
-               id      Int @id(strategy: NONE) @sequence(name: "Post_id_seq", allocationSize: 1, initialValue: 1)
+               id      Int @id @sequence(name: "Post_id_seq", allocationSize: 1, initialValue: 1)
                user_id User  @relation("Post_user_idToUser")
                user    User? @relation("PostToUser_post_id", references: [post_id])
             }
         
             model User {
-               id      Int @id(strategy: NONE) @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
+               id      Int @id @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
                post_id Post  @relation("PostToUser_post_id")
                post Post?    @relation("Post_user_idToUser")
             }
@@ -307,12 +330,12 @@ fn introspecting_a_one_to_one_relation_should_work() {
 
         let dm = r#"        
             model Post {
-               id      Int @id(strategy: NONE) @sequence(name: "Post_id_seq", allocationSize: 1, initialValue: 1)
+               id      Int @id @sequence(name: "Post_id_seq", allocationSize: 1, initialValue: 1)
                user_id User?
             }
             
             model User {
-               id      Int @id(strategy: NONE) @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
+               id      Int @id @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
                post Post? 
             }
         "#;
@@ -336,12 +359,12 @@ fn introspecting_a_one_to_many_relation_should_work() {
 
         let dm = r#"  
             model Post {
-               id      Int @id(strategy: NONE) @sequence(name: "Post_id_seq", allocationSize: 1, initialValue: 1)
+               id      Int @id @sequence(name: "Post_id_seq", allocationSize: 1, initialValue: 1)
                user_id User?
             }
             
             model User {
-               id      Int @id(strategy: NONE) @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
+               id      Int @id @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
                posts Post[] 
             }
         "#;
@@ -365,12 +388,12 @@ fn introspecting_a_one_req_to_many_relation_should_work() {
 
         let dm = r#"
             model Post {
-               id      Int @id(strategy: NONE) @sequence(name: "Post_id_seq", allocationSize: 1, initialValue: 1)
+               id      Int @id @sequence(name: "Post_id_seq", allocationSize: 1, initialValue: 1)
                user_id User
             }
             
             model User {
-               id      Int @id(strategy: NONE) @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
+               id      Int @id @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
                posts Post[] 
             }
        "#;
@@ -401,12 +424,12 @@ fn introspecting_a_prisma_many_to_many_relation_should_work() {
 
         let dm = r#"
             model Post {
-               id      Int @id(strategy: NONE) @sequence(name: "Post_id_seq", allocationSize: 1, initialValue: 1)
+               id      Int @id @sequence(name: "Post_id_seq", allocationSize: 1, initialValue: 1)
                users User[] 
             }
             
             model User {
-               id      Int @id(strategy: NONE) @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
+               id      Int @id @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
                posts Post[] 
             }
         "#;
@@ -436,7 +459,7 @@ fn introspecting_a_many_to_many_relation_should_work() {
 
         let dm = r#"
             model Post {
-               id      Int @id(strategy: NONE) @sequence(name: "Post_id_seq", allocationSize: 1, initialValue: 1)
+               id      Int @id @sequence(name: "Post_id_seq", allocationSize: 1, initialValue: 1)
                postsToUserses PostsToUsers[] @relation(references: [post_id])
             }
 
@@ -446,7 +469,7 @@ fn introspecting_a_many_to_many_relation_should_work() {
             }
             
             model User {
-               id      Int @id(strategy: NONE) @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
+               id      Int @id @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
                postsToUserses PostsToUsers[] 
             }
         "#;
@@ -476,7 +499,7 @@ fn introspecting_a_many_to_many_relation_with_extra_fields_should_work() {
 
         let dm = r#"
             model Post {
-               id      Int @id(strategy: NONE) @sequence(name: "Post_id_seq", allocationSize: 1, initialValue: 1)
+               id      Int @id @sequence(name: "Post_id_seq", allocationSize: 1, initialValue: 1)
                postsToUserses PostsToUsers[] @relation(references: [post_id])
             }
             
@@ -487,7 +510,7 @@ fn introspecting_a_many_to_many_relation_with_extra_fields_should_work() {
             }
             
             model User {
-               id      Int @id(strategy: NONE) @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
+               id      Int @id @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
                postsToUserses PostsToUsers[] 
             }
         "#;
@@ -511,7 +534,7 @@ fn introspecting_a_self_relation_should_work() {
         let dm = r#"
             model User {
                 direct_report                  User?  @relation("UserToUser_direct_report")
-                id      Int @id(strategy: NONE) @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
+                id      Int @id @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
                 recruited_by                   User?  @relation("UserToUser_recruited_by")
                 users_UserToUser_direct_report User[] @relation("UserToUser_direct_report")
                 users_UserToUser_recruited_by  User[] @relation("UserToUser_recruited_by")
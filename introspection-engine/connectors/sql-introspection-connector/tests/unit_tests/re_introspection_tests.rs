@@ -0,0 +1,151 @@
+use crate::*;
+use barrel::types;
+use introspection_connector::{IntrospectionChange, IntrospectionChangeKind, IntrospectionObject};
+use test_harness::*;
+
+fn change(
+    kind: IntrospectionChangeKind,
+    object: IntrospectionObject,
+    name: &str,
+    field: Option<&str>,
+) -> IntrospectionChange {
+    IntrospectionChange {
+        kind,
+        object,
+        name: name.to_owned(),
+        field: field.map(String::from),
+    }
+}
+
+#[test]
+fn re_introspecting_must_keep_the_names_and_documentation_of_the_datamodel() {
+    test_each_backend_with_ignores(vec![SqlFamily::Postgres, SqlFamily::Mysql], |test_setup, barrel| {
+        let _setup_schema = barrel.execute(|migration| {
+            migration.create_table("User", |t| {
+                t.add_column("id", types::primary());
+                t.add_column("mail", types::text());
+            });
+            migration.create_table("Post", |t| {
+                t.add_column("id", types::primary());
+                t.add_column("title", types::text());
+                t.inject_custom(
+                    "user_id INTEGER,
+                        FOREIGN KEY(user_id) REFERENCES User(id)",
+                )
+            });
+            migration.create_table("Comment", |t| {
+                t.add_column("id", types::primary());
+            });
+        });
+
+        let previous = r#"
+            /// The people using the app.
+            model Account {
+               id       Int       @id
+               /// Where we send the newsletter.
+               email    String    @map("mail")
+               nickname String
+               articles Article[]
+
+               @@map("User")
+            }
+
+            model Article {
+               id      Int      @id
+               user_id Account?
+
+               @@map("Post")
+            }
+
+            model Gone {
+               id Int @id
+            }
+        "#;
+
+        let dm = r#"
+            /// The people using the app.
+            model Account {
+               id       Int       @id
+               /// Where we send the newsletter.
+               email    String    @map("mail")
+               articles Article[]
+
+               @@map("User")
+            }
+
+            model Article {
+               id      Int      @id
+               title   String
+               user_id Account?
+
+               @@map("Post")
+            }
+
+            model Comment {
+               id Int @id
+            }
+        "#;
+
+        let (result, changes) = dbg!(re_introspect(test_setup, previous));
+        custom_assert(&result, dm);
+
+        let expected_changes = vec![
+            change(
+                IntrospectionChangeKind::Added,
+                IntrospectionObject::Model,
+                "Comment",
+                None,
+            ),
+            change(
+                IntrospectionChangeKind::Added,
+                IntrospectionObject::Field,
+                "Article",
+                Some("title"),
+            ),
+            change(
+                IntrospectionChangeKind::Removed,
+                IntrospectionObject::Field,
+                "Account",
+                Some("nickname"),
+            ),
+            change(
+                IntrospectionChangeKind::Removed,
+                IntrospectionObject::Model,
+                "Gone",
+                None,
+            ),
+        ];
+
+        assert_eq!(changes.len(), expected_changes.len());
+        for expected_change in expected_changes {
+            assert!(
+                changes.contains(&expected_change),
+                "Missing change {:?}",
+                expected_change
+            );
+        }
+    });
+}
+
+#[test]
+fn re_introspecting_must_keep_enum_names() {
+    test_each_backend_with_ignores(vec![SqlFamily::Sqlite, SqlFamily::Mysql], |test_setup, barrel| {
+        let _setup_schema = barrel.execute(|migration| {
+            migration.inject_custom("CREATE TYPE \"introspection-engine\".\"color\" AS ENUM ('black', 'white')");
+        });
+
+        let previous = r#"
+            /// The colors of the covers.
+            enum Color {
+               black
+               white
+
+               @@map("color")
+            }
+        "#;
+
+        let (result, changes) = dbg!(re_introspect(test_setup, previous));
+        custom_assert(&result, previous);
+        assert!(changes.is_empty(), "{:?}", changes);
+    });
+}
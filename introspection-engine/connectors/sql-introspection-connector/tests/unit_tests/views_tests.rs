@@ -0,0 +1,183 @@
+use crate::*;
+use barrel::types;
+use introspection_connector::IntrospectionWarning;
+use test_harness::*;
+
+#[test]
+fn introspecting_a_view_must_return_a_read_only_model() {
+    test_each_backend_with_ignores(vec![SqlFamily::Postgres, SqlFamily::Mysql], |test_setup, barrel| {
+        let _setup_schema = barrel.execute(|migration| {
+            migration.create_table("User", |t| {
+                t.add_column("id", types::primary());
+                t.add_column("name", types::text());
+            });
+            migration.inject_custom(
+                "CREATE VIEW \"introspection-engine\".\"UserName\" AS SELECT \"id\", \"name\" FROM \"User\"",
+            );
+        });
+
+        let result = test_setup.introspection_connector.introspect(SCHEMA_NAME).unwrap();
+
+        assert!(!result.datamodel.find_model("User").unwrap().is_view);
+
+        let view = result.datamodel.find_model("UserName").expect("UserName model");
+        assert!(view.is_view);
+        assert_eq!(
+            view.fields().map(|field| field.name.as_str()).collect::<Vec<_>>(),
+            vec!["id", "name"]
+        );
+
+        // Views have no primary key, an id has to be added by hand.
+        assert!(view.is_commented_out);
+        assert_eq!(
+            result.warnings,
+            vec![IntrospectionWarning::ViewWithoutIdentifier {
+                view: "UserName".to_string()
+            }]
+        );
+    });
+}
+
+#[test]
+fn introspecting_a_view_without_unique_index_must_comment_it_out_on_postgres() {
+    test_backend(SqlFamily::Postgres, |test_setup, barrel| {
+        let _setup_schema = barrel.execute(|migration| {
+            migration.create_table("User", |t| {
+                t.add_column("id", types::primary());
+                t.add_column("name", types::text());
+            });
+            migration.inject_custom(
+                "CREATE VIEW \"introspection-engine\".\"UserName\" AS SELECT \"id\", \"name\" FROM \"introspection-engine\".\"User\"",
+            );
+        });
+
+        assert_view_is_commented_out(test_setup);
+    });
+}
+
+#[test]
+fn introspecting_a_view_without_unique_index_must_comment_it_out_on_mysql() {
+    test_backend(SqlFamily::Mysql, |test_setup, barrel| {
+        let _setup_schema = barrel.execute(|migration| {
+            migration.create_table("User", |t| {
+                t.add_column("id", types::primary());
+                t.add_column("name", types::text());
+            });
+            migration.inject_custom(
+                "CREATE VIEW `introspection-engine`.`UserName` AS SELECT `id`, `name` FROM `introspection-engine`.`User`",
+            );
+        });
+
+        assert_view_is_commented_out(test_setup);
+    });
+}
+
+#[test]
+fn introspecting_a_materialized_view_without_unique_index_must_comment_it_out() {
+    test_backend(SqlFamily::Postgres, |test_setup, barrel| {
+        let _setup_schema = barrel.execute(|migration| {
+            migration.create_table("User", |t| {
+                t.add_column("id", types::primary());
+                t.add_column("name", types::text());
+            });
+            migration.inject_custom(
+                "CREATE MATERIALIZED VIEW \"introspection-engine\".\"UserName\" AS SELECT \"id\", \"name\" FROM \"introspection-engine\".\"User\"",
+            );
+        });
+
+        assert_view_is_commented_out(test_setup);
+    });
+}
+
+#[test]
+fn introspecting_a_materialized_view_with_a_unique_index_must_use_it_as_id() {
+    test_backend(SqlFamily::Postgres, |test_setup, barrel| {
+        let _setup_schema = barrel.execute(|migration| {
+            migration.create_table("User", |t| {
+                t.add_column("id", types::primary());
+                t.add_column("name", types::text());
+            });
+            migration.inject_custom(
+                "CREATE MATERIALIZED VIEW \"introspection-engine\".\"UserName\" AS SELECT \"id\", \"name\" FROM \"introspection-engine\".\"User\"",
+            );
+            migration.inject_custom(
+                "CREATE UNIQUE INDEX \"UserName_id_key\" ON \"introspection-engine\".\"UserName\" (\"id\")",
+            );
+        });
+
+        let result = test_setup.introspection_connector.introspect(SCHEMA_NAME).unwrap();
+
+        let view = result.datamodel.find_model("UserName").expect("UserName model");
+        assert!(view.is_view);
+        assert!(!view.is_commented_out);
+        assert!(view.indexes.is_empty());
+        assert_eq!(
+            view.singular_id_fields()
+                .map(|field| field.name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["id"]
+        );
+        assert_eq!(view.find_field("id").unwrap().arity, datamodel::FieldArity::Required);
+        assert_eq!(result.warnings, vec![]);
+
+        // The rendered view must be a valid model.
+        let rendered = introspect(test_setup);
+        let parsed = datamodel::parse_datamodel(&rendered).unwrap();
+        assert!(parsed.find_model("UserName").unwrap().is_view);
+    });
+}
+
+fn assert_view_is_commented_out(test_setup: &TestSetup) {
+    let result = test_setup.introspection_connector.introspect(SCHEMA_NAME).unwrap();
+
+    let view = result.datamodel.find_model("UserName").expect("UserName model");
+    assert!(view.is_view);
+    assert!(view.is_commented_out);
+    assert_eq!(
+        result.warnings,
+        vec![IntrospectionWarning::ViewWithoutIdentifier {
+            view: "UserName".to_string()
+        }]
+    );
+
+    // The view is rendered behind comments, so the datamodel stays valid without it.
+    let rendered = introspect(test_setup);
+    assert!(rendered.contains("// model UserName {"));
+    let parsed = datamodel::parse_datamodel(&rendered).unwrap();
+    assert!(parsed.find_model("User").is_some());
+    assert!(parsed.find_model("UserName").is_none());
+}
+
+#[test]
+fn re_introspecting_a_view_must_keep_its_id() {
+    test_each_backend_with_ignores(vec![SqlFamily::Postgres, SqlFamily::Mysql], |test_setup, barrel| {
+        let _setup_schema = barrel.execute(|migration| {
+            migration.create_table("User", |t| {
+                t.add_column("id", types::primary());
+                t.add_column("name", types::text());
+            });
+            migration.inject_custom(
+                "CREATE VIEW \"introspection-engine\".\"UserName\" AS SELECT \"id\", \"name\" FROM \"User\"",
+            );
+        });
+
+        let previous = r#"
+            model User {
+                id   Int    @id
+                name String
+            }
+
+            model UserName {
+                id   Int     @id
+                name String?
+
+                @@view
+            }
+        "#;
+
+        let (result, changes) = re_introspect(test_setup, previous);
+
+        custom_assert(&result, previous);
+        assert_eq!(changes, vec![]);
+    });
+}
@@ -0,0 +1,192 @@
+use crate::*;
+use barrel::types;
+use introspection_connector::IntrospectionWarning;
+use test_harness::*;
+
+#[test]
+fn introspecting_a_table_without_unique_identifier_must_warn() {
+    test_each_backend_with_ignores(vec![SqlFamily::Postgres, SqlFamily::Mysql], |test_setup, barrel| {
+        let _setup_schema = barrel.execute(|migration| {
+            migration.create_table("Log", |t| {
+                t.add_column("message", types::text());
+            });
+        });
+
+        let warnings = dbg!(introspection_warnings(test_setup));
+
+        assert_eq!(
+            warnings,
+            vec![IntrospectionWarning::ModelWithoutIdentifier {
+                table: "Log".to_string()
+            }]
+        );
+    });
+}
+
+#[test]
+fn introspecting_unsupported_column_types_must_warn() {
+    test_each_backend_with_ignores(vec![SqlFamily::Postgres, SqlFamily::Mysql], |test_setup, barrel| {
+        let _setup_schema = barrel.execute(|migration| {
+            migration.create_table("Place", |t| {
+                t.add_column("id", types::primary());
+                t.inject_custom("location geography");
+            });
+        });
+
+        let dm = r#"
+            model Place {
+                id       Int     @id
+                location String?
+            }
+        "#;
+
+        let result = dbg!(introspect(test_setup));
+        custom_assert(&result, dm);
+
+        assert_eq!(
+            introspection_warnings(test_setup),
+            vec![IntrospectionWarning::UnsupportedType {
+                table: "Place".to_string(),
+                column: "location".to_string(),
+                tpe: "geography".to_string(),
+            }]
+        );
+    });
+}
+
+#[test]
+fn introspecting_columns_with_invalid_names_must_rename_the_fields() {
+    test_each_backend_with_ignores(vec![SqlFamily::Postgres, SqlFamily::Mysql], |test_setup, barrel| {
+        let _setup_schema = barrel.execute(|migration| {
+            migration.create_table("User", |t| {
+                t.add_column("id", types::primary());
+                t.inject_custom("\"first-name\" TEXT NOT NULL");
+                t.inject_custom("\"2fa\" BOOLEAN NOT NULL");
+            });
+        });
+
+        let dm = r#"
+            model User {
+                id        Int     @id
+                first_name String  @map("first-name")
+                fa        Boolean @map("2fa")
+            }
+        "#;
+
+        let result = dbg!(introspect(test_setup));
+        custom_assert(&result, dm);
+
+        assert_eq!(
+            introspection_warnings(test_setup),
+            vec![
+                IntrospectionWarning::FieldRenamed {
+                    table: "User".to_string(),
+                    column: "first-name".to_string(),
+                    field: "first_name".to_string(),
+                },
+                IntrospectionWarning::FieldRenamed {
+                    table: "User".to_string(),
+                    column: "2fa".to_string(),
+                    field: "fa".to_string(),
+                },
+            ]
+        );
+    });
+}
+
+#[test]
+fn introspecting_columns_whose_sanitized_names_collide_must_deduplicate_the_fields() {
+    test_each_backend_with_ignores(vec![SqlFamily::Postgres, SqlFamily::Mysql], |test_setup, barrel| {
+        let _setup_schema = barrel.execute(|migration| {
+            migration.create_table("User", |t| {
+                t.add_column("id", types::primary());
+                t.inject_custom("\"first-name\" TEXT NOT NULL");
+                t.inject_custom("\"first_name\" TEXT NOT NULL");
+                t.inject_custom("\"first name\" TEXT NOT NULL");
+            });
+        });
+
+        let dm = r#"
+            model User {
+                id           Int    @id
+                first_name_1 String @map("first-name")
+                first_name   String
+                first_name_2 String @map("first name")
+            }
+        "#;
+
+        let result = dbg!(introspect(test_setup));
+        custom_assert(&result, dm);
+
+        assert_eq!(
+            introspection_warnings(test_setup),
+            vec![
+                IntrospectionWarning::FieldRenamed {
+                    table: "User".to_string(),
+                    column: "first-name".to_string(),
+                    field: "first_name_1".to_string(),
+                },
+                IntrospectionWarning::FieldRenamed {
+                    table: "User".to_string(),
+                    column: "first name".to_string(),
+                    field: "first_name_2".to_string(),
+                },
+            ]
+        );
+    });
+}
+
+#[test]
+fn introspecting_enum_values_with_invalid_names_must_warn() {
+    test_each_backend_with_ignores(vec![SqlFamily::Sqlite, SqlFamily::Mysql], |test_setup, barrel| {
+        let _setup_schema = barrel.execute(|migration| {
+            migration.inject_custom("CREATE TYPE \"introspection-engine\".\"mood\" AS ENUM ('happy', 'not so happy')");
+        });
+
+        let dm = r#"
+            enum mood {
+                happy
+            }
+        "#;
+
+        let result = dbg!(introspect(test_setup));
+        custom_assert(&result, dm);
+
+        assert_eq!(
+            introspection_warnings(test_setup),
+            vec![IntrospectionWarning::InvalidEnumValue {
+                name: "mood".to_string(),
+                value: "not so happy".to_string(),
+            }]
+        );
+    });
+}
+
+#[test]
+fn introspecting_an_id_with_a_database_generated_default_must_warn() {
+    test_each_backend_with_ignores(vec![SqlFamily::Sqlite, SqlFamily::Mysql], |test_setup, barrel| {
+        let _setup_schema = barrel.execute(|migration| {
+            migration.create_table("Session", |t| {
+                t.inject_custom("token TEXT PRIMARY KEY DEFAULT md5(random()::text)");
+            });
+        });
+
+        let dm = r#"
+            model Session {
+                token String @id
+            }
+        "#;
+
+        let result = dbg!(introspect(test_setup));
+        custom_assert(&result, dm);
+
+        assert_eq!(
+            introspection_warnings(test_setup),
+            vec![IntrospectionWarning::GeneratedIdDefault {
+                table: "Session".to_string(),
+                column: "token".to_string(),
+                expression: "md5((random())::text)".to_string(),
+            }]
+        );
+    });
+}
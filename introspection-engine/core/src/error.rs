@@ -9,6 +9,8 @@ pub enum CoreError {
     InvalidUrl { message: String },
     #[fail(display = "Error in connector: {}", _0)]
     ConnectorError(Error),
+    #[fail(display = "Error in datamodel: {}", _0)]
+    InvalidDatamodel(datamodel::error::ErrorCollection),
 }
 
 impl From<url::ParseError> for CoreError {
@@ -25,6 +27,12 @@ impl From<introspection_connector::ConnectorError> for CoreError {
     }
 }
 
+impl From<datamodel::error::ErrorCollection> for CoreError {
+    fn from(e: datamodel::error::ErrorCollection) -> Self {
+        CoreError::InvalidDatamodel(e)
+    }
+}
+
 impl From<CoreError> for jsonrpc_core::types::error::Error {
     fn from(e: CoreError) -> Self {
         jsonrpc_core::types::error::Error {
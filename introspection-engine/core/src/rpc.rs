@@ -1,7 +1,6 @@
 use crate::connector_loader::load_connector;
 use crate::CoreResult;
-use datamodel::Datamodel;
-use introspection_connector::DatabaseMetadata;
+use introspection_connector::{DatabaseMetadata, IntrospectionChange, IntrospectionResult, IntrospectionWarning};
 use jsonrpc_core::*;
 use jsonrpc_derive::rpc;
 
@@ -15,6 +14,12 @@ pub trait Rpc {
 
     #[rpc(name = "introspect")]
     fn introspect(&self, url: UrlInput) -> Result<String>;
+
+    #[rpc(name = "introspectWithWarnings")]
+    fn introspect_with_warnings(&self, url: UrlInput) -> Result<IntrospectionOutput>;
+
+    #[rpc(name = "reIntrospect")]
+    fn re_introspect(&self, input: ReIntrospectInput) -> Result<ReIntrospectOutput>;
 }
 
 pub struct RpcImpl {}
@@ -29,17 +34,83 @@ impl Rpc for RpcImpl {
     }
 
     fn introspect(&self, url: UrlInput) -> Result<String> {
-        let data_model = Self::introspect_internal(url)?;
-        Ok(datamodel::render_datamodel_to_string(&data_model).expect("Datamodel rendering failed"))
+        Ok(Self::introspect_with_warnings_internal(url)?.datamodel)
+    }
+
+    fn introspect_with_warnings(&self, url: UrlInput) -> Result<IntrospectionOutput> {
+        Ok(Self::introspect_with_warnings_internal(url)?)
+    }
+
+    fn re_introspect(&self, input: ReIntrospectInput) -> Result<ReIntrospectOutput> {
+        Ok(Self::re_introspect_internal(input)?)
     }
 }
 
 impl RpcImpl {
-    fn introspect_internal(url: UrlInput) -> CoreResult<Datamodel> {
+    fn introspect_internal(url: UrlInput) -> CoreResult<IntrospectionResult> {
         let connector = load_connector(&url.url)?;
         // FIXME: parse URL correctly via a to be built lib and pass database param;
-        let data_model = connector.introspect("")?;
-        Ok(data_model)
+        let result = connector.introspect_schemas("", &url.schemas)?;
+        Ok(result)
+    }
+
+    fn introspect_with_warnings_internal(url: UrlInput) -> CoreResult<IntrospectionOutput> {
+        let sources = Self::datasources_for_url(&url.url, &url.schemas);
+        let result = Self::introspect_internal(url)?;
+        let rendered = datamodel::render_datamodel_with_sources_to_string(&result.datamodel, &sources)
+            .expect("Datamodel rendering failed");
+
+        Ok(IntrospectionOutput {
+            datamodel: rendered,
+            warnings: result.warnings,
+        })
+    }
+
+    fn re_introspect_internal(input: ReIntrospectInput) -> CoreResult<ReIntrospectOutput> {
+        let previous = datamodel::parse_datamodel(&input.datamodel)?;
+        let config = datamodel::parse_configuration(&input.datamodel)?;
+
+        let connector = load_connector(&input.url)?;
+        let result = connector.re_introspect("", &input.schemas, &previous)?;
+
+        // Keep the datasources and generators of the datamodel, if it has any.
+        let rendered = if config.datasources.is_empty() {
+            let sources = Self::datasources_for_url(&input.url, &input.schemas);
+            datamodel::render_datamodel_with_sources_to_string(&result.datamodel, &sources)
+        } else {
+            datamodel::render_datamodel_and_config_to_string(&result.datamodel, &config)
+        }
+        .expect("Datamodel rendering failed");
+
+        Ok(ReIntrospectOutput {
+            datamodel: rendered,
+            changes: result.changes,
+            warnings: result.warnings,
+        })
+    }
+
+    /// A datasource named `db` for the given url, used to render native types as `@db.VarChar`.
+    fn datasources_for_url(url: &str, schemas: &[String]) -> Vec<Box<dyn datamodel::Source>> {
+        let provider = match url.split(':').next() {
+            Some("postgresql") => datamodel::POSTGRES_SOURCE_NAME,
+            Some("mysql") => datamodel::MYSQL_SOURCE_NAME,
+            Some("file") => datamodel::SQLITE_SOURCE_NAME,
+            _ => return Vec::new(),
+        };
+        let schemas = if schemas.is_empty() {
+            String::new()
+        } else {
+            let names: Vec<String> = schemas.iter().map(|schema| format!("\"{}\"", schema)).collect();
+            format!("  schemas = [{}]\n", names.join(", "))
+        };
+        let config = format!(
+            "datasource db {{\n  provider = \"{}\"\n  url = \"{}\"\n{}}}",
+            provider, url, schemas
+        );
+
+        datamodel::parse_configuration(&config)
+            .map(|config| config.datasources)
+            .unwrap_or_default()
     }
 
     fn list_databases_internal(url: UrlInput) -> CoreResult<Vec<String>> {
@@ -56,4 +127,32 @@ impl RpcImpl {
 #[derive(Serialize, Deserialize)]
 pub struct UrlInput {
     url: String,
+    /// Schemas to introspect next to the one of the connection.
+    #[serde(default)]
+    schemas: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ReIntrospectInput {
+    url: String,
+    /// Schemas to introspect next to the one of the connection.
+    #[serde(default)]
+    schemas: Vec<String>,
+    /// The current datamodel, whose names, directives and documentation are kept.
+    datamodel: String,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ReIntrospectOutput {
+    datamodel: String,
+    /// The models, fields and enums that were added to or removed from the datamodel.
+    changes: Vec<IntrospectionChange>,
+    warnings: Vec<IntrospectionWarning>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct IntrospectionOutput {
+    datamodel: String,
+    /// The constructs of the database the datamodel does not support.
+    warnings: Vec<IntrospectionWarning>,
 }
@@ -66,6 +66,7 @@ impl<'a> Renderer<'a> {
                     }
 
                     match other {
+                        ast::Top::Model(model) if model.commented_out => self.render_commented_out_model(model),
                         ast::Top::Model(model) => self.render_model(model),
                         ast::Top::Enum(enm) => self.render_enum(enm),
                         ast::Top::Source(source) => self.render_source_block(source),
@@ -188,6 +189,23 @@ impl<'a> Renderer<'a> {
         self.end_line();
     }
 
+    /// Renders the model on its own, then writes every line of it behind a line comment.
+    fn render_commented_out_model(&mut self, model: &ast::Model) {
+        let mut buffer = Vec::new();
+        Renderer::new(&mut buffer, self.indent_width).render_model(model);
+        let rendered = String::from_utf8(buffer).expect("Rendered model is not valid UTF-8.");
+
+        for line in rendered.lines() {
+            if line.is_empty() {
+                self.write("//");
+            } else {
+                self.write("// ");
+                self.write(line);
+            }
+            self.end_line();
+        }
+    }
+
     fn render_enum(&mut self, enm: &ast::Enum) {
         Self::render_documentation(self, enm);
 
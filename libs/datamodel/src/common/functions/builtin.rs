@@ -62,3 +62,28 @@ impl Functional for ServerSideTrivialFunctional {
         ))
     }
 }
+
+/// Database generated default (`dbgenerated("...")`), holding a database expression verbatim.
+pub struct DbGeneratedFunctional {}
+
+impl Functional for DbGeneratedFunctional {
+    fn name(&self) -> &str {
+        "dbgenerated"
+    }
+
+    fn apply(&self, values: &[ValueValidator], span: ast::Span) -> Result<MaybeExpression, DatamodelError> {
+        self.check_arg_count(values, 1, span)?;
+
+        let expression = values[0].as_str()?;
+
+        // The type of the expression is only known from the field it is the default of, see `ValueValidator::as_type`.
+        Ok(MaybeExpression::Expression(
+            PrismaValue::Expression(
+                String::from(self.name()),
+                PrismaType::String,
+                vec![PrismaValue::String(expression)],
+            ),
+            span,
+        ))
+    }
+}
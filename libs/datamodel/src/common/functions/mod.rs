@@ -26,13 +26,15 @@ const BUILTIN_UUID_FUNCTIONAL: builtin::ServerSideTrivialFunctional = builtin::S
     name: "uuid",
     return_type: PrismaType::String,
 };
+const BUILTIN_DBGENERATED_FUNCTIONAL: builtin::DbGeneratedFunctional = builtin::DbGeneratedFunctional {};
 
 /// Array of all builtin functionals.
-const BUILTIN_FUNCTIONALS: [&dyn Functional; 4] = [
+const BUILTIN_FUNCTIONALS: [&dyn Functional; 5] = [
     &BUILTIN_ENV_FUNCTIONAL,
     &BUILTIN_NOW_FUNCTIONAL,
     &BUILTIN_CUID_FUNCTIONAL,
     &BUILTIN_UUID_FUNCTIONAL,
+    &BUILTIN_DBGENERATED_FUNCTIONAL,
 ];
 
 /// Evaluator for arbitrary expressions.
@@ -15,10 +15,13 @@ use crate::error::DatamodelError;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+pub use rust_decimal::Decimal;
+
 /// Prisma's builtin base types.
 #[derive(Debug, Copy, PartialEq, Clone, Serialize, Deserialize)]
 pub enum PrismaType {
     Int,
+    BigInt,
     Float,
     Decimal,
     Boolean,
@@ -30,6 +33,7 @@ impl FromStrAndSpan for PrismaType {
     fn from_str_and_span(s: &str, span: ast::Span) -> Result<Self, DatamodelError> {
         match s {
             "Int" => Ok(PrismaType::Int),
+            "BigInt" => Ok(PrismaType::BigInt),
             "Float" => Ok(PrismaType::Float),
             "Decimal" => Ok(PrismaType::Decimal),
             "Boolean" => Ok(PrismaType::Boolean),
@@ -44,6 +48,7 @@ impl ToString for PrismaType {
     fn to_string(&self) -> String {
         match self {
             PrismaType::Int => String::from("Int"),
+            PrismaType::BigInt => String::from("BigInt"),
             PrismaType::Float => String::from("Float"),
             PrismaType::Decimal => String::from("Decimal"),
             PrismaType::Boolean => String::from("Boolean"),
@@ -57,8 +62,9 @@ impl ToString for PrismaType {
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub enum PrismaValue {
     Int(i32),
+    BigInt(i64),
     Float(f32),
-    Decimal(f32),
+    Decimal(Decimal),
     Boolean(bool),
     String(String),
     DateTime(DateTime<Utc>),
@@ -70,6 +76,7 @@ impl PrismaValue {
     fn get_type(&self) -> PrismaType {
         match self {
             PrismaValue::Int(_) => PrismaType::Int,
+            PrismaValue::BigInt(_) => PrismaType::BigInt,
             PrismaValue::Float(_) => PrismaType::Float,
             PrismaValue::Decimal(_) => PrismaType::Decimal,
             PrismaValue::Boolean(_) => PrismaType::Boolean,
@@ -87,6 +94,7 @@ impl ToString for PrismaValue {
     fn to_string(&self) -> String {
         match self {
             PrismaValue::Int(val) => val.to_string(),
+            PrismaValue::BigInt(val) => val.to_string(),
             PrismaValue::Float(val) => val.to_string(),
             PrismaValue::Decimal(val) => val.to_string(),
             PrismaValue::Boolean(val) => val.to_string(),
@@ -1,5 +1,12 @@
 pub trait NameNormalizer {
     fn camel_case(&self) -> String;
+
+    /// Whether the name can be used as is for a model, field or enum value.
+    fn is_valid_identifier(&self) -> bool;
+
+    /// Turns the name into a valid identifier: characters other than letters, digits and
+    /// underscores become underscores, and everything before the first letter is dropped.
+    fn sanitize(&self) -> String;
 }
 
 impl NameNormalizer for String {
@@ -10,6 +17,28 @@ impl NameNormalizer for String {
             Some(f) => f.to_lowercase().collect::<String>() + c.as_str(),
         }
     }
+
+    fn is_valid_identifier(&self) -> bool {
+        let mut c = self.chars();
+        match c.next() {
+            Some(f) if f.is_ascii_alphabetic() => c.all(|c| c.is_ascii_alphanumeric() || c == '_'),
+            _ => false,
+        }
+    }
+
+    fn sanitize(&self) -> String {
+        let sanitized: String = self
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .skip_while(|c| !c.is_ascii_alphabetic())
+            .collect();
+
+        if sanitized.is_empty() {
+            String::from("unnamed")
+        } else {
+            sanitized
+        }
+    }
 }
 
 pub struct DefaultNames {}
@@ -5,7 +5,7 @@ use crate::error::DatamodelError;
 use super::functions::FunctionalEvaluator;
 use super::interpolation::StringInterpolator;
 use super::FromStrAndSpan;
-use super::{PrismaType, PrismaValue};
+use super::{Decimal, PrismaType, PrismaValue};
 use chrono::{DateTime, Utc};
 use std::error;
 
@@ -74,12 +74,17 @@ impl ValueValidator {
         match &self.value {
             MaybeExpression::Value(_, _) => match scalar_type {
                 PrismaType::Int => self.as_int().map(dml::Value::Int),
+                PrismaType::BigInt => self.as_big_int().map(dml::Value::BigInt),
                 PrismaType::Float => self.as_float().map(dml::Value::Float),
                 PrismaType::Decimal => self.as_decimal().map(dml::Value::Decimal),
                 PrismaType::Boolean => self.as_bool().map(dml::Value::Boolean),
                 PrismaType::DateTime => self.as_date_time().map(dml::Value::DateTime),
                 PrismaType::String => self.as_str().map(dml::Value::String),
             },
+            // Database expressions take the type of the field they are the default of.
+            MaybeExpression::Expression(dml::Value::Expression(name, _, args), _) if name == "dbgenerated" => {
+                Ok(dml::Value::Expression(name.clone(), scalar_type, args.clone()))
+            }
             MaybeExpression::Expression(expr, _) => {
                 if expr.get_type() == scalar_type {
                     Ok(expr.clone())
@@ -157,6 +162,19 @@ impl ValueValidator {
         }
     }
 
+    /// Tries to convert the wrapped value to a Prisma BigInt.
+    pub fn as_big_int(&self) -> Result<i64, DatamodelError> {
+        match &self.value {
+            MaybeExpression::Value(_, ast::Expression::NumericValue(value, _)) => {
+                self.wrap_error_from_result(value.parse::<i64>(), "numeric")
+            }
+            MaybeExpression::Value(_, ast::Expression::Any(value, _)) => {
+                self.wrap_error_from_result(value.parse::<i64>(), "numeric")
+            }
+            _ => Err(self.construct_error("numeric")),
+        }
+    }
+
     /// Tries to convert the wrapped value to a Prisma Float.
     pub fn as_float(&self) -> Result<f32, DatamodelError> {
         match &self.value {
@@ -170,15 +188,15 @@ impl ValueValidator {
         }
     }
 
-    // TODO: Ask which decimal type to take.
     /// Tries to convert the wrapped value to a Prisma Decimal.
-    pub fn as_decimal(&self) -> Result<f32, DatamodelError> {
+    /// The literal is parsed exactly, without going through a binary float.
+    pub fn as_decimal(&self) -> Result<Decimal, DatamodelError> {
         match &self.value {
             MaybeExpression::Value(_, ast::Expression::NumericValue(value, _)) => {
-                self.wrap_error_from_result(value.parse::<f32>(), "numeric")
+                self.wrap_error_from_result(value.parse::<Decimal>(), "numeric")
             }
             MaybeExpression::Value(_, ast::Expression::Any(value, _)) => {
-                self.wrap_error_from_result(value.parse::<f32>(), "numeric")
+                self.wrap_error_from_result(value.parse::<Decimal>(), "numeric")
             }
             _ => Err(self.construct_error("numeric")),
         }
@@ -283,6 +301,7 @@ impl Into<ast::Expression> for &dml::Value {
             dml::Value::Decimal(value) => ast::Expression::NumericValue(value.to_string(), ast::Span::empty()),
             dml::Value::Float(value) => ast::Expression::NumericValue(value.to_string(), ast::Span::empty()),
             dml::Value::Int(value) => ast::Expression::NumericValue(value.to_string(), ast::Span::empty()),
+            dml::Value::BigInt(value) => ast::Expression::NumericValue(value.to_string(), ast::Span::empty()),
             dml::Value::Expression(name, _, args) => ast::Expression::Function(
                 name.clone(),
                 args.iter().map(|a| a.into()).collect(),
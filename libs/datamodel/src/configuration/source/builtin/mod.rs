@@ -1,5 +1,6 @@
 mod mysql_source;
 mod mysql_source_definition;
+mod native_type_directive;
 mod postgres_source;
 mod postgres_source_definition;
 mod sqlite_source;
@@ -7,7 +8,18 @@ mod sqlite_source_definition;
 
 pub use mysql_source::*;
 pub use mysql_source_definition::*;
+pub use native_type_directive::*;
 pub use postgres_source::*;
 pub use postgres_source_definition::*;
 pub use sqlite_source::*;
 pub use sqlite_source_definition::*;
+
+use crate::{common::argument::Arguments, error::DatamodelError};
+
+/// Parses the optional `schemas` list of a datasource.
+fn parse_schemas(arguments: &mut Arguments) -> Result<Vec<String>, DatamodelError> {
+    match arguments.optional_arg("schemas") {
+        Some(schemas) => schemas?.as_array()?.iter().map(|schema| schema.as_str()).collect(),
+        None => Ok(Vec::new()),
+    }
+}
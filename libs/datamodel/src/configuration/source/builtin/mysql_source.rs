@@ -1,10 +1,12 @@
-use crate::{configuration::*, dml, validator::directive::DirectiveValidator};
+use super::{NativeTypeArguments, NativeTypeDirectiveValidator};
+use crate::{common::PrismaType, configuration::*, dml, validator::directive::DirectiveValidator};
 pub const MYSQL_SOURCE_NAME: &str = "mysql";
 
 pub struct MySqlSource {
     pub(super) name: String,
     pub(super) url: StringFromEnvVar,
     pub(super) documentation: Option<String>,
+    pub(super) schemas: Vec<String>,
 }
 
 impl Source for MySqlSource {
@@ -32,7 +34,28 @@ impl Source for MySqlSource {
     }
 
     fn get_field_directives(&self) -> Vec<Box<dyn DirectiveValidator<dml::Field>>> {
-        vec![]
+        use PrismaType::*;
+
+        vec![
+            NativeTypeDirectiveValidator::boxed("TinyInt", &[Boolean], NativeTypeArguments::None),
+            NativeTypeDirectiveValidator::boxed("SmallInt", &[Int], NativeTypeArguments::None),
+            NativeTypeDirectiveValidator::boxed("MediumInt", &[Int], NativeTypeArguments::None),
+            NativeTypeDirectiveValidator::boxed("Int", &[Int], NativeTypeArguments::None),
+            NativeTypeDirectiveValidator::boxed("BigInt", &[BigInt], NativeTypeArguments::None),
+            NativeTypeDirectiveValidator::boxed("Float", &[Float], NativeTypeArguments::None),
+            NativeTypeDirectiveValidator::boxed("Double", &[Float], NativeTypeArguments::None),
+            NativeTypeDirectiveValidator::boxed("Decimal", &[Decimal, Float], NativeTypeArguments::PrecisionAndScale),
+            NativeTypeDirectiveValidator::boxed("VarChar", &[String], NativeTypeArguments::Single),
+            NativeTypeDirectiveValidator::boxed("Char", &[String], NativeTypeArguments::Single),
+            NativeTypeDirectiveValidator::boxed("Text", &[String], NativeTypeArguments::None),
+            NativeTypeDirectiveValidator::boxed("MediumText", &[String], NativeTypeArguments::None),
+            NativeTypeDirectiveValidator::boxed("LongText", &[String], NativeTypeArguments::None),
+            NativeTypeDirectiveValidator::boxed("Json", &[String], NativeTypeArguments::None),
+            NativeTypeDirectiveValidator::boxed("DateTime", &[DateTime], NativeTypeArguments::Single),
+            NativeTypeDirectiveValidator::boxed("Timestamp", &[DateTime], NativeTypeArguments::Single),
+            NativeTypeDirectiveValidator::boxed("Date", &[DateTime], NativeTypeArguments::None),
+            NativeTypeDirectiveValidator::boxed("Time", &[DateTime], NativeTypeArguments::Single),
+        ]
     }
 
     fn get_model_directives(&self) -> Vec<Box<dyn DirectiveValidator<dml::Model>>> {
@@ -46,4 +69,8 @@ impl Source for MySqlSource {
     fn documentation(&self) -> &Option<String> {
         &self.documentation
     }
+
+    fn schemas(&self) -> &[String] {
+        &self.schemas
+    }
 }
@@ -1,4 +1,4 @@
-use super::{MySqlSource, MYSQL_SOURCE_NAME};
+use super::{parse_schemas, MySqlSource, MYSQL_SOURCE_NAME};
 use crate::{common::argument::Arguments, configuration::*, error::DatamodelError};
 
 pub struct MySqlSourceDefinition {}
@@ -18,13 +18,14 @@ impl SourceDefinition for MySqlSourceDefinition {
         &self,
         name: &str,
         url: StringFromEnvVar,
-        _arguments: &mut Arguments,
+        arguments: &mut Arguments,
         documentation: &Option<String>,
     ) -> Result<Box<dyn Source>, DatamodelError> {
         Ok(Box::new(MySqlSource {
             name: String::from(name),
             url: url,
             documentation: documentation.clone(),
+            schemas: parse_schemas(arguments)?,
         }))
     }
 }
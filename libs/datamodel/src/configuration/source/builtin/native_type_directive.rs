@@ -0,0 +1,179 @@
+use crate::common::argument::Arguments;
+use crate::common::PrismaType;
+use crate::error::DatamodelError;
+use crate::validator::directive::DirectiveValidator;
+use crate::{ast, dml};
+
+/// The arguments a native type accepts.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NativeTypeArguments {
+    /// The type takes no arguments, e.g. `@db.Text`.
+    None,
+    /// The type takes an optional length or precision, e.g. `@db.VarChar(200)`.
+    Single,
+    /// The type takes an optional precision and scale, e.g. `@db.Decimal(10, scale: 2)`.
+    PrecisionAndScale,
+}
+
+/// A field directive that pins a field to a native database type, e.g. `@db.VarChar(200)`.
+///
+/// The directive replaces the field type with `FieldType::ConnectorSpecific`. The
+/// connector type is stored in a connector agnostic notation, for example `VarChar(200)`
+/// or `Decimal(10,2)`, and it is up to the connectors to map it to their SQL types.
+pub struct NativeTypeDirectiveValidator {
+    name: &'static str,
+    compatible_types: &'static [PrismaType],
+    arguments: NativeTypeArguments,
+}
+
+impl NativeTypeDirectiveValidator {
+    pub fn new(
+        name: &'static str,
+        compatible_types: &'static [PrismaType],
+        arguments: NativeTypeArguments,
+    ) -> NativeTypeDirectiveValidator {
+        NativeTypeDirectiveValidator {
+            name,
+            compatible_types,
+            arguments,
+        }
+    }
+
+    /// Shorthand for sources to build their lists of native type directives.
+    pub fn boxed(
+        name: &'static str,
+        compatible_types: &'static [PrismaType],
+        arguments: NativeTypeArguments,
+    ) -> Box<dyn DirectiveValidator<dml::Field>> {
+        Box::new(Self::new(name, compatible_types, arguments))
+    }
+
+    fn int_arg(&self, args: &mut Arguments, name: &str) -> Result<Option<i32>, DatamodelError> {
+        match args.optional_arg(name) {
+            None => Ok(None),
+            Some(value) => {
+                let value = value?;
+                let int = value.as_int().map_err(|err| self.parser_error(&err))?;
+
+                if int < 0 {
+                    return Err(DatamodelError::new_directive_validation_error(
+                        "Native type arguments can not be negative.",
+                        self.name,
+                        value.span(),
+                    ));
+                }
+
+                Ok(Some(int))
+            }
+        }
+    }
+}
+
+/// Splits a connector type like `Decimal(10,2)` into its name and arguments.
+pub fn parse_connector_type(connector_type: &str) -> (&str, Vec<&str>) {
+    match connector_type.find('(') {
+        Some(start) => {
+            let name = &connector_type[..start];
+            let args = connector_type[start + 1..]
+                .trim_end_matches(')')
+                .split(',')
+                .map(|arg| arg.trim())
+                .filter(|arg| !arg.is_empty())
+                .collect();
+
+            (name, args)
+        }
+        None => (connector_type, Vec::new()),
+    }
+}
+
+impl DirectiveValidator<dml::Field> for NativeTypeDirectiveValidator {
+    fn directive_name(&self) -> &str {
+        self.name
+    }
+
+    fn validate_and_apply(&self, args: &mut Arguments, field: &mut dml::Field) -> Result<(), DatamodelError> {
+        let base_type = match &field.field_type {
+            dml::FieldType::Base(base_type) => *base_type,
+            dml::FieldType::ConnectorSpecific { .. } => {
+                return self.error("A field can only have a single native type.", args.span())
+            }
+            _ => return self.error("Native types can only be used on scalar fields.", args.span()),
+        };
+
+        if !self.compatible_types.contains(&base_type) {
+            return self.error(
+                &format!(
+                    "Native type {} can not be used on a field of type {}.",
+                    self.name,
+                    base_type.to_string()
+                ),
+                args.span(),
+            );
+        }
+
+        let connector_type = match self.arguments {
+            NativeTypeArguments::None => self.name.to_string(),
+            NativeTypeArguments::Single => match self.int_arg(args, "")? {
+                Some(length) => format!("{}({})", self.name, length),
+                None => self.name.to_string(),
+            },
+            NativeTypeArguments::PrecisionAndScale => {
+                let precision = self.int_arg(args, "")?;
+                let scale = self.int_arg(args, "scale")?;
+
+                match (precision, scale) {
+                    (Some(precision), Some(scale)) if scale > precision => {
+                        return self.error("The scale of a native type can not exceed its precision.", args.span())
+                    }
+                    (Some(precision), Some(scale)) => format!("{}({},{})", self.name, precision, scale),
+                    (Some(precision), None) => format!("{}({})", self.name, precision),
+                    (None, Some(_)) => {
+                        return self.error("A scale can only be given together with a precision.", args.span())
+                    }
+                    (None, None) => self.name.to_string(),
+                }
+            }
+        };
+
+        field.field_type = dml::FieldType::ConnectorSpecific {
+            base_type,
+            connector_type: Some(connector_type),
+        };
+
+        Ok(())
+    }
+
+    fn serialize(
+        &self,
+        field: &dml::Field,
+        _datamodel: &dml::Datamodel,
+    ) -> Result<Vec<ast::Directive>, DatamodelError> {
+        let connector_type = match &field.field_type {
+            dml::FieldType::ConnectorSpecific {
+                connector_type: Some(connector_type),
+                ..
+            } => connector_type,
+            _ => return Ok(vec![]),
+        };
+
+        let (name, type_args) = parse_connector_type(connector_type);
+
+        if name != self.name {
+            return Ok(vec![]);
+        }
+
+        let arguments = type_args
+            .into_iter()
+            .zip(&["", "scale"])
+            .map(|(value, arg_name)| {
+                ast::Argument::new(
+                    arg_name,
+                    ast::Expression::NumericValue(value.to_string(), ast::Span::empty()),
+                )
+            })
+            .collect();
+
+        Ok(vec![ast::Directive::new(self.name, arguments)])
+    }
+}
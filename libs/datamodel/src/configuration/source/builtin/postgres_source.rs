@@ -1,10 +1,12 @@
-use crate::{configuration::*, dml, validator::directive::DirectiveValidator};
+use super::{NativeTypeArguments, NativeTypeDirectiveValidator};
+use crate::{common::PrismaType, configuration::*, dml, validator::directive::DirectiveValidator};
 pub const POSTGRES_SOURCE_NAME: &str = "postgresql";
 
 pub struct PostgresSource {
     pub(super) name: String,
     pub(super) url: StringFromEnvVar,
     pub(super) documentation: Option<String>,
+    pub(super) schemas: Vec<String>,
 }
 
 impl Source for PostgresSource {
@@ -27,7 +29,28 @@ impl Source for PostgresSource {
         };
     }
     fn get_field_directives(&self) -> Vec<Box<dyn DirectiveValidator<dml::Field>>> {
-        vec![]
+        use PrismaType::*;
+
+        vec![
+            NativeTypeDirectiveValidator::boxed("SmallInt", &[Int], NativeTypeArguments::None),
+            NativeTypeDirectiveValidator::boxed("Integer", &[Int], NativeTypeArguments::None),
+            NativeTypeDirectiveValidator::boxed("BigInt", &[BigInt], NativeTypeArguments::None),
+            NativeTypeDirectiveValidator::boxed("Real", &[Float], NativeTypeArguments::None),
+            NativeTypeDirectiveValidator::boxed("DoublePrecision", &[Float], NativeTypeArguments::None),
+            NativeTypeDirectiveValidator::boxed("Decimal", &[Decimal, Float], NativeTypeArguments::PrecisionAndScale),
+            NativeTypeDirectiveValidator::boxed("Numeric", &[Decimal, Float], NativeTypeArguments::PrecisionAndScale),
+            NativeTypeDirectiveValidator::boxed("VarChar", &[String], NativeTypeArguments::Single),
+            NativeTypeDirectiveValidator::boxed("Char", &[String], NativeTypeArguments::Single),
+            NativeTypeDirectiveValidator::boxed("Text", &[String], NativeTypeArguments::None),
+            NativeTypeDirectiveValidator::boxed("Uuid", &[String], NativeTypeArguments::None),
+            NativeTypeDirectiveValidator::boxed("Json", &[String], NativeTypeArguments::None),
+            NativeTypeDirectiveValidator::boxed("JsonB", &[String], NativeTypeArguments::None),
+            NativeTypeDirectiveValidator::boxed("Timestamp", &[DateTime], NativeTypeArguments::Single),
+            NativeTypeDirectiveValidator::boxed("Timestamptz", &[DateTime], NativeTypeArguments::Single),
+            NativeTypeDirectiveValidator::boxed("Date", &[DateTime], NativeTypeArguments::None),
+            NativeTypeDirectiveValidator::boxed("Time", &[DateTime], NativeTypeArguments::Single),
+            NativeTypeDirectiveValidator::boxed("Boolean", &[Boolean], NativeTypeArguments::None),
+        ]
     }
     fn get_model_directives(&self) -> Vec<Box<dyn DirectiveValidator<dml::Model>>> {
         vec![]
@@ -38,4 +61,8 @@ impl Source for PostgresSource {
     fn documentation(&self) -> &Option<String> {
         &self.documentation
     }
+
+    fn schemas(&self) -> &[String] {
+        &self.schemas
+    }
 }
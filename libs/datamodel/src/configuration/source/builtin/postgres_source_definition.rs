@@ -1,4 +1,4 @@
-use super::{PostgresSource, POSTGRES_SOURCE_NAME};
+use super::{parse_schemas, PostgresSource, POSTGRES_SOURCE_NAME};
 use crate::{common::argument::Arguments, configuration::*, error::DatamodelError};
 
 pub struct PostgresSourceDefinition {}
@@ -18,13 +18,14 @@ impl SourceDefinition for PostgresSourceDefinition {
         &self,
         name: &str,
         url: StringFromEnvVar,
-        _arguments: &mut Arguments,
+        arguments: &mut Arguments,
         documentation: &Option<String>,
     ) -> Result<Box<dyn Source>, DatamodelError> {
         Ok(Box::new(PostgresSource {
             name: String::from(name),
             url: url,
             documentation: documentation.clone(),
+            schemas: parse_schemas(arguments)?,
         }))
     }
 }
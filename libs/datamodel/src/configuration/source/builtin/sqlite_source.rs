@@ -1,4 +1,5 @@
-use crate::{configuration::*, dml, validator::directive::DirectiveValidator};
+use super::{NativeTypeArguments, NativeTypeDirectiveValidator};
+use crate::{common::PrismaType, configuration::*, dml, validator::directive::DirectiveValidator};
 pub const SQLITE_SOURCE_NAME: &str = "sqlite";
 
 pub struct SqliteSource {
@@ -29,7 +30,14 @@ impl Source for SqliteSource {
     }
 
     fn get_field_directives(&self) -> Vec<Box<dyn DirectiveValidator<dml::Field>>> {
-        vec![]
+        use PrismaType::*;
+
+        vec![
+            NativeTypeDirectiveValidator::boxed("Integer", &[Int], NativeTypeArguments::None),
+            NativeTypeDirectiveValidator::boxed("Real", &[Float], NativeTypeArguments::None),
+            NativeTypeDirectiveValidator::boxed("Numeric", &[Decimal, Float], NativeTypeArguments::PrecisionAndScale),
+            NativeTypeDirectiveValidator::boxed("Text", &[String], NativeTypeArguments::None),
+        ]
     }
     fn get_model_directives(&self) -> Vec<Box<dyn DirectiveValidator<dml::Model>>> {
         vec![]
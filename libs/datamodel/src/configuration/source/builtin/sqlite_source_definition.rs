@@ -18,9 +18,16 @@ impl SourceDefinition for SqliteSourceDefinition {
         &self,
         name: &str,
         url: StringFromEnvVar,
-        _arguments: &mut Arguments,
+        arguments: &mut Arguments,
         documentation: &Option<String>,
     ) -> Result<Box<dyn Source>, DatamodelError> {
+        if let Some(schemas) = arguments.optional_arg("schemas") {
+            return Err(DatamodelError::new_validation_error(
+                "The `schemas` property is not supported, SQLite databases do not have schemas",
+                schemas?.span(),
+            ));
+        }
+
         Ok(Box::new(SqliteSource {
             name: String::from(name),
             url: url,
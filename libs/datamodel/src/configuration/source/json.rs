@@ -11,6 +11,8 @@ pub struct SourceConfig {
     pub config: HashMap<String, String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub documentation: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub schemas: Vec<String>,
 }
 
 pub fn render_sources_to_json_value(sources: &[Box<dyn configuration::Source>]) -> serde_json::Value {
@@ -40,6 +42,7 @@ fn source_to_json_struct(source: &dyn configuration::Source) -> SourceConfig {
         url: source.url().clone(),
         documentation: source.documentation().clone(),
         config: source.config().clone(),
+        schemas: source.schemas().to_vec(),
     }
 }
 
@@ -92,6 +95,10 @@ fn source_from_json(source: &SourceConfig, loader: &configuration::SourceLoader)
         arguments.push(ast::Argument::new_string(&key, &value));
     }
 
+    if !source.schemas.is_empty() {
+        arguments.push(super::serializer::schemas_argument(&source.schemas));
+    }
+
     let ast_source = ast::SourceConfig {
         name: ast::Identifier::new(&source.name),
         properties: arguments,
@@ -35,6 +35,10 @@ impl SourceSerializer {
             arguments.push(ast::Argument::new_string(&key, &value));
         }
 
+        if !source.schemas().is_empty() {
+            arguments.push(schemas_argument(source.schemas()));
+        }
+
         ast::SourceConfig {
             name: ast::Identifier::new(source.name()),
             properties: arguments,
@@ -43,3 +47,13 @@ impl SourceSerializer {
         }
     }
 }
+
+/// Renders the `schemas` property of a source.
+pub(crate) fn schemas_argument(schemas: &[String]) -> ast::Argument {
+    let values = schemas
+        .iter()
+        .map(|schema| ast::Expression::StringValue(schema.clone(), ast::Span::empty()))
+        .collect();
+
+    ast::Argument::new_array("schemas", values)
+}
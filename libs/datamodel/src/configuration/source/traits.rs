@@ -42,6 +42,12 @@ pub trait Source {
 
     /// Documentation of this source.
     fn documentation(&self) -> &Option<String>;
+
+    /// The database schemas listed in the `schemas` property. Models are placed in one of them with
+    /// `@@schema`, the other ones live in the schema of the connection.
+    fn schemas(&self) -> &[String] {
+        &[]
+    }
 }
 
 /// Trait for source definitions.
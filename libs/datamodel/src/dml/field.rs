@@ -1,5 +1,5 @@
 use super::*;
-use crate::common::{PrismaType, PrismaValue};
+use crate::common::{Decimal, PrismaType, PrismaValue};
 
 /// Datamodel field arity.
 #[derive(Debug, PartialEq, Copy, Clone)]
@@ -31,6 +31,26 @@ pub enum FieldType {
     Base(PrismaType),
 }
 
+impl FieldType {
+    /// Returns the scalar type of a base or connector specific field type.
+    pub fn scalar_type(&self) -> Option<PrismaType> {
+        match self {
+            FieldType::Base(tpe) => Some(*tpe),
+            FieldType::ConnectorSpecific { base_type, .. } => Some(*base_type),
+            _ => None,
+        }
+    }
+}
+
+/// A validation rule restricting the values of a scalar field.
+#[derive(Debug, PartialEq, Clone)]
+pub enum FieldValidator {
+    /// `@length(min: .., max: ..)`, bounds on the number of characters of a string.
+    Length { min: Option<i32>, max: Option<i32> },
+    /// `@range(min: .., max: ..)`, inclusive bounds on a numeric value.
+    Range { min: Option<Decimal>, max: Option<Decimal> },
+}
+
 /// Holds information about an id, or priamry key.
 #[derive(Debug, PartialEq, Clone)]
 pub struct IdInfo {
@@ -69,6 +89,8 @@ pub struct Field {
     /// If set, signals that this field is updated_at and will be updated to now()
     /// automatically.
     pub is_updated_at: bool,
+    /// Validation rules for the values of this field.
+    pub validators: Vec<FieldValidator>,
 }
 
 impl WithName for Field {
@@ -104,6 +126,7 @@ impl Field {
             documentation: None,
             is_generated: false,
             is_updated_at: false,
+            validators: vec![],
         }
     }
     /// Creates a new field with the given name and type, marked as generated and optional.
@@ -120,6 +143,7 @@ impl Field {
             documentation: None,
             is_generated: true,
             is_updated_at: false,
+            validators: vec![],
         }
     }
 }
@@ -11,14 +11,24 @@ pub struct Model {
     pub documentation: Option<String>,
     /// The database internal name of this model.
     pub database_name: Option<String>,
+    /// The database schema of this model, set with `@@schema`. Without one, the model lives in the
+    /// schema of the connection.
+    pub schema: Option<String>,
     /// Indicates if this model is embedded or not.
     pub is_embedded: bool,
+    /// Indicates if this model is backed by a database view, set with `@@view`. Views are read only.
+    pub is_view: bool,
     /// Describes Composite Indexes
     pub indexes: Vec<IndexDefinition>,
     /// Describes Composite Primary Keys
     pub id_fields: Vec<String>,
     /// Indicates if this model is generated.
     pub is_generated: bool,
+    /// Check constraints defined with `@@check`.
+    pub check_constraints: Vec<CheckConstraint>,
+    /// Indicates if this model is rendered commented out, because it can't be used as is. Only
+    /// introspection sets it, for views without a unique identifier.
+    pub is_commented_out: bool,
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -34,6 +44,13 @@ pub enum IndexType {
     Normal,
 }
 
+/// A check constraint on a model. The expression is passed to the database as is.
+#[derive(Debug, PartialEq, Clone)]
+pub struct CheckConstraint {
+    pub name: Option<String>,
+    pub expression: String,
+}
+
 impl Model {
     /// Creates a new model with the given name.
     pub fn new(name: &str) -> Model {
@@ -44,8 +61,12 @@ impl Model {
             id_fields: vec![],
             documentation: None,
             database_name: None,
+            schema: None,
             is_embedded: false,
+            is_view: false,
             is_generated: false,
+            check_constraints: vec![],
+            is_commented_out: false,
         }
     }
 
@@ -13,7 +13,10 @@ pub struct RelationInfo {
     pub name: String,
     /// A strategy indicating what happens when
     /// a related node is deleted.
-    pub on_delete: OnDeleteStrategy,
+    pub on_delete: ReferentialAction,
+    /// A strategy indicating what happens when
+    /// the referenced fields of a related node are updated.
+    pub on_update: ReferentialAction,
 }
 
 impl RelationInfo {
@@ -24,7 +27,8 @@ impl RelationInfo {
             to: String::from(to),
             to_fields: Vec::new(),
             name: String::new(),
-            on_delete: OnDeleteStrategy::None,
+            on_delete: ReferentialAction::None,
+            on_update: ReferentialAction::None,
         }
     }
     pub fn new_with_field(to: &str, to_field: &str) -> RelationInfo {
@@ -32,7 +36,8 @@ impl RelationInfo {
             to: String::from(to),
             to_fields: vec![String::from(to_field)],
             name: String::new(),
-            on_delete: OnDeleteStrategy::None,
+            on_delete: ReferentialAction::None,
+            on_update: ReferentialAction::None,
         }
     }
 
@@ -41,34 +46,49 @@ impl RelationInfo {
             to: String::from(to),
             to_fields,
             name: String::new(),
-            on_delete: OnDeleteStrategy::None,
+            on_delete: ReferentialAction::None,
+            on_update: ReferentialAction::None,
         }
     }
 }
 
 /// Describes what happens when related nodes
-/// are deleted.
+/// are deleted or updated.
 #[derive(Debug, Copy, PartialEq, Clone)]
-pub enum OnDeleteStrategy {
-    Cascade,
+pub enum ReferentialAction {
+    /// No action was specified, the connector decides what happens.
     None,
+    /// Deletes or updates the records referencing the related node.
+    Cascade,
+    /// Prevents the related node from being deleted or updated while it is referenced.
+    Restrict,
+    /// Sets the referencing fields to null.
+    SetNull,
+    /// Sets the referencing fields to their default values.
+    SetDefault,
 }
 
-impl FromStrAndSpan for OnDeleteStrategy {
+impl FromStrAndSpan for ReferentialAction {
     fn from_str_and_span(s: &str, span: ast::Span) -> Result<Self, DatamodelError> {
         match s {
-            "CASCADE" => Ok(OnDeleteStrategy::Cascade),
-            "NONE" => Ok(OnDeleteStrategy::None),
-            _ => Err(DatamodelError::new_literal_parser_error("onDelete strategy", s, span)),
+            "CASCADE" => Ok(ReferentialAction::Cascade),
+            "RESTRICT" => Ok(ReferentialAction::Restrict),
+            "SET_NULL" => Ok(ReferentialAction::SetNull),
+            "SET_DEFAULT" => Ok(ReferentialAction::SetDefault),
+            "NONE" => Ok(ReferentialAction::None),
+            _ => Err(DatamodelError::new_literal_parser_error("referential action", s, span)),
         }
     }
 }
 
-impl ToString for OnDeleteStrategy {
+impl ToString for ReferentialAction {
     fn to_string(&self) -> String {
         match self {
-            OnDeleteStrategy::Cascade => String::from("CASCADE"),
-            OnDeleteStrategy::None => String::from("NONE"),
+            ReferentialAction::Cascade => String::from("CASCADE"),
+            ReferentialAction::Restrict => String::from("RESTRICT"),
+            ReferentialAction::SetNull => String::from("SET_NULL"),
+            ReferentialAction::SetDefault => String::from("SET_DEFAULT"),
+            ReferentialAction::None => String::from("NONE"),
         }
     }
 }
@@ -31,12 +31,16 @@ fn model_from_dmmf(model: &Model) -> dml::Model {
     dml::Model {
         name: model.name.clone(),
         database_name: model.db_name.clone(),
+        schema: model.schema.clone(),
         is_embedded: model.is_embedded,
+        is_view: model.is_view,
         fields: model.fields.iter().map(&field_from_dmmf).collect(),
         indexes: vec![],
         id_fields: model.id_fields.clone(),
+        check_constraints: vec![],
         documentation: model.documentation.clone(),
         is_generated: model.is_generated.unwrap_or(false),
+        is_commented_out: false,
     }
 }
 
@@ -64,6 +68,7 @@ fn field_from_dmmf(field: &Field) -> dml::Field {
         scalar_list_strategy: None,
         is_generated: field.is_generated.unwrap_or(false),
         is_updated_at: field.is_updated_at.unwrap_or(false),
+        validators: vec![],
         documentation: field.documentation.clone(),
     }
 }
@@ -76,7 +81,12 @@ fn default_value_from_serde(container: &Option<serde_json::Value>, field_type: &
             (serde_json::Value::String(val), PrismaType::String) => dml::Value::String(String::from(val.as_str())),
             (serde_json::Value::Number(val), PrismaType::Float) => dml::Value::Float(val.as_f64().unwrap() as f32),
             (serde_json::Value::Number(val), PrismaType::Int) => dml::Value::Int(val.as_i64().unwrap() as i32),
-            (serde_json::Value::Number(val), PrismaType::Decimal) => dml::Value::Decimal(val.as_f64().unwrap() as f32),
+            (serde_json::Value::String(val), PrismaType::BigInt) => dml::Value::BigInt(val.parse().unwrap()),
+            (serde_json::Value::Number(val), PrismaType::BigInt) => dml::Value::BigInt(val.as_i64().unwrap()),
+            (serde_json::Value::String(val), PrismaType::Decimal) => dml::Value::Decimal(val.parse().unwrap()),
+            (serde_json::Value::Number(val), PrismaType::Decimal) => {
+                dml::Value::Decimal(val.to_string().parse().unwrap())
+            }
             (serde_json::Value::String(val), PrismaType::DateTime) => {
                 dml::Value::DateTime(String::from(val.as_str()).parse::<DateTime<Utc>>().unwrap())
             }
@@ -101,6 +111,20 @@ fn type_from_string(scalar: &str) -> PrismaType {
 }
 
 fn function_from_dmmf(func: &Function, expected_type: PrismaType) -> dml::Value {
+    // The only argument of `dbgenerated` is the database expression, which is always a string.
+    if func.name == "dbgenerated" {
+        let args = func
+            .args
+            .iter()
+            .map(|arg| match arg {
+                serde_json::Value::String(expression) => dml::Value::String(expression.clone()),
+                _ => panic!("Expected a string argument for dbgenerated, but got: {}.", arg),
+            })
+            .collect();
+
+        return dml::Value::Expression(func.name.clone(), expected_type, args);
+    }
+
     if !func.args.is_empty() {
         panic!("Function argument deserialization is not supported with DMMF. There are no type annotations yet, so it's not clear which is meant.");
     }
@@ -116,10 +140,10 @@ fn function_from_dmmf(func: &Function, expected_type: PrismaType) -> dml::Value
     dml::Value::Expression(func.name.clone(), expected_type, vec![])
 }
 
-fn get_on_delete_strategy(strategy: &Option<String>) -> dml::OnDeleteStrategy {
+fn get_referential_action(strategy: &Option<String>) -> dml::ReferentialAction {
     match strategy {
-        Some(val) => dml::OnDeleteStrategy::from_str_and_span(&val, Span::empty()).unwrap(),
-        None => dml::OnDeleteStrategy::None,
+        Some(val) => dml::ReferentialAction::from_str_and_span(&val, Span::empty()).unwrap(),
+        None => dml::ReferentialAction::None,
     }
 }
 
@@ -129,7 +153,8 @@ fn get_field_type(field: &Field) -> dml::FieldType {
             to: field.field_type.clone(),
             to_fields: field.relation_to_fields.clone().unwrap_or_default(),
             name: field.relation_name.clone().unwrap_or(String::new()),
-            on_delete: get_on_delete_strategy(&field.relation_on_delete),
+            on_delete: get_referential_action(&field.relation_on_delete),
+            on_update: get_referential_action(&field.relation_on_update),
         }),
         "enum" => dml::FieldType::Enum(field.field_type.clone()),
         "scalar" => dml::FieldType::Base(type_from_string(&field.field_type)),
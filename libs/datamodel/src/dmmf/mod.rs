@@ -32,6 +32,8 @@ pub struct Field {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub relation_on_delete: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub relation_on_update: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_generated: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub is_updated_at: Option<bool>,
@@ -52,7 +54,11 @@ pub struct Function {
 pub struct Model {
     pub name: String,
     pub is_embedded: bool,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub is_view: bool,
     pub db_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
     pub fields: Vec<Field>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub is_generated: Option<bool>,
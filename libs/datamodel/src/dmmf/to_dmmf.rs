@@ -43,7 +43,9 @@ fn model_to_dmmf(model: &dml::Model) -> Model {
     Model {
         name: model.name.clone(),
         db_name: model.database_name.clone(),
+        schema: model.schema.clone(),
         is_embedded: model.is_embedded,
+        is_view: model.is_view,
         fields: model.fields().map(&field_to_dmmf).collect(),
         is_generated: Some(model.is_generated),
         documentation: model.documentation.clone(),
@@ -64,6 +66,7 @@ fn field_to_dmmf(field: &dml::Field) -> Field {
         relation_name: get_relation_name(field),
         relation_to_fields: get_relation_to_fields(field),
         relation_on_delete: get_relation_delete_strategy(field),
+        relation_on_update: get_relation_update_strategy(field),
         field_type: get_field_type(field),
         is_generated: Some(field.is_generated),
         is_updated_at: Some(field.is_updated_at),
@@ -76,7 +79,7 @@ fn get_field_kind(field: &dml::Field) -> String {
         dml::FieldType::Relation(_) => String::from("object"),
         dml::FieldType::Enum(_) => String::from("enum"),
         dml::FieldType::Base(_) => String::from("scalar"),
-        _ => unimplemented!("DMMF does not support field type {:?}", field.field_type),
+        dml::FieldType::ConnectorSpecific { .. } => String::from("scalar"),
     }
 }
 
@@ -107,7 +110,8 @@ fn value_to_serde(value: &dml::Value) -> serde_json::Value {
         dml::Value::ConstantLiteral(val) => serde_json::Value::String(val.clone()),
         dml::Value::Float(val) => serde_json::Value::Number(serde_json::Number::from_f64(*val as f64).unwrap()),
         dml::Value::Int(val) => serde_json::Value::Number(serde_json::Number::from_f64(*val as f64).unwrap()),
-        dml::Value::Decimal(val) => serde_json::Value::Number(serde_json::Number::from_f64(*val as f64).unwrap()),
+        dml::Value::BigInt(val) => serde_json::Value::String(val.to_string()),
+        dml::Value::Decimal(val) => serde_json::Value::String(val.to_string()),
         dml::Value::DateTime(val) => serde_json::Value::String(val.to_rfc3339()),
         dml::Value::Expression(name, return_type, args) => function_to_serde(&name, *return_type, &args),
     }
@@ -143,3 +147,10 @@ fn get_relation_delete_strategy(field: &dml::Field) -> Option<String> {
         _ => None,
     }
 }
+
+fn get_relation_update_strategy(field: &dml::Field) -> Option<String> {
+    match &field.field_type {
+        dml::FieldType::Relation(relation_info) => Some(relation_info.on_update.to_string()),
+        _ => None,
+    }
+}
@@ -83,6 +83,49 @@ pub fn lift_ast(ast: &ast::SchemaAst) -> Result<Datamodel, error::ErrorCollectio
     }
 }
 
+/// Validates a [Schema AST](/ast/struct.SchemaAst.html) without datasource blocks, like the
+/// ones calculated from migration steps, and returns its [Datamodel](/struct.Datamodel.html).
+///
+/// Scoped directives, e.g. native types, are resolved with the builtin source of the given connector type.
+pub fn lift_ast_with_connector_type(
+    ast: &ast::SchemaAst,
+    connector_type: &str,
+) -> Result<Datamodel, error::ErrorCollection> {
+    let mut scopes: Vec<&str> = Vec::new();
+
+    for model in ast.tops.iter().filter_map(ast::Top::as_model) {
+        for directive in model.fields.iter().flat_map(|field| field.directives.iter()) {
+            if let Some(idx) = directive.name.name.find('.') {
+                let scope = &directive.name.name[..idx];
+
+                if !scopes.contains(&scope) {
+                    scopes.push(scope);
+                }
+            }
+        }
+    }
+
+    let mut sources = Vec::new();
+
+    if let Some(definition) = get_builtin_sources()
+        .into_iter()
+        .find(|definition| definition.connector_type() == connector_type)
+    {
+        for scope in scopes {
+            let mut arguments = common::argument::Arguments::new(&[], ast::Span::empty());
+            let url = StringFromEnvVar {
+                from_env_var: None,
+                value: String::new(),
+            };
+
+            sources.push(definition.create(scope, url, &mut arguments, &None)?);
+        }
+    }
+
+    let validator = ValidationPipeline::with_sources(&sources);
+    validator.validate(&ast)
+}
+
 /// Parses and validates a datamodel string, using core attributes only.
 /// In case of an error, a pretty, colorful string is returned.
 pub fn parse_datamodel_or_pretty_error(datamodel_string: &str, file_name: &str) -> Result<Datamodel, String> {
@@ -177,6 +220,17 @@ pub fn render_datamodel_to_string(datamodel: &dml::Datamodel) -> Result<String,
     Ok(writable_string.into())
 }
 
+/// Renders to a return string, including the native type directives of the given sources.
+pub fn render_datamodel_with_sources_to_string(
+    datamodel: &dml::Datamodel,
+    sources: &[Box<dyn configuration::Source>],
+) -> Result<String, error::ErrorCollection> {
+    let mut writable_string = common::WritableString::new();
+    let lowered = validator::LowerDmlToAst::with_sources(sources).lower(datamodel)?;
+    render_schema_ast_to(&mut writable_string, &lowered, 2);
+    Ok(writable_string.into())
+}
+
 /// Renders an AST to a string.
 pub fn render_schema_ast_to_string(schema: &SchemaAst) -> Result<String, error::ErrorCollection> {
     let mut writable_string = common::WritableString::new();
@@ -210,7 +264,7 @@ pub fn render_datamodel_and_config_to(
     datamodel: &dml::Datamodel,
     config: &configuration::Configuration,
 ) -> Result<(), error::ErrorCollection> {
-    let mut lowered = validator::LowerDmlToAst::new().lower(datamodel)?;
+    let mut lowered = validator::LowerDmlToAst::with_sources(&config.datasources).lower(datamodel)?;
     SourceSerializer::add_sources_to_ast(&config.datasources, &mut lowered);
     GeneratorLoader::add_generators_to_ast(&config.generators, &mut lowered);
     render_schema_ast_to(stream, &lowered, 2);
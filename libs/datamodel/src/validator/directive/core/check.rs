@@ -0,0 +1,58 @@
+use crate::error::DatamodelError;
+use crate::validator::directive::{Args, DirectiveValidator};
+use crate::{ast, dml};
+
+/// Prismas builtin `@@check` directive.
+pub struct ModelLevelCheckDirectiveValidator {}
+
+impl DirectiveValidator<dml::Model> for ModelLevelCheckDirectiveValidator {
+    fn directive_name(&self) -> &str {
+        "check"
+    }
+
+    fn is_duplicate_definition_allowed(&self) -> bool {
+        true
+    }
+
+    fn validate_and_apply(&self, args: &mut Args, obj: &mut dml::Model) -> Result<(), DatamodelError> {
+        let expression = args
+            .default_arg("expression")?
+            .as_str()
+            .map_err(|err| self.parser_error(&err))?;
+
+        if expression.trim().is_empty() {
+            return self.error("The expression of a check constraint can not be empty.", args.span());
+        }
+
+        let name = match args.optional_arg("name") {
+            Some(name_arg) => Some(name_arg?.as_str()?),
+            None => None,
+        };
+
+        obj.check_constraints.push(dml::CheckConstraint { name, expression });
+
+        Ok(())
+    }
+
+    fn serialize(
+        &self,
+        model: &dml::Model,
+        _datamodel: &dml::Datamodel,
+    ) -> Result<Vec<ast::Directive>, DatamodelError> {
+        let directives = model
+            .check_constraints
+            .iter()
+            .map(|check| {
+                let mut args = vec![ast::Argument::new_string("", &check.expression)];
+
+                if let Some(name) = &check.name {
+                    args.push(ast::Argument::new_string("name", name));
+                }
+
+                ast::Directive::new(self.directive_name(), args)
+            })
+            .collect();
+
+        Ok(directives)
+    }
+}
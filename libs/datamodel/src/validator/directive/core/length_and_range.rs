@@ -0,0 +1,158 @@
+use crate::common::{Decimal, PrismaType};
+use crate::error::DatamodelError;
+use crate::validator::directive::{Args, DirectiveValidator};
+use crate::{ast, dml};
+
+/// Prismas builtin `@length` directive.
+pub struct LengthDirectiveValidator {}
+
+impl DirectiveValidator<dml::Field> for LengthDirectiveValidator {
+    fn directive_name(&self) -> &'static str {
+        &"length"
+    }
+
+    fn validate_and_apply(&self, args: &mut Args, obj: &mut dml::Field) -> Result<(), DatamodelError> {
+        if obj.field_type.scalar_type() != Some(PrismaType::String) {
+            return self.error(
+                "The length of a field can only be restricted on fields of type String.",
+                args.span(),
+            );
+        }
+
+        if obj.arity == dml::FieldArity::List {
+            return self.error("Validators can not be used on list fields.", args.span());
+        }
+
+        let min = self.bound(args, "min")?;
+        let max = self.bound(args, "max")?;
+
+        match (min, max) {
+            (None, None) => return self.error("Either a `min` or a `max` length must be given.", args.span()),
+            (Some(min), _) if min < 0 => return self.error("The minimum length can not be negative.", args.span()),
+            (_, Some(max)) if max < 0 => return self.error("The maximum length can not be negative.", args.span()),
+            (Some(min), Some(max)) if min > max => {
+                return self.error("The minimum length can not exceed the maximum length.", args.span())
+            }
+            _ => (),
+        }
+
+        obj.validators.push(dml::FieldValidator::Length { min, max });
+
+        Ok(())
+    }
+
+    fn serialize(
+        &self,
+        field: &dml::Field,
+        _datamodel: &dml::Datamodel,
+    ) -> Result<Vec<ast::Directive>, DatamodelError> {
+        let directives = field
+            .validators
+            .iter()
+            .filter_map(|validator| match validator {
+                dml::FieldValidator::Length { min, max } => Some(ast::Directive::new(
+                    self.directive_name(),
+                    bound_arguments(
+                        min.as_ref().map(|min| min.to_string()),
+                        max.as_ref().map(|max| max.to_string()),
+                    ),
+                )),
+                _ => None,
+            })
+            .collect();
+
+        Ok(directives)
+    }
+}
+
+impl LengthDirectiveValidator {
+    fn bound(&self, args: &mut Args, name: &str) -> Result<Option<i32>, DatamodelError> {
+        match args.optional_arg(name) {
+            Some(value) => Ok(Some(value?.as_int().map_err(|err| self.parser_error(&err))?)),
+            None => Ok(None),
+        }
+    }
+}
+
+/// Prismas builtin `@range` directive.
+pub struct RangeDirectiveValidator {}
+
+impl DirectiveValidator<dml::Field> for RangeDirectiveValidator {
+    fn directive_name(&self) -> &'static str {
+        &"range"
+    }
+
+    fn validate_and_apply(&self, args: &mut Args, obj: &mut dml::Field) -> Result<(), DatamodelError> {
+        let is_numeric = match obj.field_type.scalar_type() {
+            Some(PrismaType::Int) | Some(PrismaType::BigInt) | Some(PrismaType::Float) | Some(PrismaType::Decimal) => {
+                true
+            }
+            _ => false,
+        };
+
+        if !is_numeric {
+            return self.error(
+                "The range of a field can only be restricted on fields of type Int, BigInt, Float or Decimal.",
+                args.span(),
+            );
+        }
+
+        if obj.arity == dml::FieldArity::List {
+            return self.error("Validators can not be used on list fields.", args.span());
+        }
+
+        let min = self.bound(args, "min")?;
+        let max = self.bound(args, "max")?;
+
+        match (min, max) {
+            (None, None) => return self.error("Either a `min` or a `max` value must be given.", args.span()),
+            (Some(min), Some(max)) if min > max => {
+                return self.error("The minimum value can not exceed the maximum value.", args.span())
+            }
+            _ => (),
+        }
+
+        obj.validators.push(dml::FieldValidator::Range { min, max });
+
+        Ok(())
+    }
+
+    fn serialize(
+        &self,
+        field: &dml::Field,
+        _datamodel: &dml::Datamodel,
+    ) -> Result<Vec<ast::Directive>, DatamodelError> {
+        let directives = field
+            .validators
+            .iter()
+            .filter_map(|validator| match validator {
+                dml::FieldValidator::Range { min, max } => Some(ast::Directive::new(
+                    self.directive_name(),
+                    bound_arguments(
+                        min.as_ref().map(|min| min.to_string()),
+                        max.as_ref().map(|max| max.to_string()),
+                    ),
+                )),
+                _ => None,
+            })
+            .collect();
+
+        Ok(directives)
+    }
+}
+
+impl RangeDirectiveValidator {
+    fn bound(&self, args: &mut Args, name: &str) -> Result<Option<Decimal>, DatamodelError> {
+        match args.optional_arg(name) {
+            Some(value) => Ok(Some(value?.as_decimal().map_err(|err| self.parser_error(&err))?)),
+            None => Ok(None),
+        }
+    }
+}
+
+fn bound_arguments(min: Option<String>, max: Option<String>) -> Vec<ast::Argument> {
+    let min = min.map(|min| ast::Argument::new("min", ast::Expression::NumericValue(min, ast::Span::empty())));
+    let max = max.map(|max| ast::Argument::new("max", ast::Expression::NumericValue(max, ast::Span::empty())));
+
+    min.into_iter().chain(max).collect()
+}
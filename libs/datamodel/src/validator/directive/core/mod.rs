@@ -1,15 +1,19 @@
 use crate::dml;
 use crate::validator::directive::DirectiveListValidator;
 
+mod check;
 mod default;
 mod embedded;
 mod id;
+mod length_and_range;
 mod map;
 mod relation;
 mod scalarlist;
+mod schema;
 mod sequence;
 mod unique_and_index;
 mod updated_at;
+mod view;
 
 /// Returns a directive list validator containing all builtin field directives.
 pub fn new_builtin_field_directives() -> DirectiveListValidator<dml::Field> {
@@ -23,6 +27,8 @@ pub fn new_builtin_field_directives() -> DirectiveListValidator<dml::Field> {
     validator.add(Box::new(default::DefaultDirectiveValidator {}));
     validator.add(Box::new(relation::RelationDirectiveValidator {}));
     validator.add(Box::new(updated_at::UpdatedAtDirectiveValidator {}));
+    validator.add(Box::new(length_and_range::LengthDirectiveValidator {}));
+    validator.add(Box::new(length_and_range::RangeDirectiveValidator {}));
 
     validator
 }
@@ -36,11 +42,18 @@ pub fn new_builtin_model_directives() -> DirectiveListValidator<dml::Model> {
     validator.add(Box::new(unique_and_index::ModelLevelUniqueDirectiveValidator {}));
     validator.add(Box::new(unique_and_index::ModelLevelIndexDirectiveValidator {}));
     validator.add(Box::new(id::ModelLevelIdDirectiveValidator {}));
+    validator.add(Box::new(check::ModelLevelCheckDirectiveValidator {}));
+    validator.add(Box::new(schema::SchemaDirectiveValidator {}));
+    validator.add(Box::new(view::ViewDirectiveValidator {}));
 
     validator
 }
 
 /// Returns a directive list validator containing all builtin enum directives.
 pub fn new_builtin_enum_directives() -> DirectiveListValidator<dml::Enum> {
-    DirectiveListValidator::<dml::Enum>::new()
+    let mut validator = DirectiveListValidator::<dml::Enum>::new();
+
+    validator.add(Box::new(map::MapDirectiveValidator {}));
+
+    validator
 }
@@ -28,7 +28,21 @@ impl DirectiveValidator<dml::Field> for RelationDirectiveValidator {
             }
 
             if let Ok(on_delete) = args.arg("onDelete") {
-                relation_info.on_delete = on_delete.parse_literal::<dml::OnDeleteStrategy>()?;
+                relation_info.on_delete = on_delete.parse_literal::<dml::ReferentialAction>()?;
+            }
+
+            if let Ok(on_update) = args.arg("onUpdate") {
+                relation_info.on_update = on_update.parse_literal::<dml::ReferentialAction>()?;
+            }
+
+            let uses_set_null = relation_info.on_delete == dml::ReferentialAction::SetNull
+                || relation_info.on_update == dml::ReferentialAction::SetNull;
+
+            if uses_set_null && field.arity == dml::FieldArity::Required {
+                return self.error(
+                    "The referential action `SET_NULL` can not be used on a required relation field.",
+                    args.span(),
+                );
             }
 
             Ok(())
@@ -79,13 +93,20 @@ impl DirectiveValidator<dml::Field> for RelationDirectiveValidator {
                 args.push(ast::Argument::new_array("references", related_fields));
             }
 
-            if relation_info.on_delete != dml::OnDeleteStrategy::None {
+            if relation_info.on_delete != dml::ReferentialAction::None {
                 args.push(ast::Argument::new_constant(
                     "onDelete",
                     &relation_info.on_delete.to_string(),
                 ));
             }
 
+            if relation_info.on_update != dml::ReferentialAction::None {
+                args.push(ast::Argument::new_constant(
+                    "onUpdate",
+                    &relation_info.on_update.to_string(),
+                ));
+            }
+
             if !args.is_empty() {
                 return Ok(vec![ast::Directive::new(self.directive_name(), args)]);
             }
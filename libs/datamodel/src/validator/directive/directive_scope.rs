@@ -31,9 +31,21 @@ impl<T> DirectiveValidator<T> for DirectiveScope<T> {
         &self.name
     }
     fn validate_and_apply(&self, args: &mut Args, obj: &mut T) -> Result<(), DatamodelError> {
-        self.inner.validate_and_apply(args, obj)
+        // Errors of the inner directive should point to the scoped name the user wrote.
+        self.inner.validate_and_apply(args, obj).map_err(|err| match err {
+            DatamodelError::DirectiveValidationError { message, span, .. } => {
+                DatamodelError::new_directive_validation_error(&message, &self.name, span)
+            }
+            err => err,
+        })
     }
     fn serialize(&self, obj: &T, datamodel: &dml::Datamodel) -> Result<Vec<ast::Directive>, DatamodelError> {
-        self.inner.serialize(obj, datamodel)
+        let mut directives = self.inner.serialize(obj, datamodel)?;
+
+        for directive in directives.iter_mut() {
+            directive.name = ast::Identifier::new(&self.name);
+        }
+
+        Ok(directives)
     }
 }
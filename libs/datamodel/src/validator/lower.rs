@@ -19,8 +19,7 @@ impl LowerDmlToAst {
     /// the directives defined by the given sources registered.
     ///
     /// The directives defined by the given sources will be namespaced.
-    #[allow(unused)]
-    fn with_sources(sources: &[Box<dyn configuration::Source>]) -> LowerDmlToAst {
+    pub fn with_sources(sources: &[Box<dyn configuration::Source>]) -> LowerDmlToAst {
         LowerDmlToAst {
             directives: DirectiveBox::with_sources(sources),
         }
@@ -72,6 +71,7 @@ impl LowerDmlToAst {
             directives: self.directives.model.serialize(model, datamodel)?,
             documentation: model.documentation.clone().map(|text| ast::Comment { text }),
             span: ast::Span::empty(),
+            commented_out: model.is_commented_out,
         })
     }
 
@@ -128,6 +128,8 @@ impl LowerDmlToAst {
     ) -> ast::Identifier {
         match field_type {
             dml::FieldType::Base(tpe) => ast::Identifier::new(&tpe.to_string()),
+            // The native type itself is rendered by the source's directive.
+            dml::FieldType::ConnectorSpecific { base_type, .. } => ast::Identifier::new(&base_type.to_string()),
             dml::FieldType::Enum(tpe) => ast::Identifier::new(&tpe.to_string()),
             dml::FieldType::Relation(rel) => {
                 let related_model = datamodel.find_model(&rel.to).expect(STATE_ERROR);
@@ -154,7 +156,6 @@ impl LowerDmlToAst {
                     ast::Identifier::new(&rel.to)
                 }
             }
-            _ => unimplemented!("Connector specific types are not supported atm."),
         }
     }
 }
@@ -1,5 +1,5 @@
 use super::common::*;
-use crate::{ast, common::names::*, configuration, dml, error::ErrorCollection, OnDeleteStrategy};
+use crate::{ast, common::names::*, configuration, dml, error::ErrorCollection, ReferentialAction};
 use prisma_inflector;
 
 /// Helper for standardsing a datamodel.
@@ -152,11 +152,16 @@ impl Standardiser {
             documentation: None,
             name: relation_name,
             database_name: None,
+            // Relation tables live next to the first model of the relation.
+            schema: a_model.schema.clone(),
             is_embedded: false,
+            is_view: false,
             fields: vec![a_related_field, b_related_field],
             indexes: vec![],
             id_fields: vec![],
+            check_constraints: vec![],
             is_generated: true,
+            is_commented_out: false,
         }
     }
 
@@ -167,7 +172,8 @@ impl Standardiser {
                 to: model.name.clone(),
                 to_fields: model.id_field_names(),
                 name: String::from(relation_name), // Will be corrected in later step
-                on_delete: dml::OnDeleteStrategy::None,
+                on_delete: dml::ReferentialAction::None,
+                on_update: dml::ReferentialAction::None,
             }),
         )
     }
@@ -296,7 +302,8 @@ impl Standardiser {
                         to: model.name.clone(),
                         to_fields: vec![],
                         name: rel.name.clone(),
-                        on_delete: OnDeleteStrategy::None,
+                        on_delete: ReferentialAction::None,
+                        on_update: ReferentialAction::None,
                     };
 
                     let (arity, field_name) = if field.arity.is_singular() {
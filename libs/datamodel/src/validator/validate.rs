@@ -6,7 +6,10 @@ use crate::{
 /// Helper for validating a datamodel.
 ///
 /// When validating, we check if the datamodel is valid, and generate errors otherwise.
-pub struct Validator {}
+pub struct Validator {
+    /// The schemas listed by the datasource, if there is one.
+    source_schemas: Option<Vec<String>>,
+}
 
 /// State error message. Seeing this error means something went really wrong internally. It's the datamodel equivalent of a bluescreen.
 const STATE_ERROR: &str = "Failed lookup of model, field or optional property during internal processing. This means that the internal representation was mutated incorrectly.";
@@ -14,15 +17,17 @@ const STATE_ERROR: &str = "Failed lookup of model, field or optional property du
 impl Validator {
     /// Creates a new instance, with all builtin directives registered.
     pub fn new() -> Validator {
-        Self {}
+        Self { source_schemas: None }
     }
 
     /// Creates a new instance, with all builtin directives and
     /// the directives defined by the given sources registered.
     ///
     /// The directives defined by the given sources will be namespaced.
-    pub fn with_sources(_sources: &[Box<dyn configuration::Source>]) -> Validator {
-        Self::new()
+    pub fn with_sources(sources: &[Box<dyn configuration::Source>]) -> Validator {
+        Self {
+            source_schemas: sources.first().map(|source| source.schemas().to_vec()),
+        }
     }
 
     pub fn validate(&self, ast_schema: &ast::SchemaAst, schema: &mut dml::Datamodel) -> Result<(), ErrorCollection> {
@@ -43,6 +48,14 @@ impl Validator {
             if let Err(err) = self.validate_embedded_types_have_no_back_relation(ast_schema, schema, model) {
                 errors.push(err);
             }
+            if let Err(err) = self.validate_views_have_no_relations(ast_schema, schema, model) {
+                errors.push(err);
+            }
+            if let Err(err) =
+                self.validate_schema_is_listed(ast_schema.find_model(&model.name).expect(STATE_ERROR), model)
+            {
+                errors.push(err);
+            }
         }
 
         if errors.has_errors() {
@@ -79,12 +92,38 @@ impl Validator {
         }
     }
 
+    /// The schema of a model must be one of the schemas of the datasource.
+    fn validate_schema_is_listed(&self, ast_model: &ast::Model, model: &dml::Model) -> Result<(), DatamodelError> {
+        match (&model.schema, &self.source_schemas) {
+            (Some(schema), Some(source_schemas)) if !source_schemas.contains(schema) => {
+                Err(DatamodelError::new_model_validation_error(
+                    &format!(
+                        "The schema `{}` is not listed in the `schemas` property of the datasource",
+                        schema
+                    ),
+                    &model.name,
+                    ast_model.span,
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+
     fn validate_id_fields_valid(&self, ast_schema: &ast::SchemaAst, model: &dml::Model) -> Result<(), DatamodelError> {
+        // The ids of views are never generated, any column identifying the rows will do.
+        if model.is_view {
+            return Ok(());
+        }
+
         for id_field in model.singular_id_fields() {
-            let is_valid = match (&id_field.default_value, &id_field.field_type, &id_field.arity) {
+            let is_valid = match (
+                &id_field.default_value,
+                id_field.field_type.scalar_type(),
+                &id_field.arity,
+            ) {
                 (
                     Some(dml::Value::Expression(name, return_type, args)),
-                    dml::FieldType::Base(dml::ScalarType::String),
+                    Some(dml::ScalarType::String),
                     dml::FieldArity::Required,
                 ) => {
                     let name_eq = name == "cuid" || name == "uuid";
@@ -93,13 +132,14 @@ impl Validator {
 
                     name_eq && type_eq && args_eq
                 }
-                (None, dml::FieldType::Base(dml::ScalarType::Int), dml::FieldArity::Required) => true,
+                (None, Some(dml::ScalarType::Int), dml::FieldArity::Required) => true,
+                (None, Some(dml::ScalarType::BigInt), dml::FieldArity::Required) => true,
                 _ => false,
             };
 
             if !is_valid {
                 return Err(DatamodelError::new_model_validation_error(
-                    "Invalid ID field. ID field must be one of: Int @id, String @id @default(cuid()), String @id @default(uuid()).",
+                    "Invalid ID field. ID field must be one of: Int @id, BigInt @id, String @id @default(cuid()), String @id @default(uuid()).",
                     &model.name,
                     ast_schema.find_field(&model.name, &id_field.name).expect(STATE_ERROR).span));
             }
@@ -140,6 +180,30 @@ impl Validator {
         Ok(())
     }
 
+    /// Views have no foreign keys, so they can neither hold nor be the target of relations.
+    fn validate_views_have_no_relations(
+        &self,
+        ast_schema: &ast::SchemaAst,
+        datamodel: &dml::Datamodel,
+        model: &dml::Model,
+    ) -> Result<(), DatamodelError> {
+        for field in model.fields() {
+            if let dml::FieldType::Relation(rel) = &field.field_type {
+                let related_is_view = datamodel.find_model(&rel.to).map(|related| related.is_view);
+
+                if model.is_view || related_is_view == Some(true) {
+                    return Err(DatamodelError::new_model_validation_error(
+                        "Views cannot be part of relations.",
+                        &model.name,
+                        ast_schema.find_field(&model.name, &field.name).expect(STATE_ERROR).span,
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     /// Elegantly checks if any relations in the model are ambigious.
     fn validate_relations_not_ambiguous(
         &self,
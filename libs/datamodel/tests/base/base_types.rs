@@ -8,6 +8,7 @@ fn parse_scalar_types() {
         id Int @id
         firstName String
         age Int
+        followers BigInt
         isPro Boolean
         balance Decimal
         averageGrade Float
@@ -20,6 +21,9 @@ fn parse_scalar_types() {
         .assert_has_field("firstName")
         .assert_base_type(&PrismaType::String);
     user_model.assert_has_field("age").assert_base_type(&PrismaType::Int);
+    user_model
+        .assert_has_field("followers")
+        .assert_base_type(&PrismaType::BigInt);
     user_model
         .assert_has_field("isPro")
         .assert_base_type(&PrismaType::Boolean);
@@ -29,6 +29,22 @@ fn db_directive() {
     post_model.assert_has_field("text").assert_with_db_name("post_text");
 }
 
+#[test]
+fn db_directive_on_enums() {
+    let dml = r#"
+    enum Color {
+        black
+        white
+
+        @@map("color")
+    }
+    "#;
+
+    let schema = parse(dml);
+    let color = schema.assert_has_enum("Color");
+    assert_eq!(color.database_name, Some("color".to_string()));
+}
+
 #[test]
 fn unique_directive() {
     let dml = r#"
@@ -8,6 +8,7 @@ fn should_set_default_for_all_scalar_types() {
     model Model {
         id Int @id
         int Int @default(3)
+        bigInt BigInt @default(9223372036854775807)
         float Float @default(3.14)
         decimal Decimal @default(3.15)
         string String @default("String")
@@ -22,6 +23,10 @@ fn should_set_default_for_all_scalar_types() {
         .assert_has_field("int")
         .assert_base_type(&PrismaType::Int)
         .assert_default_value(PrismaValue::Int(3));
+    user_model
+        .assert_has_field("bigInt")
+        .assert_base_type(&PrismaType::BigInt)
+        .assert_default_value(PrismaValue::BigInt(9223372036854775807));
     user_model
         .assert_has_field("float")
         .assert_base_type(&PrismaType::Float)
@@ -29,7 +34,7 @@ fn should_set_default_for_all_scalar_types() {
     user_model
         .assert_has_field("decimal")
         .assert_base_type(&PrismaType::Decimal)
-        .assert_default_value(PrismaValue::Decimal(3.15));
+        .assert_default_value(PrismaValue::Decimal("3.15".parse().unwrap()));
     user_model
         .assert_has_field("string")
         .assert_base_type(&PrismaType::String)
@@ -68,3 +73,20 @@ fn should_set_default_an_enum_type() {
         .assert_enum_type("Role")
         .assert_default_value(PrismaValue::ConstantLiteral(String::from("A_VARIANT_WITH_UNDERSCORES")));
 }
+
+#[test]
+fn should_keep_the_exact_value_of_decimal_defaults() {
+    let dml = r#"
+    model Model {
+        id Int @id
+        balance Decimal @default(12345678901234.5678901234)
+    }
+    "#;
+
+    let datamodel = parse(dml);
+    let user_model = datamodel.assert_has_model("Model");
+    user_model
+        .assert_has_field("balance")
+        .assert_base_type(&PrismaType::Decimal)
+        .assert_default_value(PrismaValue::Decimal("12345678901234.5678901234".parse().unwrap()));
+}
@@ -114,7 +114,7 @@ fn it_must_error_when_multi_field_is_referring_to_undefined_fields() {
 }
 
 const ID_TYPE_ERROR: &str =
-    "Invalid ID field. ID field must be one of: Int @id, String @id @default(cuid()), String @id @default(uuid()).";
+    "Invalid ID field. ID field must be one of: Int @id, BigInt @id, String @id @default(cuid()), String @id @default(uuid()).";
 
 #[test]
 fn id_should_error_if_the_id_field_is_not_of_valid_type() {
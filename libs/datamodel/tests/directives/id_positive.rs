@@ -21,6 +21,22 @@ fn id_without_strategy_should_use_defaults() {
         .assert_id_strategy(IdStrategy::Auto);
 }
 
+#[test]
+fn big_int_id_should_be_allowed() {
+    let dml = r#"
+    model Model {
+        id BigInt @id
+    }
+    "#;
+
+    let datamodel = parse(dml);
+    let user_model = datamodel.assert_has_model("Model");
+    user_model
+        .assert_has_field("id")
+        .assert_base_type(&ScalarType::BigInt)
+        .assert_is_id(true);
+}
+
 #[test]
 fn id_with_explicit_auto_strategy() {
     let dml = r#"
@@ -9,8 +9,11 @@ pub mod relations_consistency;
 pub mod relations_legacy;
 pub mod relations_negative;
 pub mod relations_positive;
+pub mod schema;
 pub mod unique;
 pub mod updated_at_negative;
 pub mod updated_at_positive;
+pub mod validators;
+pub mod view;
 
 pub mod arg_parsing;
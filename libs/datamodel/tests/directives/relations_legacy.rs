@@ -81,7 +81,7 @@ fn settings_must_be_deteced() {
         .assert_relation_to("Todo")
         .assert_relation_to_fields(&["id"])
         .assert_arity(&dml::FieldArity::Optional)
-        .assert_relation_delete_strategy(dml::OnDeleteStrategy::Cascade);
+        .assert_relation_delete_strategy(dml::ReferentialAction::Cascade);
 }
 
 #[test]
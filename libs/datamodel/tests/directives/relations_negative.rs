@@ -179,3 +179,49 @@ fn should_fail_on_conflicting_generated_back_relation_fields() {
         Span::new(98, 152),
     ));
 }
+
+#[test]
+fn should_fail_on_set_null_for_required_relation_fields() {
+    let dml = r#"
+    model Post {
+        id Int @id
+        author User @relation(onDelete: SET_NULL)
+    }
+
+    model User {
+        id Int @id
+        posts Post[]
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_directive_validation_error(
+        "The referential action `SET_NULL` can not be used on a required relation field.",
+        "relation",
+        Span::new(58, 86),
+    ));
+}
+
+#[test]
+fn should_fail_on_unknown_referential_actions() {
+    let dml = r#"
+    model Post {
+        id Int @id
+        author User @relation(onUpdate: NOTHING)
+    }
+
+    model User {
+        id Int @id
+        posts Post[]
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_literal_parser_error(
+        "referential action",
+        "NOTHING",
+        Span::new(77, 84),
+    ));
+}
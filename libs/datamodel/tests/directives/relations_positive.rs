@@ -107,3 +107,37 @@ fn must_generate_back_relation_fields_for_named_relation_fields() {
         .assert_relation_name("AssignedTodos")
         .assert_relation_to("Todo");
 }
+
+#[test]
+fn should_apply_referential_actions() {
+    let dml = r#"
+    model Post {
+        id Int @id
+        author User @relation(onDelete: CASCADE, onUpdate: RESTRICT)
+        editor User? @relation(name: "edits", onDelete: SET_NULL, onUpdate: SET_DEFAULT)
+    }
+
+    model User {
+        id Int @id
+        posts Post[]
+        edits Post[] @relation(name: "edits")
+    }
+    "#;
+
+    let schema = parse(dml);
+    let post_model = schema.assert_has_model("Post");
+    post_model
+        .assert_has_field("author")
+        .assert_relation_delete_strategy(dml::ReferentialAction::Cascade)
+        .assert_relation_update_strategy(dml::ReferentialAction::Restrict);
+    post_model
+        .assert_has_field("editor")
+        .assert_relation_delete_strategy(dml::ReferentialAction::SetNull)
+        .assert_relation_update_strategy(dml::ReferentialAction::SetDefault);
+
+    schema
+        .assert_has_model("User")
+        .assert_has_field("posts")
+        .assert_relation_delete_strategy(dml::ReferentialAction::None)
+        .assert_relation_update_strategy(dml::ReferentialAction::None);
+}
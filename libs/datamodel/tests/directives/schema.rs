@@ -0,0 +1,104 @@
+use crate::common::*;
+use datamodel::{ast::Span, error::DatamodelError};
+
+#[test]
+fn should_apply_the_schema_directive() {
+    let dml = r#"
+    datasource db {
+        provider = "postgresql"
+        url      = "postgresql://localhost/db"
+        schemas  = ["billing", "auth"]
+    }
+
+    model Invoice {
+        id     Int  @id
+        user   User
+
+        @@schema("billing")
+    }
+
+    model User {
+        id       Int       @id
+        invoices Invoice[]
+
+        @@schema("auth")
+    }
+
+    model Post {
+        id Int @id
+    }
+    "#;
+
+    let datamodel = parse(dml);
+
+    assert_eq!(
+        datamodel.assert_has_model("Invoice").schema,
+        Some(String::from("billing"))
+    );
+    assert_eq!(datamodel.assert_has_model("User").schema, Some(String::from("auth")));
+    assert_eq!(datamodel.assert_has_model("Post").schema, None);
+
+    let rendered = datamodel::render_datamodel_to_string(&datamodel).unwrap();
+    assert!(rendered.contains(r#"@@schema("billing")"#));
+    assert_eq!(parse(&rendered), datamodel);
+}
+
+#[test]
+fn should_fail_on_schemas_not_listed_in_the_datasource() {
+    let dml = r#"
+    datasource db {
+        provider = "postgresql"
+        url      = "postgresql://localhost/db"
+        schemas  = ["billing"]
+    }
+
+    model User {
+        id Int @id
+
+        @@schema("auth")
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_model_validation_error(
+        "The schema `auth` is not listed in the `schemas` property of the datasource",
+        "User",
+        Span::new(142, 205),
+    ));
+}
+
+#[test]
+fn should_parse_and_render_the_schemas_of_a_datasource() {
+    let dml = r#"datasource db {
+  provider = "postgresql"
+  url      = "postgresql://localhost/db"
+  schemas  = ["billing", "auth", "public"]
+}
+"#;
+
+    let config = datamodel::parse_configuration(dml).unwrap();
+    let source = config.datasources.first().unwrap();
+
+    assert_eq!(source.schemas(), &["billing", "auth", "public"]);
+    let rendered = datamodel::render_datamodel_and_config_to_string(&datamodel::Datamodel::new(), &config).unwrap();
+    assert_eq!(rendered.trim(), dml.trim());
+}
+
+#[test]
+fn should_fail_on_schemas_for_sqlite() {
+    let dml = r#"
+    datasource db {
+        provider = "sqlite"
+        url      = "file:dev.db"
+        schemas  = ["billing"]
+    }
+    "#;
+
+    let errors = datamodel::parse_configuration(dml).unwrap_err();
+
+    errors.assert_is(DatamodelError::new_validation_error(
+        "The `schemas` property is not supported, SQLite databases do not have schemas",
+        Span::new(101, 112),
+    ));
+}
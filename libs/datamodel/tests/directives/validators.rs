@@ -0,0 +1,168 @@
+use crate::common::*;
+use datamodel::{ast::Span, common::Decimal, dml, error::DatamodelError};
+
+#[test]
+fn should_apply_field_validators_and_check_constraints() {
+    let dml = r#"
+    model Product {
+        id    Int     @id
+        name  String  @length(min: 1, max: 255)
+        price Decimal @range(min: 0)
+        stock Int     @range(min: -10, max: 1000)
+
+        @@check("price > 0", name: "positive_price")
+        @@check("stock < 500")
+    }
+    "#;
+
+    let datamodel = parse(dml);
+    let product_model = datamodel.assert_has_model("Product");
+
+    assert_eq!(
+        product_model.assert_has_field("name").validators,
+        vec![dml::FieldValidator::Length {
+            min: Some(1),
+            max: Some(255)
+        }]
+    );
+    assert_eq!(
+        product_model.assert_has_field("price").validators,
+        vec![dml::FieldValidator::Range {
+            min: Some(Decimal::new(0, 0)),
+            max: None
+        }]
+    );
+    assert_eq!(
+        product_model.assert_has_field("stock").validators,
+        vec![dml::FieldValidator::Range {
+            min: Some(Decimal::new(-10, 0)),
+            max: Some(Decimal::new(1000, 0))
+        }]
+    );
+    assert!(product_model.assert_has_field("id").validators.is_empty());
+
+    assert_eq!(
+        product_model.check_constraints,
+        vec![
+            dml::CheckConstraint {
+                name: Some(String::from("positive_price")),
+                expression: String::from("price > 0"),
+            },
+            dml::CheckConstraint {
+                name: None,
+                expression: String::from("stock < 500"),
+            },
+        ]
+    );
+}
+
+#[test]
+fn should_fail_on_length_for_non_string_fields() {
+    let dml = r#"
+    model User {
+        id Int @id
+        age Int @length(max: 3)
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_directive_validation_error(
+        "The length of a field can only be restricted on fields of type String.",
+        "length",
+        Span::new(54, 68),
+    ));
+}
+
+#[test]
+fn should_fail_if_minimum_length_exceeds_maximum_length() {
+    let dml = r#"
+    model User {
+        id Int @id
+        name String @length(min: 10, max: 5)
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_directive_validation_error(
+        "The minimum length can not exceed the maximum length.",
+        "length",
+        Span::new(58, 81),
+    ));
+}
+
+#[test]
+fn should_fail_on_range_for_non_numeric_fields() {
+    let dml = r#"
+    model User {
+        id Int @id
+        name String @range(min: 0)
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_directive_validation_error(
+        "The range of a field can only be restricted on fields of type Int, BigInt, Float or Decimal.",
+        "range",
+        Span::new(58, 71),
+    ));
+}
+
+#[test]
+fn should_fail_on_range_without_bounds() {
+    let dml = r#"
+    model User {
+        id Int @id
+        age Int @range()
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_directive_validation_error(
+        "Either a `min` or a `max` value must be given.",
+        "range",
+        Span::new(54, 61),
+    ));
+}
+
+#[test]
+fn should_fail_on_empty_check_expressions() {
+    let dml = r#"
+    model Product {
+        id Int @id
+        price Float
+
+        @@check("")
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_directive_validation_error(
+        "The expression of a check constraint can not be empty.",
+        "check",
+        Span::new(71, 80),
+    ));
+}
+
+const DATAMODEL_WITH_VALIDATORS: &str = r#"model Product {
+  id    Int     @id
+  name  String  @length(min: 1, max: 255)
+  price Decimal @range(min: 0.5)
+  stock Int     @range(max: 1000)
+
+  @@check("price > 0", name: "positive_price")
+}"#;
+
+#[test]
+fn should_render_validators_and_check_constraints() {
+    let dml = datamodel::parse_datamodel(DATAMODEL_WITH_VALIDATORS).unwrap();
+    let rendered = datamodel::render_datamodel_to_string(&dml).unwrap();
+
+    print!("{}", rendered);
+
+    assert_eq!(rendered, DATAMODEL_WITH_VALIDATORS);
+}
@@ -0,0 +1,51 @@
+use crate::common::*;
+use datamodel::{ast::Span, error::DatamodelError};
+
+#[test]
+fn should_apply_the_view_directive() {
+    let dml = r#"
+    model User {
+        id Int @id
+    }
+
+    model ActiveUser {
+        id       String @id
+        lastSeen DateTime
+
+        @@view
+    }
+    "#;
+
+    let datamodel = parse(dml);
+
+    assert!(!datamodel.assert_has_model("User").is_view);
+    assert!(datamodel.assert_has_model("ActiveUser").is_view);
+
+    let rendered = datamodel::render_datamodel_to_string(&datamodel).unwrap();
+    assert!(rendered.contains("@@view"));
+    assert_eq!(parse(&rendered), datamodel);
+}
+
+#[test]
+fn should_fail_on_relations_to_views() {
+    let dml = r#"
+    model Post {
+        id     Int    @id
+        report Report
+    }
+
+    model Report {
+        id    Int    @id
+        posts Post[]
+
+        @@view
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is_at(
+        0,
+        DatamodelError::new_model_validation_error("Views cannot be part of relations.", "Post", Span::new(52, 65)),
+    );
+}
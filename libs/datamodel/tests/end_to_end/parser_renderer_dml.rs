@@ -6,7 +6,7 @@ const DATAMODEL_STRING: &str = r#"model User {
   createdAt DateTime
   email     String   @unique
   name      String?
-  posts     Post[]   @relation("author", onDelete: CASCADE)
+  posts     Post[]   @relation("author", onDelete: CASCADE, onUpdate: RESTRICT)
   profile   Profile?
 
   @@map("user")
@@ -72,3 +72,24 @@ fn correctly_handle_server_side_uuid_function() {
             vec![],
         ));
 }
+
+#[test]
+fn correctly_handle_server_side_dbgenerated_function() {
+    let dml = r#"
+    model User {
+        id Int @id
+        rank Int @default(dbgenerated("nextval('rank_seq'::regclass)"))
+    }
+    "#;
+
+    let schema = parse(dml);
+    let user_model = schema.assert_has_model("User");
+    user_model
+        .assert_has_field("rank")
+        .assert_base_type(&PrismaType::Int)
+        .assert_default_value(PrismaValue::Expression(
+            String::from("dbgenerated"),
+            PrismaType::Int,
+            vec![PrismaValue::String(String::from("nextval('rank_seq'::regclass)"))],
+        ));
+}
@@ -0,0 +1,40 @@
+#[test]
+fn commented_out_models_must_be_rendered_behind_line_comments() {
+    let input = r#"
+        model User {
+            id   Int    @id
+            name String
+        }
+
+        model UserName {
+            id   Int     @id
+            name String?
+
+            @@view
+        }
+    "#;
+
+    let expected = r#"model User {
+  id   Int    @id
+  name String
+}
+
+// model UserName {
+//   id   Int     @id
+//   name String?
+//
+//   @@view
+// }"#;
+
+    let mut dml = datamodel::parse_datamodel(input).unwrap();
+    dml.find_model_mut("UserName").unwrap().is_commented_out = true;
+    let rendered = datamodel::render_datamodel_to_string(&dml).unwrap();
+
+    print!("{}", rendered);
+
+    assert_eq!(rendered, expected);
+
+    // Nothing is left of the commented out model once the datamodel is parsed again.
+    let reparsed = datamodel::parse_datamodel(&rendered).unwrap();
+    assert!(reparsed.find_model("UserName").is_none());
+}
@@ -1 +1,2 @@
+pub mod commented_out_models;
 pub mod simplification;
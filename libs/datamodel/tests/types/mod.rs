@@ -1,2 +1,3 @@
+pub mod native_types;
 pub mod negative;
 pub mod positive;
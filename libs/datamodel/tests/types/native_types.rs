@@ -0,0 +1,164 @@
+use crate::common::*;
+use datamodel::{ast::Span, common::PrismaType, dml, error::DatamodelError};
+
+fn assert_connector_type(field: &dml::Field, base: PrismaType, native: &str) {
+    assert_eq!(
+        field.field_type,
+        dml::FieldType::ConnectorSpecific {
+            base_type: base,
+            connector_type: Some(String::from(native)),
+        }
+    );
+}
+
+#[test]
+fn should_apply_native_types_of_the_datasource() {
+    let dml = r#"
+    datasource pg {
+        provider = "postgresql"
+        url = "postgresql://localhost"
+    }
+
+    model User {
+        id        Int      @id @pg.SmallInt
+        name      String   @pg.VarChar(200)
+        bio       String   @pg.Text
+        price     Decimal  @pg.Decimal(10, scale: 2)
+        createdAt DateTime @default(now()) @pg.Timestamptz(3)
+        updatedAt DateTime @updatedAt @pg.Timestamp
+    }
+    "#;
+
+    let datamodel = parse(dml);
+    let user_model = datamodel.assert_has_model("User");
+
+    assert_connector_type(user_model.assert_has_field("id"), PrismaType::Int, "SmallInt");
+    assert_connector_type(user_model.assert_has_field("name"), PrismaType::String, "VarChar(200)");
+    assert_connector_type(user_model.assert_has_field("bio"), PrismaType::String, "Text");
+    assert_connector_type(
+        user_model.assert_has_field("price"),
+        PrismaType::Decimal,
+        "Decimal(10,2)",
+    );
+    assert_connector_type(
+        user_model.assert_has_field("createdAt"),
+        PrismaType::DateTime,
+        "Timestamptz(3)",
+    );
+    user_model.assert_has_field("updatedAt").assert_is_updated_at(true);
+    user_model.assert_has_field("id").assert_is_id(true);
+}
+
+#[test]
+fn should_fail_on_native_types_incompatible_with_the_field_type() {
+    let dml = r#"
+    datasource db {
+        provider = "postgresql"
+        url = "postgresql://localhost"
+    }
+
+    model User {
+        id Int @id
+        age Int @db.Text
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_directive_validation_error(
+        "Native type Text can not be used on a field of type Int.",
+        "db.Text",
+        Span::new(152, 159),
+    ));
+}
+
+#[test]
+fn should_fail_on_multiple_native_types() {
+    let dml = r#"
+    datasource db {
+        provider = "mysql"
+        url = "mysql://localhost"
+    }
+
+    model User {
+        id Int @id
+        name String @db.VarChar(200) @db.Text
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_directive_validation_error(
+        "A field can only have a single native type.",
+        "db.Text",
+        Span::new(163, 170),
+    ));
+}
+
+#[test]
+fn should_fail_on_a_scale_larger_than_the_precision() {
+    let dml = r#"
+    datasource db {
+        provider = "postgresql"
+        url = "postgresql://localhost"
+    }
+
+    model User {
+        id Int @id
+        price Decimal @db.Decimal(2, scale: 4)
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_directive_validation_error(
+        "The scale of a native type can not exceed its precision.",
+        "db.Decimal",
+        Span::new(158, 181),
+    ));
+}
+
+#[test]
+fn should_not_know_native_types_of_other_connectors() {
+    let dml = r#"
+    datasource db {
+        provider = "sqlite"
+        url = "file:dev.db"
+    }
+
+    model User {
+        id Int @id
+        name String @db.VarChar(200)
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_directive_not_known_error(
+        "db.VarChar",
+        Span::new(141, 151),
+    ));
+}
+
+const DATAMODEL_WITH_NATIVE_TYPES: &str = r#"datasource db {
+  provider = "postgresql"
+  url      = "postgresql://localhost"
+}
+
+model User {
+  id        Int      @db.SmallInt @id
+  name      String   @db.VarChar(200)
+  price     Decimal  @db.Decimal(10, scale: 2)
+  createdAt DateTime @db.Timestamptz(3) @default(now())
+}"#;
+
+#[test]
+fn should_render_native_types() {
+    let dml = datamodel::parse_datamodel(DATAMODEL_WITH_NATIVE_TYPES).unwrap();
+    let config = datamodel::parse_configuration(DATAMODEL_WITH_NATIVE_TYPES).unwrap();
+    let rendered = datamodel::render_datamodel_and_config_to_string(&dml, &config).unwrap();
+
+    print!("{}", rendered);
+
+    assert_eq!(rendered, DATAMODEL_WITH_NATIVE_TYPES);
+}
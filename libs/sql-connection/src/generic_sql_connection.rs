@@ -114,4 +114,16 @@ impl SyncSqlConnection for GenericSqlConnection {
     fn execute_raw(&self, sql: &str, params: &[ParameterizedValue<'_>]) -> Result<u64, QuaintError> {
         self.as_sync_sql_connection().execute_raw(sql, params)
     }
+
+    fn execute_in_transaction(&self, statements: &[String]) -> Result<(), QuaintError> {
+        self.as_sync_sql_connection().execute_in_transaction(statements)
+    }
+
+    fn execute_queries_in_transaction(&self, queries: Vec<Query<'_>>) -> Result<(), QuaintError> {
+        self.as_sync_sql_connection().execute_queries_in_transaction(queries)
+    }
+
+    fn with_pinned_connection(&self, f: &mut dyn FnMut(&dyn SyncSqlConnection)) -> Result<(), QuaintError> {
+        self.as_sync_sql_connection().with_pinned_connection(f)
+    }
 }
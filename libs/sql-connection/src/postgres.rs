@@ -78,4 +78,24 @@ impl SyncSqlConnection for Postgresql {
         let conn = self.runtime.block_on(self.pool.check_out())?;
         self.runtime.block_on(conn.execute_raw(sql, params))
     }
+
+    fn execute_in_transaction(&self, statements: &[String]) -> Result<(), QueryError> {
+        let conn = self.runtime.block_on(self.pool.check_out())?;
+        self.runtime.block_on(execute_in_transaction(&*conn, statements))
+    }
+
+    fn execute_queries_in_transaction(&self, queries: Vec<Query<'_>>) -> Result<(), QueryError> {
+        let conn = self.runtime.block_on(self.pool.check_out())?;
+        self.runtime.block_on(execute_queries_in_transaction(&*conn, queries))
+    }
+
+    fn with_pinned_connection(&self, f: &mut dyn FnMut(&dyn SyncSqlConnection)) -> Result<(), QueryError> {
+        let conn = self.runtime.block_on(self.pool.check_out())?;
+        f(&PinnedConnection {
+            runtime: &self.runtime,
+            conn: &*conn,
+        });
+
+        Ok(())
+    }
 }
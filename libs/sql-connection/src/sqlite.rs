@@ -1,4 +1,4 @@
-use crate::traits::{SqlConnection, SyncSqlConnection};
+use crate::traits::{execute_in_transaction, execute_queries_in_transaction, PinnedConnection, SqlConnection, SyncSqlConnection};
 use quaint::{
     ast::*,
     connector::{Queryable, ResultSet, SqliteParams},
@@ -95,4 +95,24 @@ impl SyncSqlConnection for Sqlite {
         self.runtime
             .block_on(<Self as SqlConnection>::execute_raw(self, sql, params))
     }
+
+    fn execute_in_transaction(&self, statements: &[String]) -> Result<(), QueryError> {
+        let conn = self.runtime.block_on(self.get_connection())?;
+        self.runtime.block_on(execute_in_transaction(&*conn, statements))
+    }
+
+    fn execute_queries_in_transaction(&self, queries: Vec<Query<'_>>) -> Result<(), QueryError> {
+        let conn = self.runtime.block_on(self.get_connection())?;
+        self.runtime.block_on(execute_queries_in_transaction(&*conn, queries))
+    }
+
+    fn with_pinned_connection(&self, f: &mut dyn FnMut(&dyn SyncSqlConnection)) -> Result<(), QueryError> {
+        let conn = self.runtime.block_on(self.get_connection())?;
+        f(&PinnedConnection {
+            runtime: &self.runtime,
+            conn: &*conn,
+        });
+
+        Ok(())
+    }
 }
@@ -1,8 +1,9 @@
 use quaint::{
     ast::*,
-    connector::{ResultSet},
+    connector::{Queryable, ResultSet},
     error::Error as QueryError,
 };
+use tokio::runtime::Runtime;
 
 /// A generic synchronous SQL connection interface.
 pub trait SyncSqlConnection {
@@ -29,6 +30,18 @@ pub trait SyncSqlConnection {
     ///
     /// The `db` param is only used on SQLite to give a name to the attached database.
     fn execute_raw(&self, sql: &str, params: &[ParameterizedValue<'_>]) -> Result<u64, QueryError>;
+
+    /// Runs the statements in a transaction, on a single connection. The transaction is committed
+    /// if all the statements succeed, and rolled back otherwise.
+    fn execute_in_transaction(&self, statements: &[String]) -> Result<(), QueryError>;
+
+    /// Like `execute_in_transaction`, for queries built with the quaint AST, so their values are
+    /// passed as parameters.
+    fn execute_queries_in_transaction(&self, queries: Vec<Query<'_>>) -> Result<(), QueryError>;
+
+    /// Checks out a single connection and keeps it while `f` runs. All the queries `f` runs on the
+    /// connection it is given share the same session, e.g. to take and release session-level locks.
+    fn with_pinned_connection(&self, f: &mut dyn FnMut(&dyn SyncSqlConnection)) -> Result<(), QueryError>;
 }
 
 /// A generic asynchronous SQL connection interface.
@@ -58,3 +71,72 @@ pub trait SqlConnection: Send + Sync + 'static {
     /// The `db` param is only used on SQLite to give a name to the attached database.
     async fn execute_raw<'a>(&self, sql: &str, params: &[ParameterizedValue<'a>]) -> Result<u64, QueryError>;
 }
+
+pub(crate) async fn execute_in_transaction(conn: &dyn Queryable, statements: &[String]) -> Result<(), QueryError> {
+    conn.execute_raw("BEGIN", &[]).await?;
+
+    for statement in statements {
+        if let Err(err) = conn.query_raw(statement, &[]).await {
+            // The error of the statement is the one worth reporting.
+            conn.execute_raw("ROLLBACK", &[]).await.ok();
+            return Err(err);
+        }
+    }
+
+    conn.execute_raw("COMMIT", &[]).await?;
+
+    Ok(())
+}
+
+pub(crate) async fn execute_queries_in_transaction(conn: &dyn Queryable, queries: Vec<Query<'_>>) -> Result<(), QueryError> {
+    conn.execute_raw("BEGIN", &[]).await?;
+
+    for query in queries {
+        if let Err(err) = conn.execute(query).await {
+            conn.execute_raw("ROLLBACK", &[]).await.ok();
+            return Err(err);
+        }
+    }
+
+    conn.execute_raw("COMMIT", &[]).await?;
+
+    Ok(())
+}
+
+/// A connection checked out of a pool, see `SyncSqlConnection::with_pinned_connection`.
+pub(crate) struct PinnedConnection<'a> {
+    pub(crate) runtime: &'a Runtime,
+    pub(crate) conn: &'a dyn Queryable,
+}
+
+impl SyncSqlConnection for PinnedConnection<'_> {
+    fn execute(&self, q: Query<'_>) -> Result<Option<Id>, QueryError> {
+        self.runtime.block_on(self.conn.execute(q))
+    }
+
+    fn query(&self, q: Query<'_>) -> Result<ResultSet, QueryError> {
+        self.runtime.block_on(self.conn.query(q))
+    }
+
+    fn query_raw(&self, sql: &str, params: &[ParameterizedValue<'_>]) -> Result<ResultSet, QueryError> {
+        self.runtime.block_on(self.conn.query_raw(sql, params))
+    }
+
+    fn execute_raw(&self, sql: &str, params: &[ParameterizedValue<'_>]) -> Result<u64, QueryError> {
+        self.runtime.block_on(self.conn.execute_raw(sql, params))
+    }
+
+    fn execute_in_transaction(&self, statements: &[String]) -> Result<(), QueryError> {
+        self.runtime.block_on(execute_in_transaction(self.conn, statements))
+    }
+
+    fn execute_queries_in_transaction(&self, queries: Vec<Query<'_>>) -> Result<(), QueryError> {
+        self.runtime.block_on(execute_queries_in_transaction(self.conn, queries))
+    }
+
+    fn with_pinned_connection(&self, f: &mut dyn FnMut(&dyn SyncSqlConnection)) -> Result<(), QueryError> {
+        f(self);
+
+        Ok(())
+    }
+}
@@ -2,7 +2,6 @@
 
 use failure::Fail;
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
 use std::fmt;
 
 pub mod mysql;
@@ -28,6 +27,37 @@ pub trait SqlSchemaDescriberBackend: Send + Sync + 'static {
     fn get_metadata(&self, schema: &str) -> SqlSchemaDescriberResult<SQLMetadata>;
     /// Describe a database schema.
     fn describe(&self, schema: &str) -> SqlSchemaDescriberResult<SqlSchema>;
+
+    /// Describe the default schema together with additional schemas. Tables and views of the
+    /// additional schemas carry their schema name, the enums and sequences are only those of the default schema.
+    fn describe_schemas(&self, default_schema: &str, schemas: &[String]) -> SqlSchemaDescriberResult<SqlSchema> {
+        let mut sql_schema = self.describe(default_schema)?;
+
+        for schema in schemas.iter().filter(|schema| schema.as_str() != default_schema) {
+            let described = self.describe(schema)?;
+
+            for mut table in described.tables {
+                table.schema = Some(schema.clone());
+
+                for foreign_key in table.foreign_keys.iter_mut() {
+                    foreign_key.referenced_schema = match foreign_key.referenced_schema.take() {
+                        None => Some(schema.clone()),
+                        Some(ref referenced_schema) if referenced_schema == default_schema => None,
+                        referenced_schema => referenced_schema,
+                    };
+                }
+
+                sql_schema.tables.push(table);
+            }
+
+            for mut view in described.views {
+                view.schema = Some(schema.clone());
+                sql_schema.views.push(view);
+            }
+        }
+
+        Ok(sql_schema)
+    }
 }
 
 #[derive(Serialize, Deserialize)]
@@ -46,6 +76,9 @@ pub struct SqlSchema {
     pub enums: Vec<Enum>,
     /// The schema's sequences, unique to Postgres.
     pub sequences: Vec<Sequence>,
+    /// The schema's views.
+    #[serde(default)]
+    pub views: Vec<View>,
 }
 
 impl SqlSchema {
@@ -74,6 +107,18 @@ impl SqlSchema {
         self.table(&name).unwrap()
     }
 
+    /// Get a table by schema and name, `None` standing for the default schema.
+    pub fn get_table_in_schema(&self, schema: Option<&str>, name: &str) -> Option<&Table> {
+        self.tables
+            .iter()
+            .find(|table| table.schema.as_ref().map(String::as_str) == schema && table.name == name)
+    }
+
+    /// Get a view.
+    pub fn get_view(&self, name: &str) -> Option<&View> {
+        self.views.iter().find(|x| x.name == name)
+    }
+
     /// Get a sequence.
     pub fn get_sequence(&self, name: &str) -> Option<&Sequence> {
         self.sequences.iter().find(|x| x.name == name)
@@ -84,6 +129,7 @@ impl SqlSchema {
             tables: Vec::new(),
             enums: Vec::new(),
             sequences: Vec::new(),
+            views: Vec::new(),
         }
     }
 }
@@ -94,6 +140,9 @@ impl SqlSchema {
 pub struct Table {
     /// The table's name.
     pub name: String,
+    /// The schema the table lives in, when it is not the default schema of the connection.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
     /// The table's columns.
     pub columns: Vec<Column>,
     /// The table's indices.
@@ -102,6 +151,12 @@ pub struct Table {
     pub primary_key: Option<PrimaryKey>,
     /// The table's foreign keys.
     pub foreign_keys: Vec<ForeignKey>,
+    /// The table's check constraints.
+    #[serde(default)]
+    pub check_constraints: Vec<CheckConstraint>,
+    /// The table's comment, e.g. from `COMMENT ON TABLE` on Postgres.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
 }
 
 impl Table {
@@ -148,6 +203,42 @@ impl Table {
         })
     }
 }
+
+/// A view found in a schema. Views are read only, the migration engine leaves them alone.
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct View {
+    /// The view's name.
+    pub name: String,
+    /// The schema the view lives in, when it is not the default schema of the connection.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
+    /// The view's columns.
+    pub columns: Vec<Column>,
+    /// Whether this is a Postgres materialized view.
+    #[serde(default)]
+    pub materialized: bool,
+    /// The view's indices, only materialized views can have them.
+    #[serde(default)]
+    pub indices: Vec<Index>,
+}
+
+impl View {
+    /// The view as a table without keys or constraints.
+    pub fn as_table(&self) -> Table {
+        Table {
+            name: self.name.clone(),
+            schema: self.schema.clone(),
+            columns: self.columns.clone(),
+            indices: self.indices.clone(),
+            primary_key: None,
+            foreign_keys: Vec::new(),
+            check_constraints: Vec::new(),
+            comment: None,
+        }
+    }
+}
+
 /// The type of an index.
 #[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -186,6 +277,16 @@ impl PrimaryKey {
     }
 }
 
+/// A check constraint of a table.
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckConstraint {
+    /// Constraint name.
+    pub name: String,
+    /// The checked expression, without the `CHECK` keyword.
+    pub expression: String,
+}
+
 /// A column of a table.
 #[derive(PartialEq, Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -199,8 +300,18 @@ pub struct Column {
     /// Column default.
     // Does this field need to be richer? E.g. to easier detect the usages of sequences here
     pub default: Option<String>,
+    /// Is the default an expression evaluated by the database, e.g. `now()`? Expression defaults
+    /// are kept verbatim.
+    #[serde(default)]
+    pub default_is_expression: bool,
     /// Is the column auto-incrementing?
     pub auto_increment: bool,
+    /// Is the column set to the current timestamp on every update (MySQL's `ON UPDATE CURRENT_TIMESTAMP`)?
+    #[serde(default)]
+    pub on_update_current_timestamp: bool,
+    /// The column's comment, e.g. from `COMMENT ON COLUMN` on Postgres.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
 }
 
 impl Column {
@@ -229,6 +340,15 @@ pub struct ColumnType {
     pub raw: String,
     /// The family of the raw type.
     pub family: ColumnTypeFamily,
+    /// The maximum length of character types, e.g. 100 for `varchar(100)`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub character_maximum_length: Option<u32>,
+    /// The total number of digits of exact numeric types, e.g. 10 for `decimal(10,2)`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub numeric_precision: Option<u32>,
+    /// The number of fractional digits of exact numeric types, e.g. 2 for `decimal(10,2)`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub numeric_scale: Option<u32>,
 }
 
 impl ColumnType {
@@ -236,6 +356,9 @@ impl ColumnType {
         ColumnType {
             raw: "".to_string(),
             family,
+            character_maximum_length: None,
+            numeric_precision: None,
+            numeric_scale: None,
         }
     }
 }
@@ -247,8 +370,12 @@ impl ColumnType {
 pub enum ColumnTypeFamily {
     /// Integer types.
     Int,
+    /// 64-bit integer types.
+    BigInt,
     /// Floating point types.
     Float,
+    /// Exact numeric types, e.g. `decimal` and `numeric`.
+    Decimal,
     /// Boolean types.
     Boolean,
     /// String types.
@@ -269,6 +396,8 @@ pub enum ColumnTypeFamily {
     TextSearch,
     /// Transaction ID types.
     TransactionId,
+    /// Enum types, carrying the name of the enum.
+    Enum(String),
     /// Unknown
     Unknown,
 }
@@ -277,7 +406,9 @@ impl fmt::Display for ColumnTypeFamily {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let str = match self {
             Self::Int => "int",
+            Self::BigInt => "bigInt",
             Self::Float => "float",
+            Self::Decimal => "decimal",
             Self::Boolean => "boolean",
             Self::String => "string",
             Self::DateTime => "dateTime",
@@ -288,6 +419,7 @@ impl fmt::Display for ColumnTypeFamily {
             Self::LogSequenceNumber => "logSequenceNumber",
             Self::TextSearch => "textSearch",
             Self::TransactionId => "transactionId",
+            Self::Enum(_) => "enum",
             Self::Unknown => "unknown",
         };
         write!(f, "{}", str)
@@ -328,6 +460,12 @@ pub enum ForeignKeyAction {
     SetDefault,
 }
 
+impl Default for ForeignKeyAction {
+    fn default() -> Self {
+        ForeignKeyAction::NoAction
+    }
+}
+
 /// A foreign key.
 #[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -338,10 +476,16 @@ pub struct ForeignKey {
     pub columns: Vec<String>,
     /// Referenced table.
     pub referenced_table: String,
+    /// The schema of the referenced table, when it is not the default schema of the connection.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub referenced_schema: Option<String>,
     /// Referenced columns.
     pub referenced_columns: Vec<String>,
     /// Action on deletion.
     pub on_delete_action: ForeignKeyAction,
+    /// Action on update.
+    #[serde(default)]
+    pub on_update_action: ForeignKeyAction,
 }
 
 /// A SQL enum.
@@ -350,8 +494,8 @@ pub struct ForeignKey {
 pub struct Enum {
     /// Enum name.
     pub name: String,
-    /// Possible enum values.
-    pub values: HashSet<String>,
+    /// Possible enum values, in declaration order.
+    pub values: Vec<String>,
 }
 
 /// A SQL sequence.
@@ -30,10 +30,16 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
             .into_iter()
             .map(|t| self.get_table(schema, &t))
             .collect();
+        let views = self
+            .get_view_names(schema)
+            .into_iter()
+            .map(|v| self.get_view(schema, &v))
+            .collect();
         Ok(SqlSchema {
             tables,
             enums: vec![],
             sequences: vec![],
+            views,
         })
     }
 }
@@ -65,7 +71,6 @@ impl SqlSchemaDescriber {
         debug!("Getting table names");
         let sql = "SELECT table_name as table_name FROM information_schema.tables
             WHERE table_schema = ?
-            -- Views are not supported yet
             AND table_type = 'BASE TABLE'
             ORDER BY table_name";
         let rows = self.conn.query_raw(sql, &[schema.into()]).expect("get table names ");
@@ -82,6 +87,26 @@ impl SqlSchemaDescriber {
         names
     }
 
+    fn get_view_names(&self, schema: &str) -> Vec<String> {
+        debug!("Getting view names");
+        let sql = "SELECT table_name as table_name FROM information_schema.tables
+            WHERE table_schema = ?
+            AND table_type = 'VIEW'
+            ORDER BY table_name";
+        let rows = self.conn.query_raw(sql, &[schema.into()]).expect("get view names ");
+        let names = rows
+            .into_iter()
+            .map(|row| {
+                row.get("table_name")
+                    .and_then(|x| x.to_string())
+                    .expect("get view name")
+            })
+            .collect();
+
+        debug!("Found view names: {:?}", names);
+        names
+    }
+
     fn get_size(&self, schema: &str) -> usize {
         debug!("Getting table names");
         let sql = "SELECT 
@@ -103,12 +128,48 @@ impl SqlSchemaDescriber {
         let columns = self.get_columns(schema, name);
         let foreign_keys = self.get_foreign_keys(schema, name);
         let (indices, primary_key) = self.get_indices(schema, name, &foreign_keys);
+        let check_constraints = self.get_check_constraints(schema, name);
+        let comment = self.get_table_comment(schema, name);
         Table {
             name: name.to_string(),
+            schema: None,
             columns,
             foreign_keys,
             indices,
             primary_key,
+            check_constraints,
+            comment,
+        }
+    }
+
+    fn get_table_comment(&self, schema: &str, table: &str) -> Option<String> {
+        let sql = "SELECT table_comment table_comment
+            FROM information_schema.tables
+            WHERE table_schema = ? AND table_name = ?";
+        let rows = self
+            .conn
+            .query_raw(sql, &[schema.into(), table.into()])
+            .expect("querying for table comment");
+        let comment = rows
+            .into_iter()
+            .next()
+            .and_then(|row| row.get("table_comment").and_then(|x| x.to_string()))
+            .and_then(non_empty_comment);
+
+        debug!("Found table comment: {:?}", comment);
+        comment
+    }
+
+    fn get_view(&self, schema: &str, name: &str) -> View {
+        debug!("Getting view '{}'", name);
+        // The columns of views are listed in `information_schema.columns` like those of tables.
+        let columns = self.get_columns(schema, name);
+        View {
+            name: name.to_string(),
+            schema: None,
+            columns,
+            materialized: false,
+            indices: Vec::new(),
         }
     }
 
@@ -117,7 +178,9 @@ impl SqlSchemaDescriber {
         // information schema column names became upper-case in MySQL 8, causing the code fetching
         // the result values by column name below to fail.
         let sql = "
-            SELECT column_name column_name, data_type data_type, column_default column_default, is_nullable is_nullable, extra extra
+            SELECT column_name column_name, data_type data_type, column_type column_type, column_default column_default, is_nullable is_nullable, extra extra,
+                column_comment column_comment, character_maximum_length character_maximum_length,
+                numeric_precision numeric_precision, numeric_scale numeric_scale
             FROM information_schema.columns
             WHERE table_schema = ? AND table_name = ?
             ORDER BY column_name";
@@ -131,6 +194,10 @@ impl SqlSchemaDescriber {
             .map(|col| {
                 debug!("Got column: {:?}", col);
 
+                let name = col
+                    .get("column_name")
+                    .and_then(|x| x.to_string())
+                    .expect("get column name");
                 let data_type = col.get("data_type").and_then(|x| x.to_string()).expect("get data_type");
                 let is_nullable = col
                     .get("is_nullable")
@@ -142,7 +209,29 @@ impl SqlSchemaDescriber {
                     "yes" => false,
                     x => panic!(format!("unrecognized is_nullable variant '{}'", x)),
                 };
-                let tpe = get_column_type(data_type.as_ref());
+                let tpe = match data_type.as_str() {
+                    // MySQL enums are declared inline on the column, e.g. `enum('A','B')`, so the
+                    // values stay in the raw type and the enum is named after its column.
+                    "enum" => ColumnType {
+                        raw: col
+                            .get("column_type")
+                            .and_then(|x| x.to_string())
+                            .expect("get column_type"),
+                        family: ColumnTypeFamily::Enum(format!("{}_{}", table, name)),
+                        character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
+                    },
+                    _ => {
+                        let type_argument = |name: &str| col.get(name).and_then(|x| x.as_i64()).map(|x| x as u32);
+                        get_column_type(
+                            data_type.as_ref(),
+                            type_argument("character_maximum_length"),
+                            type_argument("numeric_precision"),
+                            type_argument("numeric_scale"),
+                        )
+                    }
+                };
                 let arity = if tpe.raw.starts_with("_") {
                     ColumnArity::List
                 } else if is_required {
@@ -159,15 +248,28 @@ impl SqlSchemaDescriber {
                     "auto_increment" => true,
                     _ => false,
                 };
+                let default = col.get("column_default").and_then(|x| x.to_string());
+                // MySQL 8 flags expression defaults as `DEFAULT_GENERATED`, older versions only
+                // allow `CURRENT_TIMESTAMP` as an expression, on temporal columns.
+                let default_is_expression = extra.contains("default_generated")
+                    || (tpe.family == ColumnTypeFamily::DateTime
+                        && default
+                            .as_ref()
+                            .map(|x| x.to_lowercase().starts_with("current_timestamp"))
+                            .unwrap_or(false));
+                let on_update_current_timestamp = extra.contains("on update current_timestamp");
                 Column {
-                    name: col
-                        .get("column_name")
-                        .and_then(|x| x.to_string())
-                        .expect("get column name"),
+                    name,
                     tpe,
                     arity,
-                    default: col.get("column_default").and_then(|x| x.to_string()),
+                    default,
+                    default_is_expression,
                     auto_increment: auto_increment,
+                    on_update_current_timestamp,
+                    comment: col
+                        .get("column_comment")
+                        .and_then(|x| x.to_string())
+                        .and_then(non_empty_comment),
                 }
             })
             .collect();
@@ -176,6 +278,46 @@ impl SqlSchemaDescriber {
         cols
     }
 
+    fn get_check_constraints(&self, schema: &str, table: &str) -> Vec<CheckConstraint> {
+        // The check_constraints table only exists from MySQL 8.0.16 on, older versions parse
+        // check constraints but do not store them, so there is nothing to describe there.
+        let sql = "
+            SELECT tc.constraint_name constraint_name, cc.check_clause check_clause
+            FROM information_schema.table_constraints AS tc
+            INNER JOIN information_schema.check_constraints AS cc ON
+            tc.constraint_schema = cc.constraint_schema AND tc.constraint_name = cc.constraint_name
+            WHERE tc.table_schema = ? AND tc.table_name = ? AND tc.constraint_type = 'CHECK'
+            ORDER BY constraint_name
+        ";
+        debug!("describing table check constraints, SQL: '{}'", sql);
+
+        let rows = match self.conn.query_raw(sql, &[schema.into(), table.into()]) {
+            Ok(rows) => rows,
+            Err(err) => {
+                debug!("Could not describe check constraints: {:?}", err);
+                return Vec::new();
+            }
+        };
+        let checks = rows
+            .into_iter()
+            .map(|row| {
+                let name = row
+                    .get("constraint_name")
+                    .and_then(|x| x.to_string())
+                    .expect("get constraint_name");
+                let expression = row
+                    .get("check_clause")
+                    .and_then(|x| x.to_string())
+                    .expect("get check_clause");
+
+                CheckConstraint { name, expression }
+            })
+            .collect();
+
+        debug!("Found check constraints: {:?}", checks);
+        checks
+    }
+
     fn get_foreign_keys(&self, schema: &str, table: &str) -> Vec<ForeignKey> {
         // XXX: Is constraint_name unique? Need a way to uniquely associate rows with foreign keys
         // One should think it's unique since it's used to join information_schema.key_column_usage
@@ -190,9 +332,11 @@ impl SqlSchemaDescriber {
                 kcu.constraint_name constraint_name,
                 kcu.column_name column_name,
                 kcu.referenced_table_name referenced_table_name,
+                kcu.referenced_table_schema referenced_table_schema,
                 kcu.referenced_column_name referenced_column_name,
                 kcu.ordinal_position ordinal_position,
-                rc.delete_rule delete_rule
+                rc.delete_rule delete_rule,
+                rc.update_rule update_rule
             FROM information_schema.key_column_usage AS kcu
             INNER JOIN information_schema.referential_constraints AS rc ON
             kcu.constraint_name = rc.constraint_name
@@ -220,6 +364,10 @@ impl SqlSchemaDescriber {
                 .get("referenced_table_name")
                 .and_then(|x| x.to_string())
                 .expect("get referenced_table_name");
+            let referenced_schema = row
+                .get("referenced_table_schema")
+                .and_then(|x| x.to_string())
+                .expect("get referenced_table_schema");
             let referenced_column = row
                 .get("referenced_column_name")
                 .and_then(|x| x.to_string())
@@ -228,20 +376,16 @@ impl SqlSchemaDescriber {
                 .get("ordinal_position")
                 .and_then(|x| x.as_i64())
                 .expect("get ordinal_position");
-            let on_delete_action = match row
-                .get("delete_rule")
-                .and_then(|x| x.to_string())
-                .expect("get delete_rule")
-                .to_lowercase()
-                .as_str()
-            {
-                "cascade" => ForeignKeyAction::Cascade,
-                "set null" => ForeignKeyAction::SetNull,
-                "set default" => ForeignKeyAction::SetDefault,
-                "restrict" => ForeignKeyAction::Restrict,
-                "no action" => ForeignKeyAction::NoAction,
-                s @ _ => panic!(format!("Unrecognized on delete action '{}'", s)),
-            };
+            let on_delete_action = get_foreign_key_action(
+                &row.get("delete_rule")
+                    .and_then(|x| x.to_string())
+                    .expect("get delete_rule"),
+            );
+            let on_update_action = get_foreign_key_action(
+                &row.get("update_rule")
+                    .and_then(|x| x.to_string())
+                    .expect("get update_rule"),
+            );
             match intermediate_fks.get_mut(&constraint_name) {
                 Some(fk) => {
                     let pos = ord_pos as usize - 1;
@@ -259,8 +403,14 @@ impl SqlSchemaDescriber {
                         constraint_name: Some(constraint_name.clone()),
                         columns: vec![column],
                         referenced_table,
+                        referenced_schema: if referenced_schema == schema {
+                            None
+                        } else {
+                            Some(referenced_schema)
+                        },
                         referenced_columns: vec![referenced_column],
                         on_delete_action,
+                        on_update_action,
                     };
                     intermediate_fks.insert(constraint_name, fk);
                 }
@@ -378,15 +528,33 @@ impl SqlSchemaDescriber {
     }
 }
 
-fn get_column_type(data_type: &str) -> ColumnType {
+fn get_foreign_key_action(rule: &str) -> ForeignKeyAction {
+    match rule.to_lowercase().as_str() {
+        "cascade" => ForeignKeyAction::Cascade,
+        "set null" => ForeignKeyAction::SetNull,
+        "set default" => ForeignKeyAction::SetDefault,
+        "restrict" => ForeignKeyAction::Restrict,
+        "no action" => ForeignKeyAction::NoAction,
+        s => panic!(format!("Unrecognized foreign key action '{}'", s)),
+    }
+}
+
+/// The lengths and precisions are only kept for the types that take them as arguments, MySQL
+/// also reports them for integers, floats and the text types.
+fn get_column_type(
+    data_type: &str,
+    character_maximum_length: Option<u32>,
+    numeric_precision: Option<u32>,
+    numeric_scale: Option<u32>,
+) -> ColumnType {
     let family = match data_type {
         "int" => ColumnTypeFamily::Int,
         "smallint" => ColumnTypeFamily::Int,
         "tinyint" => ColumnTypeFamily::Boolean,
         "mediumint" => ColumnTypeFamily::Int,
-        "bigint" => ColumnTypeFamily::Int,
-        "decimal" => ColumnTypeFamily::Float,
-        "numeric" => ColumnTypeFamily::Float,
+        "bigint" => ColumnTypeFamily::BigInt,
+        "decimal" => ColumnTypeFamily::Decimal,
+        "numeric" => ColumnTypeFamily::Decimal,
         "float" => ColumnTypeFamily::Float,
         "double" => ColumnTypeFamily::Float,
         "date" => ColumnTypeFamily::DateTime,
@@ -418,10 +586,24 @@ fn get_column_type(data_type: &str) -> ColumnType {
         "multipolygon" => ColumnTypeFamily::Geometric,
         "geometrycollection" => ColumnTypeFamily::Geometric,
         "json" => ColumnTypeFamily::Json,
-        x => panic!(format!("type '{}' is not supported here yet.", x)),
+        _ => ColumnTypeFamily::Unknown,
     };
+    let is_character_type = data_type == "varchar" || data_type == "char";
+    let is_exact_numeric_type = data_type == "decimal" || data_type == "numeric";
     ColumnType {
         raw: data_type.to_string(),
         family: family,
+        character_maximum_length: character_maximum_length.filter(|_| is_character_type),
+        numeric_precision: numeric_precision.filter(|_| is_exact_numeric_type),
+        numeric_scale: numeric_scale.filter(|_| is_exact_numeric_type),
+    }
+}
+
+/// MySQL reports a missing comment as an empty string.
+fn non_empty_comment(comment: String) -> Option<String> {
+    if comment.is_empty() {
+        None
+    } else {
+        Some(comment)
     }
 }
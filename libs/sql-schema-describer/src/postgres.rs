@@ -1,9 +1,10 @@
 //! Postgres description.
 use super::*;
+use lazy_static::lazy_static;
 use log::debug;
 use regex::Regex;
 use sql_connection::SyncSqlConnection;
-use std::collections::{HashMap, HashSet};
+use std::collections::HashMap;
 use std::convert::TryInto;
 use std::sync::Arc;
 
@@ -29,16 +30,22 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
     fn describe(&self, schema: &str) -> SqlSchemaDescriberResult<SqlSchema> {
         debug!("describing schema '{}'", schema);
         let sequences = self.get_sequences(schema)?;
+        let enums = self.get_enums(schema)?;
         let tables = self
             .get_table_names(schema)
             .into_iter()
-            .map(|t| self.get_table(schema, &t, &sequences))
+            .map(|t| self.get_table(schema, &t, &sequences, &enums))
+            .collect();
+        let views = self
+            .get_view_names(schema)
+            .into_iter()
+            .map(|(v, materialized)| self.get_view(schema, &v, materialized, &enums))
             .collect();
-        let enums = self.get_enums(schema)?;
         Ok(SqlSchema {
             enums,
             sequences,
             tables,
+            views,
         })
     }
 }
@@ -70,7 +77,6 @@ impl SqlSchemaDescriber {
         debug!("Getting table names");
         let sql = "SELECT table_name as table_name FROM information_schema.tables
             WHERE table_schema = $1
-            -- Views are not supported yet
             AND table_type = 'BASE TABLE'
             ORDER BY table_name";
         let rows = self.conn.query_raw(sql, &[schema.into()]).expect("get table names ");
@@ -87,6 +93,32 @@ impl SqlSchemaDescriber {
         names
     }
 
+    /// The names of the views and materialized views, the latter flagged with `true`.
+    fn get_view_names(&self, schema: &str) -> Vec<(String, bool)> {
+        debug!("Getting view names");
+        let sql = "SELECT cl.relname as view_name, cl.relkind = 'm' as materialized
+            FROM pg_class cl
+            JOIN pg_namespace ns ON ns.oid = cl.relnamespace
+            WHERE ns.nspname = $1
+            AND cl.relkind IN ('v', 'm')
+            ORDER BY cl.relname";
+        let rows = self.conn.query_raw(sql, &[schema.into()]).expect("get view names ");
+        let names = rows
+            .into_iter()
+            .map(|row| {
+                let name = row.get("view_name").and_then(|x| x.to_string()).expect("get view name");
+                let materialized = row
+                    .get("materialized")
+                    .and_then(|x| x.as_bool())
+                    .expect("get materialized");
+                (name, materialized)
+            })
+            .collect();
+
+        debug!("Found view names: {:?}", names);
+        names
+    }
+
     fn get_size(&self, schema: &str) -> usize {
         debug!("Getting db size");
         let sql =
@@ -107,28 +139,92 @@ impl SqlSchemaDescriber {
         size.try_into().unwrap()
     }
 
-    fn get_table(&self, schema: &str, name: &str, sequences: &Vec<Sequence>) -> Table {
+    fn get_table(&self, schema: &str, name: &str, sequences: &Vec<Sequence>, enums: &Vec<Enum>) -> Table {
         debug!("Getting table '{}'", name);
-        let columns = self.get_columns(schema, name);
+        let columns = self.get_columns(schema, name, enums);
         let (indices, primary_key) = self.get_indices(schema, name, sequences);
         let foreign_keys = self.get_foreign_keys(schema, name);
+        let check_constraints = self.get_check_constraints(schema, name);
+        let comment = self.get_table_comment(schema, name);
         Table {
             name: name.to_string(),
+            schema: None,
             columns,
             foreign_keys,
             indices,
             primary_key,
+            check_constraints,
+            comment,
+        }
+    }
+
+    fn get_table_comment(&self, schema: &str, table: &str) -> Option<String> {
+        let sql = "SELECT obj_description(cl.oid, 'pg_class') as comment
+            FROM pg_class cl
+            JOIN pg_namespace ns ON ns.oid = cl.relnamespace
+            WHERE ns.nspname = $1 AND cl.relname = $2";
+        let rows = self
+            .conn
+            .query_raw(sql, &[schema.into(), table.into()])
+            .expect("querying for table comment");
+        let comment = rows
+            .into_iter()
+            .next()
+            .and_then(|row| row.get("comment").and_then(|x| x.to_string()));
+
+        debug!("Found table comment: {:?}", comment);
+        comment
+    }
+
+    fn get_view(&self, schema: &str, name: &str, materialized: bool, enums: &Vec<Enum>) -> View {
+        debug!("Getting view '{}'", name);
+        let columns = self.get_view_columns(schema, name, enums);
+        // Only materialized views can be indexed, and none of them has a primary key.
+        let indices = if materialized {
+            self.get_indices(schema, name, &Vec::new()).0
+        } else {
+            Vec::new()
+        };
+        View {
+            name: name.to_string(),
+            schema: None,
+            columns,
+            materialized,
+            indices,
         }
     }
 
-    fn get_columns(&self, schema: &str, table: &str) -> Vec<Column> {
-        let sql = "SELECT column_name, udt_name, column_default, is_nullable, is_identity, data_type
+    fn get_columns(&self, schema: &str, table: &str, enums: &Vec<Enum>) -> Vec<Column> {
+        let sql = "SELECT column_name, udt_name, column_default, is_nullable, is_identity, data_type,
+                character_maximum_length::int4, numeric_precision::int4, numeric_scale::int4,
+                col_description((quote_ident(table_schema) || '.' || quote_ident(table_name))::regclass, ordinal_position::int) as column_comment
             FROM information_schema.columns
             WHERE table_schema = $1 AND table_name = $2
             ORDER BY column_name";
+        self.query_columns(sql, schema, table, enums)
+    }
+
+    /// Materialized views are missing from `information_schema.columns`, so the columns of views
+    /// are read from the catalog. Views have neither defaults nor identity columns, and as they are
+    /// not migrated, the lengths and precisions of their types are not needed.
+    fn get_view_columns(&self, schema: &str, view: &str, enums: &Vec<Enum>) -> Vec<Column> {
+        let sql = "SELECT att.attname as column_name, typ.typname as udt_name, NULL::text as column_default,
+                CASE WHEN att.attnotnull THEN 'NO' ELSE 'YES' END as is_nullable, 'NO'::text as is_identity,
+                NULL::int4 as character_maximum_length, NULL::int4 as numeric_precision, NULL::int4 as numeric_scale,
+                col_description(att.attrelid, att.attnum) as column_comment
+            FROM pg_attribute att
+            JOIN pg_class cl ON cl.oid = att.attrelid
+            JOIN pg_namespace ns ON ns.oid = cl.relnamespace
+            JOIN pg_type typ ON typ.oid = att.atttypid
+            WHERE ns.nspname = $1 AND cl.relname = $2 AND att.attnum > 0 AND NOT att.attisdropped
+            ORDER BY column_name";
+        self.query_columns(sql, schema, view, enums)
+    }
+
+    fn query_columns(&self, sql: &str, schema: &str, table: &str, enums: &Vec<Enum>) -> Vec<Column> {
         let rows = self
             .conn
-            .query_raw(&sql, &[schema.into(), table.into()])
+            .query_raw(sql, &[schema.into(), table.into()])
             .expect("querying for columns");
         let cols = rows
             .into_iter()
@@ -159,7 +255,24 @@ impl SqlSchemaDescriber {
                     "yes" => false,
                     x => panic!(format!("unrecognized is_nullable variant '{}'", x)),
                 };
-                let tpe = get_column_type(udt.as_ref());
+                let is_enum = enums.iter().any(|e| e.name == udt);
+                let tpe = if is_enum {
+                    ColumnType {
+                        raw: udt.clone(),
+                        family: ColumnTypeFamily::Enum(udt.clone()),
+                        character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
+                    }
+                } else {
+                    let type_argument = |name: &str| col.get(name).and_then(|x| x.as_i64()).map(|x| x as u32);
+                    get_column_type(
+                        udt.as_ref(),
+                        type_argument("character_maximum_length"),
+                        type_argument("numeric_precision"),
+                        type_argument("numeric_scale"),
+                    )
+                };
                 let arity = if tpe.raw.starts_with("_") {
                     ColumnArity::List
                 } else if is_required {
@@ -168,24 +281,42 @@ impl SqlSchemaDescriber {
                     ColumnArity::Nullable
                 };
 
-                let default = col.get("column_default").and_then(|param_value| {
-                    param_value
-                        .to_string()
-                        .map(|x| x.replace("\'", "").replace("::text", ""))
-                });
+                let raw_default = col
+                    .get("column_default")
+                    .and_then(|param_value| param_value.to_string());
+                let default_is_expression = raw_default.as_ref().map(|x| is_expression(x)).unwrap_or(false);
+                let normalized_default = raw_default
+                    .as_ref()
+                    .map(|x| x.replace("\'", "").replace("::text", ""))
+                    // Enum defaults are cast to the enum type, e.g. `'A'::"Color"`.
+                    .map(|x| match is_enum {
+                        true => x
+                            .replace(&format!("::\"{}\"", udt), "")
+                            .replace(&format!("::{}", udt), ""),
+                        false => x,
+                    });
                 let is_auto_increment = is_identity
-                    || match default {
+                    || match normalized_default {
                         Some(ref val) => {
                             val == &format!("nextval(\"{}\".\"{}_{}_seq\"::regclass)", schema, table, col_name,)
                         }
                         _ => false,
                     };
+                // Expressions are kept verbatim, so they can be rendered back as they are.
+                let default = if default_is_expression {
+                    raw_default
+                } else {
+                    normalized_default
+                };
                 Column {
                     name: col_name,
                     tpe,
                     arity,
                     default,
+                    default_is_expression,
                     auto_increment: is_auto_increment,
+                    on_update_current_timestamp: false,
+                    comment: col.get("column_comment").and_then(|x| x.to_string()),
                 }
             })
             .collect();
@@ -199,8 +330,10 @@ impl SqlSchemaDescriber {
                 con.oid as \"con_id\",
                 att2.attname as \"child_column\", 
                 cl.relname as \"parent_table\", 
+                parent_ns.nspname as \"parent_schema\",
                 att.attname as \"parent_column\",
                 con.confdeltype,
+                con.confupdtype,
                 conname as constraint_name
             FROM
             (SELECT 
@@ -210,7 +343,8 @@ impl SqlSchemaDescriber {
                     con1.confrelid, 
                     con1.conrelid,
                     con1.conname,
-                    con1.confdeltype
+                    con1.confdeltype,
+                    con1.confupdtype
                 FROM
                     pg_class cl
                     join pg_namespace ns on cl.relnamespace = ns.oid
@@ -224,6 +358,8 @@ impl SqlSchemaDescriber {
                 att.attrelid = con.confrelid and att.attnum = con.child
             JOIN pg_class cl on
                 cl.oid = con.confrelid
+            JOIN pg_namespace parent_ns on
+                parent_ns.oid = cl.relnamespace
             JOIN pg_attribute att2 on
                 att2.attrelid = con.conrelid and att2.attnum = con.parent
             ORDER BY con_id";
@@ -248,6 +384,10 @@ impl SqlSchemaDescriber {
                 .get("parent_table")
                 .and_then(|x| x.to_string())
                 .expect("get parent_table");
+            let referenced_schema = row
+                .get("parent_schema")
+                .and_then(|x| x.to_string())
+                .expect("get parent_schema");
             let referenced_column = row
                 .get("parent_column")
                 .and_then(|x| x.to_string())
@@ -256,18 +396,16 @@ impl SqlSchemaDescriber {
                 .get("confdeltype")
                 .and_then(|x| x.as_char())
                 .expect("get confdeltype");
+            let confupdtype = row
+                .get("confupdtype")
+                .and_then(|x| x.as_char())
+                .expect("get confupdtype");
             let constraint_name = row
                 .get("constraint_name")
                 .and_then(|x| x.to_string())
                 .expect("get constraint_name");
-            let on_delete_action = match confdeltype {
-                'a' => ForeignKeyAction::NoAction,
-                'r' => ForeignKeyAction::Restrict,
-                'c' => ForeignKeyAction::Cascade,
-                'n' => ForeignKeyAction::SetNull,
-                'd' => ForeignKeyAction::SetDefault,
-                _ => panic!(format!("unrecognized foreign key action '{}'", confdeltype)),
-            };
+            let on_delete_action = get_foreign_key_action(confdeltype);
+            let on_update_action = get_foreign_key_action(confupdtype);
             match intermediate_fks.get_mut(&id) {
                 Some(fk) => {
                     fk.columns.push(column);
@@ -278,8 +416,14 @@ impl SqlSchemaDescriber {
                         constraint_name: Some(constraint_name),
                         columns: vec![column],
                         referenced_table,
+                        referenced_schema: if referenced_schema == schema {
+                            None
+                        } else {
+                            Some(referenced_schema)
+                        },
                         referenced_columns: vec![referenced_column],
                         on_delete_action,
+                        on_update_action,
                     };
                     intermediate_fks.insert(id, fk);
                 }
@@ -302,6 +446,41 @@ impl SqlSchemaDescriber {
         fks
     }
 
+    fn get_check_constraints(&self, schema: &str, table: &str) -> Vec<CheckConstraint> {
+        let sql = "SELECT con.conname AS constraint_name, pg_get_constraintdef(con.oid) AS definition
+            FROM pg_constraint con
+            JOIN pg_class cl ON cl.oid = con.conrelid
+            JOIN pg_namespace ns ON ns.oid = cl.relnamespace
+            WHERE con.contype = 'c' AND cl.relname = $1 AND ns.nspname = $2
+            ORDER BY con.conname";
+        debug!("describing table check constraints, SQL: '{}'", sql);
+
+        let rows = self
+            .conn
+            .query_raw(&sql, &[table.into(), schema.into()])
+            .expect("querying for check constraints");
+        let checks = rows
+            .into_iter()
+            .map(|row| {
+                let name = row
+                    .get("constraint_name")
+                    .and_then(|x| x.to_string())
+                    .expect("get constraint_name");
+                // The definition comes back as `CHECK (<expression>)`.
+                let definition = row
+                    .get("definition")
+                    .and_then(|x| x.to_string())
+                    .expect("get definition");
+                let expression = definition.trim_start_matches("CHECK ").to_string();
+
+                CheckConstraint { name, expression }
+            })
+            .collect();
+
+        debug!("Found check constraints: {:?}", checks);
+        checks
+    }
+
     fn get_indices(
         &self,
         schema: &str,
@@ -328,8 +507,8 @@ impl SqlSchemaDescriber {
             -- find table columns
             AND columnInfos.attrelid = tableInfos.oid
             AND columnInfos.attnum = ANY(rawIndex.indkey)
-            -- we only consider ordinary tables
-            AND tableInfos.relkind = 'r'
+            -- we only consider ordinary tables and materialized views
+            AND tableInfos.relkind IN ('r', 'm')
             -- we only consider stuff out of one specific schema
             AND tableInfos.relnamespace = schemaInfo.oid
             AND schemaInfo.nspname = $1
@@ -459,34 +638,40 @@ impl SqlSchemaDescriber {
             FROM pg_type t 
             JOIN pg_enum e ON t.oid = e.enumtypid  
             JOIN pg_catalog.pg_namespace n ON n.oid = t.typnamespace
-            WHERE n.nspname = $1";
+            WHERE n.nspname = $1
+            ORDER BY t.typname, e.enumsortorder";
         let rows = self.conn.query_raw(&sql, &[schema.into()]).expect("querying for enums");
-        let mut enum_values: HashMap<String, HashSet<String>> = HashMap::new();
+        let mut enums: Vec<Enum> = Vec::new();
         for row in rows.into_iter() {
             debug!("Got enum row: {:?}", row);
             let name = row.get("name").and_then(|x| x.to_string()).expect("get name");
             let value = row.get("value").and_then(|x| x.to_string()).expect("get value");
-            if !enum_values.contains_key(&name) {
-                enum_values.insert(name.clone(), HashSet::new());
+            match enums.last_mut() {
+                Some(e) if e.name == name => e.values.push(value),
+                _ => enums.push(Enum {
+                    name,
+                    values: vec![value],
+                }),
             }
-            let vals = enum_values.get_mut(&name).expect("get enum values");
-            vals.insert(value);
         }
 
-        let enums: Vec<Enum> = enum_values
-            .into_iter()
-            .map(|(k, v)| Enum { name: k, values: v })
-            .collect();
         debug!("Found enums: {:?}", enums);
         Ok(enums)
     }
 }
 
-fn get_column_type(udt: &str) -> ColumnType {
+/// The lengths and precisions are only kept for the types that take them as arguments, Postgres
+/// also reports the binary precision of integers and floats.
+fn get_column_type(
+    udt: &str,
+    character_maximum_length: Option<u32>,
+    numeric_precision: Option<u32>,
+    numeric_scale: Option<u32>,
+) -> ColumnType {
     let family = match udt {
         "int2" => ColumnTypeFamily::Int,
         "int4" => ColumnTypeFamily::Int,
-        "int8" => ColumnTypeFamily::Int,
+        "int8" => ColumnTypeFamily::BigInt,
         "float4" => ColumnTypeFamily::Float,
         "float8" => ColumnTypeFamily::Float,
         "bool" => ColumnTypeFamily::Boolean,
@@ -507,7 +692,7 @@ fn get_column_type(udt: &str) -> ColumnType {
         "polygon" => ColumnTypeFamily::Geometric,
         "bpchar" => ColumnTypeFamily::String,
         "interval" => ColumnTypeFamily::DateTime,
-        "numeric" => ColumnTypeFamily::Float,
+        "numeric" => ColumnTypeFamily::Decimal,
         "pg_lsn" => ColumnTypeFamily::LogSequenceNumber,
         "time" => ColumnTypeFamily::DateTime,
         "timetz" => ColumnTypeFamily::DateTime,
@@ -523,12 +708,41 @@ fn get_column_type(udt: &str) -> ColumnType {
         "_float8" => ColumnTypeFamily::Float,
         "_float4" => ColumnTypeFamily::Float,
         "_int4" => ColumnTypeFamily::Int,
+        "_int8" => ColumnTypeFamily::BigInt,
         "_text" => ColumnTypeFamily::String,
         "_varchar" => ColumnTypeFamily::String,
-        x => panic!(format!("type '{}' is not supported here yet.", x)),
+        _ => ColumnTypeFamily::Unknown,
     };
+    let is_character_type = udt == "varchar" || udt == "bpchar";
+    let is_exact_numeric_type = udt == "numeric";
     ColumnType {
         raw: udt.to_string(),
         family: family,
+        character_maximum_length: character_maximum_length.filter(|_| is_character_type),
+        numeric_precision: numeric_precision.filter(|_| is_exact_numeric_type),
+        numeric_scale: numeric_scale.filter(|_| is_exact_numeric_type),
+    }
+}
+
+fn get_foreign_key_action(action: char) -> ForeignKeyAction {
+    match action {
+        'a' => ForeignKeyAction::NoAction,
+        'r' => ForeignKeyAction::Restrict,
+        'c' => ForeignKeyAction::Cascade,
+        'n' => ForeignKeyAction::SetNull,
+        'd' => ForeignKeyAction::SetDefault,
+        _ => panic!(format!("unrecognized foreign key action '{}'", action)),
     }
 }
+
+lazy_static! {
+    static ref LITERAL_DEFAULT: Regex =
+        Regex::new(r#"(?i)^\(?('([^']|'')*'|-?\d+(\.\d+)?|true|false|null)\)?(::[\w\s"\[\]]+)?$"#)
+            .expect("compile regex");
+}
+
+/// Postgres reports literal defaults as quoted values with an optional cast, e.g. `'foo'::text`,
+/// or as plain numbers and booleans. Everything else, e.g. `now()`, is an expression.
+fn is_expression(default: &str) -> bool {
+    !LITERAL_DEFAULT.is_match(default.trim())
+}
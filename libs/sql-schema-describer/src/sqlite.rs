@@ -1,8 +1,10 @@
 //! SQLite description.
 use super::*;
 use failure::_core::convert::TryInto;
+use lazy_static::lazy_static;
 use log::debug;
 use quaint::ast::ParameterizedValue;
+use regex::Regex;
 use sql_connection::SyncSqlConnection;
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -34,12 +36,18 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
             .filter(|table| !is_system_table(&table))
             .map(|t| self.get_table(schema, &t))
             .collect();
+        let views = self
+            .get_view_names(schema)
+            .into_iter()
+            .map(|v| self.get_view(schema, &v))
+            .collect();
         Ok(SqlSchema {
             // There's no enum type in SQLite.
             enums: vec![],
             // There are no sequences in SQLite.
             sequences: vec![],
             tables: tables,
+            views,
         })
     }
 }
@@ -81,6 +89,18 @@ impl SqlSchemaDescriber {
         names
     }
 
+    fn get_view_names(&self, schema: &str) -> Vec<String> {
+        let sql = format!(r#"SELECT name FROM "{}".sqlite_master WHERE type='view'"#, schema);
+        debug!("describing view names with query: '{}'", sql);
+        let result_set = self.conn.query_raw(&sql, &[]).expect("get view names");
+        let names = result_set
+            .into_iter()
+            .map(|row| row.get("name").and_then(|x| x.to_string()).unwrap())
+            .collect();
+        debug!("Found view names: {:?}", names);
+        names
+    }
+
     fn get_size(&self, _schema: &str) -> usize {
         debug!("Getting db size");
         let sql = format!(r#"SELECT page_count * page_size as size FROM pragma_page_count(), pragma_page_size();"#);
@@ -98,12 +118,30 @@ impl SqlSchemaDescriber {
         let (columns, primary_key) = self.get_columns(schema, name);
         let foreign_keys = self.get_foreign_keys(schema, name);
         let indices = self.get_indices(schema, name);
+        let check_constraints = self.get_check_constraints(schema, name);
         Table {
             name: name.to_string(),
+            schema: None,
             columns,
             indices,
             primary_key,
             foreign_keys,
+            check_constraints,
+            // SQLite has no comments.
+            comment: None,
+        }
+    }
+
+    fn get_view(&self, schema: &str, name: &str) -> View {
+        debug!("describing view '{}' in schema '{}", name, schema);
+        // `table_info` works on views too, their columns never belong to a primary key.
+        let (columns, _) = self.get_columns(schema, name);
+        View {
+            name: name.to_string(),
+            schema: None,
+            columns,
+            materialized: false,
+            indices: Vec::new(),
         }
     }
 
@@ -116,7 +154,13 @@ impl SqlSchemaDescriber {
             .into_iter()
             .map(|row| {
                 debug!("Got column row {:?}", row);
+                let default_is_expression = match row.get("dflt_value") {
+                    Some(ParameterizedValue::Text(v)) => is_expression(v),
+                    _ => false,
+                };
                 let default_value = match row.get("dflt_value") {
+                    // Expressions are kept as they were declared, so they can be rendered back as they are.
+                    Some(ParameterizedValue::Text(v)) if default_is_expression => Some(render_expression(v)),
                     Some(ParameterizedValue::Text(v)) => Some(v.to_string().replace("\"", "")),
                     Some(ParameterizedValue::Null) => None,
                     Some(p) => panic!(format!("expected a string value but got {:?}", p)),
@@ -137,7 +181,10 @@ impl SqlSchemaDescriber {
                     tpe,
                     arity: arity.clone(),
                     default: default_value.clone(),
+                    default_is_expression,
                     auto_increment: false,
+                    on_update_current_timestamp: false,
+                    comment: None,
                 };
                 if pk_col > 0 {
                     pk_cols.insert(pk_col, col.name.clone());
@@ -195,12 +242,35 @@ impl SqlSchemaDescriber {
         (cols, primary_key)
     }
 
+    /// SQLite has no catalog for check constraints, so they are read from the table definition.
+    fn get_check_constraints(&self, schema: &str, table: &str) -> Vec<CheckConstraint> {
+        let sql = format!(
+            r#"SELECT sql FROM "{}".sqlite_master WHERE type='table' AND name = ?"#,
+            schema
+        );
+        debug!("describing table check constraints, SQL: '{}'", sql);
+        let result_set = self
+            .conn
+            .query_raw(&sql, &[table.into()])
+            .expect("querying for table definition");
+        let checks = result_set
+            .into_iter()
+            .next()
+            .and_then(|row| row.get("sql").and_then(|x| x.to_string()))
+            .map(|create_table| parse_check_constraints(&create_table))
+            .unwrap_or_default();
+
+        debug!("Found check constraints: {:?}", checks);
+        checks
+    }
+
     fn get_foreign_keys(&self, schema: &str, table: &str) -> Vec<ForeignKey> {
         struct IntermediateForeignKey {
             pub columns: HashMap<i64, String>,
             pub referenced_table: String,
             pub referenced_columns: HashMap<i64, String>,
             pub on_delete_action: ForeignKeyAction,
+            pub on_update_action: ForeignKeyAction,
         }
 
         let sql = format!(r#"PRAGMA "{}".foreign_key_list("{}");"#, schema, table);
@@ -228,25 +298,16 @@ impl SqlSchemaDescriber {
                     columns.insert(seq, column);
                     let mut referenced_columns: HashMap<i64, String> = HashMap::new();
                     referenced_columns.insert(seq, referenced_column);
-                    let on_delete_action = match row
-                        .get("on_delete")
-                        .and_then(|x| x.to_string())
-                        .expect("on_delete")
-                        .to_lowercase()
-                        .as_str()
-                    {
-                        "no action" => ForeignKeyAction::NoAction,
-                        "restrict" => ForeignKeyAction::Restrict,
-                        "set null" => ForeignKeyAction::SetNull,
-                        "set default" => ForeignKeyAction::SetDefault,
-                        "cascade" => ForeignKeyAction::Cascade,
-                        s @ _ => panic!(format!("Unrecognized on delete action '{}'", s)),
-                    };
+                    let on_delete_action =
+                        get_foreign_key_action(&row.get("on_delete").and_then(|x| x.to_string()).expect("on_delete"));
+                    let on_update_action =
+                        get_foreign_key_action(&row.get("on_update").and_then(|x| x.to_string()).expect("on_update"));
                     let fk = IntermediateForeignKey {
                         columns,
                         referenced_table,
                         referenced_columns,
                         on_delete_action,
+                        on_update_action,
                     };
                     intermediate_fks.insert(id, fk);
                 }
@@ -276,8 +337,10 @@ impl SqlSchemaDescriber {
                 let fk = ForeignKey {
                     columns,
                     referenced_table: intermediate_fk.referenced_table.to_owned(),
+                    referenced_schema: None,
                     referenced_columns,
                     on_delete_action: intermediate_fk.on_delete_action.to_owned(),
+                    on_update_action: intermediate_fk.on_update_action.to_owned(),
 
                     // Not relevant in SQLite since we cannot ALTER or DROP foreign keys by
                     // constraint name.
@@ -339,13 +402,14 @@ fn get_column_type(tpe: &str) -> ColumnType {
         // SQLite only has a few native data types: https://www.sqlite.org/datatype3.html
         // It's tolerant though, and you can assign any data type you like to columns
         "integer" => ColumnTypeFamily::Int,
+        "bigint" => ColumnTypeFamily::BigInt,
         "real" => ColumnTypeFamily::Float,
         "float" => ColumnTypeFamily::Float,
         "serial" => ColumnTypeFamily::Int,
         "boolean" => ColumnTypeFamily::Boolean,
+        s if s.contains("decimal") || s.contains("numeric") => ColumnTypeFamily::Decimal,
         "text" => ColumnTypeFamily::String,
         s if s.contains("char") => ColumnTypeFamily::String,
-        s if s.contains("numeric") => ColumnTypeFamily::Float,
         "date" => ColumnTypeFamily::DateTime,
         "datetime" => ColumnTypeFamily::DateTime,
         "binary" => ColumnTypeFamily::Binary,
@@ -357,15 +421,75 @@ fn get_column_type(tpe: &str) -> ColumnType {
         "double[]" => ColumnTypeFamily::Float,
         "float[]" => ColumnTypeFamily::Float,
         "integer[]" => ColumnTypeFamily::Int,
+        "bigint[]" => ColumnTypeFamily::BigInt,
         "text[]" => ColumnTypeFamily::String,
         _ => ColumnTypeFamily::Unknown, //        x => panic!(format!("type '{}' is not supported here yet", x)),
     };
+    // SQLite does not enforce the arguments of the declared types, but it keeps them in the schema.
+    let arguments: Vec<u32> = match tpe_lower.find('(') {
+        Some(start) => tpe_lower[start + 1..]
+            .trim_end_matches(')')
+            .split(',')
+            .filter_map(|arg| arg.trim().parse().ok())
+            .collect(),
+        None => Vec::new(),
+    };
+    let is_character_type = tpe_lower.contains("char");
+    let is_exact_numeric_type = tpe_lower.contains("numeric") || tpe_lower.contains("decimal");
     ColumnType {
         raw: tpe.to_string(),
         family: family,
+        character_maximum_length: arguments.first().cloned().filter(|_| is_character_type),
+        numeric_precision: arguments.first().cloned().filter(|_| is_exact_numeric_type),
+        numeric_scale: arguments.get(1).cloned().filter(|_| is_exact_numeric_type),
     }
 }
 
+fn get_foreign_key_action(action: &str) -> ForeignKeyAction {
+    match action.to_lowercase().as_str() {
+        "no action" => ForeignKeyAction::NoAction,
+        "restrict" => ForeignKeyAction::Restrict,
+        "set null" => ForeignKeyAction::SetNull,
+        "set default" => ForeignKeyAction::SetDefault,
+        "cascade" => ForeignKeyAction::Cascade,
+        s => panic!(format!("Unrecognized foreign key action '{}'", s)),
+    }
+}
+
+/// Extracts the named check constraints from a `CREATE TABLE` statement.
+fn parse_check_constraints(create_table: &str) -> Vec<CheckConstraint> {
+    RE_CHECK
+        .captures_iter(create_table)
+        .filter_map(|captures| {
+            let name = captures
+                .get(1)
+                .or_else(|| captures.get(2))
+                .or_else(|| captures.get(3))?;
+            let start = captures.get(0)?.end();
+            let mut depth = 1;
+            let mut in_string = false;
+
+            for (idx, c) in create_table[start..].char_indices() {
+                match c {
+                    '\'' => in_string = !in_string,
+                    '(' if !in_string => depth += 1,
+                    ')' if !in_string => depth -= 1,
+                    _ => (),
+                }
+
+                if depth == 0 {
+                    return Some(CheckConstraint {
+                        name: name.as_str().to_string(),
+                        expression: create_table[start..start + idx].trim().to_string(),
+                    });
+                }
+            }
+
+            None
+        })
+        .collect()
+}
+
 /// Returns whether a table is one of the SQLite system tables.
 fn is_system_table(table_name: &str) -> bool {
     SQLITE_SYSTEM_TABLES
@@ -381,3 +505,24 @@ const SQLITE_SYSTEM_TABLES: &[&str] = &[
     "sqlite_stat3",
     "sqlite_stat4",
 ];
+
+lazy_static! {
+    static ref RE_CHECK: Regex =
+        Regex::new(r#"(?i)CONSTRAINT\s+(?:"([^"]+)"|`([^`]+)`|(\w+))\s+CHECK\s*\("#).expect("compile regex");
+    static ref LITERAL_DEFAULT: Regex =
+        Regex::new(r#"(?i)^('([^']|'')*'|"([^"]|"")*"|[+-]?\d+(\.\d+)?|true|false|null)$"#).expect("compile regex");
+}
+
+/// SQLite reports literal defaults as they were written, e.g. `'foo'` or `5`. Everything else is an
+/// expression, e.g. `CURRENT_TIMESTAMP` or a parenthesized expression.
+fn is_expression(default: &str) -> bool {
+    !LITERAL_DEFAULT.is_match(default.trim())
+}
+
+/// SQLite drops the parentheses around expression defaults, which are required to declare them again.
+fn render_expression(expression: &str) -> String {
+    match expression.to_lowercase().as_str() {
+        "current_timestamp" | "current_date" | "current_time" => expression.to_string(),
+        _ => format!("({})", expression),
+    }
+}
@@ -55,20 +55,32 @@ fn is_required_must_work() {
                     tpe: ColumnType {
                         raw: int_type(db_type),
                         family: ColumnTypeFamily::Int,
+                        character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
                     },
                     arity: ColumnArity::Required,
                     default: None,
+                    default_is_expression: false,
                     auto_increment: false,
+                    on_update_current_timestamp: false,
+                    comment: None,
                 },
                 Column {
                     name: "column2".to_string(),
                     tpe: ColumnType {
                         raw: int_type(db_type),
                         family: ColumnTypeFamily::Int,
+                        character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
                     },
                     arity: ColumnArity::Nullable,
                     default: None,
+                    default_is_expression: false,
                     auto_increment: false,
+                    on_update_current_timestamp: false,
+                    comment: None,
                 },
             ];
             assert_eq!(user_table.columns, expected_columns);
@@ -104,10 +116,16 @@ fn foreign_keys_must_work() {
                 tpe: ColumnType {
                     raw: int_type(db_type),
                     family: ColumnTypeFamily::Int,
+                    character_maximum_length: None,
+                    numeric_precision: None,
+                    numeric_scale: None,
                 },
                 arity: ColumnArity::Required,
                 default: None,
+                default_is_expression: false,
                 auto_increment: false,
+                on_update_current_timestamp: false,
+                comment: None,
             }];
 
             let on_delete_action = match db_type {
@@ -118,9 +136,12 @@ fn foreign_keys_must_work() {
                 user_table,
                 &Table {
                     name: "User".to_string(),
+                    schema: None,
                     columns: expected_columns,
                     indices: vec![],
                     primary_key: None,
+                    check_constraints: vec![],
+                    comment: None,
                     foreign_keys: vec![ForeignKey {
                         constraint_name: match db_type {
                             DbType::Postgres => Some("User_city_fkey".to_owned()),
@@ -130,7 +151,9 @@ fn foreign_keys_must_work() {
                         columns: vec!["city".to_string()],
                         referenced_columns: vec!["id".to_string()],
                         referenced_table: "City".to_string(),
-                        on_delete_action,
+                        referenced_schema: None,
+                        on_delete_action: on_delete_action.clone(),
+                        on_update_action: on_delete_action,
                     }],
                 }
             );
@@ -177,20 +200,32 @@ fn multi_column_foreign_keys_must_work() {
                     tpe: ColumnType {
                         raw: int_type(db_type),
                         family: ColumnTypeFamily::Int,
+                        character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
                     },
                     arity: ColumnArity::Required,
                     default: None,
+                    default_is_expression: false,
                     auto_increment: false,
+                    on_update_current_timestamp: false,
+                    comment: None,
                 },
                 Column {
                     name: "city_name".to_string(),
                     tpe: ColumnType {
                         raw: varchar_type(db_type, 255),
                         family: ColumnTypeFamily::String,
+                        character_maximum_length: Some(255),
+                        numeric_precision: None,
+                        numeric_scale: None,
                     },
                     arity: ColumnArity::Required,
                     default: None,
+                    default_is_expression: false,
                     auto_increment: false,
+                    on_update_current_timestamp: false,
+                    comment: None,
                 },
             ];
 
@@ -203,9 +238,12 @@ fn multi_column_foreign_keys_must_work() {
                 user_table,
                 &Table {
                     name: "User".to_string(),
+                    schema: None,
                     columns: expected_columns,
                     indices: vec![],
                     primary_key: None,
+                    check_constraints: vec![],
+                    comment: None,
                     foreign_keys: vec![ForeignKey {
                         constraint_name: match db_type {
                             DbType::Postgres => Some("User_city_fkey".to_owned()),
@@ -215,7 +253,9 @@ fn multi_column_foreign_keys_must_work() {
                         columns: vec!["city".to_string(), "city_name".to_string()],
                         referenced_columns: vec!["id".to_string(), "name".to_string()],
                         referenced_table: "City".to_string(),
-                        on_delete_action,
+                        referenced_schema: None,
+                        on_delete_action: on_delete_action.clone(),
+                        on_update_action: on_delete_action,
                     },],
                 }
             );
@@ -241,10 +281,16 @@ fn names_with_hyphens_must_work() {
                 tpe: ColumnType {
                     raw: int_type(db_type),
                     family: ColumnTypeFamily::Int,
+                    character_maximum_length: None,
+                    numeric_precision: None,
+                    numeric_scale: None,
                 },
                 arity: ColumnArity::Required,
                 default: None,
+                default_is_expression: false,
                 auto_increment: false,
+                on_update_current_timestamp: false,
+                comment: None,
             }];
             assert_eq!(user_table.columns, expected_columns);
         },
@@ -291,20 +337,32 @@ fn composite_primary_keys_must_work() {
                     tpe: ColumnType {
                         raw: exp_int.to_string(),
                         family: ColumnTypeFamily::Int,
+                        character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
                     },
                     arity: ColumnArity::Required,
                     default: None,
+                    default_is_expression: false,
                     auto_increment: false,
+                    on_update_current_timestamp: false,
+                    comment: None,
                 },
                 Column {
                     name: "name".to_string(),
                     tpe: ColumnType {
                         raw: exp_varchar.to_string(),
                         family: ColumnTypeFamily::String,
+                        character_maximum_length: Some(255),
+                        numeric_precision: None,
+                        numeric_scale: None,
                     },
                     arity: ColumnArity::Required,
                     default: None,
+                    default_is_expression: false,
                     auto_increment: false,
+                    on_update_current_timestamp: false,
+                    comment: None,
                 },
             ];
             expected_columns.sort_unstable_by_key(|c| c.name.to_owned());
@@ -313,12 +371,15 @@ fn composite_primary_keys_must_work() {
                 table,
                 &Table {
                     name: "User".to_string(),
+                    schema: None,
                     columns: expected_columns,
                     indices: vec![],
                     primary_key: Some(PrimaryKey {
                         columns: vec!["id".to_string(), "name".to_string()],
                         sequence: None,
                     }),
+                    check_constraints: vec![],
+                    comment: None,
                     foreign_keys: vec![],
                 }
             );
@@ -342,7 +403,7 @@ fn indices_must_work() {
             let result = inspector.describe(&SCHEMA.to_string()).expect("describing");
             let user_table = result.get_table("User").expect("getting User table");
             let default = match db_type {
-                DbType::Postgres => Some(format!("nextval(\"{}\".\"User_id_seq\"::regclass)", SCHEMA)),
+                DbType::Postgres => Some(format!("nextval('\"{}\".\"User_id_seq\"'::regclass)", SCHEMA)),
                 _ => None,
             };
             let expected_columns = vec![
@@ -351,20 +412,32 @@ fn indices_must_work() {
                     tpe: ColumnType {
                         raw: int_type(db_type),
                         family: ColumnTypeFamily::Int,
+                        character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
                     },
                     arity: ColumnArity::Required,
                     default: None,
+                    default_is_expression: false,
                     auto_increment: false,
+                    on_update_current_timestamp: false,
+                    comment: None,
                 },
                 Column {
                     name: "id".to_string(),
                     tpe: ColumnType {
                         raw: int_type(db_type),
                         family: ColumnTypeFamily::Int,
+                        character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
                     },
                     arity: ColumnArity::Required,
+                    default_is_expression: default.is_some(),
                     default,
                     auto_increment: true,
+                    on_update_current_timestamp: false,
+                    comment: None,
                 },
             ];
             let pk_sequence = match db_type {
@@ -379,6 +452,7 @@ fn indices_must_work() {
                 user_table,
                 &Table {
                     name: "User".to_string(),
+                    schema: None,
                     columns: expected_columns,
                     indices: vec![Index {
                         name: "count".to_string(),
@@ -389,6 +463,8 @@ fn indices_must_work() {
                         columns: vec!["id".to_string()],
                         sequence: pk_sequence,
                     }),
+                    check_constraints: vec![],
+                    comment: None,
                     foreign_keys: vec![],
                 }
             );
@@ -422,20 +498,32 @@ fn column_uniqueness_must_be_detected() {
                     tpe: ColumnType {
                         raw: int_type(db_type),
                         family: ColumnTypeFamily::Int,
+                        character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
                     },
                     arity: ColumnArity::Required,
                     default: None,
+                    default_is_expression: false,
                     auto_increment: false,
+                    on_update_current_timestamp: false,
+                    comment: None,
                 },
                 Column {
                     name: "uniq2".to_string(),
                     tpe: ColumnType {
                         raw: int_type(db_type),
                         family: ColumnTypeFamily::Int,
+                        character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
                     },
                     arity: ColumnArity::Required,
                     default: None,
+                    default_is_expression: false,
                     auto_increment: false,
+                    on_update_current_timestamp: false,
+                    comment: None,
                 },
             ];
             let mut expected_indices = vec![Index {
@@ -467,9 +555,12 @@ fn column_uniqueness_must_be_detected() {
                 user_table,
                 &Table {
                     name: "User".to_string(),
+                    schema: None,
                     columns: expected_columns,
                     indices: expected_indices,
                     primary_key: None,
+                    check_constraints: vec![],
+                    comment: None,
                     foreign_keys: vec![],
                 }
             );
@@ -507,18 +598,27 @@ fn defaults_must_work() {
                 tpe: ColumnType {
                     raw: int_type(db_type),
                     family: ColumnTypeFamily::Int,
+                    character_maximum_length: None,
+                    numeric_precision: None,
+                    numeric_scale: None,
                 },
                 arity: ColumnArity::Nullable,
                 default: Some(default),
+                default_is_expression: false,
                 auto_increment: false,
+                on_update_current_timestamp: false,
+                comment: None,
             }];
             assert_eq!(
                 user_table,
                 &Table {
                     name: "User".to_string(),
+                    schema: None,
                     columns: expected_columns,
                     indices: vec![],
                     primary_key: None,
+                    check_constraints: vec![],
+                    comment: None,
                     foreign_keys: vec![],
                 }
             );
@@ -66,380 +66,608 @@ fn all_mysql_column_types_must_work() {
             tpe: ColumnType {
                 raw: "int".to_string(),
                 family: ColumnTypeFamily::Int,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: true,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "int_col".to_string(),
             tpe: ColumnType {
                 raw: "int".to_string(),
                 family: ColumnTypeFamily::Int,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "smallint_col".to_string(),
             tpe: ColumnType {
                 raw: "smallint".to_string(),
                 family: ColumnTypeFamily::Int,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "tinyint_col".to_string(),
             tpe: ColumnType {
                 raw: "tinyint".to_string(),
                 family: ColumnTypeFamily::Boolean,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "mediumint_col".to_string(),
             tpe: ColumnType {
                 raw: "mediumint".to_string(),
                 family: ColumnTypeFamily::Int,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "bigint_col".to_string(),
             tpe: ColumnType {
                 raw: "bigint".to_string(),
-                family: ColumnTypeFamily::Int,
+                family: ColumnTypeFamily::BigInt,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "decimal_col".to_string(),
             tpe: ColumnType {
                 raw: "decimal".to_string(),
-                family: ColumnTypeFamily::Float,
+                family: ColumnTypeFamily::Decimal,
+                character_maximum_length: None,
+                numeric_precision: Some(10),
+                numeric_scale: Some(0),
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "numeric_col".to_string(),
             tpe: ColumnType {
                 raw: "decimal".to_string(),
-                family: ColumnTypeFamily::Float,
+                family: ColumnTypeFamily::Decimal,
+                character_maximum_length: None,
+                numeric_precision: Some(10),
+                numeric_scale: Some(0),
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "float_col".to_string(),
             tpe: ColumnType {
                 raw: "float".to_string(),
                 family: ColumnTypeFamily::Float,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "double_col".to_string(),
             tpe: ColumnType {
                 raw: "double".to_string(),
                 family: ColumnTypeFamily::Float,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "date_col".to_string(),
             tpe: ColumnType {
                 raw: "date".to_string(),
                 family: ColumnTypeFamily::DateTime,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "time_col".to_string(),
             tpe: ColumnType {
                 raw: "time".to_string(),
                 family: ColumnTypeFamily::DateTime,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "datetime_col".to_string(),
             tpe: ColumnType {
                 raw: "datetime".to_string(),
                 family: ColumnTypeFamily::DateTime,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "timestamp_col".to_string(),
             tpe: ColumnType {
                 raw: "timestamp".to_string(),
                 family: ColumnTypeFamily::DateTime,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: Some("CURRENT_TIMESTAMP".to_string()),
+            default_is_expression: true,
             auto_increment: false,
+            on_update_current_timestamp: true,
+            comment: None,
         },
         Column {
             name: "year_col".to_string(),
             tpe: ColumnType {
                 raw: "year".to_string(),
                 family: ColumnTypeFamily::DateTime,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "char_col".to_string(),
             tpe: ColumnType {
                 raw: "char".to_string(),
                 family: ColumnTypeFamily::String,
+                character_maximum_length: Some(1),
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "varchar_col".to_string(),
             tpe: ColumnType {
                 raw: "varchar".to_string(),
                 family: ColumnTypeFamily::String,
+                character_maximum_length: Some(255),
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "text_col".to_string(),
             tpe: ColumnType {
                 raw: "text".to_string(),
                 family: ColumnTypeFamily::String,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "tinytext_col".to_string(),
             tpe: ColumnType {
                 raw: "tinytext".to_string(),
                 family: ColumnTypeFamily::String,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "mediumtext_col".to_string(),
             tpe: ColumnType {
                 raw: "mediumtext".to_string(),
                 family: ColumnTypeFamily::String,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "longtext_col".to_string(),
             tpe: ColumnType {
                 raw: "longtext".to_string(),
                 family: ColumnTypeFamily::String,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "enum_col".to_string(),
             tpe: ColumnType {
-                raw: "enum".to_string(),
-                family: ColumnTypeFamily::String,
+                raw: "enum('a','b')".to_string(),
+                family: ColumnTypeFamily::Enum("User_enum_col".to_string()),
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "set_col".to_string(),
             tpe: ColumnType {
                 raw: "set".to_string(),
                 family: ColumnTypeFamily::String,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "binary_col".to_string(),
             tpe: ColumnType {
                 raw: "binary".to_string(),
                 family: ColumnTypeFamily::Binary,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "varbinary_col".to_string(),
             tpe: ColumnType {
                 raw: "varbinary".to_string(),
                 family: ColumnTypeFamily::Binary,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "blob_col".to_string(),
             tpe: ColumnType {
                 raw: "blob".to_string(),
                 family: ColumnTypeFamily::Binary,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "tinyblob_col".to_string(),
             tpe: ColumnType {
                 raw: "tinyblob".to_string(),
                 family: ColumnTypeFamily::Binary,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "mediumblob_col".to_string(),
             tpe: ColumnType {
                 raw: "mediumblob".to_string(),
                 family: ColumnTypeFamily::Binary,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "longblob_col".to_string(),
             tpe: ColumnType {
                 raw: "longblob".to_string(),
                 family: ColumnTypeFamily::Binary,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "geometry_col".to_string(),
             tpe: ColumnType {
                 raw: "geometry".to_string(),
                 family: ColumnTypeFamily::Geometric,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "point_col".to_string(),
             tpe: ColumnType {
                 raw: "point".to_string(),
                 family: ColumnTypeFamily::Geometric,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "linestring_col".to_string(),
             tpe: ColumnType {
                 raw: "linestring".to_string(),
                 family: ColumnTypeFamily::Geometric,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "polygon_col".to_string(),
             tpe: ColumnType {
                 raw: "polygon".to_string(),
                 family: ColumnTypeFamily::Geometric,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "multipoint_col".to_string(),
             tpe: ColumnType {
                 raw: "multipoint".to_string(),
                 family: ColumnTypeFamily::Geometric,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "multilinestring_col".to_string(),
             tpe: ColumnType {
                 raw: "multilinestring".to_string(),
                 family: ColumnTypeFamily::Geometric,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "multipolygon_col".to_string(),
             tpe: ColumnType {
                 raw: "multipolygon".to_string(),
                 family: ColumnTypeFamily::Geometric,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "geometrycollection_col".to_string(),
             tpe: ColumnType {
                 raw: "geometrycollection".to_string(),
                 family: ColumnTypeFamily::Geometric,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "json_col".to_string(),
             tpe: ColumnType {
                 raw: "json".to_string(),
                 family: ColumnTypeFamily::Json,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
     ];
     expected_columns.sort_unstable_by_key(|c| c.name.to_owned());
@@ -448,12 +676,15 @@ fn all_mysql_column_types_must_work() {
         table,
         Table {
             name: "User".to_string(),
+            schema: None,
             columns: expected_columns,
             indices: vec![],
             primary_key: Some(PrimaryKey {
                 columns: vec!["primary_col".to_string()],
                 sequence: None,
             }),
+            check_constraints: vec![],
+            comment: None,
             foreign_keys: vec![],
         }
     );
@@ -469,10 +700,10 @@ fn mysql_foreign_key_on_delete_must_be_handled() {
         "CREATE TABLE `{0}`.City (id INTEGER NOT NULL AUTO_INCREMENT PRIMARY KEY);
          CREATE TABLE `{0}`.User (
             id INTEGER NOT NULL AUTO_INCREMENT PRIMARY KEY,
-            city INTEGER, FOREIGN KEY(city) REFERENCES City (id) ON DELETE NO ACTION,
-            city_cascade INTEGER, FOREIGN KEY(city_cascade) REFERENCES City (id) ON DELETE CASCADE,
-            city_restrict INTEGER, FOREIGN KEY(city_restrict) REFERENCES City (id) ON DELETE RESTRICT,
-            city_set_null INTEGER, FOREIGN KEY(city_set_null) REFERENCES City (id) ON DELETE SET NULL
+            city INTEGER, FOREIGN KEY(city) REFERENCES City (id) ON DELETE NO ACTION ON UPDATE NO ACTION,
+            city_cascade INTEGER, FOREIGN KEY(city_cascade) REFERENCES City (id) ON DELETE CASCADE ON UPDATE CASCADE,
+            city_restrict INTEGER, FOREIGN KEY(city_restrict) REFERENCES City (id) ON DELETE RESTRICT ON UPDATE RESTRICT,
+            city_set_null INTEGER, FOREIGN KEY(city_set_null) REFERENCES City (id) ON DELETE SET NULL ON UPDATE SET NULL
         )",
         SCHEMA
     );
@@ -486,56 +717,87 @@ fn mysql_foreign_key_on_delete_must_be_handled() {
         table,
         Table {
             name: "User".to_string(),
+            schema: None,
             columns: vec![
                 Column {
                     name: "city".to_string(),
                     tpe: ColumnType {
                         raw: "int".to_string(),
                         family: ColumnTypeFamily::Int,
+                        character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
                     },
                     arity: ColumnArity::Nullable,
                     default: None,
+                    default_is_expression: false,
                     auto_increment: false,
+                    on_update_current_timestamp: false,
+                    comment: None,
                 },
                 Column {
                     name: "city_cascade".to_string(),
                     tpe: ColumnType {
                         raw: "int".to_string(),
                         family: ColumnTypeFamily::Int,
+                        character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
                     },
                     arity: ColumnArity::Nullable,
                     default: None,
+                    default_is_expression: false,
                     auto_increment: false,
+                    on_update_current_timestamp: false,
+                    comment: None,
                 },
                 Column {
                     name: "city_restrict".to_string(),
                     tpe: ColumnType {
                         raw: "int".to_string(),
                         family: ColumnTypeFamily::Int,
+                        character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
                     },
                     arity: ColumnArity::Nullable,
                     default: None,
+                    default_is_expression: false,
                     auto_increment: false,
+                    on_update_current_timestamp: false,
+                    comment: None,
                 },
                 Column {
                     name: "city_set_null".to_string(),
                     tpe: ColumnType {
                         raw: "int".to_string(),
                         family: ColumnTypeFamily::Int,
+                        character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
                     },
                     arity: ColumnArity::Nullable,
                     default: None,
+                    default_is_expression: false,
                     auto_increment: false,
+                    on_update_current_timestamp: false,
+                    comment: None,
                 },
                 Column {
                     name: "id".to_string(),
                     tpe: ColumnType {
                         raw: "int".to_string(),
                         family: ColumnTypeFamily::Int,
+                        character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
                     },
                     arity: ColumnArity::Required,
                     default: None,
+                    default_is_expression: false,
                     auto_increment: true,
+                    on_update_current_timestamp: false,
+                    comment: None,
                 },
             ],
             indices: vec![],
@@ -543,34 +805,44 @@ fn mysql_foreign_key_on_delete_must_be_handled() {
                 columns: vec!["id".to_string()],
                 sequence: None,
             }),
+            check_constraints: vec![],
+            comment: None,
             foreign_keys: vec![
                 ForeignKey {
                     constraint_name: Some("User_ibfk_1".to_owned()),
                     columns: vec!["city".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::NoAction,
+                    on_update_action: ForeignKeyAction::NoAction,
                 },
                 ForeignKey {
                     constraint_name: Some("User_ibfk_2".to_owned()),
                     columns: vec!["city_cascade".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::Cascade,
+                    on_update_action: ForeignKeyAction::Cascade,
                 },
                 ForeignKey {
                     constraint_name: Some("User_ibfk_3".to_owned()),
                     columns: vec!["city_restrict".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::Restrict,
+                    on_update_action: ForeignKeyAction::Restrict,
                 },
                 ForeignKey {
                     constraint_name: Some("User_ibfk_4".to_owned()),
                     columns: vec!["city_set_null".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::SetNull,
+                    on_update_action: ForeignKeyAction::SetNull,
                 },
             ],
         }
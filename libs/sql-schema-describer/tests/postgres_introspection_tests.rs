@@ -1,7 +1,6 @@
 use barrel::{types, Migration};
 use pretty_assertions::assert_eq;
 use sql_schema_describer::*;
-use std::collections::HashSet;
 
 mod common;
 mod postgres;
@@ -74,443 +73,710 @@ fn all_postgres_column_types_must_work() {
             tpe: ColumnType {
                 raw: "_bytea".into(),
                 family: ColumnTypeFamily::Binary,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::List,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "array_bool_col".into(),
             tpe: ColumnType {
                 raw: "_bool".into(),
                 family: ColumnTypeFamily::Boolean,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::List,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "array_date_col".into(),
             tpe: ColumnType {
                 raw: "_date".into(),
                 family: ColumnTypeFamily::DateTime,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::List,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "array_double_col".into(),
             tpe: ColumnType {
                 raw: "_float8".into(),
                 family: ColumnTypeFamily::Float,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::List,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "array_float_col".into(),
             tpe: ColumnType {
                 raw: "_float8".into(),
                 family: ColumnTypeFamily::Float,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::List,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "array_int_col".into(),
             tpe: ColumnType {
                 raw: "_int4".into(),
                 family: ColumnTypeFamily::Int,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::List,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "array_text_col".into(),
             tpe: ColumnType {
                 raw: "_text".into(),
                 family: ColumnTypeFamily::String,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::List,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "array_varchar_col".into(),
             tpe: ColumnType {
                 raw: "_varchar".into(),
                 family: ColumnTypeFamily::String,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::List,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "binary_col".into(),
             tpe: ColumnType {
                 raw: "bytea".into(),
                 family: ColumnTypeFamily::Binary,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "boolean_col".into(),
             tpe: ColumnType {
                 raw: "bool".into(),
                 family: ColumnTypeFamily::Boolean,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "date_time_col".into(),
             tpe: ColumnType {
                 raw: "date".into(),
                 family: ColumnTypeFamily::DateTime,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "double_col".into(),
             tpe: ColumnType {
                 raw: "float8".into(),
                 family: ColumnTypeFamily::Float,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "float_col".into(),
             tpe: ColumnType {
                 raw: "float8".into(),
                 family: ColumnTypeFamily::Float,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "int_col".into(),
             tpe: ColumnType {
                 raw: "int4".into(),
                 family: ColumnTypeFamily::Int,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "primary_col".into(),
             tpe: ColumnType {
                 raw: "int4".into(),
                 family: ColumnTypeFamily::Int,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
-            default: Some(format!("nextval(\"{}\".\"User_primary_col_seq\"::regclass)", SCHEMA)),
+            default: Some(format!("nextval('\"{}\".\"User_primary_col_seq\"'::regclass)", SCHEMA)),
+            default_is_expression: true,
             auto_increment: true,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "string1_col".into(),
             tpe: ColumnType {
                 raw: "text".into(),
                 family: ColumnTypeFamily::String,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "string2_col".into(),
             tpe: ColumnType {
                 raw: "varchar".into(),
                 family: ColumnTypeFamily::String,
+                character_maximum_length: Some(1),
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "bigint_col".into(),
             tpe: ColumnType {
                 raw: "int8".into(),
-                family: ColumnTypeFamily::Int,
+                family: ColumnTypeFamily::BigInt,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "bigserial_col".into(),
             tpe: ColumnType {
                 raw: "int8".into(),
-                family: ColumnTypeFamily::Int,
+                family: ColumnTypeFamily::BigInt,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
-            default: Some(format!("nextval(\"{}\".\"User_bigserial_col_seq\"::regclass)", SCHEMA)),
+            default: Some(format!(
+                "nextval('\"{}\".\"User_bigserial_col_seq\"'::regclass)",
+                SCHEMA
+            )),
+            default_is_expression: true,
             auto_increment: true,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "bit_col".into(),
             tpe: ColumnType {
                 raw: "bit".into(),
                 family: ColumnTypeFamily::Binary,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "bit_varying_col".into(),
             tpe: ColumnType {
                 raw: "varbit".into(),
                 family: ColumnTypeFamily::Binary,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "box_col".into(),
             tpe: ColumnType {
                 raw: "box".into(),
                 family: ColumnTypeFamily::Geometric,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "char_col".into(),
             tpe: ColumnType {
                 raw: "bpchar".into(),
                 family: ColumnTypeFamily::String,
+                character_maximum_length: Some(1),
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "circle_col".into(),
             tpe: ColumnType {
                 raw: "circle".into(),
                 family: ColumnTypeFamily::Geometric,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "interval_col".into(),
             tpe: ColumnType {
                 raw: "interval".into(),
                 family: ColumnTypeFamily::DateTime,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "line_col".into(),
             tpe: ColumnType {
                 raw: "line".into(),
                 family: ColumnTypeFamily::Geometric,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "lseg_col".into(),
             tpe: ColumnType {
                 raw: "lseg".into(),
                 family: ColumnTypeFamily::Geometric,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "numeric_col".into(),
             tpe: ColumnType {
                 raw: "numeric".into(),
-                family: ColumnTypeFamily::Float,
+                family: ColumnTypeFamily::Decimal,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "path_col".into(),
             tpe: ColumnType {
                 raw: "path".into(),
                 family: ColumnTypeFamily::Geometric,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "pg_lsn_col".into(),
             tpe: ColumnType {
                 raw: "pg_lsn".into(),
                 family: ColumnTypeFamily::LogSequenceNumber,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "polygon_col".into(),
             tpe: ColumnType {
                 raw: "polygon".into(),
                 family: ColumnTypeFamily::Geometric,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "smallint_col".into(),
             tpe: ColumnType {
                 raw: "int2".into(),
                 family: ColumnTypeFamily::Int,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "smallserial_col".into(),
             tpe: ColumnType {
                 raw: "int2".into(),
                 family: ColumnTypeFamily::Int,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: Some(format!(
-                "nextval(\"{}\".\"User_smallserial_col_seq\"::regclass)",
+                "nextval('\"{}\".\"User_smallserial_col_seq\"'::regclass)",
                 SCHEMA
             )),
+            default_is_expression: true,
             auto_increment: true,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "serial_col".into(),
             tpe: ColumnType {
                 raw: "int4".into(),
                 family: ColumnTypeFamily::Int,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
-            default: Some(format!("nextval(\"{}\".\"User_serial_col_seq\"::regclass)", SCHEMA)),
+            default: Some(format!("nextval('\"{}\".\"User_serial_col_seq\"'::regclass)", SCHEMA)),
+            default_is_expression: true,
             auto_increment: true,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "time_col".into(),
             tpe: ColumnType {
                 raw: "time".into(),
                 family: ColumnTypeFamily::DateTime,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "time_with_zone_col".into(),
             tpe: ColumnType {
                 raw: "timetz".into(),
                 family: ColumnTypeFamily::DateTime,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "timestamp_col".into(),
             tpe: ColumnType {
                 raw: "timestamp".into(),
                 family: ColumnTypeFamily::DateTime,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "timestamp_with_zone_col".into(),
             tpe: ColumnType {
                 raw: "timestamptz".into(),
                 family: ColumnTypeFamily::DateTime,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "tsquery_col".into(),
             tpe: ColumnType {
                 raw: "tsquery".into(),
                 family: ColumnTypeFamily::TextSearch,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "tsvector_col".into(),
             tpe: ColumnType {
                 raw: "tsvector".into(),
                 family: ColumnTypeFamily::TextSearch,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "txid_col".into(),
             tpe: ColumnType {
                 raw: "txid_snapshot".into(),
                 family: ColumnTypeFamily::TransactionId,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "json_col".into(),
             tpe: ColumnType {
                 raw: "json".into(),
                 family: ColumnTypeFamily::Json,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "jsonb_col".into(),
             tpe: ColumnType {
                 raw: "jsonb".into(),
                 family: ColumnTypeFamily::Json,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "uuid_col".into(),
             tpe: ColumnType {
                 raw: "uuid".into(),
                 family: ColumnTypeFamily::Uuid,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
     ];
     expected_columns.sort_unstable_by_key(|c| c.name.to_owned());
@@ -519,6 +785,7 @@ fn all_postgres_column_types_must_work() {
         table,
         Table {
             name: "User".into(),
+            schema: None,
             columns: expected_columns,
             indices: vec![Index {
                 name: "User_uuid_col_key".into(),
@@ -533,6 +800,8 @@ fn all_postgres_column_types_must_work() {
                     allocation_size: 1,
                 },),
             }),
+            check_constraints: vec![],
+            comment: None,
             foreign_keys: vec![],
         }
     );
@@ -546,11 +815,11 @@ fn postgres_foreign_key_on_delete_must_be_handled() {
         "CREATE TABLE \"{0}\".\"City\" (id INT PRIMARY KEY);
          CREATE TABLE \"{0}\".\"User\" (
             id INT PRIMARY KEY,
-            city INT REFERENCES \"{0}\".\"City\" (id) ON DELETE NO ACTION,
-            city_cascade INT REFERENCES \"{0}\".\"City\" (id) ON DELETE CASCADE,
-            city_restrict INT REFERENCES \"{0}\".\"City\" (id) ON DELETE RESTRICT,
-            city_set_null INT REFERENCES \"{0}\".\"City\" (id) ON DELETE SET NULL,
-            city_set_default INT REFERENCES \"{0}\".\"City\" (id) ON DELETE SET DEFAULT
+            city INT REFERENCES \"{0}\".\"City\" (id) ON DELETE NO ACTION ON UPDATE NO ACTION,
+            city_cascade INT REFERENCES \"{0}\".\"City\" (id) ON DELETE CASCADE ON UPDATE CASCADE,
+            city_restrict INT REFERENCES \"{0}\".\"City\" (id) ON DELETE RESTRICT ON UPDATE RESTRICT,
+            city_set_null INT REFERENCES \"{0}\".\"City\" (id) ON DELETE SET NULL ON UPDATE SET NULL,
+            city_set_default INT REFERENCES \"{0}\".\"City\" (id) ON DELETE SET DEFAULT ON UPDATE SET DEFAULT
         );
         ",
         SCHEMA
@@ -565,66 +834,103 @@ fn postgres_foreign_key_on_delete_must_be_handled() {
         table,
         Table {
             name: "User".into(),
+            schema: None,
             columns: vec![
                 Column {
                     name: "city".into(),
                     tpe: ColumnType {
                         raw: "int4".into(),
                         family: ColumnTypeFamily::Int,
+                        character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
                     },
                     arity: ColumnArity::Nullable,
                     default: None,
+                    default_is_expression: false,
                     auto_increment: false,
+                    on_update_current_timestamp: false,
+                    comment: None,
                 },
                 Column {
                     name: "city_cascade".into(),
                     tpe: ColumnType {
                         raw: "int4".into(),
                         family: ColumnTypeFamily::Int,
+                        character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
                     },
                     arity: ColumnArity::Nullable,
                     default: None,
+                    default_is_expression: false,
                     auto_increment: false,
+                    on_update_current_timestamp: false,
+                    comment: None,
                 },
                 Column {
                     name: "city_restrict".into(),
                     tpe: ColumnType {
                         raw: "int4".into(),
                         family: ColumnTypeFamily::Int,
+                        character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
                     },
                     arity: ColumnArity::Nullable,
                     default: None,
+                    default_is_expression: false,
                     auto_increment: false,
+                    on_update_current_timestamp: false,
+                    comment: None,
                 },
                 Column {
                     name: "city_set_default".into(),
                     tpe: ColumnType {
                         raw: "int4".into(),
                         family: ColumnTypeFamily::Int,
+                        character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
                     },
                     arity: ColumnArity::Nullable,
                     default: None,
+                    default_is_expression: false,
                     auto_increment: false,
+                    on_update_current_timestamp: false,
+                    comment: None,
                 },
                 Column {
                     name: "city_set_null".into(),
                     tpe: ColumnType {
                         raw: "int4".into(),
                         family: ColumnTypeFamily::Int,
+                        character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
                     },
                     arity: ColumnArity::Nullable,
                     default: None,
+                    default_is_expression: false,
                     auto_increment: false,
+                    on_update_current_timestamp: false,
+                    comment: None,
                 },
                 Column {
                     name: "id".into(),
                     tpe: ColumnType {
                         raw: "int4".into(),
                         family: ColumnTypeFamily::Int,
+                        character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
                     },
                     arity: ColumnArity::Required,
                     default: None,
+                    default_is_expression: false,
                     auto_increment: false,
+                    on_update_current_timestamp: false,
+                    comment: None,
                 },
             ],
             indices: vec![],
@@ -632,41 +938,53 @@ fn postgres_foreign_key_on_delete_must_be_handled() {
                 columns: vec!["id".into()],
                 sequence: None,
             }),
+            check_constraints: vec![],
+            comment: None,
             foreign_keys: vec![
                 ForeignKey {
                     constraint_name: Some("User_city_fkey".to_owned()),
                     columns: vec!["city".into()],
                     referenced_columns: vec!["id".into()],
                     referenced_table: "City".into(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::NoAction,
+                    on_update_action: ForeignKeyAction::NoAction,
                 },
                 ForeignKey {
                     constraint_name: Some("User_city_cascade_fkey".to_owned()),
                     columns: vec!["city_cascade".into()],
                     referenced_columns: vec!["id".into()],
                     referenced_table: "City".into(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::Cascade,
+                    on_update_action: ForeignKeyAction::Cascade,
                 },
                 ForeignKey {
                     constraint_name: Some("User_city_restrict_fkey".to_owned()),
                     columns: vec!["city_restrict".into()],
                     referenced_columns: vec!["id".into()],
                     referenced_table: "City".into(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::Restrict,
+                    on_update_action: ForeignKeyAction::Restrict,
                 },
                 ForeignKey {
                     constraint_name: Some("User_city_set_default_fkey".to_owned()),
                     columns: vec!["city_set_default".into()],
                     referenced_columns: vec!["id".into()],
                     referenced_table: "City".into(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::SetDefault,
+                    on_update_action: ForeignKeyAction::SetDefault,
                 },
                 ForeignKey {
                     constraint_name: Some("User_city_set_null_fkey".to_owned()),
                     columns: vec!["city_set_null".into()],
                     referenced_columns: vec!["id".into()],
                     referenced_table: "City".into(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::SetNull,
+                    on_update_action: ForeignKeyAction::SetNull,
                 },
             ],
         }
@@ -685,7 +1003,7 @@ fn postgres_enums_must_work() {
     let schema = inspector.describe(SCHEMA).expect("describing");
     let got_enum = schema.get_enum("mood").expect("get enum");
 
-    let values: HashSet<String> = ["happy".into(), "ok".into(), "sad".into()].iter().cloned().collect();
+    let values: Vec<String> = vec!["sad".into(), "ok".into(), "happy".into()];
     assert_eq!(
         got_enum,
         &Enum {
@@ -713,3 +1031,115 @@ fn postgres_sequences_must_work() {
         },
     );
 }
+
+#[test]
+fn postgres_views_must_work() {
+    setup();
+
+    let inspector = get_postgres_describer(&format!(
+        "CREATE TABLE \"{0}\".\"User\" (id SERIAL PRIMARY KEY, name TEXT NOT NULL);
+        CREATE VIEW \"{0}\".\"UserName\" AS SELECT name FROM \"{0}\".\"User\";
+        CREATE MATERIALIZED VIEW \"{0}\".\"UserCount\" AS SELECT count(*)::int4 AS count FROM \"{0}\".\"User\"",
+        SCHEMA
+    ));
+
+    let schema = inspector.describe(SCHEMA).expect("describing");
+
+    assert_eq!(schema.tables.len(), 1);
+    assert_eq!(
+        schema.views,
+        vec![
+            View {
+                name: "UserCount".into(),
+                schema: None,
+                columns: vec![Column {
+                    name: "count".into(),
+                    tpe: ColumnType {
+                        raw: "int4".into(),
+                        family: ColumnTypeFamily::Int,
+                        character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
+                    },
+                    arity: ColumnArity::Nullable,
+                    default: None,
+                    default_is_expression: false,
+                    auto_increment: false,
+                    on_update_current_timestamp: false,
+                    comment: None,
+                }],
+                materialized: true,
+                indices: vec![],
+            },
+            View {
+                name: "UserName".into(),
+                schema: None,
+                columns: vec![Column {
+                    name: "name".into(),
+                    tpe: ColumnType {
+                        raw: "text".into(),
+                        family: ColumnTypeFamily::String,
+                        character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
+                    },
+                    arity: ColumnArity::Nullable,
+                    default: None,
+                    default_is_expression: false,
+                    auto_increment: false,
+                    on_update_current_timestamp: false,
+                    comment: None,
+                }],
+                materialized: false,
+                indices: vec![],
+            },
+        ]
+    );
+}
+
+#[test]
+fn postgres_materialized_view_indices_must_be_described() {
+    setup();
+
+    let inspector = get_postgres_describer(&format!(
+        "CREATE TABLE \"{0}\".\"User\" (id SERIAL PRIMARY KEY, name TEXT NOT NULL);
+        CREATE MATERIALIZED VIEW \"{0}\".\"UserName\" AS SELECT id, name FROM \"{0}\".\"User\";
+        CREATE UNIQUE INDEX \"UserName_id_key\" ON \"{0}\".\"UserName\" (id)",
+        SCHEMA
+    ));
+
+    let schema = inspector.describe(SCHEMA).expect("describing");
+    let view = schema.views.iter().find(|view| view.name == "UserName").unwrap();
+
+    assert_eq!(
+        view.indices,
+        vec![Index {
+            name: "UserName_id_key".into(),
+            columns: vec!["id".into()],
+            tpe: IndexType::Unique,
+        }]
+    );
+    assert_eq!(view.as_table().indices, view.indices);
+}
+
+#[test]
+fn postgres_table_and_column_comments_must_be_described() {
+    setup();
+
+    let inspector = get_postgres_describer(&format!(
+        "CREATE TABLE \"{0}\".\"User\" (id SERIAL PRIMARY KEY, name TEXT NOT NULL);
+        COMMENT ON TABLE \"{0}\".\"User\" IS 'The users of the app';
+        COMMENT ON COLUMN \"{0}\".\"User\".\"name\" IS 'The user''s full name'",
+        SCHEMA
+    ));
+
+    let schema = inspector.describe(SCHEMA).expect("describing");
+    let table = schema.table_bang("User");
+
+    assert_eq!(table.comment, Some("The users of the app".to_owned()));
+    assert_eq!(
+        table.column_bang("name").comment,
+        Some("The user's full name".to_owned())
+    );
+    assert_eq!(table.column_bang("id").comment, None);
+}
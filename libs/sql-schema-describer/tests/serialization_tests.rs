@@ -6,7 +6,6 @@ use log::{debug, LevelFilter};
 use pretty_assertions::assert_eq;
 use quaint::connector::{Queryable, Sqlite as SqliteDatabaseClient};
 use sql_schema_describer::*;
-use std::collections::HashSet;
 use std::fs::File;
 use std::path::Path;
 use std::sync::atomic::{AtomicBool, Ordering};
@@ -51,43 +50,60 @@ fn setup() {
 fn database_schema_is_serializable() {
     setup();
 
-    let mut enum_values = HashSet::new();
-    enum_values.insert("option1".to_string());
-    enum_values.insert("option2".to_string());
+    let enum_values = vec!["option1".to_string(), "option2".to_string()];
     let schema = SqlSchema {
         tables: vec![
             Table {
                 name: "table1".to_string(),
+                schema: None,
                 columns: vec![
                     Column {
                         name: "column1".to_string(),
                         tpe: ColumnType {
                             raw: "integer".to_string(),
                             family: ColumnTypeFamily::Int,
+                            character_maximum_length: None,
+                            numeric_precision: None,
+                            numeric_scale: None,
                         },
                         arity: ColumnArity::Required,
                         default: None,
+                        default_is_expression: false,
                         auto_increment: true,
+                        on_update_current_timestamp: false,
+                        comment: None,
                     },
                     Column {
                         name: "column2".to_string(),
                         tpe: ColumnType {
                             raw: "varchar(255)".to_string(),
                             family: ColumnTypeFamily::String,
+                            character_maximum_length: None,
+                            numeric_precision: None,
+                            numeric_scale: None,
                         },
                         arity: ColumnArity::Nullable,
                         default: Some("default value".to_string()),
+                        default_is_expression: false,
                         auto_increment: false,
+                        on_update_current_timestamp: false,
+                        comment: None,
                     },
                     Column {
                         name: "column3".to_string(),
                         tpe: ColumnType {
                             raw: "integer".to_string(),
                             family: ColumnTypeFamily::Int,
+                            character_maximum_length: None,
+                            numeric_precision: None,
+                            numeric_scale: None,
                         },
                         arity: ColumnArity::Required,
                         default: None,
+                        default_is_expression: false,
                         auto_increment: false,
+                        on_update_current_timestamp: false,
+                        comment: None,
                     },
                 ],
                 indices: vec![Index {
@@ -99,31 +115,44 @@ fn database_schema_is_serializable() {
                     columns: vec!["column1".to_string()],
                     sequence: None,
                 }),
+                check_constraints: vec![],
+                comment: None,
                 foreign_keys: vec![ForeignKey {
                     constraint_name: None,
                     columns: vec!["column3".to_string()],
                     referenced_table: "table2".to_string(),
+                    referenced_schema: None,
                     referenced_columns: vec!["id".to_string()],
                     on_delete_action: ForeignKeyAction::NoAction,
+                    on_update_action: ForeignKeyAction::NoAction,
                 }],
             },
             Table {
                 name: "table2".to_string(),
+                schema: None,
                 columns: vec![Column {
                     name: "id".to_string(),
                     tpe: ColumnType {
                         raw: "integer".to_string(),
                         family: ColumnTypeFamily::Int,
+                        character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
                     },
                     arity: ColumnArity::Required,
                     default: None,
+                    default_is_expression: false,
                     auto_increment: true,
+                    on_update_current_timestamp: false,
+                    comment: None,
                 }],
                 indices: vec![],
                 primary_key: Some(PrimaryKey {
                     columns: vec!["id".to_string()],
                     sequence: None,
                 }),
+                check_constraints: vec![],
+                comment: None,
                 foreign_keys: vec![],
             },
         ],
@@ -156,22 +185,32 @@ fn database_schema_without_primary_key_is_serializable() {
     let schema = SqlSchema {
         tables: vec![Table {
             name: "table1".to_string(),
+            schema: None,
             columns: vec![Column {
                 name: "column1".to_string(),
                 tpe: ColumnType {
                     raw: "integer".to_string(),
                     family: ColumnTypeFamily::Int,
+                    character_maximum_length: None,
+                    numeric_precision: None,
+                    numeric_scale: None,
                 },
                 arity: ColumnArity::Nullable,
                 default: None,
+                default_is_expression: false,
                 auto_increment: false,
+                on_update_current_timestamp: false,
+                comment: None,
             }],
             indices: vec![],
             primary_key: None,
+            check_constraints: vec![],
+            comment: None,
             foreign_keys: vec![],
         }],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let ref_schema_json = include_str!("./resources/schema-without-primary-key.json");
     let ref_schema: SqlSchema = serde_json::from_str(ref_schema_json).expect("deserialize reference schema");
@@ -211,22 +250,32 @@ fn database_schema_is_serializable_for_every_column_type_family() {
         tpe: ColumnType {
             raw: "raw type".to_string(),
             family: family.to_owned(),
+            character_maximum_length: None,
+            numeric_precision: None,
+            numeric_scale: None,
         },
         arity: ColumnArity::Nullable,
         default: None,
+        default_is_expression: false,
         auto_increment: false,
+        on_update_current_timestamp: false,
+        comment: None,
     })
     .collect();
     let schema = SqlSchema {
         tables: vec![Table {
             name: "table1".to_string(),
+            schema: None,
             columns,
             indices: vec![],
             primary_key: None,
+            check_constraints: vec![],
+            comment: None,
             foreign_keys: vec![],
         }],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let ref_schema_json = include_str!("./resources/schema-all-column-type-families.json");
     let ref_schema: SqlSchema = serde_json::from_str(ref_schema_json).expect("deserialize reference schema");
@@ -253,22 +302,32 @@ fn database_schema_is_serializable_for_every_column_arity() {
             tpe: ColumnType {
                 raw: "int".to_string(),
                 family: ColumnTypeFamily::Int,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: arity.to_owned(),
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         })
         .collect();
     let schema = SqlSchema {
         tables: vec![Table {
             name: "table1".to_string(),
+            schema: None,
             columns,
             indices: vec![],
             primary_key: None,
+            check_constraints: vec![],
+            comment: None,
             foreign_keys: vec![],
         }],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let ref_schema_json = include_str!("./resources/schema-all-column-arities.json");
     let ref_schema: SqlSchema = serde_json::from_str(ref_schema_json).expect("deserialize reference schema");
@@ -290,15 +349,22 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
     let schema = SqlSchema {
         tables: vec![Table {
             name: "table1".to_string(),
+            schema: None,
             columns: vec![
                 Column {
                     name: "column1".to_string(),
                     tpe: ColumnType {
                         raw: "int".to_string(),
                         family: ColumnTypeFamily::Int,
+                        character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
                     },
                     arity: ColumnArity::Nullable,
+                    default_is_expression: false,
                     auto_increment: false,
+                    on_update_current_timestamp: false,
+                    comment: None,
                     default: None,
                 },
                 Column {
@@ -306,9 +372,15 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                     tpe: ColumnType {
                         raw: "int".to_string(),
                         family: ColumnTypeFamily::Int,
+                        character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
                     },
                     arity: ColumnArity::Nullable,
+                    default_is_expression: false,
                     auto_increment: false,
+                    on_update_current_timestamp: false,
+                    comment: None,
                     default: None,
                 },
                 Column {
@@ -316,9 +388,15 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                     tpe: ColumnType {
                         raw: "int".to_string(),
                         family: ColumnTypeFamily::Int,
+                        character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
                     },
                     arity: ColumnArity::Nullable,
+                    default_is_expression: false,
                     auto_increment: false,
+                    on_update_current_timestamp: false,
+                    comment: None,
                     default: None,
                 },
                 Column {
@@ -326,9 +404,15 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                     tpe: ColumnType {
                         raw: "int".to_string(),
                         family: ColumnTypeFamily::Int,
+                        character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
                     },
                     arity: ColumnArity::Nullable,
+                    default_is_expression: false,
                     auto_increment: false,
+                    on_update_current_timestamp: false,
+                    comment: None,
                     default: None,
                 },
                 Column {
@@ -336,54 +420,73 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                     tpe: ColumnType {
                         raw: "int".to_string(),
                         family: ColumnTypeFamily::Int,
+                        character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
                     },
                     arity: ColumnArity::Nullable,
+                    default_is_expression: false,
                     auto_increment: false,
+                    on_update_current_timestamp: false,
+                    comment: None,
                     default: None,
                 },
             ],
             indices: vec![],
             primary_key: None,
+            check_constraints: vec![],
+            comment: None,
             foreign_keys: vec![
                 ForeignKey {
                     constraint_name: None,
                     columns: vec!["column1".to_string()],
                     referenced_table: "table2".to_string(),
+                    referenced_schema: None,
                     referenced_columns: vec!["id".to_string()],
                     on_delete_action: ForeignKeyAction::NoAction,
+                    on_update_action: ForeignKeyAction::NoAction,
                 },
                 ForeignKey {
                     constraint_name: None,
                     columns: vec!["column2".to_string()],
                     referenced_table: "table2".to_string(),
+                    referenced_schema: None,
                     referenced_columns: vec!["id".to_string()],
                     on_delete_action: ForeignKeyAction::Restrict,
+                    on_update_action: ForeignKeyAction::NoAction,
                 },
                 ForeignKey {
                     constraint_name: None,
                     columns: vec!["column3".to_string()],
                     referenced_table: "table2".to_string(),
+                    referenced_schema: None,
                     referenced_columns: vec!["id".to_string()],
                     on_delete_action: ForeignKeyAction::Cascade,
+                    on_update_action: ForeignKeyAction::NoAction,
                 },
                 ForeignKey {
                     constraint_name: None,
                     columns: vec!["column4".to_string()],
                     referenced_table: "table2".to_string(),
+                    referenced_schema: None,
                     referenced_columns: vec!["id".to_string()],
                     on_delete_action: ForeignKeyAction::SetNull,
+                    on_update_action: ForeignKeyAction::NoAction,
                 },
                 ForeignKey {
                     constraint_name: None,
                     columns: vec!["column5".to_string()],
                     referenced_table: "table2".to_string(),
+                    referenced_schema: None,
                     referenced_columns: vec!["id".to_string()],
                     on_delete_action: ForeignKeyAction::SetDefault,
+                    on_update_action: ForeignKeyAction::NoAction,
                 },
             ],
         }],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let ref_schema_json = include_str!("./resources/schema-all-foreign-key-actions.json");
     let ref_schema: SqlSchema = serde_json::from_str(ref_schema_json).expect("deserialize reference schema");
@@ -30,40 +30,64 @@ fn sqlite_column_types_must_work() {
             tpe: ColumnType {
                 raw: "INTEGER".to_string(),
                 family: ColumnTypeFamily::Int,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "text_col".to_string(),
             tpe: ColumnType {
                 raw: "TEXT".to_string(),
                 family: ColumnTypeFamily::String,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "real_col".to_string(),
             tpe: ColumnType {
                 raw: "REAL".to_string(),
                 family: ColumnTypeFamily::Float,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: false,
+            on_update_current_timestamp: false,
+            comment: None,
         },
         Column {
             name: "primary_col".to_string(),
             tpe: ColumnType {
                 raw: "INTEGER".to_string(),
                 family: ColumnTypeFamily::Int,
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
             },
             arity: ColumnArity::Required,
             default: None,
+            default_is_expression: false,
             auto_increment: true,
+            on_update_current_timestamp: false,
+            comment: None,
         },
     ];
     expected_columns.sort_unstable_by_key(|c| c.name.to_owned());
@@ -72,12 +96,15 @@ fn sqlite_column_types_must_work() {
         table,
         &Table {
             name: "User".to_string(),
+            schema: None,
             columns: expected_columns,
             indices: vec![],
             primary_key: Some(PrimaryKey {
                 columns: vec!["primary_col".to_string()],
                 sequence: None,
             }),
+            check_constraints: vec![],
+            comment: None,
             foreign_keys: vec![],
         }
     );
@@ -91,11 +118,11 @@ fn sqlite_foreign_key_on_delete_must_be_handled() {
         "CREATE TABLE \"{0}\".City (id INTEGER NOT NULL PRIMARY KEY);
          CREATE TABLE \"{0}\".User (
             id INTEGER NOT NULL PRIMARY KEY,
-            city INTEGER REFERENCES City(id) ON DELETE NO ACTION,
-            city_cascade INTEGER REFERENCES City(id) ON DELETE CASCADE,
-            city_restrict INTEGER REFERENCES City (id) ON DELETE RESTRICT,
-            city_set_default INTEGER REFERENCES City(id) ON DELETE SET DEFAULT,
-            city_set_null INTEGER REFERENCES City(id) ON DELETE SET NULL
+            city INTEGER REFERENCES City(id) ON DELETE NO ACTION ON UPDATE NO ACTION,
+            city_cascade INTEGER REFERENCES City(id) ON DELETE CASCADE ON UPDATE CASCADE,
+            city_restrict INTEGER REFERENCES City (id) ON DELETE RESTRICT ON UPDATE RESTRICT,
+            city_set_default INTEGER REFERENCES City(id) ON DELETE SET DEFAULT ON UPDATE SET DEFAULT,
+            city_set_null INTEGER REFERENCES City(id) ON DELETE SET NULL ON UPDATE SET NULL
         )",
         SCHEMA
     );
@@ -109,66 +136,103 @@ fn sqlite_foreign_key_on_delete_must_be_handled() {
         table,
         Table {
             name: "User".to_string(),
+            schema: None,
             columns: vec![
                 Column {
                     name: "city".to_string(),
                     tpe: ColumnType {
                         raw: "INTEGER".to_string(),
                         family: ColumnTypeFamily::Int,
+                        character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
                     },
                     arity: ColumnArity::Nullable,
                     default: None,
+                    default_is_expression: false,
                     auto_increment: false,
+                    on_update_current_timestamp: false,
+                    comment: None,
                 },
                 Column {
                     name: "city_cascade".to_string(),
                     tpe: ColumnType {
                         raw: "INTEGER".to_string(),
                         family: ColumnTypeFamily::Int,
+                        character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
                     },
                     arity: ColumnArity::Nullable,
                     default: None,
+                    default_is_expression: false,
                     auto_increment: false,
+                    on_update_current_timestamp: false,
+                    comment: None,
                 },
                 Column {
                     name: "city_restrict".to_string(),
                     tpe: ColumnType {
                         raw: "INTEGER".to_string(),
                         family: ColumnTypeFamily::Int,
+                        character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
                     },
                     arity: ColumnArity::Nullable,
                     default: None,
+                    default_is_expression: false,
                     auto_increment: false,
+                    on_update_current_timestamp: false,
+                    comment: None,
                 },
                 Column {
                     name: "city_set_default".to_string(),
                     tpe: ColumnType {
                         raw: "INTEGER".to_string(),
                         family: ColumnTypeFamily::Int,
+                        character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
                     },
                     arity: ColumnArity::Nullable,
                     default: None,
+                    default_is_expression: false,
                     auto_increment: false,
+                    on_update_current_timestamp: false,
+                    comment: None,
                 },
                 Column {
                     name: "city_set_null".to_string(),
                     tpe: ColumnType {
                         raw: "INTEGER".to_string(),
                         family: ColumnTypeFamily::Int,
+                        character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
                     },
                     arity: ColumnArity::Nullable,
                     default: None,
+                    default_is_expression: false,
                     auto_increment: false,
+                    on_update_current_timestamp: false,
+                    comment: None,
                 },
                 Column {
                     name: "id".to_string(),
                     tpe: ColumnType {
                         raw: "INTEGER".to_string(),
                         family: ColumnTypeFamily::Int,
+                        character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
                     },
                     arity: ColumnArity::Required,
                     default: None,
+                    default_is_expression: false,
                     auto_increment: true,
+                    on_update_current_timestamp: false,
+                    comment: None,
                 },
             ],
             indices: vec![],
@@ -176,41 +240,53 @@ fn sqlite_foreign_key_on_delete_must_be_handled() {
                 columns: vec!["id".to_string()],
                 sequence: None,
             }),
+            check_constraints: vec![],
+            comment: None,
             foreign_keys: vec![
                 ForeignKey {
                     constraint_name: None,
                     columns: vec!["city".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::NoAction,
+                    on_update_action: ForeignKeyAction::NoAction,
                 },
                 ForeignKey {
                     constraint_name: None,
                     columns: vec!["city_cascade".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::Cascade,
+                    on_update_action: ForeignKeyAction::Cascade,
                 },
                 ForeignKey {
                     constraint_name: None,
                     columns: vec!["city_restrict".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::Restrict,
+                    on_update_action: ForeignKeyAction::Restrict,
                 },
                 ForeignKey {
                     constraint_name: None,
                     columns: vec!["city_set_default".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::SetDefault,
+                    on_update_action: ForeignKeyAction::SetDefault,
                 },
                 ForeignKey {
                     constraint_name: None,
                     columns: vec!["city_set_null".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::SetNull,
+                    on_update_action: ForeignKeyAction::SetNull,
                 },
             ],
         }
@@ -0,0 +1,40 @@
+use serde::{Deserialize, Serialize};
+
+/// A user-authored change to the data, run at a defined point of a migration. For example, the
+/// values of a new required column can be backfilled after the column is added, and before it is
+/// made required.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct DataMigration {
+    pub hook: DataMigrationHook,
+    pub action: DataMigrationAction,
+}
+
+/// When a [DataMigration](struct.DataMigration.html) runs. Data migrations with the same hook run in
+/// the order they were given in.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum DataMigrationHook {
+    /// Before the first step of the migration.
+    BeforeMigration,
+    /// After the column has been added, and before constraints like `NOT NULL` are applied to it.
+    AfterAddColumn { table: String, column: String },
+    /// After the last step of the migration.
+    AfterMigration,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum DataMigrationAction {
+    /// SQL that is run as is.
+    Sql { sql: String },
+    /// Sets the column to the value of the SQL expression, on the rows matching the condition, or on
+    /// all the rows when there is none.
+    Backfill {
+        table: String,
+        column: String,
+        expression: String,
+        #[serde(default)]
+        condition: Option<String>,
+    },
+}
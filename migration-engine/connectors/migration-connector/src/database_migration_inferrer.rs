@@ -1,4 +1,4 @@
-use crate::{ConnectorResult, MigrationStep};
+use crate::{ConnectorResult, DataMigration, MigrationStep};
 use datamodel::Datamodel;
 
 /// The component responsible for generating a [DatabaseMigration](trait.MigrationConnector.html#associatedtype.DatabaseMigration)
@@ -17,4 +17,15 @@ pub trait DatabaseMigrationInferrer<T>: Send + Sync + 'static {
         next: &Datamodel,
         steps: &[MigrationStep],
     ) -> ConnectorResult<T>;
+
+    /// Adds the user-authored data migrations to an inferred migration, at the points their hooks
+    /// designate.
+    fn add_data_migrations(&self, database_migration: T, data_migrations: &[DataMigration]) -> ConnectorResult<T>;
+
+    /// Rewrites an inferred migration so it can be applied while the database is in use, avoiding
+    /// locks that block reads and writes for the whole migration. Connectors without such a mode
+    /// return the migration unchanged.
+    fn make_online(&self, database_migration: T) -> ConnectorResult<T> {
+        Ok(database_migration)
+    }
 }
@@ -3,14 +3,28 @@ use crate::*;
 /// Apply a single migration step to the connector's database. At this level, we are working with database migrations,
 /// i.e. the [associated type on MigrationConnector](trait.MigrationConnector.html#associatedtype.DatabaseMigration).
 pub trait DatabaseMigrationStepApplier<T>: Send + Sync + 'static {
-    /// Applies the step to the database
-    /// Returns true to signal to the caller that there are more steps to apply.
+    /// Applies the step to the database.
+    /// Returns false to signal to the caller that there was no step left to apply.
     fn apply_step(&self, database_migration: &T, step: usize) -> ConnectorResult<bool>;
 
-    /// Applies the step to the database.
-    /// Returns true to signal to the caller that there are more steps to unapply.
+    /// Unapplies the step from the database.
+    /// Returns false to signal to the caller that there was no step left to unapply.
     fn unapply_step(&self, database_migration: &T, step: usize) -> ConnectorResult<bool>;
 
+    /// Whether the whole migration can be applied in a single transaction. This depends on the
+    /// database supporting transactional DDL, and on the steps of the migration.
+    fn can_apply_in_transaction(&self, database_migration: &T) -> bool;
+
+    /// Applies all the steps in a single transaction, so a failing step leaves the database as it
+    /// was. Returns the number of applied steps.
+    fn apply_in_transaction(&self, database_migration: &T) -> ConnectorResult<usize>;
+
     /// Render steps for the CLI. Each step will contain the raw field.
     fn render_steps_pretty(&self, database_migration: &T) -> ConnectorResult<Vec<serde_json::Value>>;
+
+    /// Render the whole migration as a script that can be run by other tools.
+    fn render_script(&self, database_migration: &T) -> ConnectorResult<String>;
+
+    /// Render the rollback of the migration as a script that can be run by other tools.
+    fn render_rollback_script(&self, database_migration: &T) -> ConnectorResult<String>;
 }
@@ -0,0 +1,49 @@
+use crate::ConnectorResult;
+use serde::Serialize;
+
+/// Implementors of this trait are responsible for detecting changes that were made to the database
+/// outside of the migration engine, e.g. by manual hotfixes.
+///
+/// The type parameter is the connector's [DatabaseMigration](trait.MigrationConnector.html#associatedtype.DatabaseMigration)
+/// type.
+pub trait DriftDetector<T>: Send + Sync + 'static
+where
+    T: Send + Sync + 'static,
+{
+    /// Compares the live database with the state the last applied migration left it in. `None`
+    /// means no migration has been applied, and the database is expected to be empty.
+    fn detect_drift(&self, last_applied_migration: Option<&T>) -> ConnectorResult<Vec<SchemaDrift>>;
+}
+
+/// A difference between the live database and the schema expected from the migration history.
+#[derive(Debug, Serialize, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SchemaDrift {
+    pub kind: DriftKind,
+    pub object: DriftObject,
+    /// The table the drifted object belongs to, or the drifted table or enum itself.
+    pub table: String,
+    /// The name of the drifted column, index, foreign key or check constraint. `None` for tables and enums.
+    pub name: Option<String>,
+    pub description: String,
+}
+
+/// Whether the drifted object only exists in the database, is missing from it, or differs.
+#[derive(Debug, Serialize, PartialEq, Clone, Copy)]
+#[serde(rename_all = "camelCase")]
+pub enum DriftKind {
+    Added,
+    Removed,
+    Changed,
+}
+
+#[derive(Debug, Serialize, PartialEq, Clone, Copy)]
+#[serde(rename_all = "camelCase")]
+pub enum DriftObject {
+    Table,
+    Column,
+    Index,
+    ForeignKey,
+    CheckConstraint,
+    Enum,
+}
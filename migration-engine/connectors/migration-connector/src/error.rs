@@ -31,6 +31,12 @@ pub enum ConnectorError {
 
     #[fail(display = "Error opening a TLS connection. {}", message)]
     TlsError { message: String },
+
+    #[fail(
+        display = "Could not acquire {} within {} seconds: another migration engine is migrating the database.",
+        lock, timeout_secs
+    )]
+    MigrationLockTimeout { lock: String, timeout_secs: u64 },
 }
 
 impl From<quaint::error::Error> for ConnectorError {
@@ -1,19 +1,25 @@
 //! This crate defines the API exposed by the connectors to the migration engine core. The entry point for this API is the [MigrationConnector](trait.MigrationConnector.html) trait.
 
+mod data_migration;
 mod database_migration_inferrer;
 mod database_migration_step_applier;
 mod destructive_changes_checker;
+mod drift_detector;
 mod error;
 mod migration_applier;
+mod migration_lock;
 mod migration_persistence;
 
 pub mod steps;
 
+pub use data_migration::*;
 pub use database_migration_inferrer::*;
 pub use database_migration_step_applier::*;
 pub use destructive_changes_checker::*;
+pub use drift_detector::*;
 pub use error::*;
 pub use migration_applier::*;
+pub use migration_lock::*;
 pub use migration_persistence::*;
 pub use steps::MigrationStep;
 
@@ -54,6 +60,12 @@ pub trait MigrationConnector: Send + Sync + 'static {
     /// See [DestructiveChangesChecker](trait.DestructiveChangesChecker.html).
     fn destructive_changes_checker(&self) -> Arc<dyn DestructiveChangesChecker<Self::DatabaseMigration>>;
 
+    /// See [DriftDetector](trait.DriftDetector.html).
+    fn drift_detector(&self) -> Arc<dyn DriftDetector<Self::DatabaseMigration>>;
+
+    /// See [MigrationLock](trait.MigrationLock.html).
+    fn migration_lock(&self) -> Arc<dyn MigrationLock>;
+
     // TODO: figure out if this is the best way to do this or move to a better place/interface
     // this is placed here so i can use the associated type
     fn deserialize_database_migration(&self, json: serde_json::Value) -> Self::DatabaseMigration;
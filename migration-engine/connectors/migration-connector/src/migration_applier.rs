@@ -20,7 +20,13 @@ impl<T: 'static> MigrationApplier<T> for MigrationApplierImpl<T> {
         migration_updates.status = MigrationStatus::MigrationInProgress;
         self.migration_persistence.update(&migration_updates);
 
-        let apply_result = self.go_forward(&mut migration_updates, database_migration);
+        let apply_result = if self.step_applier.can_apply_in_transaction(database_migration) {
+            self.step_applier
+                .apply_in_transaction(database_migration)
+                .map(|applied| migration_updates.applied = applied)
+        } else {
+            self.go_forward(&mut migration_updates, database_migration)
+        };
 
         match apply_result {
             Ok(()) => {
@@ -0,0 +1,31 @@
+use crate::ConnectorResult;
+use std::time::Duration;
+
+/// How long the migration engine waits by default for another migration engine to release the
+/// [MigrationLock](trait.MigrationLock.html).
+pub const DEFAULT_MIGRATION_LOCK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Implementors of this trait prevent several migration engines from migrating the same database at
+/// the same time, e.g. when two deploy jobs start together.
+pub trait MigrationLock: Send + Sync + 'static {
+    /// Runs `f` while holding the lock. Waits at most `timeout` for another migration engine to
+    /// release the lock, and fails with a
+    /// [MigrationLockTimeout](enum.ConnectorError.html#variant.MigrationLockTimeout) error after that.
+    /// The lock is released when `f` returns.
+    fn with_lock(&self, timeout: Duration, f: &mut dyn FnMut()) -> ConnectorResult<()>;
+}
+
+/// Runs `f` while holding the migration lock, and returns its result. See
+/// [MigrationLock::with_lock](trait.MigrationLock.html#tymethod.with_lock).
+pub fn with_migration_lock<T>(
+    lock: &dyn MigrationLock,
+    timeout: Duration,
+    f: impl FnOnce() -> T,
+) -> ConnectorResult<T> {
+    let mut f = Some(f);
+    let mut result = None;
+
+    lock.with_lock(timeout, &mut || result = f.take().map(|f| f()))?;
+
+    Ok(result.expect("The migration lock was acquired without running the closure."))
+}
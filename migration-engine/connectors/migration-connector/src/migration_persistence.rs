@@ -1,12 +1,14 @@
 use crate::steps::*;
+use crate::ConnectorResult;
 use chrono::{DateTime, Utc};
 use datamodel::{ast::SchemaAst, Datamodel};
 use serde::Serialize;
+use sha2::{Digest, Sha256};
 
 /// This trait is implemented by each connector. It provides a generic API to store and retrieve [Migration](struct.Migration.html) records.
 pub trait MigrationPersistence: Send + Sync + 'static {
     /// Initialize migration persistence state. E.g. create the migrations table in an SQL database.
-    fn init(&self);
+    fn init(&self) -> ConnectorResult<()>;
 
     /// Drop all persisted state.
     fn reset(&self);
@@ -74,6 +76,12 @@ pub trait MigrationPersistence: Send + Sync + 'static {
     /// Used by the MigrationApplier to write the progress of a [Migration](struct.Migration.html)
     /// into the database.
     fn update(&self, params: &MigrationUpdateParams);
+
+    /// Replaces the migrations up to and including `last_squashed` with the baseline. The baseline
+    /// takes the revision of `last_squashed`, so the migrations after it keep their order. The
+    /// squashed migrations are kept, marked as squashed into the baseline, but are no longer
+    /// returned as part of the history.
+    fn squash(&self, last_squashed: &Migration, baseline: Migration) -> ConnectorResult<Migration>;
 }
 
 /// The representation of a migration as persisted through [MigrationPersistence](trait.MigrationPersistence.html).
@@ -91,6 +99,10 @@ pub struct Migration {
     pub errors: Vec<String>,
     pub started_at: DateTime<Utc>,
     pub finished_at: Option<DateTime<Utc>>,
+    /// The checksum of the migration, computed by the persistence when the migration is written.
+    /// Migrations written before checksums were introduced have none. See
+    /// [migration_checksum](fn.migration_checksum.html).
+    pub checksum: Option<String>,
 }
 
 /// Updates to be made to a persisted [Migration](struct.Migration.html).
@@ -132,6 +144,7 @@ impl Migration {
             errors: Vec::new(),
             started_at: Self::timestamp_without_nanos(),
             finished_at: None,
+            checksum: None,
         }
     }
 
@@ -165,6 +178,28 @@ impl Migration {
     }
 }
 
+/// The SHA-256 of the given bytes, hex encoded.
+pub fn sha256_hex(bytes: &[u8]) -> String {
+    Sha256::digest(bytes)
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+/// The SHA-256 of everything persisted with a migration that affects what it applies: its
+/// datamodel steps, its datamodel and its database migration. Comparing it with the persisted
+/// checksum detects edits to the migration history.
+pub fn migration_checksum(migration: &Migration) -> String {
+    let content = serde_json::to_string(&(
+        &migration.datamodel_steps,
+        &migration.datamodel_string,
+        &migration.database_migration,
+    ))
+    .expect("Serializing the migration failed");
+
+    sha256_hex(content.as_bytes())
+}
+
 impl IsWatchMigration for Migration {
     fn is_watch_migration(&self) -> bool {
         self.name.starts_with("watch")
@@ -213,7 +248,9 @@ impl MigrationStatus {
 pub struct EmptyMigrationPersistence {}
 
 impl MigrationPersistence for EmptyMigrationPersistence {
-    fn init(&self) {}
+    fn init(&self) -> ConnectorResult<()> {
+        Ok(())
+    }
 
     fn reset(&self) {}
 
@@ -237,6 +274,10 @@ impl MigrationPersistence for EmptyMigrationPersistence {
         unimplemented!("Not allowed on a EmptyMigrationPersistence")
     }
 
+    fn squash(&self, _last_squashed: &Migration, _baseline: Migration) -> ConnectorResult<Migration> {
+        unimplemented!("Not allowed on a EmptyMigrationPersistence")
+    }
+
     fn current_datamodel_ast(&self) -> datamodel::ast::SchemaAst {
         datamodel::ast::SchemaAst { tops: Vec::new() }
     }
@@ -2,17 +2,26 @@
 extern crate log;
 
 mod error;
+mod sql_data_migrations;
 mod sql_database_migration_inferrer;
 mod sql_database_step_applier;
 mod sql_destructive_changes_checker;
+mod sql_diff;
+mod sql_drift_detector;
+mod sql_enums;
 mod sql_migration;
+mod sql_migration_lock;
 mod sql_migration_persistence;
+mod sql_native_types;
+mod sql_online_migration;
+mod sql_renames;
 mod sql_renderer;
 mod sql_schema_calculator;
 mod sql_schema_differ;
 
 pub use error::*;
 pub use sql_connection::SqlFamily;
+pub use sql_diff::{DiffTarget, SqlDiff};
 pub use sql_migration::*;
 
 use migration_connector::*;
@@ -20,6 +29,8 @@ use sql_connection::{GenericSqlConnection, SyncSqlConnection};
 use sql_database_migration_inferrer::*;
 use sql_database_step_applier::*;
 use sql_destructive_changes_checker::*;
+use sql_drift_detector::*;
+use sql_migration_lock::*;
 use sql_migration_persistence::*;
 use sql_schema_describer::SqlSchemaDescriberBackend;
 use std::{fs, path::PathBuf, sync::Arc};
@@ -32,11 +43,15 @@ pub struct SqlMigrationConnector {
     pub file_path: Option<String>,
     pub sql_family: SqlFamily,
     pub schema_name: String,
+    /// The schemas listed in the datasource, next to the one of the connection string.
+    pub schemas: Vec<String>,
     pub database: Arc<dyn SyncSqlConnection + Send + Sync + 'static>,
     pub migration_persistence: Arc<dyn MigrationPersistence>,
     pub database_migration_inferrer: Arc<dyn DatabaseMigrationInferrer<SqlMigration>>,
     pub database_migration_step_applier: Arc<dyn DatabaseMigrationStepApplier<SqlMigration>>,
     pub destructive_changes_checker: Arc<dyn DestructiveChangesChecker<SqlMigration>>,
+    pub drift_detector: Arc<dyn DriftDetector<SqlMigration>>,
+    pub migration_lock: Arc<dyn MigrationLock>,
     pub database_introspector: Arc<dyn SqlSchemaDescriberBackend + Send + Sync + 'static>,
 }
 
@@ -44,16 +59,20 @@ impl SqlMigrationConnector {
     pub fn new_from_database_str(database_str: &str) -> std::result::Result<Self, ConnectorError> {
         let connection = GenericSqlConnection::from_database_str(database_str, Some("lift"))?;
 
-        Self::create_connector(connection, database_str)
+        Self::create_connector(connection, database_str, Vec::new())
     }
 
     pub fn new(datasource: &dyn datamodel::Source) -> std::result::Result<Self, ConnectorError> {
         let connection = GenericSqlConnection::from_datasource(datasource, Some("lift"))?;
 
-        Self::create_connector(connection, &datasource.url().value)
+        Self::create_connector(connection, &datasource.url().value, datasource.schemas().to_vec())
     }
 
-    fn create_connector(connection: GenericSqlConnection, url: &str) -> std::result::Result<Self, ConnectorError> {
+    fn create_connector(
+        connection: GenericSqlConnection,
+        url: &str,
+        schemas: Vec<String>,
+    ) -> std::result::Result<Self, ConnectorError> {
         // async connections can be lazy, so we issue a simple query to fail early if the database
         // is not reachable.
         connection.query_raw("SELECT 1", &[])?;
@@ -85,6 +104,7 @@ impl SqlMigrationConnector {
             sql_family,
             introspector: Arc::clone(&inspector),
             schema_name: schema_name.to_string(),
+            schemas: schemas.clone(),
         });
 
         let database_migration_step_applier = Arc::new(SqlDatabaseStepApplier {
@@ -94,23 +114,45 @@ impl SqlMigrationConnector {
         });
 
         let destructive_changes_checker = Arc::new(SqlDestructiveChangesChecker {
+            sql_family,
             schema_name: schema_name.clone(),
             database: Arc::clone(&conn),
         });
 
+        let drift_detector = Arc::new(SqlDriftDetector {
+            schema_name: schema_name.clone(),
+            schemas: schemas.clone(),
+            introspector: Arc::clone(&inspector),
+        });
+
+        let migration_lock = Arc::new(SqlMigrationLock {
+            sql_family,
+            schema_name: schema_name.clone(),
+            file_path: file_path.clone(),
+            url: url.to_owned(),
+        });
+
         Ok(Self {
             url: url.to_string(),
             file_path,
             sql_family,
             schema_name,
+            schemas,
             database: Arc::clone(&conn),
             migration_persistence,
             database_migration_inferrer,
             database_migration_step_applier,
             destructive_changes_checker,
+            drift_detector,
+            migration_lock,
             database_introspector: Arc::clone(&inspector),
         })
     }
+
+    /// The schema of the connection, followed by the other schemas of the datasource.
+    fn all_schemas(&self) -> impl Iterator<Item = &String> {
+        std::iter::once(&self.schema_name).chain(self.schemas.iter().filter(move |schema| **schema != self.schema_name))
+    }
 }
 
 impl MigrationConnector for SqlMigrationConnector {
@@ -153,25 +195,29 @@ impl MigrationConnector for SqlMigrationConnector {
                 }
             }
             SqlFamily::Postgres => {
-                let schema_sql = format!("CREATE SCHEMA IF NOT EXISTS \"{}\";", &self.schema_name);
+                for schema_name in self.all_schemas() {
+                    let schema_sql = format!("CREATE SCHEMA IF NOT EXISTS \"{}\";", schema_name);
 
-                debug!("{}", schema_sql);
+                    debug!("{}", schema_sql);
 
-                self.database.query_raw(&schema_sql, &[])?;
+                    self.database.query_raw(&schema_sql, &[])?;
+                }
             }
             SqlFamily::Mysql => {
-                let schema_sql = format!(
-                    "CREATE SCHEMA IF NOT EXISTS `{}` DEFAULT CHARACTER SET latin1;",
-                    &self.schema_name
-                );
+                for schema_name in self.all_schemas() {
+                    let schema_sql = format!(
+                        "CREATE SCHEMA IF NOT EXISTS `{}` DEFAULT CHARACTER SET latin1;",
+                        schema_name
+                    );
 
-                debug!("{}", schema_sql);
+                    debug!("{}", schema_sql);
 
-                self.database.query_raw(&schema_sql, &[])?;
+                    self.database.query_raw(&schema_sql, &[])?;
+                }
             }
         }
 
-        self.migration_persistence.init();
+        self.migration_persistence.init()?;
 
         Ok(())
     }
@@ -197,6 +243,14 @@ impl MigrationConnector for SqlMigrationConnector {
         Arc::clone(&self.destructive_changes_checker)
     }
 
+    fn drift_detector(&self) -> Arc<dyn DriftDetector<SqlMigration>> {
+        Arc::clone(&self.drift_detector)
+    }
+
+    fn migration_lock(&self) -> Arc<dyn MigrationLock> {
+        Arc::clone(&self.migration_lock)
+    }
+
     fn deserialize_database_migration(&self, json: serde_json::Value) -> SqlMigration {
         serde_json::from_value(json).expect("Deserializing the database migration failed.")
     }
@@ -0,0 +1,163 @@
+use crate::*;
+use sql_schema_describer::*;
+
+/// Inserts the data migrations into the steps of the migration.
+///
+/// A table alteration adding a column with data migrations is split in two: the first alteration
+/// adds the column as nullable, and the second one makes it required and performs the other
+/// changes to the table, e.g. dropping the columns the data was migrated from. The data migrations
+/// run in between.
+pub(crate) fn add_data_migrations(
+    migration: SqlMigration,
+    data_migrations: &[DataMigration],
+    sql_family: SqlFamily,
+) -> SqlResult<SqlMigration> {
+    let with_hook = |predicate: &dyn Fn(&DataMigrationHook) -> bool| -> Vec<SqlMigrationStep> {
+        data_migrations
+            .iter()
+            .filter(|data_migration| predicate(&data_migration.hook))
+            .cloned()
+            .map(SqlMigrationStep::DataMigration)
+            .collect()
+    };
+
+    let mut corrected_steps = with_hook(&|hook| hook == &DataMigrationHook::BeforeMigration);
+    let mut hooked_columns: Vec<(&str, &str)> = data_migrations
+        .iter()
+        .filter_map(|data_migration| match &data_migration.hook {
+            DataMigrationHook::AfterAddColumn { table, column } => Some((table.as_str(), column.as_str())),
+            _ => None,
+        })
+        .collect();
+
+    for step in &migration.corrected_steps {
+        let alter_table = match step {
+            SqlMigrationStep::AlterTable(alter_table) => alter_table,
+            _ => {
+                corrected_steps.push(step.clone());
+                continue;
+            }
+        };
+
+        let added_columns: Vec<&Column> = alter_table
+            .changes
+            .iter()
+            .filter_map(|change| match change {
+                TableChange::AddColumn(AddColumn { column })
+                    if hooked_columns.contains(&(alter_table.table.name.as_str(), column.name.as_str())) =>
+                {
+                    Some(column)
+                }
+                _ => None,
+            })
+            .collect();
+
+        if added_columns.is_empty() {
+            corrected_steps.push(step.clone());
+            continue;
+        }
+
+        hooked_columns.retain(|(table, column)| {
+            table != &alter_table.table.name || added_columns.iter().all(|added| &added.name != column)
+        });
+
+        let (expand, contract) = split_alter_table(alter_table, &added_columns);
+        corrected_steps.push(SqlMigrationStep::AlterTable(expand));
+
+        for column in &added_columns {
+            corrected_steps.extend(with_hook(&|hook| match hook {
+                DataMigrationHook::AfterAddColumn {
+                    table,
+                    column: column_name,
+                } => table == &alter_table.table.name && column_name == &column.name,
+                _ => false,
+            }));
+        }
+
+        corrected_steps.extend(contract.map(SqlMigrationStep::AlterTable));
+    }
+
+    // On SQLite, tables are redefined instead of altered, which leaves no point to run the data
+    // migrations at.
+    if let Some((table, column)) = hooked_columns.first() {
+        let reason = match sql_family {
+            SqlFamily::Sqlite => "Data migrations can not run after a column is added on SQLite.".to_owned(),
+            _ => format!(
+                "The migration does not add the column `{}` to the table `{}`.",
+                column, table
+            ),
+        };
+
+        return Err(SqlError::Generic(format!("Invalid data migration hook: {}", reason)));
+    }
+
+    corrected_steps.extend(with_hook(&|hook| hook == &DataMigrationHook::AfterMigration));
+
+    Ok(SqlMigration {
+        corrected_steps,
+        ..migration
+    })
+}
+
+fn split_alter_table(alter_table: &AlterTable, added_columns: &[&Column]) -> (AlterTable, Option<AlterTable>) {
+    let is_added = |name: &str| added_columns.iter().any(|column| column.name == name);
+    let nullable = |column: &Column| Column {
+        arity: ColumnArity::Nullable,
+        ..column.clone()
+    };
+
+    let mut expand_table = alter_table.table.clone();
+    for column in expand_table.columns.iter_mut().filter(|column| is_added(&column.name)) {
+        if column.is_required() {
+            column.arity = ColumnArity::Nullable;
+        }
+    }
+
+    let mut expand_changes = Vec::new();
+    let mut contract_changes = Vec::new();
+
+    for change in &alter_table.changes {
+        match change {
+            TableChange::AddColumn(AddColumn { column }) if is_added(&column.name) => {
+                let column = if column.is_required() {
+                    nullable(column)
+                } else {
+                    column.clone()
+                };
+                expand_changes.push(TableChange::AddColumn(AddColumn { column }));
+            }
+            _ => contract_changes.push(change.clone()),
+        }
+    }
+
+    contract_changes.extend(
+        added_columns
+            .iter()
+            .filter(|column| column.is_required())
+            .map(|column| {
+                TableChange::AlterColumn(AlterColumn {
+                    name: column.name.clone(),
+                    column: (*column).clone(),
+                    in_place: Some(ColumnAlteration {
+                        type_change: None,
+                        arity_changed: true,
+                    }),
+                })
+            }),
+    );
+
+    let expand = AlterTable {
+        table: expand_table,
+        changes: expand_changes,
+    };
+    let contract = if contract_changes.is_empty() {
+        None
+    } else {
+        Some(AlterTable {
+            table: alter_table.table.clone(),
+            changes: contract_changes,
+        })
+    };
+
+    (expand, contract)
+}
@@ -1,3 +1,5 @@
+use crate::sql_renames::SqlRenames;
+use crate::sql_renderer::{render_default, render_nullability, SqlRenderer};
 use crate::sql_schema_calculator::SqlSchemaCalculator;
 use crate::sql_schema_differ::{SqlSchemaDiff, SqlSchemaDiffer};
 use crate::*;
@@ -11,22 +13,21 @@ pub struct SqlDatabaseMigrationInferrer {
     pub sql_family: SqlFamily,
     pub introspector: Arc<dyn SqlSchemaDescriberBackend + Send + Sync + 'static>,
     pub schema_name: String,
+    /// The other schemas of the datasource, described along with the default one.
+    pub schemas: Vec<String>,
 }
 
 impl DatabaseMigrationInferrer<SqlMigration> for SqlDatabaseMigrationInferrer {
-    fn infer(
-        &self,
-        _previous: &Datamodel,
-        next: &Datamodel,
-        _steps: &[MigrationStep],
-    ) -> ConnectorResult<SqlMigration> {
-        let current_database_schema: SqlSchema = self.introspect(&self.schema_name)?;
-        let expected_database_schema = SqlSchemaCalculator::calculate(next)?;
+    fn infer(&self, previous: &Datamodel, next: &Datamodel, steps: &[MigrationStep]) -> ConnectorResult<SqlMigration> {
+        let current_database_schema: SqlSchema = self.introspect()?;
+        let expected_database_schema = SqlSchemaCalculator::calculate(next, self.sql_family, &self.schema_name)?;
         infer(
             &current_database_schema,
             &expected_database_schema,
+            &SqlRenames::from_steps(previous, next, steps),
             &self.schema_name,
             self.sql_family,
+            true,
         )
     }
 
@@ -34,42 +35,73 @@ impl DatabaseMigrationInferrer<SqlMigration> for SqlDatabaseMigrationInferrer {
         &self,
         previous: &Datamodel,
         next: &Datamodel,
-        _steps: &[MigrationStep],
+        steps: &[MigrationStep],
     ) -> ConnectorResult<SqlMigration> {
-        let current_database_schema: SqlSchema = SqlSchemaCalculator::calculate(previous)?;
-        let expected_database_schema = SqlSchemaCalculator::calculate(next)?;
+        let current_database_schema: SqlSchema =
+            SqlSchemaCalculator::calculate(previous, self.sql_family, &self.schema_name)?;
+        let expected_database_schema = SqlSchemaCalculator::calculate(next, self.sql_family, &self.schema_name)?;
         infer(
             &current_database_schema,
             &expected_database_schema,
+            &SqlRenames::from_steps(previous, next, steps),
             &self.schema_name,
             self.sql_family,
+            false,
         )
     }
+
+    fn add_data_migrations(
+        &self,
+        database_migration: SqlMigration,
+        data_migrations: &[DataMigration],
+    ) -> ConnectorResult<SqlMigration> {
+        Ok(sql_data_migrations::add_data_migrations(
+            database_migration,
+            data_migrations,
+            self.sql_family,
+        )?)
+    }
+
+    fn make_online(&self, database_migration: SqlMigration) -> ConnectorResult<SqlMigration> {
+        Ok(sql_online_migration::make_online(
+            database_migration,
+            self.sql_family,
+            &self.schema_name,
+        )?)
+    }
 }
 
 impl SqlDatabaseMigrationInferrer {
-    fn introspect(&self, schema: &str) -> SqlResult<SqlSchema> {
-        Ok(self.introspector.describe(&schema)?)
+    fn introspect(&self) -> SqlResult<SqlSchema> {
+        Ok(self.introspector.describe_schemas(&self.schema_name, &self.schemas)?)
     }
 }
 
-fn infer(
+/// `current_is_described` tells whether the current schema was described from the database,
+/// rather than calculated from a datamodel.
+pub(crate) fn infer(
     current_database_schema: &SqlSchema,
     expected_database_schema: &SqlSchema,
+    renames: &SqlRenames,
     schema_name: &str,
     sql_family: SqlFamily,
+    current_is_described: bool,
 ) -> ConnectorResult<SqlMigration> {
     let (original_steps, corrected_steps) = infer_database_migration_steps_and_fix(
         &current_database_schema,
         &expected_database_schema,
+        renames,
         &schema_name,
         sql_family,
+        current_is_described,
     )?;
     let (_, rollback) = infer_database_migration_steps_and_fix(
         &expected_database_schema,
         &current_database_schema,
+        &renames.inverse(),
         &schema_name,
         sql_family,
+        false,
     )?;
     Ok(SqlMigration {
         before: current_database_schema.clone(),
@@ -83,26 +115,35 @@ fn infer(
 fn infer_database_migration_steps_and_fix(
     from: &SqlSchema,
     to: &SqlSchema,
+    renames: &SqlRenames,
     schema_name: &str,
     sql_family: SqlFamily,
+    from_is_described: bool,
 ) -> SqlResult<(Vec<SqlMigrationStep>, Vec<SqlMigrationStep>)> {
-    let diff: SqlSchemaDiff = SqlSchemaDiffer::diff(&from, &to);
+    // The renames run first, so everything else is diffed against the renamed schema.
+    let (from, rename_steps) = renames.apply(from);
+    let mut diff: SqlSchemaDiff = SqlSchemaDiffer::diff(&from, &to);
+    migrate_comments(&mut diff, &from, &to, sql_family, from_is_described)?;
+    let original_steps = rename_steps.iter().cloned().chain(diff.clone().into_steps()).collect();
     let is_sqlite = sql_family == SqlFamily::Sqlite;
 
     let corrected_steps = if is_sqlite {
         fix_stupid_sqlite(diff, &from, &to, &schema_name)?
     } else {
         let steps = delay_foreign_key_creation(diff);
-        fix_id_column_type_change(&from, &to, schema_name, steps)?
+        fix_id_column_type_change(&from, &to, schema_name, sql_family, steps)?
     };
 
-    Ok((SqlSchemaDiffer::diff(&from, &to).into_steps(), corrected_steps))
+    let corrected_steps = rename_steps.into_iter().chain(corrected_steps).collect();
+
+    Ok((original_steps, corrected_steps))
 }
 
 fn fix_id_column_type_change(
     from: &SqlSchema,
     to: &SqlSchema,
     _schema_name: &str,
+    sql_family: SqlFamily,
     steps: Vec<SqlMigrationStep>,
 ) -> SqlResult<Vec<SqlMigrationStep>> {
     let has_id_type_change = steps
@@ -140,11 +181,24 @@ fn fix_id_column_type_change(
         let tables_to_drop: Vec<String> = from
             .tables
             .iter()
-            .filter(|t| t.name != "_Migration")
+            .filter(|t| t.name != "_Migration" && t.schema.is_none())
             .map(|t| t.name.clone())
             .collect();
         radical_steps.push(SqlMigrationStep::DropTables(DropTables { names: tables_to_drop }));
-        let diff_from_empty: SqlSchemaDiff = SqlSchemaDiffer::diff(&SqlSchema::empty(), &to);
+        // `DropTables` only covers the default schema.
+        radical_steps.extend(from.tables.iter().filter(|t| t.schema.is_some()).map(|t| {
+            SqlMigrationStep::DropTable(DropTable {
+                name: t.name.clone(),
+                schema: t.schema.clone(),
+            })
+        }));
+        // The enums survive the dropped tables.
+        let without_tables = SqlSchema {
+            enums: from.enums.clone(),
+            ..SqlSchema::empty()
+        };
+        let mut diff_from_empty: SqlSchemaDiff = SqlSchemaDiffer::diff(&without_tables, &to);
+        migrate_comments(&mut diff_from_empty, &without_tables, &to, sql_family, false)?;
         let mut steps_from_empty = delay_foreign_key_creation(diff_from_empty);
         radical_steps.append(&mut steps_from_empty);
 
@@ -154,6 +208,149 @@ fn fix_id_column_type_change(
     }
 }
 
+/// Postgres sets comments in statements of their own, SQLite has none. MySQL sets column comments
+/// as part of the column definition, so created and altered columns carry theirs, and the other
+/// columns get restated.
+fn migrate_comments(
+    diff: &mut SqlSchemaDiff,
+    from: &SqlSchema,
+    to: &SqlSchema,
+    sql_family: SqlFamily,
+    from_is_described: bool,
+) -> SqlResult<()> {
+    match sql_family {
+        SqlFamily::Postgres => Ok(()),
+        SqlFamily::Sqlite => {
+            diff.set_comments.clear();
+            Ok(())
+        }
+        SqlFamily::Mysql => restate_mysql_commented_columns(diff, from, to, from_is_described),
+    }
+}
+
+fn restate_mysql_commented_columns(
+    diff: &mut SqlSchemaDiff,
+    from: &SqlSchema,
+    to: &SqlSchema,
+    from_is_described: bool,
+) -> SqlResult<()> {
+    let set_comments = std::mem::replace(&mut diff.set_comments, Vec::new());
+
+    for set_comment in set_comments {
+        let column_name = match &set_comment.column {
+            Some(column_name) => column_name,
+            None => {
+                diff.set_comments.push(set_comment);
+                continue;
+            }
+        };
+        // Created tables and added columns are rendered with their comments.
+        let (previous_table, previous_column) = match comment_table(from, &set_comment)
+            .and_then(|table| table.column(column_name).map(|column| (table, column)))
+        {
+            Some(previous) => previous,
+            None => continue,
+        };
+        let next_table = comment_table(to, &set_comment).expect("The table of a comment must exist.");
+        let next_column = next_table.column_bang(column_name);
+
+        let alter_table_position = diff.alter_tables.iter().position(|alter_table| {
+            alter_table.table.name == next_table.name && alter_table.table.schema == next_table.schema
+        });
+        let is_altered = alter_table_position
+            .map(|position| {
+                diff.alter_tables[position].changes.iter().any(|change| match change {
+                    TableChange::AlterColumn(alter_column) => alter_column.column.name == next_column.name,
+                    _ => false,
+                })
+            })
+            .unwrap_or(false);
+
+        if is_altered {
+            continue;
+        }
+
+        check_comment_restatement(
+            previous_table,
+            previous_column,
+            next_table,
+            next_column,
+            from_is_described,
+        )?;
+
+        let change = TableChange::AlterColumn(AlterColumn {
+            name: previous_column.name.clone(),
+            column: next_column.clone(),
+            in_place: Some(ColumnAlteration {
+                type_change: None,
+                arity_changed: false,
+            }),
+        });
+
+        match alter_table_position {
+            Some(position) => diff.alter_tables[position].changes.push(change),
+            None => diff.alter_tables.push(AlterTable {
+                table: next_table.clone(),
+                changes: vec![change],
+            }),
+        }
+    }
+
+    Ok(())
+}
+
+fn comment_table<'a>(schema: &'a SqlSchema, set_comment: &SetComment) -> Option<&'a Table> {
+    schema
+        .tables
+        .iter()
+        .find(|table| table.name == set_comment.table && table.schema == set_comment.schema)
+}
+
+/// Restating a column to change its comment must leave the rest of its definition alone. We
+/// refuse when the rendered definition would drop a `NOT NULL`, a default, an auto increment, an
+/// `ON UPDATE` or a length the column has in the database.
+fn check_comment_restatement(
+    previous_table: &Table,
+    previous_column: &Column,
+    next_table: &Table,
+    next_column: &Column,
+    from_is_described: bool,
+) -> SqlResult<()> {
+    // A calculated column exists in the database as we render it.
+    let (was_required, had_default) = if from_is_described {
+        (previous_column.is_required(), previous_column.default.is_some())
+    } else {
+        (
+            !render_nullability(previous_table, previous_column).is_empty(),
+            !render_default(previous_column).is_empty(),
+        )
+    };
+    let restated_type = SqlRenderer::for_family(&SqlFamily::Mysql)
+        .render_column_type("", &next_column.tpe)
+        .to_lowercase();
+    let length_changed = from_is_described
+        && previous_column
+            .tpe
+            .character_maximum_length
+            .map(|length| restated_type != format!("{}({})", previous_column.tpe.raw.to_lowercase(), length))
+            .unwrap_or(false);
+
+    let definition_changed = was_required == render_nullability(next_table, next_column).is_empty()
+        || had_default == render_default(next_column).is_empty()
+        || previous_column.auto_increment != next_column.auto_increment
+        || previous_column.on_update_current_timestamp != next_column.on_update_current_timestamp
+        || length_changed;
+
+    if definition_changed {
+        return Err(SqlError::Generic(format!(
+            "The comment of the column `{}` on the `{}` table can not be changed on MySQL without changing the rest of its definition. Make the column match the datamodel first.",
+            next_column.name, next_table.name
+        )));
+    }
+
+    Ok(())
+}
+
 // this function caters for the case that a table gets created that has a foreign key to a table that still needs to be created
 // Example: Table A has a reference to Table B and Table B has a reference to Table A.
 // We therefore split the creation of foreign key columns into separate steps when the referenced tables are not existing yet.
@@ -248,8 +445,13 @@ fn needs_fix(alter_table: &AlterTable) -> bool {
             add_column.column.arity == ColumnArity::Required
         }
         TableChange::DropColumn(_) => true,
+        // Supported since SQLite 3.25.
+        TableChange::RenameColumn(_) => false,
         TableChange::AlterColumn(_) => true,
         TableChange::DropForeignKey(_) => true,
+        // SQLite can only define check constraints as part of CREATE TABLE.
+        TableChange::AddCheckConstraint(_) => true,
+        TableChange::DropCheckConstraint(_) => true,
     });
 
     change_that_does_not_work_on_sqlite.is_some()
@@ -277,7 +479,7 @@ fn fix(current: &Table, next: &Table, schema_name: &str) -> Vec<SqlMigrationStep
     result.push(SqlMigrationStep::RawSql {
         raw: "PRAGMA foreign_keys=OFF;".to_string(),
     });
-    // todo: start transaction now. Unclear if we really want to do that.
+    // The step applier runs the steps between the foreign key toggles in a transaction.
     result.push(SqlMigrationStep::CreateTable(CreateTable { table: temporary_table }));
     result.push(
         // copy table contents; Here we have to handle escpaing ourselves.
@@ -306,10 +508,12 @@ fn fix(current: &Table, next: &Table, schema_name: &str) -> Vec<SqlMigrationStep
 
     result.push(SqlMigrationStep::DropTable(DropTable {
         name: current.name.clone(),
+        schema: None,
     }));
     result.push(SqlMigrationStep::RenameTable {
         name: name_of_temporary_table,
         new_name: next.name.clone(),
+        schema: None,
     });
     result.append(
         &mut next
@@ -319,6 +523,7 @@ fn fix(current: &Table, next: &Table, schema_name: &str) -> Vec<SqlMigrationStep
                 SqlMigrationStep::CreateIndex(CreateIndex {
                     table: next.name.clone(),
                     index: index.clone(),
+                    schema: None,
                 })
             })
             .collect(),
@@ -327,7 +532,6 @@ fn fix(current: &Table, next: &Table, schema_name: &str) -> Vec<SqlMigrationStep
     result.push(SqlMigrationStep::RawSql {
         raw: format!(r#"PRAGMA "{}".foreign_key_check;"#, schema_name),
     });
-    // todo: commit transaction
     result.push(SqlMigrationStep::RawSql {
         raw: "PRAGMA foreign_keys=ON;".to_string(),
     });
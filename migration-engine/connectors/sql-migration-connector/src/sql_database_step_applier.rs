@@ -1,3 +1,4 @@
+use crate::sql_enums::quote_enum_value;
 use crate::*;
 use sql_connection::SyncSqlConnection;
 use sql_renderer::SqlRenderer;
@@ -19,6 +20,19 @@ impl DatabaseMigrationStepApplier<SqlMigration> for SqlDatabaseStepApplier {
         Ok(self.apply_next_step(&database_migration.rollback, index)?)
     }
 
+    fn can_apply_in_transaction(&self, database_migration: &SqlMigration) -> bool {
+        // MySQL implicitly commits the transaction before and after each DDL statement.
+        self.sql_family != SqlFamily::Mysql
+            && database_migration
+                .corrected_steps
+                .iter()
+                .all(SqlMigrationStep::can_run_in_transaction)
+    }
+
+    fn apply_in_transaction(&self, database_migration: &SqlMigration) -> ConnectorResult<usize> {
+        Ok(self.apply_all_in_transaction(&database_migration.corrected_steps)?)
+    }
+
     fn render_steps_pretty(&self, database_migration: &SqlMigration) -> ConnectorResult<Vec<serde_json::Value>> {
         Ok(
             render_steps_pretty(&database_migration, self.sql_family, &self.schema_name)?
@@ -27,9 +41,65 @@ impl DatabaseMigrationStepApplier<SqlMigration> for SqlDatabaseStepApplier {
                 .collect(),
         )
     }
+
+    fn render_script(&self, database_migration: &SqlMigration) -> ConnectorResult<String> {
+        Ok(render_script(
+            &database_migration.corrected_steps,
+            self.sql_family,
+            &self.schema_name,
+        )?)
+    }
+
+    fn render_rollback_script(&self, database_migration: &SqlMigration) -> ConnectorResult<String> {
+        Ok(render_script(
+            &database_migration.rollback,
+            self.sql_family,
+            &self.schema_name,
+        )?)
+    }
 }
 
 impl SqlDatabaseStepApplier {
+    fn apply_all_in_transaction(&self, steps: &[SqlMigrationStep]) -> SqlResult<usize> {
+        // Foreign keys can't be toggled in a transaction on SQLite, so the migration is wrapped in
+        // the toggles instead of containing them.
+        let (foreign_key_toggles, migration_steps): (Vec<&SqlMigrationStep>, Vec<&SqlMigrationStep>) =
+            steps.iter().partition(|step| match step {
+                SqlMigrationStep::RawSql { raw } => raw.starts_with("PRAGMA foreign_keys="),
+                _ => false,
+            });
+        let statements: Vec<String> = migration_steps
+            .iter()
+            .map(|step| render_raw_sql(step, self.sql_family, &self.schema_name))
+            .collect::<SqlResult<_>>()?;
+        debug!("{}", statements.join("\n"));
+
+        let toggle_foreign_keys = !foreign_key_toggles.is_empty();
+        let mut result = Ok(());
+
+        // The pragma only applies to the connection it runs on, so the toggles and the
+        // transaction have to share a connection.
+        self.conn.with_pinned_connection(&mut |conn| {
+            result = (|| -> SqlResult<()> {
+                if toggle_foreign_keys {
+                    conn.query_raw("PRAGMA foreign_keys=OFF;", &[])?;
+                }
+
+                let migration_result = conn.execute_in_transaction(&statements);
+
+                if toggle_foreign_keys {
+                    conn.query_raw("PRAGMA foreign_keys=ON;", &[])?;
+                }
+
+                Ok(migration_result?)
+            })();
+        })?;
+
+        result?;
+
+        Ok(steps.len())
+    }
+
     fn apply_next_step(&self, steps: &Vec<SqlMigrationStep>, index: usize) -> SqlResult<bool> {
         let has_this_one = steps.get(index).is_some();
         if !has_this_one {
@@ -37,7 +107,7 @@ impl SqlDatabaseStepApplier {
         }
 
         let step = &steps[index];
-        let sql_string = render_raw_sql(&step, self.sql_family, &self.schema_name);
+        let sql_string = render_raw_sql(&step, self.sql_family, &self.schema_name)?;
         debug!("{}", sql_string);
 
         let result = self.conn.query_raw(&sql_string, &[]);
@@ -45,8 +115,7 @@ impl SqlDatabaseStepApplier {
         // TODO: this does not evaluate the results of SQLites PRAGMA foreign_key_check
         result?;
 
-        let has_more = steps.get(index + 1).is_some();
-        Ok(has_more)
+        Ok(true)
     }
 }
 
@@ -58,19 +127,38 @@ fn render_steps_pretty(
     let steps = database_migration
         .corrected_steps
         .iter()
-        .map(|step| PrettySqlMigrationStep {
-            step: step.clone(),
-            raw: render_raw_sql(&step, sql_family, schema_name),
+        .map(|step| {
+            Ok(PrettySqlMigrationStep {
+                step: step.clone(),
+                raw: render_raw_sql(&step, sql_family, schema_name)?,
+            })
         })
-        .collect();
+        .collect::<SqlResult<_>>()?;
     Ok(steps)
 }
 
-fn render_raw_sql(step: &SqlMigrationStep, sql_family: SqlFamily, schema_name: &str) -> String {
+/// Renders the steps as a script with one statement per line.
+pub(crate) fn render_script(steps: &[SqlMigrationStep], sql_family: SqlFamily, schema_name: &str) -> SqlResult<String> {
+    steps
+        .iter()
+        .map(|step| {
+            let sql = render_raw_sql(step, sql_family, schema_name)?;
+            let sql = sql.trim_end();
+
+            if sql.ends_with(';') {
+                Ok(format!("{}\n", sql))
+            } else {
+                Ok(format!("{};\n", sql))
+            }
+        })
+        .collect()
+}
+
+pub(crate) fn render_raw_sql(step: &SqlMigrationStep, sql_family: SqlFamily, schema_name: &str) -> SqlResult<String> {
     let schema_name = schema_name.to_string();
     let renderer = SqlRenderer::for_family(&sql_family);
 
-    match step {
+    let sql = match step {
         SqlMigrationStep::CreateTable(CreateTable { table }) => {
             let cloned_columns = table.columns.clone();
             let primary_columns = table.primary_key_columns();
@@ -89,16 +177,21 @@ fn render_raw_sql(step: &SqlMigrationStep, sql_family: SqlFamily, schema_name: &
                     .collect();
                 lines.push(format!("  PRIMARY KEY ({})", column_names.join(",")))
             }
+
+            for check_constraint in &table.check_constraints {
+                lines.push(format!("  {}", renderer.render_check_constraint(check_constraint)));
+            }
             format!(
                 "CREATE TABLE {} (\n{}\n){};",
-                renderer.quote_with_schema(&schema_name, &table.name),
+                renderer.quote_with_schema(table_schema(&table.schema, &schema_name), &table.name),
                 lines.join(",\n"),
                 create_table_suffix(sql_family),
             )
         }
-        SqlMigrationStep::DropTable(DropTable { name }) => {
-            format!("DROP TABLE {};", renderer.quote_with_schema(&schema_name, &name))
-        }
+        SqlMigrationStep::DropTable(DropTable { name, schema }) => format!(
+            "DROP TABLE {};",
+            renderer.quote_with_schema(table_schema(schema, &schema_name), &name)
+        ),
         SqlMigrationStep::DropTables(DropTables { names }) => {
             let fully_qualified_names: Vec<String> = names
                 .iter()
@@ -106,14 +199,15 @@ fn render_raw_sql(step: &SqlMigrationStep, sql_family: SqlFamily, schema_name: &
                 .collect();
             format!("DROP TABLE {};", fully_qualified_names.join(","))
         }
-        SqlMigrationStep::RenameTable { name, new_name } => {
+        SqlMigrationStep::RenameTable { name, new_name, schema } => {
+            let schema = table_schema(schema, &schema_name);
             let new_name = match sql_family {
                 SqlFamily::Sqlite => renderer.quote(new_name),
-                _ => renderer.quote_with_schema(&schema_name, &new_name),
+                _ => renderer.quote_with_schema(schema, &new_name),
             };
             format!(
                 "ALTER TABLE {} RENAME TO {};",
-                renderer.quote_with_schema(&schema_name, &name),
+                renderer.quote_with_schema(schema, &name),
                 new_name
             )
         }
@@ -130,7 +224,22 @@ fn render_raw_sql(step: &SqlMigrationStep, sql_family: SqlFamily, schema_name: &
                         let name = renderer.quote(&name);
                         lines.push(format!("DROP COLUMN {}", name));
                     }
-                    TableChange::AlterColumn(AlterColumn { name, column }) => {
+                    TableChange::RenameColumn(RenameColumn { name, new_name }) => {
+                        let column = table.column_bang(&new_name);
+                        lines.push(renderer.render_rename_column(&schema_name, &table, &name, &column));
+                    }
+                    TableChange::AlterColumn(AlterColumn {
+                        column,
+                        in_place: Some(alteration),
+                        ..
+                    }) => {
+                        lines.extend(renderer.render_alter_column(&schema_name, &table, &column, &alteration)?);
+                    }
+                    TableChange::AlterColumn(AlterColumn {
+                        name,
+                        column,
+                        in_place: None,
+                    }) => {
                         let name = renderer.quote(&name);
                         lines.push(format!("DROP COLUMN {}", name));
                         let col_sql = renderer.render_column(&schema_name, &table, &column, true);
@@ -143,27 +252,38 @@ fn render_raw_sql(step: &SqlMigrationStep, sql_family: SqlFamily, schema_name: &
                         }
                         _ => (),
                     },
+                    TableChange::AddCheckConstraint(AddCheckConstraint { check_constraint }) => {
+                        lines.push(format!("ADD {}", renderer.render_check_constraint(&check_constraint)));
+                    }
+                    TableChange::DropCheckConstraint(DropCheckConstraint { name }) => {
+                        let name = renderer.quote(&name);
+                        match sql_family {
+                            SqlFamily::Mysql => lines.push(format!("DROP CHECK {}", name)),
+                            _ => lines.push(format!("DROP CONSTRAINT {}", name)),
+                        }
+                    }
                 }
             }
             format!(
                 "ALTER TABLE {} {};",
-                renderer.quote_with_schema(&schema_name, &table.name),
+                renderer.quote_with_schema(table_schema(&table.schema, &schema_name), &table.name),
                 lines.join(",\n")
             )
         }
-        SqlMigrationStep::CreateIndex(CreateIndex { table, index }) => {
+        SqlMigrationStep::CreateIndex(CreateIndex { table, index, schema }) => {
+            let schema = table_schema(schema, &schema_name);
             let Index { name, columns, tpe } = index;
             let index_type = match tpe {
                 IndexType::Unique => "UNIQUE",
                 IndexType::Normal => "",
             };
             let index_name = match sql_family {
-                SqlFamily::Sqlite => renderer.quote_with_schema(&schema_name, &name),
+                SqlFamily::Sqlite => renderer.quote_with_schema(schema, &name),
                 _ => renderer.quote(&name),
             };
             let table_reference = match sql_family {
                 SqlFamily::Sqlite => renderer.quote(&table),
-                _ => renderer.quote_with_schema(&schema_name, &table),
+                _ => renderer.quote_with_schema(schema, &table),
             };
             let columns: Vec<String> = columns.iter().map(|c| renderer.quote(c)).collect();
             format!(
@@ -174,36 +294,105 @@ fn render_raw_sql(step: &SqlMigrationStep, sql_family: SqlFamily, schema_name: &
                 columns.join(",")
             )
         }
-        SqlMigrationStep::DropIndex(DropIndex { table, name }) => match sql_family {
-            SqlFamily::Mysql => format!(
-                "DROP INDEX {} ON {}",
-                renderer.quote(&name),
-                renderer.quote_with_schema(&schema_name, &table),
-            ),
-            SqlFamily::Postgres | SqlFamily::Sqlite => {
-                format!("DROP INDEX {}", renderer.quote_with_schema(&schema_name, &name),)
+        SqlMigrationStep::DropIndex(DropIndex { table, name, schema }) => {
+            let schema = table_schema(schema, &schema_name);
+
+            match sql_family {
+                SqlFamily::Mysql => format!(
+                    "DROP INDEX {} ON {}",
+                    renderer.quote(&name),
+                    renderer.quote_with_schema(schema, &table),
+                ),
+                SqlFamily::Postgres | SqlFamily::Sqlite => {
+                    format!("DROP INDEX {}", renderer.quote_with_schema(schema, &name),)
+                }
             }
-        },
+        }
         SqlMigrationStep::AlterIndex(AlterIndex {
             table,
             index_name,
             index_new_name,
-        }) => match sql_family {
-            SqlFamily::Mysql => format!(
-                "ALTER TABLE {table_name} RENAME INDEX {index_name} TO {index_new_name}",
-                table_name = renderer.quote_with_schema(&schema_name, &table),
-                index_name = renderer.quote(index_name),
-                index_new_name = renderer.quote(index_new_name)
-            ),
-            SqlFamily::Postgres => format!(
-                "ALTER INDEX {} RENAME TO {}",
-                renderer.quote_with_schema(&schema_name, index_name),
-                renderer.quote(index_new_name)
-            ),
-            SqlFamily::Sqlite => unimplemented!("Index renaming on SQLite."),
+            schema,
+        }) => {
+            let schema = table_schema(schema, &schema_name);
+
+            match sql_family {
+                SqlFamily::Mysql => format!(
+                    "ALTER TABLE {table_name} RENAME INDEX {index_name} TO {index_new_name}",
+                    table_name = renderer.quote_with_schema(schema, &table),
+                    index_name = renderer.quote(index_name),
+                    index_new_name = renderer.quote(index_new_name)
+                ),
+                SqlFamily::Postgres => format!(
+                    "ALTER INDEX {} RENAME TO {}",
+                    renderer.quote_with_schema(schema, index_name),
+                    renderer.quote(index_new_name)
+                ),
+                SqlFamily::Sqlite => unimplemented!("Index renaming on SQLite."),
+            }
+        }
+        SqlMigrationStep::SetComment(SetComment {
+            table,
+            schema,
+            column,
+            comment,
+        }) => renderer.render_set_comment(
+            table_schema(schema, &schema_name),
+            table,
+            column.as_ref().map(String::as_str),
+            comment.as_ref().map(String::as_str),
+        )?,
+        SqlMigrationStep::CreateEnum(CreateEnum { name, values }) => {
+            let values: Vec<String> = values.iter().map(|value| quote_enum_value(value)).collect();
+            format!(
+                "CREATE TYPE {} AS ENUM ({})",
+                renderer.quote_with_schema(&schema_name, &name),
+                values.join(", ")
+            )
+        }
+        SqlMigrationStep::AddEnumValue(AddEnumValue { name, value }) => format!(
+            "ALTER TYPE {} ADD VALUE {}",
+            renderer.quote_with_schema(&schema_name, &name),
+            quote_enum_value(value)
+        ),
+        SqlMigrationStep::RenameEnum { name, new_name } => format!(
+            "ALTER TYPE {} RENAME TO {}",
+            renderer.quote_with_schema(&schema_name, &name),
+            renderer.quote(&new_name)
+        ),
+        SqlMigrationStep::DropEnum(DropEnum { name }) => {
+            format!("DROP TYPE {}", renderer.quote_with_schema(&schema_name, &name))
+        }
+        SqlMigrationStep::RawSql { raw } | SqlMigrationStep::RawSqlOutsideTransaction { raw } => raw.to_string(),
+        SqlMigrationStep::DataMigration(DataMigration { action, .. }) => match action {
+            DataMigrationAction::Sql { sql } => sql.to_string(),
+            DataMigrationAction::Backfill {
+                table,
+                column,
+                expression,
+                condition,
+            } => {
+                let update = format!(
+                    "UPDATE {} SET {} = {}",
+                    renderer.quote_with_schema(&schema_name, &table),
+                    renderer.quote(&column),
+                    expression
+                );
+
+                match condition {
+                    Some(condition) => format!("{} WHERE {}", update, condition),
+                    None => update,
+                }
+            }
         },
-        SqlMigrationStep::RawSql { raw } => raw.to_string(),
-    }
+    };
+
+    Ok(sql)
+}
+
+/// The schema a table lives in, falling back to the default schema of the connection.
+pub(crate) fn table_schema<'a>(schema: &'a Option<String>, default_schema: &'a str) -> &'a str {
+    schema.as_ref().map(String::as_str).unwrap_or(default_schema)
 }
 
 fn create_table_suffix(sql_family: SqlFamily) -> &'static str {
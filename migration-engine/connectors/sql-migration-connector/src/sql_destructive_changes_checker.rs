@@ -1,19 +1,36 @@
+use crate::sql_database_step_applier::table_schema;
+use crate::sql_enums::{enum_values, quote_enum_value};
+use crate::sql_renames::{rename_column, rename_table};
+use crate::sql_renderer::SqlRenderer;
 use crate::{
-    AlterColumn, DropColumn, DropTable, DropTables, SqlError, SqlMigration, SqlMigrationStep, SqlResult, TableChange,
+    AlterColumn, ColumnAlteration, DropColumn, DropTable, DropTables, RenameColumn, SqlError, SqlFamily, SqlMigration,
+    SqlMigrationStep, SqlResult, TableChange, TypeChange,
 };
 use migration_connector::*;
 use quaint::ast::*;
 use sql_connection::SyncSqlConnection;
+use sql_schema_describer::{ColumnTypeFamily, SqlSchema};
 use std::sync::Arc;
 
+/// Matches strings that Postgres and MySQL can cast to an integer.
+const INTEGER_PATTERN: &str = "^[[:space:]]*[-+]?[0-9]+[[:space:]]*$";
+/// Matches strings that Postgres and MySQL can cast to a decimal number.
+const NUMBER_PATTERN: &str = "^[[:space:]]*[-+]?([0-9]+[.]?[0-9]*|[.][0-9]+)([eE][-+]?[0-9]+)?[[:space:]]*$";
+
 pub struct SqlDestructiveChangesChecker {
+    pub sql_family: SqlFamily,
     pub schema_name: String,
     pub database: Arc<dyn SyncSqlConnection + Send + Sync>,
 }
 
 impl SqlDestructiveChangesChecker {
-    fn check_table_drop(&self, table_name: &str, diagnostics: &mut DestructiveChangeDiagnostics) -> SqlResult<()> {
-        let query = Select::from_table((self.schema_name.as_str(), table_name)).value(count(asterisk()));
+    fn check_table_drop(
+        &self,
+        table_name: &str,
+        schema: &Option<String>,
+        diagnostics: &mut DestructiveChangeDiagnostics,
+    ) -> SqlResult<()> {
+        let query = Select::from_table((table_schema(schema, &self.schema_name), table_name)).value(count(asterisk()));
         let result_set = self.database.query(query.into())?;
         let first_row = result_set.first().ok_or_else(|| {
             SqlError::Generic("No row was returned when checking for existing rows in dropped table.".to_owned())
@@ -36,7 +53,7 @@ impl SqlDestructiveChangesChecker {
     }
 
     fn count_values_in_column(&self, column_name: &str, table: &sql_schema_describer::Table) -> SqlResult<i64> {
-        let query = Select::from_table((self.schema_name.as_str(), table.name.as_str()))
+        let query = Select::from_table((table_schema(&table.schema, &self.schema_name), table.name.as_str()))
             .value(count(quaint::ast::Column::new(column_name)))
             .so_that(column_name.is_not_null());
 
@@ -81,10 +98,102 @@ impl SqlDestructiveChangesChecker {
         Ok(())
     }
 
-    /// Emit a warning when we alter a column that contains non-null values. We will implement
-    /// non-destructive alter column for a subset of changes in the future, but at the moment all
-    /// alter columns are destructive.
+    /// Emit a warning when we alter a column in a way that loses data. Columns that can not be
+    /// altered in place lose all their values, the others only the values that are not compatible
+    /// with the new column definition.
     fn check_alter_column(
+        &self,
+        alter_column: &AlterColumn,
+        table: &sql_schema_describer::Table,
+        after: &SqlSchema,
+        diagnostics: &mut DestructiveChangeDiagnostics,
+    ) -> SqlResult<()> {
+        match &alter_column.in_place {
+            Some(alteration) => self.check_column_alteration(alter_column, alteration, table, after, diagnostics),
+            None => self.check_column_recreation(alter_column, table, diagnostics),
+        }
+    }
+
+    fn check_column_alteration(
+        &self,
+        alter_column: &AlterColumn,
+        alteration: &ColumnAlteration,
+        table: &sql_schema_describer::Table,
+        after: &SqlSchema,
+        diagnostics: &mut DestructiveChangeDiagnostics,
+    ) -> SqlResult<()> {
+        let previous_column = match table.column(&alter_column.name) {
+            Some(column) => column,
+            None => return Ok(()),
+        };
+        let renderer = SqlRenderer::for_family(&self.sql_family);
+        let column_name = renderer.quote(&alter_column.name);
+        let mut conditions = Vec::new();
+
+        if alteration.type_change == Some(TypeChange::Lossy) {
+            let next_enum_values = enum_values(after, &alter_column.column);
+
+            match lossy_cast_condition(
+                self.sql_family,
+                &column_name,
+                previous_column,
+                &alter_column.column,
+                next_enum_values.as_ref().map(|values| values.as_slice()),
+            ) {
+                Some(condition) => conditions.push(condition),
+                // We can't tell which values convert, so all of them are at risk.
+                None => conditions.push(format!("{} IS NOT NULL", column_name)),
+            }
+        }
+
+        if alteration.arity_changed && !previous_column.is_required() && alter_column.column.is_required() {
+            conditions.push(format!("{} IS NULL", column_name));
+        }
+
+        if conditions.is_empty() {
+            return Ok(());
+        }
+
+        let query = format!(
+            "SELECT COUNT(*) FROM {} WHERE {}",
+            renderer.quote_with_schema(table_schema(&table.schema, &self.schema_name), &table.name),
+            conditions
+                .iter()
+                .map(|condition| format!("({})", condition))
+                .collect::<Vec<String>>()
+                .join(" OR ")
+        );
+        let values_count: i64 =
+            self.database
+                .query_raw(&query, &[])
+                .map_err(SqlError::from)
+                .and_then(|result_set| {
+                    result_set
+                        .first()
+                        .as_ref()
+                        .and_then(|row| row.at(0))
+                        .and_then(|count| count.as_i64())
+                        .ok_or_else(|| {
+                            SqlError::Generic("Unexpected result set shape when checking altered columns.".to_owned())
+                        })
+                })?;
+
+        if values_count > 0 {
+            diagnostics.add_warning(MigrationWarning {
+                description: format!(
+                    "You are about to alter the column `{column_name}` on the `{table_name}` table, which contains {values_count} values that are not compatible with the new column definition. Converting them may fail or lose data.",
+                    column_name = alter_column.name,
+                    table_name = &table.name,
+                    values_count = values_count,
+                ),
+            })
+        }
+
+        Ok(())
+    }
+
+    /// Emit a warning when we drop and re-add a column that contains non-null values.
+    fn check_column_recreation(
         &self,
         alter_column: &AlterColumn,
         table: &sql_schema_describer::Table,
@@ -111,50 +220,185 @@ impl DestructiveChangesChecker<SqlMigration> for SqlDestructiveChangesChecker {
     fn check(&self, database_migration: &SqlMigration) -> ConnectorResult<DestructiveChangeDiagnostics> {
         let mut diagnostics = DestructiveChangeDiagnostics::new();
 
-        for step in &database_migration.original_steps {
-            match step {
-                SqlMigrationStep::AlterTable(alter_table) => {
-                    // The table in alter_table is the updated table, but we want to
-                    // check against the current state of the table.
-                    let before_table = database_migration
-                        .before
-                        .get_table(&alter_table.table.name)
-                        .ok_or_else(|| {
-                            SqlError::Generic(format!(
-                                "Internal Error: altering previously-unknown table {}",
-                                &alter_table.table.name
-                            ))
-                        })?;
-
-                    alter_table
-                        .changes
-                        .iter()
-                        .map(|change| match *change {
-                            TableChange::DropColumn(ref drop_column) => {
-                                self.check_column_drop(drop_column, before_table, &mut diagnostics)
-                            }
-                            TableChange::AlterColumn(ref alter_column) => {
-                                self.check_alter_column(alter_column, before_table, &mut diagnostics)
-                            }
-                            _ => Ok(()),
-                        })
-                        .collect::<Result<(), SqlError>>()?;
-                }
+        for change in potentially_destructive_changes(database_migration)? {
+            match change {
                 // Here, check for each table we are going to delete if it is empty. If
                 // not, return a warning.
-                SqlMigrationStep::DropTable(DropTable { name }) => {
-                    self.check_table_drop(name, &mut diagnostics)?;
+                PotentiallyDestructiveChange::DropTable(name, schema) => {
+                    self.check_table_drop(name, schema, &mut diagnostics)?
                 }
-                SqlMigrationStep::DropTables(DropTables { names }) => {
-                    for name in names {
-                        self.check_table_drop(name, &mut diagnostics)?;
-                    }
+                PotentiallyDestructiveChange::DropColumn(drop_column, table) => {
+                    self.check_column_drop(drop_column, &table, &mut diagnostics)?
+                }
+                PotentiallyDestructiveChange::AlterColumn(alter_column, table) => {
+                    self.check_alter_column(alter_column, &table, &database_migration.after, &mut diagnostics)?
                 }
-                // do nothing
-                _ => (),
             }
         }
 
         Ok(diagnostics)
     }
 }
+
+/// Warns about every change of the migration that could lose data, without looking at the data.
+/// This is what we can do when there is no database to check against.
+pub(crate) fn check_statically(database_migration: &SqlMigration) -> SqlResult<DestructiveChangeDiagnostics> {
+    let mut diagnostics = DestructiveChangeDiagnostics::new();
+
+    for change in potentially_destructive_changes(database_migration)? {
+        let description = match change {
+            PotentiallyDestructiveChange::DropTable(name, _) => format!(
+                "You are about to drop the table `{}`. All the data in it will be lost.",
+                name
+            ),
+            PotentiallyDestructiveChange::DropColumn(drop_column, table) => format!(
+                "You are about to drop the column `{}` on the `{}` table. All the data in the column will be lost.",
+                drop_column.name, table.name
+            ),
+            PotentiallyDestructiveChange::AlterColumn(alter_column, table) => match &alter_column.in_place {
+                Some(alteration) => {
+                    let becomes_required = alteration.arity_changed
+                        && alter_column.column.is_required()
+                        && table
+                            .column(&alter_column.name)
+                            .map(|c| !c.is_required())
+                            .unwrap_or(false);
+
+                    if alteration.type_change != Some(TypeChange::Lossy) && !becomes_required {
+                        continue;
+                    }
+
+                    format!(
+                        "You are about to alter the column `{}` on the `{}` table. Values that are not compatible with the new column definition may fail to convert or lose data.",
+                        alter_column.name, table.name
+                    )
+                }
+                None => format!(
+                    "You are about to alter the column `{}` on the `{}` table. The data in that column will be lost.",
+                    alter_column.name, table.name
+                ),
+            },
+        };
+
+        diagnostics.add_warning(MigrationWarning { description });
+    }
+
+    Ok(diagnostics)
+}
+
+/// A change that can lose data, along with the table it applies to, as it is before the change.
+enum PotentiallyDestructiveChange<'a> {
+    /// The name and the schema of the table.
+    DropTable(&'a str, &'a Option<String>),
+    DropColumn(&'a DropColumn, sql_schema_describer::Table),
+    AlterColumn(&'a AlterColumn, sql_schema_describer::Table),
+}
+
+fn potentially_destructive_changes(database_migration: &SqlMigration) -> SqlResult<Vec<PotentiallyDestructiveChange>> {
+    let mut changes = Vec::new();
+    // Renames run before the other steps, which refer to tables and columns by their new names.
+    let mut before = database_migration.before.clone();
+
+    for step in &database_migration.original_steps {
+        match step {
+            SqlMigrationStep::RenameTable { name, new_name, .. } => {
+                rename_table(&mut before, name, new_name);
+            }
+            SqlMigrationStep::AlterTable(alter_table) => {
+                for change in &alter_table.changes {
+                    if let TableChange::RenameColumn(RenameColumn { name, new_name }) = change {
+                        rename_column(&mut before, &alter_table.table.name, name, new_name);
+                    }
+                }
+
+                // The table in alter_table is the updated table, but we want to
+                // check against the current state of the table.
+                let before_table = before.get_table(&alter_table.table.name).ok_or_else(|| {
+                    SqlError::Generic(format!(
+                        "Internal Error: altering previously-unknown table {}",
+                        &alter_table.table.name
+                    ))
+                })?;
+
+                for change in &alter_table.changes {
+                    match change {
+                        TableChange::DropColumn(drop_column) => changes.push(PotentiallyDestructiveChange::DropColumn(
+                            drop_column,
+                            before_table.clone(),
+                        )),
+                        TableChange::AlterColumn(alter_column) => changes.push(
+                            PotentiallyDestructiveChange::AlterColumn(alter_column, before_table.clone()),
+                        ),
+                        _ => (),
+                    }
+                }
+            }
+            SqlMigrationStep::DropTable(DropTable { name, schema }) => {
+                changes.push(PotentiallyDestructiveChange::DropTable(name, schema));
+            }
+            SqlMigrationStep::DropTables(DropTables { names }) => {
+                changes.extend(
+                    names
+                        .iter()
+                        .map(|name| PotentiallyDestructiveChange::DropTable(name, &None)),
+                );
+            }
+            // do nothing
+            _ => (),
+        }
+    }
+
+    Ok(changes)
+}
+
+/// A condition matching the values of `column_name` that can not be cast from the type of
+/// `previous` to the type of `next` without loss. `None` when we can't tell which values are
+/// affected. `next_enum_values` are the values of the enum `next` is, if it is one.
+fn lossy_cast_condition(
+    sql_family: SqlFamily,
+    column_name: &str,
+    previous: &sql_schema_describer::Column,
+    next: &sql_schema_describer::Column,
+    next_enum_values: Option<&[String]>,
+) -> Option<String> {
+    use ColumnTypeFamily::*;
+
+    let mismatch = |pattern: &str| match sql_family {
+        SqlFamily::Postgres => Some(format!("{} !~ '{}'", column_name, pattern)),
+        SqlFamily::Mysql => Some(format!("{} NOT REGEXP '{}'", column_name, pattern)),
+        SqlFamily::Sqlite => None,
+    };
+
+    match (&previous.tpe.family, &next.tpe.family) {
+        (String, Int) | (String, BigInt) => mismatch(INTEGER_PATTERN),
+        (String, Float) | (String, Decimal) => mismatch(NUMBER_PATTERN),
+        (String, Boolean) if sql_family == SqlFamily::Postgres => Some(format!(
+            "LOWER(TRIM({})) NOT IN ('true', 'false', 't', 'f', 'yes', 'no', 'y', 'n', 'on', 'off', '1', '0')",
+            column_name
+        )),
+        // MySQL booleans are tinyints.
+        (String, Boolean) => mismatch("^[[:space:]]*[01][[:space:]]*$"),
+        (BigInt, Int) => Some(format!("{} NOT BETWEEN -2147483648 AND 2147483647", column_name)),
+        (Float, Int) | (Float, BigInt) | (Decimal, Int) | (Decimal, BigInt) => {
+            Some(format!("{} <> ROUND({})", column_name, column_name))
+        }
+        (String, String) if sql_family != SqlFamily::Sqlite => next
+            .tpe
+            .character_maximum_length
+            .map(|length| format!("CHAR_LENGTH({}) > {}", column_name, length)),
+        // Values that are not in the enum anymore, e.g. when a value is removed from it.
+        (_, Enum(_)) => next_enum_values.map(|values| enum_mismatch(sql_family, column_name, values)),
+        _ => None,
+    }
+}
+
+/// A condition matching the values of `column_name` that are not among the values of an enum.
+fn enum_mismatch(sql_family: SqlFamily, column_name: &str, values: &[String]) -> String {
+    let values: Vec<String> = values.iter().map(|value| quote_enum_value(value)).collect();
+
+    match sql_family {
+        // Postgres does not compare enums of different types, so we compare the labels.
+        SqlFamily::Postgres => format!("{}::text NOT IN ({})", column_name, values.join(", ")),
+        _ => format!("{} NOT IN ({})", column_name, values.join(", ")),
+    }
+}
@@ -0,0 +1,237 @@
+use crate::sql_database_migration_inferrer::infer;
+use crate::sql_database_step_applier::render_script;
+use crate::sql_destructive_changes_checker::check_statically;
+use crate::sql_renames::SqlRenames;
+use crate::sql_schema_calculator::SqlSchemaCalculator;
+use crate::*;
+use failure::err_msg;
+use migration_connector::{ConnectorError, ConnectorResult, MigrationWarning};
+use serde::Deserialize;
+use sql_connection::ConnectionInfo;
+use sql_schema_describer::SqlSchema;
+
+const MIGRATION_TABLE_NAME: &str = "_Migration";
+
+/// One side of a schema diff.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "tag", rename_all = "camelCase")]
+pub enum DiffTarget {
+    /// The contents of a datamodel file.
+    Datamodel { datamodel: String },
+    /// The connection string of a live database, which gets introspected.
+    Database { url: String },
+    /// A schema as serialized by the `sql-schema-describer`.
+    SqlSchema { schema: SqlSchema },
+    /// An empty database.
+    Empty,
+}
+
+impl DiffTarget {
+    /// The database family, when the target determines it.
+    fn sql_family(&self) -> ConnectorResult<Option<SqlFamily>> {
+        match self {
+            DiffTarget::Datamodel { datamodel } => {
+                Ok(datamodel_connection_info(datamodel)?.map(|info| info.sql_family()))
+            }
+            DiffTarget::Database { url } => Ok(Some(ConnectionInfo::from_url_str(url)?.sql_family())),
+            DiffTarget::SqlSchema { .. } | DiffTarget::Empty => Ok(None),
+        }
+    }
+
+    fn schema_name(&self) -> ConnectorResult<Option<String>> {
+        match self {
+            DiffTarget::Datamodel { datamodel } => {
+                Ok(datamodel_connection_info(datamodel)?.and_then(|info| info.schema_name()))
+            }
+            DiffTarget::Database { url } => Ok(ConnectionInfo::from_url_str(url)?.schema_name()),
+            DiffTarget::SqlSchema { .. } | DiffTarget::Empty => Ok(None),
+        }
+    }
+
+    /// Whether the schema of the target is described from a database.
+    fn is_described(&self) -> bool {
+        match self {
+            DiffTarget::Database { .. } | DiffTarget::SqlSchema { .. } => true,
+            DiffTarget::Datamodel { .. } | DiffTarget::Empty => false,
+        }
+    }
+
+    fn sql_schema(&self, sql_family: SqlFamily, schema_name: &str) -> ConnectorResult<SqlSchema> {
+        match self {
+            DiffTarget::Datamodel { datamodel } => {
+                let datamodel = datamodel::parse_datamodel_or_pretty_error(datamodel, "datamodel file, line")
+                    .map_err(|error| ConnectorError::Generic(err_msg(error)))?;
+
+                Ok(SqlSchemaCalculator::calculate(&datamodel, sql_family, schema_name)?)
+            }
+            DiffTarget::Database { url } => {
+                let connector = SqlMigrationConnector::new_from_database_str(url)?;
+                let mut schema = connector
+                    .database_introspector
+                    .describe(&connector.schema_name)
+                    .map_err(SqlError::from)?;
+                schema.tables.retain(|table| table.name != MIGRATION_TABLE_NAME);
+
+                Ok(schema)
+            }
+            DiffTarget::SqlSchema { schema } => Ok(schema.clone()),
+            DiffTarget::Empty => Ok(SqlSchema::empty()),
+        }
+    }
+}
+
+fn datamodel_connection_info(datamodel: &str) -> ConnectorResult<Option<ConnectionInfo>> {
+    let configuration = datamodel::parse_configuration(datamodel)
+        .map_err(|errors| ConnectorError::Generic(err_msg(format!("{:?}", errors))))?;
+
+    match configuration.datasources.first() {
+        Some(source) => Ok(Some(ConnectionInfo::from_datasource(source.as_ref())?)),
+        None => Ok(None),
+    }
+}
+
+/// The migration between two arbitrary schemas. Unlike the migrations we infer for the migration
+/// engine commands, neither side needs to be the current state of a database.
+#[derive(Debug)]
+pub struct SqlDiff {
+    pub sql_family: SqlFamily,
+    pub schema_name: String,
+    pub migration: SqlMigration,
+}
+
+impl SqlDiff {
+    /// Computes the migration from `from` to `to`. The database family and schema name are taken
+    /// from the targets when they are not given.
+    pub fn new(
+        from: &DiffTarget,
+        to: &DiffTarget,
+        sql_family: Option<SqlFamily>,
+        schema_name: Option<String>,
+    ) -> ConnectorResult<SqlDiff> {
+        let sql_family = match sql_family {
+            Some(sql_family) => sql_family,
+            None => from.sql_family()?.or(to.sql_family()?).ok_or_else(|| {
+                ConnectorError::Generic(err_msg(
+                    "The database family can not be determined from the diff targets. Please specify it explicitly.",
+                ))
+            })?,
+        };
+        let schema_name = match schema_name {
+            Some(schema_name) => schema_name,
+            None => from
+                .schema_name()?
+                .or(to.schema_name()?)
+                .unwrap_or_else(|| "lift".to_owned()),
+        };
+
+        let migration = infer(
+            &from.sql_schema(sql_family, &schema_name)?,
+            &to.sql_schema(sql_family, &schema_name)?,
+            &SqlRenames::default(),
+            &schema_name,
+            sql_family,
+            from.is_described(),
+        )?;
+
+        Ok(SqlDiff {
+            sql_family,
+            schema_name,
+            migration,
+        })
+    }
+
+    pub fn steps(&self) -> &[SqlMigrationStep] {
+        &self.migration.corrected_steps
+    }
+
+    /// The migration as a SQL script for the database family of the diff.
+    pub fn render_sql(&self) -> ConnectorResult<String> {
+        Ok(render_script(self.steps(), self.sql_family, &self.schema_name)?)
+    }
+
+    /// Warnings for the changes that could lose data. Neither side needs to be a database, so we
+    /// can't tell whether data would actually be lost.
+    pub fn warnings(&self) -> ConnectorResult<Vec<MigrationWarning>> {
+        Ok(check_statically(&self.migration)?.warnings)
+    }
+
+    /// A human readable summary of the migration, one line per change.
+    pub fn summary(&self) -> String {
+        let lines: Vec<String> = self.migration.original_steps.iter().flat_map(summarize_step).collect();
+
+        if lines.is_empty() {
+            "No difference detected.\n".to_owned()
+        } else {
+            lines.into_iter().map(|line| format!("{}\n", line)).collect()
+        }
+    }
+}
+
+fn summarize_step(step: &SqlMigrationStep) -> Vec<String> {
+    match step {
+        SqlMigrationStep::CreateTable(CreateTable { table }) => vec![format!("Create table `{}`", table.name)],
+        SqlMigrationStep::DropTable(DropTable { name, .. }) => vec![format!("Drop table `{}`", name)],
+        SqlMigrationStep::DropTables(DropTables { names }) => {
+            names.iter().map(|name| format!("Drop table `{}`", name)).collect()
+        }
+        SqlMigrationStep::RenameTable { name, new_name, .. } => {
+            vec![format!("Rename table `{}` to `{}`", name, new_name)]
+        }
+        SqlMigrationStep::AlterTable(AlterTable { table, changes }) => changes
+            .iter()
+            .filter_map(|change| {
+                let description = match change {
+                    TableChange::AddColumn(AddColumn { column }) => format!("add column `{}`", column.name),
+                    TableChange::DropColumn(DropColumn { name }) => format!("drop column `{}`", name),
+                    TableChange::RenameColumn(RenameColumn { name, new_name }) => {
+                        format!("rename column `{}` to `{}`", name, new_name)
+                    }
+                    TableChange::AlterColumn(AlterColumn { name, .. }) => format!("alter column `{}`", name),
+                    TableChange::AddCheckConstraint(AddCheckConstraint { check_constraint }) => {
+                        format!("add check constraint `{}`", check_constraint.name)
+                    }
+                    TableChange::DropCheckConstraint(DropCheckConstraint { name }) => {
+                        format!("drop check constraint `{}`", name)
+                    }
+                    // Foreign keys get dropped as part of the column changes.
+                    TableChange::DropForeignKey(_) => return None,
+                };
+
+                Some(format!("Alter table `{}`: {}", table.name, description))
+            })
+            .collect(),
+        SqlMigrationStep::CreateIndex(CreateIndex { table, index, .. }) => {
+            vec![format!("Create index `{}` on table `{}`", index.name, table)]
+        }
+        SqlMigrationStep::DropIndex(DropIndex { table, name, .. }) => {
+            vec![format!("Drop index `{}` on table `{}`", name, table)]
+        }
+        SqlMigrationStep::AlterIndex(AlterIndex {
+            table,
+            index_name,
+            index_new_name,
+            ..
+        }) => vec![format!(
+            "Rename index `{}` to `{}` on table `{}`",
+            index_name, index_new_name, table
+        )],
+        SqlMigrationStep::SetComment(SetComment { table, column, .. }) => match column {
+            Some(column) => vec![format!("Set the comment of column `{}` on table `{}`", column, table)],
+            None => vec![format!("Set the comment of table `{}`", table)],
+        },
+        SqlMigrationStep::CreateEnum(CreateEnum { name, .. }) => vec![format!("Create enum `{}`", name)],
+        SqlMigrationStep::AddEnumValue(AddEnumValue { name, value }) => {
+            vec![format!("Add value `{}` to enum `{}`", value, name)]
+        }
+        SqlMigrationStep::RenameEnum { name, new_name } => vec![format!("Rename enum `{}` to `{}`", name, new_name)],
+        SqlMigrationStep::DropEnum(DropEnum { name }) => vec![format!("Drop enum `{}`", name)],
+        SqlMigrationStep::RawSql { raw } => vec![format!("Run `{}`", raw)],
+        SqlMigrationStep::RawSqlOutsideTransaction { raw } => vec![format!("Run `{}` outside of a transaction", raw)],
+        SqlMigrationStep::DataMigration(DataMigration { action, .. }) => match action {
+            DataMigrationAction::Sql { sql } => vec![format!("Run the data migration `{}`", sql)],
+            DataMigrationAction::Backfill { table, column, .. } => {
+                vec![format!("Backfill the column `{}` of the table `{}`", column, table)]
+            }
+        },
+    }
+}
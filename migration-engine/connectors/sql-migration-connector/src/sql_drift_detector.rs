@@ -0,0 +1,398 @@
+use crate::sql_schema_differ::{
+    check_constraints_match, default_changed, foreign_keys_match_except_on_update, indexes_are_equivalent, type_change,
+};
+use crate::*;
+use migration_connector::*;
+use sql_schema_describer::*;
+use std::sync::Arc;
+
+const MIGRATION_TABLE_NAME: &str = "_Migration";
+
+pub struct SqlDriftDetector {
+    pub schema_name: String,
+    /// The other schemas of the datasource, described along with the default one.
+    pub schemas: Vec<String>,
+    pub introspector: Arc<dyn SqlSchemaDescriberBackend + Send + Sync + 'static>,
+}
+
+impl DriftDetector<SqlMigration> for SqlDriftDetector {
+    fn detect_drift(&self, last_applied_migration: Option<&SqlMigration>) -> ConnectorResult<Vec<SchemaDrift>> {
+        let actual = self
+            .introspector
+            .describe_schemas(&self.schema_name, &self.schemas)
+            .map_err(SqlError::from)?;
+        let expected = match last_applied_migration {
+            Some(migration) => migration.after.clone(),
+            None => SqlSchema::empty(),
+        };
+
+        Ok(detect_drift(&expected, &actual))
+    }
+}
+
+/// Lists the differences between the expected schema and the actual one, in the same order as the
+/// tables of the schemas. The comparison is as lenient as the one of the schema differ, so a
+/// database without drift is one for which we would not infer any migration steps.
+pub(crate) fn detect_drift(expected: &SqlSchema, actual: &SqlSchema) -> Vec<SchemaDrift> {
+    let mut drift = Vec::new();
+
+    for expected_table in user_tables(expected) {
+        match actual.get_table_in_schema(expected_table.schema.as_ref().map(String::as_str), &expected_table.name) {
+            Some(actual_table) => drift.extend(detect_table_drift(expected_table, actual_table)),
+            None => drift.push(SchemaDrift {
+                kind: DriftKind::Removed,
+                object: DriftObject::Table,
+                table: expected_table.name.clone(),
+                name: None,
+                description: format!("The table `{}` is missing from the database.", expected_table.name),
+            }),
+        }
+    }
+
+    for actual_table in user_tables(actual) {
+        if expected
+            .get_table_in_schema(actual_table.schema.as_ref().map(String::as_str), &actual_table.name)
+            .is_none()
+        {
+            drift.push(SchemaDrift {
+                kind: DriftKind::Added,
+                object: DriftObject::Table,
+                table: actual_table.name.clone(),
+                name: None,
+                description: format!(
+                    "The table `{}` exists in the database, but not in the migration history.",
+                    actual_table.name
+                ),
+            });
+        }
+    }
+
+    drift.extend(detect_enum_drift(expected, actual));
+
+    drift
+}
+
+/// Enums are compared by their values, in any order, like in the differ.
+fn detect_enum_drift(expected: &SqlSchema, actual: &SqlSchema) -> Vec<SchemaDrift> {
+    let mut drift = Vec::new();
+    let mut push = |kind: DriftKind, name: &str, description: String| {
+        drift.push(SchemaDrift {
+            kind,
+            object: DriftObject::Enum,
+            table: name.to_owned(),
+            name: None,
+            description,
+        })
+    };
+
+    for expected_enum in &expected.enums {
+        match actual.get_enum(&expected_enum.name) {
+            Some(actual_enum) => {
+                let values_match = expected_enum.values.len() == actual_enum.values.len()
+                    && expected_enum
+                        .values
+                        .iter()
+                        .all(|value| actual_enum.values.contains(value));
+
+                if !values_match {
+                    push(
+                        DriftKind::Changed,
+                        &expected_enum.name,
+                        format!(
+                            "The enum `{}` has the values {:?}, but {:?} were expected.",
+                            expected_enum.name, actual_enum.values, expected_enum.values
+                        ),
+                    );
+                }
+            }
+            None => push(
+                DriftKind::Removed,
+                &expected_enum.name,
+                format!("The enum `{}` is missing from the database.", expected_enum.name),
+            ),
+        }
+    }
+
+    for actual_enum in &actual.enums {
+        if expected.get_enum(&actual_enum.name).is_none() {
+            push(
+                DriftKind::Added,
+                &actual_enum.name,
+                format!(
+                    "The enum `{}` exists in the database, but not in the migration history.",
+                    actual_enum.name
+                ),
+            );
+        }
+    }
+
+    drift
+}
+
+fn user_tables(schema: &SqlSchema) -> impl Iterator<Item = &Table> {
+    schema.tables.iter().filter(|table| table.name != MIGRATION_TABLE_NAME)
+}
+
+fn detect_table_drift(expected: &Table, actual: &Table) -> Vec<SchemaDrift> {
+    let table = &expected.name;
+    let mut drift = Vec::new();
+    let mut push = |kind: DriftKind, object: DriftObject, name: &str, description: String| {
+        drift.push(SchemaDrift {
+            kind,
+            object,
+            table: table.clone(),
+            name: Some(name.to_owned()),
+            description,
+        })
+    };
+
+    for expected_column in &expected.columns {
+        match actual.column(&expected_column.name) {
+            Some(actual_column) => {
+                // Like the differ, we ignore the arity of foreign key columns.
+                let is_fk_case = expected.foreign_key_for_column(&expected_column.name).is_some()
+                    && actual.foreign_key_for_column(&actual_column.name).is_some();
+                let arity_changed = expected_column.arity != actual_column.arity && !is_fk_case;
+
+                // The differ migrates from the described column to the calculated one, only the
+                // latter says which lengths and precisions matter.
+                if type_change(&actual_column.tpe, &expected_column.tpe).is_some() || arity_changed {
+                    push(
+                        DriftKind::Changed,
+                        DriftObject::Column,
+                        &expected_column.name,
+                        format!(
+                            "The column `{}` on the `{}` table is `{}` ({:?}), but `{}` ({:?}) was expected.",
+                            actual_column.name,
+                            table,
+                            actual_column.tpe.raw,
+                            actual_column.arity,
+                            expected_column.tpe.raw,
+                            expected_column.arity
+                        ),
+                    );
+                }
+
+                if default_changed(actual_column, expected_column) {
+                    push(
+                        DriftKind::Changed,
+                        DriftObject::Column,
+                        &expected_column.name,
+                        format!(
+                            "The column `{}` on the `{}` table does not default to `{}`.",
+                            actual_column.name,
+                            table,
+                            expected_column.default.as_ref().map(String::as_str).unwrap_or("")
+                        ),
+                    );
+                }
+            }
+            None => push(
+                DriftKind::Removed,
+                DriftObject::Column,
+                &expected_column.name,
+                format!(
+                    "The column `{}` is missing from the `{}` table.",
+                    expected_column.name, table
+                ),
+            ),
+        }
+    }
+
+    for actual_column in &actual.columns {
+        if !expected.has_column(&actual_column.name) {
+            push(
+                DriftKind::Added,
+                DriftObject::Column,
+                &actual_column.name,
+                format!(
+                    "The column `{}` on the `{}` table is not part of the migration history.",
+                    actual_column.name, table
+                ),
+            );
+        }
+    }
+
+    // Like in the differ, indexes are matched by their columns and type, their names don't matter.
+    for expected_index in secondary_indexes(expected) {
+        if actual
+            .indices
+            .iter()
+            .any(|index| indexes_are_equivalent(expected_index, index))
+        {
+            continue;
+        }
+
+        match actual.indices.iter().find(|index| index.name == expected_index.name) {
+            Some(actual_index) => push(
+                DriftKind::Changed,
+                DriftObject::Index,
+                &expected_index.name,
+                format!(
+                    "The index `{}` on the `{}` table covers the columns {:?} ({:?}), but {:?} ({:?}) were expected.",
+                    expected_index.name,
+                    table,
+                    actual_index.columns,
+                    actual_index.tpe,
+                    expected_index.columns,
+                    expected_index.tpe
+                ),
+            ),
+            None => push(
+                DriftKind::Removed,
+                DriftObject::Index,
+                &expected_index.name,
+                format!(
+                    "The index `{}` is missing from the `{}` table.",
+                    expected_index.name, table
+                ),
+            ),
+        }
+    }
+
+    for actual_index in secondary_indexes(actual) {
+        let is_expected = expected
+            .indices
+            .iter()
+            .any(|index| indexes_are_equivalent(index, actual_index));
+        // An index with the name of an expected index without equivalent was reported as changed above.
+        let is_changed = secondary_indexes(expected).any(|index| {
+            index.name == actual_index.name && !actual.indices.iter().any(|other| indexes_are_equivalent(index, other))
+        });
+
+        if !is_expected && !is_changed {
+            push(
+                DriftKind::Added,
+                DriftObject::Index,
+                &actual_index.name,
+                format!(
+                    "The index `{}` on the `{}` table is not part of the migration history.",
+                    actual_index.name, table
+                ),
+            );
+        }
+    }
+
+    // Foreign keys calculated from the datamodel have no constraint names, so they are compared by
+    // their definition.
+    for expected_fk in &expected.foreign_keys {
+        if !actual
+            .foreign_keys
+            .iter()
+            .any(|fk| foreign_keys_are_equivalent(expected_fk, fk))
+        {
+            push(
+                DriftKind::Removed,
+                DriftObject::ForeignKey,
+                &foreign_key_name(expected_fk),
+                format!(
+                    "The foreign key on the columns {:?} of the `{}` table, referencing `{}`, is missing or differs.",
+                    expected_fk.columns, table, expected_fk.referenced_table
+                ),
+            );
+        }
+    }
+
+    for actual_fk in &actual.foreign_keys {
+        if !expected
+            .foreign_keys
+            .iter()
+            .any(|fk| foreign_keys_are_equivalent(fk, actual_fk))
+        {
+            push(
+                DriftKind::Added,
+                DriftObject::ForeignKey,
+                &foreign_key_name(actual_fk),
+                format!(
+                    "The foreign key on the columns {:?} of the `{}` table, referencing `{}`, is not part of the migration history.",
+                    actual_fk.columns, table, actual_fk.referenced_table
+                ),
+            );
+        }
+    }
+
+    // Like in the differ, check constraints are matched by their name and normalized expression.
+    for expected_check in &expected.check_constraints {
+        if actual
+            .check_constraints
+            .iter()
+            .any(|check| check_constraints_match(expected_check, check))
+        {
+            continue;
+        }
+
+        match actual
+            .check_constraints
+            .iter()
+            .find(|check| check.name == expected_check.name)
+        {
+            Some(actual_check) => push(
+                DriftKind::Changed,
+                DriftObject::CheckConstraint,
+                &expected_check.name,
+                format!(
+                    "The check constraint `{}` on the `{}` table checks `{}`, but `{}` was expected.",
+                    expected_check.name, table, actual_check.expression, expected_check.expression
+                ),
+            ),
+            None => push(
+                DriftKind::Removed,
+                DriftObject::CheckConstraint,
+                &expected_check.name,
+                format!(
+                    "The check constraint `{}` is missing from the `{}` table.",
+                    expected_check.name, table
+                ),
+            ),
+        }
+    }
+
+    for actual_check in &actual.check_constraints {
+        let is_known = expected
+            .check_constraints
+            .iter()
+            .any(|check| check.name == actual_check.name);
+
+        if !is_known {
+            push(
+                DriftKind::Added,
+                DriftObject::CheckConstraint,
+                &actual_check.name,
+                format!(
+                    "The check constraint `{}` on the `{}` table is not part of the migration history.",
+                    actual_check.name, table
+                ),
+            );
+        }
+    }
+
+    drift
+}
+
+/// Indexes covering the primary key are an implementation detail of some databases.
+fn secondary_indexes(table: &Table) -> impl Iterator<Item = &Index> {
+    table.indices.iter().filter(move |index| match &table.primary_key {
+        Some(pk) => pk.columns != index.columns,
+        None => true,
+    })
+}
+
+/// Databases report foreign keys without an `ON UPDATE` clause as either `NO ACTION` or `RESTRICT`,
+/// which behave the same for constraints that are not deferred.
+fn foreign_keys_are_equivalent(expected: &ForeignKey, actual: &ForeignKey) -> bool {
+    let is_default = |action: &ForeignKeyAction| match action {
+        ForeignKeyAction::NoAction | ForeignKeyAction::Restrict => true,
+        _ => false,
+    };
+    let on_update_matches = expected.on_update_action == actual.on_update_action
+        || (is_default(&expected.on_update_action) && is_default(&actual.on_update_action));
+
+    on_update_matches && foreign_keys_match_except_on_update(expected, actual)
+}
+
+fn foreign_key_name(foreign_key: &ForeignKey) -> String {
+    foreign_key
+        .constraint_name
+        .clone()
+        .unwrap_or_else(|| foreign_key.columns.join("_"))
+}
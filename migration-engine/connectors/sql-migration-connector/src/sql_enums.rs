@@ -0,0 +1,59 @@
+//! Helpers for native enum columns. Postgres enums are named types of their own, described in
+//! `SqlSchema::enums`, while MySQL enums are declared inline on each column, e.g. `enum('A','B')`.
+
+use sql_schema_describer::{Column, ColumnTypeFamily, SqlSchema};
+
+/// Renders an inline MySQL enum type.
+pub(crate) fn render_inline_enum(values: &[String]) -> String {
+    let values: Vec<String> = values.iter().map(|value| quote_enum_value(value)).collect();
+
+    format!("enum({})", values.join(","))
+}
+
+/// Renders an enum value as a SQL string literal.
+pub(crate) fn quote_enum_value(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
+
+/// Whether the raw type is an inline MySQL enum type like `enum('A','B')`.
+pub(crate) fn is_inline_enum(raw: &str) -> bool {
+    raw.trim_start().to_lowercase().starts_with("enum(")
+}
+
+/// Parses the values out of an inline MySQL enum type like `enum('A','B')`.
+pub(crate) fn parse_inline_enum(raw: &str) -> Vec<String> {
+    let mut values = Vec::new();
+    let mut value = String::new();
+    let mut in_value = false;
+    let mut chars = raw.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            // Quotes are escaped by doubling them.
+            '\'' if in_value && chars.peek() == Some(&'\'') => {
+                chars.next();
+                value.push('\'');
+            }
+            '\'' if in_value => {
+                values.push(std::mem::replace(&mut value, String::new()));
+                in_value = false;
+            }
+            '\'' => in_value = true,
+            c if in_value => value.push(c),
+            _ => (),
+        }
+    }
+
+    values
+}
+
+/// The values a column of an enum type can take, if it is one.
+pub(crate) fn enum_values(schema: &SqlSchema, column: &Column) -> Option<Vec<String>> {
+    match &column.tpe.family {
+        ColumnTypeFamily::Enum(name) => Some(match schema.get_enum(name) {
+            Some(sql_enum) => sql_enum.values.clone(),
+            None => parse_inline_enum(&column.tpe.raw),
+        }),
+        _ => None,
+    }
+}
@@ -1,4 +1,4 @@
-use migration_connector::DatabaseMigrationMarker;
+use migration_connector::{DataMigration, DatabaseMigrationMarker};
 use serde::{Deserialize, Serialize};
 use sql_schema_describer::*;
 
@@ -38,11 +38,43 @@ pub enum SqlMigrationStep {
     AlterTable(AlterTable),
     DropTable(DropTable),
     DropTables(DropTables),
-    RenameTable { name: String, new_name: String },
-    RawSql { raw: String },
+    RenameTable {
+        name: String,
+        new_name: String,
+        /// The schema of the table, `None` for the default schema.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        schema: Option<String>,
+    },
+    RawSql {
+        raw: String,
+    },
+    RawSqlOutsideTransaction {
+        raw: String,
+    },
+    DataMigration(DataMigration),
     CreateIndex(CreateIndex),
     DropIndex(DropIndex),
     AlterIndex(AlterIndex),
+    SetComment(SetComment),
+    CreateEnum(CreateEnum),
+    AddEnumValue(AddEnumValue),
+    RenameEnum {
+        name: String,
+        new_name: String,
+    },
+    DropEnum(DropEnum),
+}
+
+impl SqlMigrationStep {
+    /// Some statements, like `CREATE INDEX CONCURRENTLY` or `ALTER TYPE ... ADD VALUE` on Postgres,
+    /// fail in a transaction block.
+    pub fn can_run_in_transaction(&self) -> bool {
+        match self {
+            SqlMigrationStep::RawSqlOutsideTransaction { .. } => false,
+            SqlMigrationStep::AddEnumValue(_) => false,
+            _ => true,
+        }
+    }
 }
 
 /// A helper struct to serialize an [SqlMigrationStep](/sql-migration/enum.SqlMigrationStep.html)
@@ -62,6 +94,9 @@ pub struct CreateTable {
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct DropTable {
     pub name: String,
+    /// The schema of the table, `None` for the default schema.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -80,9 +115,12 @@ pub enum TableChange {
     AddColumn(AddColumn),
     AlterColumn(AlterColumn),
     DropColumn(DropColumn),
+    RenameColumn(RenameColumn),
     /// This is actually producing SQL only on MySQL, where we have to drop the foreign key
     /// constraint before any column that is part of it.
     DropForeignKey(DropForeignKey),
+    AddCheckConstraint(AddCheckConstraint),
+    DropCheckConstraint(DropCheckConstraint),
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -99,6 +137,33 @@ pub struct DropColumn {
 pub struct AlterColumn {
     pub name: String,
     pub column: Column,
+    /// `None` when the column can not be altered in place, in which case it is dropped and added
+    /// again.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub in_place: Option<ColumnAlteration>,
+}
+
+/// What changes about a column that is altered in place. Defaults are always set to the ones of
+/// the new column.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ColumnAlteration {
+    /// `None` when the type stays the same.
+    pub type_change: Option<TypeChange>,
+    pub arity_changed: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub enum TypeChange {
+    /// Every value of the previous type can be represented in the new type, e.g. `Int` to `BigInt`.
+    Widening,
+    /// Some values may fail to convert or lose precision, e.g. `String` to `Int`.
+    Lossy,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RenameColumn {
+    pub name: String,
+    pub new_name: String,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -106,16 +171,45 @@ pub struct DropForeignKey {
     pub constraint_name: String,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct AddCheckConstraint {
+    pub check_constraint: CheckConstraint,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct DropCheckConstraint {
+    pub name: String,
+}
+
+/// Sets the comment of a table, or of one of its columns.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct SetComment {
+    pub table: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
+    /// The column to set the comment of, `None` for the table itself.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub column: Option<String>,
+    /// The new comment, `None` to remove it.
+    pub comment: Option<String>,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct CreateIndex {
     pub table: String,
     pub index: Index,
+    /// The schema of the table, `None` for the default schema.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct DropIndex {
     pub table: String,
     pub name: String,
+    /// The schema of the table, `None` for the default schema.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -123,4 +217,25 @@ pub struct AlterIndex {
     pub table: String,
     pub index_name: String,
     pub index_new_name: String,
+    /// The schema of the table, `None` for the default schema.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
+}
+
+/// A Postgres enum type. MySQL enums are declared inline on the columns.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct CreateEnum {
+    pub name: String,
+    pub values: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct AddEnumValue {
+    pub name: String,
+    pub value: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct DropEnum {
+    pub name: String,
 }
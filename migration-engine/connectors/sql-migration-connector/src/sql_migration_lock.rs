@@ -0,0 +1,190 @@
+use crate::*;
+use sql_connection::{GenericSqlConnection, SyncSqlConnection};
+use std::{
+    fs, io,
+    time::{Duration, Instant},
+};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Locks the database with session-level advisory locks on Postgres and MySQL, which the database
+/// releases by itself if the migration engine holding them dies. SQLite has no such locks, so a
+/// lock file is created next to the database file instead.
+pub struct SqlMigrationLock {
+    pub sql_family: SqlFamily,
+    pub schema_name: String,
+    pub file_path: Option<String>,
+    /// The advisory locks are taken on a connection of their own, opened from this url.
+    pub url: String,
+}
+
+impl MigrationLock for SqlMigrationLock {
+    fn with_lock(&self, timeout: Duration, f: &mut dyn FnMut()) -> ConnectorResult<()> {
+        match self.sql_family {
+            SqlFamily::Sqlite => self.with_lock_file(timeout, f),
+            SqlFamily::Postgres | SqlFamily::Mysql => self.with_advisory_lock(timeout, f),
+        }
+    }
+}
+
+impl SqlMigrationLock {
+    fn with_advisory_lock(&self, timeout: Duration, f: &mut dyn FnMut()) -> ConnectorResult<()> {
+        // MySQL lock names are limited to 64 characters.
+        let lock_name: String = format!("prisma_migrate_{}", self.schema_name)
+            .chars()
+            .take(64)
+            .collect();
+        let mut result = Ok(());
+
+        // The lock belongs to the session that took it, so it has to be taken and released on the
+        // same connection. That connection doesn't come from the pool of the migration, which
+        // could not run anything while the lock holds its only connection.
+        let lock_conn = GenericSqlConnection::from_database_str(&self.url, None)?;
+
+        lock_conn.with_pinned_connection(&mut |conn| {
+            result = self.acquire_advisory_lock(conn, &lock_name, timeout).and_then(|()| {
+                f();
+                self.release_advisory_lock(conn, &lock_name)
+            });
+        })?;
+
+        result
+    }
+
+    fn acquire_advisory_lock(
+        &self,
+        conn: &dyn SyncSqlConnection,
+        lock_name: &str,
+        timeout: Duration,
+    ) -> ConnectorResult<()> {
+        let acquired = match self.sql_family {
+            SqlFamily::Mysql => {
+                let result_set = conn.query_raw(
+                    "SELECT GET_LOCK(?, ?)",
+                    &[lock_name.into(), (timeout.as_secs() as i64).into()],
+                )?;
+
+                result_set
+                    .first()
+                    .as_ref()
+                    .and_then(|row| row.at(0))
+                    .and_then(|value| value.as_i64())
+                    == Some(1)
+            }
+            _ => poll(timeout, || {
+                let result_set = conn.query_raw(
+                    "SELECT pg_try_advisory_lock($1)",
+                    &[advisory_lock_key(lock_name).into()],
+                )?;
+
+                Ok(result_set
+                    .first()
+                    .as_ref()
+                    .and_then(|row| row.at(0))
+                    .and_then(|value| value.as_bool())
+                    .unwrap_or(false))
+            })?,
+        };
+
+        if acquired {
+            Ok(())
+        } else {
+            Err(ConnectorError::MigrationLockTimeout {
+                lock: format!("the advisory lock `{}`", lock_name),
+                timeout_secs: timeout.as_secs(),
+            })
+        }
+    }
+
+    fn release_advisory_lock(&self, conn: &dyn SyncSqlConnection, lock_name: &str) -> ConnectorResult<()> {
+        match self.sql_family {
+            SqlFamily::Mysql => conn.query_raw("SELECT RELEASE_LOCK(?)", &[lock_name.into()])?,
+            _ => conn.query_raw("SELECT pg_advisory_unlock($1)", &[advisory_lock_key(lock_name).into()])?,
+        };
+
+        Ok(())
+    }
+
+    fn with_lock_file(&self, timeout: Duration, f: &mut dyn FnMut()) -> ConnectorResult<()> {
+        let file_path = match &self.file_path {
+            Some(file_path) => file_path,
+            None => {
+                f();
+                return Ok(());
+            }
+        };
+        let lock_path = format!("{}.migration-lock", file_path);
+
+        let acquired = poll(timeout, || {
+            match fs::OpenOptions::new().write(true).create_new(true).open(&lock_path) {
+                Ok(_) => Ok(true),
+                Err(err) if err.kind() == io::ErrorKind::AlreadyExists => Ok(false),
+                Err(err) => Err(ConnectorError::Generic(err.into())),
+            }
+        })?;
+
+        if !acquired {
+            return Err(ConnectorError::MigrationLockTimeout {
+                lock: format!(
+                    "the lock file `{}` (delete it if no migration engine is running)",
+                    lock_path
+                ),
+                timeout_secs: timeout.as_secs(),
+            });
+        }
+
+        let lock_file = LockFile { path: Some(lock_path) };
+
+        f();
+
+        lock_file.release()
+    }
+}
+
+/// Removes the lock file when dropped, so that the lock is released even if the migration panics.
+struct LockFile {
+    path: Option<String>,
+}
+
+impl LockFile {
+    fn release(mut self) -> ConnectorResult<()> {
+        match self.path.take() {
+            Some(path) => fs::remove_file(&path).map_err(|err| ConnectorError::Generic(err.into())),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Drop for LockFile {
+    fn drop(&mut self) {
+        if let Some(path) = self.path.take() {
+            let _ = fs::remove_file(&path);
+        }
+    }
+}
+
+/// The key of the Postgres advisory lock with the given name. `hashtext` is internal to Postgres,
+/// its results are not guaranteed to stay the same across versions, so the key is derived here.
+fn advisory_lock_key(lock_name: &str) -> i64 {
+    let hash = sha256_hex(lock_name.as_bytes());
+
+    // The first 64 bits of the hash, as the signed key Postgres takes.
+    u64::from_str_radix(&hash[..16], 16).expect("parse hex hash") as i64
+}
+
+/// Calls `try_acquire` until it succeeds or the timeout expires. Returns whether it succeeded.
+fn poll(timeout: Duration, mut try_acquire: impl FnMut() -> ConnectorResult<bool>) -> ConnectorResult<bool> {
+    let start = Instant::now();
+
+    loop {
+        if try_acquire()? {
+            return Ok(true);
+        }
+
+        if start.elapsed() >= timeout {
+            return Ok(false);
+        }
+
+        std::thread::sleep(POLL_INTERVAL);
+    }
+}
@@ -16,7 +16,7 @@ pub struct SqlMigrationPersistence {
 
 #[allow(unused, dead_code)]
 impl MigrationPersistence for SqlMigrationPersistence {
-    fn init(&self) {
+    fn init(&self) -> ConnectorResult<()> {
         let sql_str = match self.sql_family {
             SqlFamily::Sqlite => {
                 let mut m = barrel::Migration::new().schema(self.schema_name.clone());
@@ -37,6 +37,8 @@ impl MigrationPersistence for SqlMigrationPersistence {
         };
 
         let _ = self.connection.query_raw(&sql_str, &[]);
+
+        self.upgrade()
     }
 
     fn reset(&self) {
@@ -80,7 +82,9 @@ impl MigrationPersistence for SqlMigrationPersistence {
     }
 
     fn last(&self) -> Option<Migration> {
-        let conditions = STATUS_COLUMN.equals(MigrationStatus::MigrationSuccess.code());
+        let conditions = STATUS_COLUMN
+            .equals(MigrationStatus::MigrationSuccess.code())
+            .and(SQUASHED_INTO_COLUMN.is_null());
         let query = Select::from_table(self.table())
             .so_that(conditions)
             .order_by(REVISION_COLUMN.descend());
@@ -90,14 +94,16 @@ impl MigrationPersistence for SqlMigrationPersistence {
     }
 
     fn load_all(&self) -> Vec<Migration> {
-        let query = Select::from_table(self.table()).order_by(REVISION_COLUMN.ascend());
+        let query = Select::from_table(self.table())
+            .so_that(SQUASHED_INTO_COLUMN.is_null())
+            .order_by(REVISION_COLUMN.ascend());
 
         let result_set = self.connection.query(query.into()).unwrap();
         parse_rows_new(result_set)
     }
 
     fn by_name(&self, name: &str) -> Option<Migration> {
-        let conditions = NAME_COLUMN.equals(name);
+        let conditions = NAME_COLUMN.equals(name).and(SQUASHED_INTO_COLUMN.is_null());
         let query = Select::from_table(self.table())
             .so_that(conditions)
             .order_by(REVISION_COLUMN.descend());
@@ -108,22 +114,11 @@ impl MigrationPersistence for SqlMigrationPersistence {
 
     fn create(&self, migration: Migration) -> Migration {
         let mut cloned = migration.clone();
-        let model_steps_json = serde_json::to_string(&migration.datamodel_steps).unwrap();
-        let database_migration_json = serde_json::to_string(&migration.database_migration).unwrap();
-        let errors_json = serde_json::to_string(&migration.errors).unwrap();
-        let serialized_datamodel = datamodel::render_datamodel_to_string(&migration.datamodel).unwrap();
+        // The checksum covers the datamodel as it is persisted.
+        cloned.datamodel_string = datamodel::render_datamodel_to_string(&migration.datamodel).unwrap();
+        cloned.checksum = Some(migration_checksum(&cloned));
 
-        let insert = Insert::single_into(self.table())
-            .value(NAME_COLUMN, migration.name)
-            .value(DATAMODEL_COLUMN, serialized_datamodel)
-            .value(STATUS_COLUMN, migration.status.code())
-            .value(APPLIED_COLUMN, migration.applied)
-            .value(ROLLED_BACK_COLUMN, migration.rolled_back)
-            .value(DATAMODEL_STEPS_COLUMN, model_steps_json)
-            .value(DATABASE_MIGRATION_COLUMN, database_migration_json)
-            .value(ERRORS_COLUMN, errors_json)
-            .value(STARTED_AT_COLUMN, self.convert_datetime(migration.started_at))
-            .value(FINISHED_AT_COLUMN, ParameterizedValue::Null);
+        let insert = self.insert_query(&cloned);
 
         match self.sql_family {
             SqlFamily::Sqlite | SqlFamily::Mysql => {
@@ -165,6 +160,59 @@ impl MigrationPersistence for SqlMigrationPersistence {
 
         self.connection.query(query.into()).unwrap();
     }
+
+    fn squash(&self, last_squashed: &Migration, baseline: Migration) -> ConnectorResult<Migration> {
+        // The baseline takes over the row of the last squashed migration, which is kept as a copy.
+        let squashed_copy = self
+            .insert_query(last_squashed)
+            .value(SQUASHED_INTO_COLUMN, baseline.name.clone());
+        let mark_as_squashed = Update::table(self.table())
+            .set(SQUASHED_INTO_COLUMN, baseline.name.clone())
+            .so_that(
+                REVISION_COLUMN
+                    .less_than(last_squashed.revision)
+                    .and(SQUASHED_INTO_COLUMN.is_null()),
+            );
+
+        let finished_at_value = match baseline.finished_at {
+            Some(x) => self.convert_datetime(x),
+            None => ParameterizedValue::Null,
+        };
+        let model_steps_json = serde_json::to_string(&baseline.datamodel_steps).unwrap();
+        let database_migration_json = serde_json::to_string(&baseline.database_migration).unwrap();
+        let errors_json = serde_json::to_string(&baseline.errors).unwrap();
+        let baseline = Migration {
+            datamodel_string: datamodel::render_datamodel_to_string(&baseline.datamodel).unwrap(),
+            ..baseline
+        };
+        let checksum = migration_checksum(&baseline);
+
+        let query = Update::table(self.table())
+            .set(NAME_COLUMN, baseline.name.clone())
+            .set(DATAMODEL_COLUMN, baseline.datamodel_string.clone())
+            .set(STATUS_COLUMN, baseline.status.code())
+            .set(APPLIED_COLUMN, baseline.applied)
+            .set(ROLLED_BACK_COLUMN, baseline.rolled_back)
+            .set(DATAMODEL_STEPS_COLUMN, model_steps_json)
+            .set(DATABASE_MIGRATION_COLUMN, database_migration_json)
+            .set(ERRORS_COLUMN, errors_json)
+            .set(STARTED_AT_COLUMN, self.convert_datetime(baseline.started_at))
+            .set(FINISHED_AT_COLUMN, finished_at_value)
+            .set(CHECKSUM_COLUMN, checksum.clone())
+            .so_that(REVISION_COLUMN.equals(last_squashed.revision));
+
+        self.connection.execute_queries_in_transaction(vec![
+            squashed_copy.into(),
+            mark_as_squashed.into(),
+            query.into(),
+        ])?;
+
+        Ok(Migration {
+            revision: last_squashed.revision,
+            checksum: Some(checksum),
+            ..baseline
+        })
+    }
 }
 
 fn migration_table_setup_sqlite(t: &mut barrel::Table) {
@@ -195,6 +243,8 @@ fn migration_table_setup(
     t.add_column(ERRORS_COLUMN, unlimited_text_type.clone());
     t.add_column(STARTED_AT_COLUMN, datetime_type.clone());
     t.add_column(FINISHED_AT_COLUMN, datetime_type.clone().nullable(true));
+    t.add_column(CHECKSUM_COLUMN, types::text().nullable(true));
+    t.add_column(SQUASHED_INTO_COLUMN, types::text().nullable(true));
 }
 
 impl SqlMigrationPersistence {
@@ -208,6 +258,86 @@ impl SqlMigrationPersistence {
         }
     }
 
+    fn insert_query(&self, migration: &Migration) -> SingleRowInsert<'_> {
+        let model_steps_json = serde_json::to_string(&migration.datamodel_steps).unwrap();
+        let database_migration_json = serde_json::to_string(&migration.database_migration).unwrap();
+        let errors_json = serde_json::to_string(&migration.errors).unwrap();
+        let finished_at_value = match migration.finished_at {
+            Some(x) => self.convert_datetime(x),
+            None => ParameterizedValue::Null,
+        };
+
+        Insert::single_into(self.table())
+            .value(NAME_COLUMN, migration.name.clone())
+            .value(DATAMODEL_COLUMN, migration.datamodel_string.clone())
+            .value(STATUS_COLUMN, migration.status.code())
+            .value(APPLIED_COLUMN, migration.applied)
+            .value(ROLLED_BACK_COLUMN, migration.rolled_back)
+            .value(DATAMODEL_STEPS_COLUMN, model_steps_json)
+            .value(DATABASE_MIGRATION_COLUMN, database_migration_json)
+            .value(ERRORS_COLUMN, errors_json)
+            .value(STARTED_AT_COLUMN, self.convert_datetime(migration.started_at))
+            .value(FINISHED_AT_COLUMN, finished_at_value)
+            .value(
+                CHECKSUM_COLUMN,
+                migration
+                    .checksum
+                    .clone()
+                    .map(ParameterizedValue::from)
+                    .unwrap_or(ParameterizedValue::Null),
+            )
+    }
+
+    /// Adds the columns that were introduced after the first version of the table, if they are
+    /// not there yet.
+    fn upgrade(&self) -> ConnectorResult<()> {
+        let existing_columns = self.existing_columns()?;
+        let table = match self.sql_family {
+            SqlFamily::Mysql => format!("`{}`.`{}`", self.schema_name, TABLE_NAME),
+            _ => format!(r#""{}"."{}""#, self.schema_name, TABLE_NAME),
+        };
+
+        for column in &[CHECKSUM_COLUMN, SQUASHED_INTO_COLUMN] {
+            if existing_columns.iter().any(|existing| existing == column) {
+                continue;
+            }
+
+            let sql_str = format!("ALTER TABLE {} ADD COLUMN {} TEXT", table, column);
+
+            self.connection.query_raw(&sql_str, &[])?;
+        }
+
+        Ok(())
+    }
+
+    fn existing_columns(&self) -> ConnectorResult<Vec<String>> {
+        let result_set = match self.sql_family {
+            SqlFamily::Sqlite => {
+                let sql_str = format!(r#"PRAGMA "{}".table_info ("{}")"#, self.schema_name, TABLE_NAME);
+                self.connection.query_raw(&sql_str, &[])?
+            }
+            SqlFamily::Postgres => self.connection.query_raw(
+                "SELECT column_name FROM information_schema.columns WHERE table_schema = $1 AND table_name = $2",
+                &[self.schema_name.as_str().into(), TABLE_NAME.into()],
+            )?,
+            // The alias keeps the column name lower-case on MySQL 8.
+            SqlFamily::Mysql => self.connection.query_raw(
+                "SELECT column_name column_name FROM information_schema.columns WHERE table_schema = ? AND table_name = ?",
+                &[self.schema_name.as_str().into(), TABLE_NAME.into()],
+            )?,
+        };
+
+        let name_column = match self.sql_family {
+            SqlFamily::Sqlite => "name",
+            _ => "column_name",
+        };
+
+        Ok(result_set
+            .into_iter()
+            .filter_map(|row| row.get(name_column).and_then(|name| name.to_string()))
+            .collect())
+    }
+
     fn convert_datetime(&self, datetime: DateTime<Utc>) -> ParameterizedValue {
         match self.sql_family {
             SqlFamily::Sqlite => ParameterizedValue::Integer(datetime.timestamp_millis()),
@@ -270,6 +400,7 @@ fn parse_rows_new(result_set: ResultSet) -> Vec<Migration> {
                 errors,
                 started_at: convert_parameterized_date_value(&row[STARTED_AT_COLUMN]),
                 finished_at,
+                checksum: row[CHECKSUM_COLUMN].to_string(),
             }
         })
         .collect()
@@ -287,3 +418,5 @@ static DATABASE_MIGRATION_COLUMN: &str = "database_migration";
 static ERRORS_COLUMN: &str = "errors";
 static STARTED_AT_COLUMN: &str = "started_at";
 static FINISHED_AT_COLUMN: &str = "finished_at";
+static CHECKSUM_COLUMN: &str = "checksum";
+static SQUASHED_INTO_COLUMN: &str = "squashed_into";
@@ -0,0 +1,118 @@
+//! The native types of the datamodel, one table per connector. The renderers take the SQL names
+//! from these tables and the differ ranks type changes with them.
+
+use datamodel::configuration::builtin::parse_connector_type;
+
+/// A native type of the datamodel, e.g. `VarChar` or `BigInt`.
+#[derive(Debug)]
+pub(crate) struct NativeType {
+    /// The name of the type in the datamodel.
+    pub name: &'static str,
+    /// The name the renderers use in SQL.
+    pub sql_name: &'static str,
+    /// Other names the describer reports for the type, e.g. `int4` for `integer` on Postgres.
+    pub aliases: &'static [&'static str],
+    /// The types a value can be converted between without reinterpreting it, e.g. `integer`.
+    pub kind: &'static str,
+    /// Types of the same kind are ranked by the values they can hold, a type can be widened into
+    /// any type of its kind with a higher or equal rank.
+    pub rank: u8,
+}
+
+impl NativeType {
+    const fn new(
+        name: &'static str,
+        sql_name: &'static str,
+        aliases: &'static [&'static str],
+        kind: &'static str,
+        rank: u8,
+    ) -> Self {
+        NativeType {
+            name,
+            sql_name,
+            aliases,
+            kind,
+            rank,
+        }
+    }
+
+    /// Whether a raw type reported by the describer is this type.
+    pub fn matches_raw(&self, raw: &str) -> bool {
+        self.sql_name.eq_ignore_ascii_case(raw) || self.aliases.iter().any(|alias| alias.eq_ignore_ascii_case(raw))
+    }
+}
+
+pub(crate) const MYSQL_NATIVE_TYPES: &[NativeType] = &[
+    NativeType::new("TinyInt", "tinyint", &[], "integer", 0),
+    NativeType::new("SmallInt", "smallint", &[], "integer", 1),
+    NativeType::new("MediumInt", "mediumint", &[], "integer", 2),
+    NativeType::new("Int", "int", &[], "integer", 3),
+    NativeType::new("BigInt", "bigint", &[], "integer", 4),
+    NativeType::new("Float", "float", &[], "float", 0),
+    NativeType::new("Double", "double", &[], "float", 1),
+    NativeType::new("Decimal", "decimal", &[], "decimal", 0),
+    NativeType::new("VarChar", "varchar", &[], "string", 0),
+    NativeType::new("Char", "char", &[], "string", 0),
+    NativeType::new("Text", "text", &[], "string", 1),
+    NativeType::new("MediumText", "mediumtext", &[], "string", 2),
+    NativeType::new("LongText", "longtext", &[], "string", 3),
+    NativeType::new("Json", "json", &[], "json", 0),
+    NativeType::new("DateTime", "datetime", &[], "datetime", 2),
+    NativeType::new("Timestamp", "timestamp", &[], "datetime", 1),
+    NativeType::new("Date", "date", &[], "datetime", 0),
+    NativeType::new("Time", "time", &[], "time", 0),
+];
+
+pub(crate) const POSTGRES_NATIVE_TYPES: &[NativeType] = &[
+    NativeType::new("SmallInt", "smallint", &["int2"], "integer", 1),
+    NativeType::new("Integer", "integer", &["int4"], "integer", 3),
+    NativeType::new("BigInt", "bigint", &["int8"], "integer", 4),
+    NativeType::new("Real", "real", &["float4"], "float", 0),
+    NativeType::new("DoublePrecision", "double precision", &["float8"], "float", 1),
+    NativeType::new("Decimal", "decimal", &["numeric"], "decimal", 0),
+    NativeType::new("Numeric", "numeric", &["decimal"], "decimal", 0),
+    NativeType::new("VarChar", "varchar", &[], "string", 0),
+    NativeType::new("Char", "char", &["bpchar"], "string", 0),
+    NativeType::new("Text", "text", &[], "string", 1),
+    NativeType::new("Uuid", "uuid", &[], "uuid", 0),
+    NativeType::new("Json", "json", &[], "json", 0),
+    NativeType::new("JsonB", "jsonb", &[], "json", 1),
+    NativeType::new("Timestamp", "timestamp", &[], "datetime", 1),
+    NativeType::new("Timestamptz", "timestamptz", &[], "datetime", 3),
+    NativeType::new("Date", "date", &[], "datetime", 0),
+    NativeType::new("Time", "time", &[], "time", 0),
+    NativeType::new("Boolean", "boolean", &["bool"], "boolean", 0),
+];
+
+pub(crate) const SQLITE_NATIVE_TYPES: &[NativeType] = &[
+    NativeType::new("Integer", "INTEGER", &[], "integer", 3),
+    NativeType::new("Real", "REAL", &[], "float", 0),
+    NativeType::new("Numeric", "NUMERIC", &[], "decimal", 0),
+    NativeType::new("Text", "TEXT", &[], "string", 1),
+];
+
+/// The native types of all connectors. Types of the same name share their kind and rank.
+pub(crate) fn all_native_types() -> impl Iterator<Item = &'static NativeType> {
+    MYSQL_NATIVE_TYPES
+        .iter()
+        .chain(POSTGRES_NATIVE_TYPES.iter())
+        .chain(SQLITE_NATIVE_TYPES.iter())
+}
+
+/// Renders a native type from the datamodel, e.g. `VarChar(200)`, with its SQL name.
+///
+/// Returns `None` for raw types that are not native types of the connector.
+pub(crate) fn render_native_type(raw: &str, native_types: &[NativeType]) -> Option<String> {
+    let (name, args) = parse_connector_type(raw);
+
+    native_types
+        .iter()
+        .find(|native_type| native_type.name == name)
+        .map(|native_type| {
+            if args.is_empty() {
+                native_type.sql_name.to_string()
+            } else {
+                format!("{}({})", native_type.sql_name, args.join(","))
+            }
+        })
+}
@@ -0,0 +1,262 @@
+use crate::sql_database_step_applier::{render_raw_sql, table_schema};
+use crate::sql_renderer::{render_default_value, SqlRenderer};
+use crate::*;
+use sql_schema_describer::*;
+
+/// Rewrites the steps of a migration so they can run while the database is in use.
+///
+/// Only tables that exist before the migration are concerned: tables created by the migration are
+/// not in use yet. SQLite has no such mode, its migrations are returned unchanged.
+pub(crate) fn make_online(
+    migration: SqlMigration,
+    sql_family: SqlFamily,
+    schema_name: &str,
+) -> SqlResult<SqlMigration> {
+    let corrected_steps = match sql_family {
+        SqlFamily::Postgres => postgres_online_steps(&migration, schema_name)?,
+        SqlFamily::Mysql => mysql_online_steps(&migration, schema_name)?,
+        SqlFamily::Sqlite => return Ok(migration),
+    };
+
+    Ok(SqlMigration {
+        corrected_steps,
+        ..migration
+    })
+}
+
+/// On Postgres, indexes are created and dropped concurrently, and constraints are added as
+/// `NOT VALID` and validated afterwards, which does not block writes. New required columns are
+/// added in two phases: the expand phase adds them as nullable columns, and the contract phase at
+/// the end of the migration backfills their default and makes them required.
+fn postgres_online_steps(migration: &SqlMigration, schema_name: &str) -> SqlResult<Vec<SqlMigrationStep>> {
+    let renderer = SqlRenderer::for_family(&SqlFamily::Postgres);
+    let mut expand = Vec::new();
+    let mut contract = Vec::new();
+
+    for step in &migration.corrected_steps {
+        match step {
+            SqlMigrationStep::CreateIndex(CreateIndex { table, index, schema })
+                if migration.before.has_table(table) =>
+            {
+                let columns: Vec<String> = index.columns.iter().map(|column| renderer.quote(column)).collect();
+                let unique = match index.tpe {
+                    IndexType::Unique => "UNIQUE ",
+                    IndexType::Normal => "",
+                };
+
+                expand.push(SqlMigrationStep::RawSqlOutsideTransaction {
+                    raw: format!(
+                        "CREATE {}INDEX CONCURRENTLY {} ON {}({})",
+                        unique,
+                        renderer.quote(&index.name),
+                        renderer.quote_with_schema(table_schema(schema, schema_name), table),
+                        columns.join(",")
+                    ),
+                });
+            }
+            SqlMigrationStep::DropIndex(DropIndex { name, schema, .. }) => {
+                expand.push(SqlMigrationStep::RawSqlOutsideTransaction {
+                    raw: format!(
+                        "DROP INDEX CONCURRENTLY {}",
+                        renderer.quote_with_schema(table_schema(schema, schema_name), name)
+                    ),
+                });
+            }
+            SqlMigrationStep::AlterTable(alter_table) if migration.before.has_table(&alter_table.table.name) => {
+                let (expanded, contract_steps) = expand_postgres_alter_table(alter_table, schema_name)?;
+
+                expand.extend(expanded.map(SqlMigrationStep::AlterTable));
+                contract.extend(contract_steps);
+            }
+            _ => expand.push(step.clone()),
+        }
+    }
+
+    Ok(expand.into_iter().chain(contract).collect())
+}
+
+/// Splits the changes of the table into the ones that can be made right away and the steps that
+/// add the new constraints, in the contract phase.
+fn expand_postgres_alter_table(
+    alter_table: &AlterTable,
+    schema_name: &str,
+) -> SqlResult<(Option<AlterTable>, Vec<SqlMigrationStep>)> {
+    let renderer = SqlRenderer::for_family(&SqlFamily::Postgres);
+    let table_name = renderer.quote_with_schema(
+        table_schema(&alter_table.table.schema, schema_name),
+        &alter_table.table.name,
+    );
+    let mut table = alter_table.table.clone();
+    let mut changes = Vec::new();
+    let mut contract = Vec::new();
+    let add_constraint = |constraint_name: &str, definition: String| {
+        vec![
+            SqlMigrationStep::RawSql {
+                raw: format!(
+                    "ALTER TABLE {} ADD CONSTRAINT {} {} NOT VALID",
+                    table_name,
+                    renderer.quote(constraint_name),
+                    definition
+                ),
+            },
+            SqlMigrationStep::RawSqlOutsideTransaction {
+                raw: format!(
+                    "ALTER TABLE {} VALIDATE CONSTRAINT {}",
+                    table_name,
+                    renderer.quote(constraint_name)
+                ),
+            },
+        ]
+    };
+
+    for change in &alter_table.changes {
+        match change {
+            TableChange::AddColumn(AddColumn { column }) => {
+                let column_name = renderer.quote(&column.name);
+
+                // The constraint name is the one Postgres generates for inline references.
+                if let Some(foreign_key) = alter_table.table.foreign_key_for_column(&column.name) {
+                    table.foreign_keys.retain(|fk| fk != foreign_key);
+                    contract.extend(add_constraint(
+                        &format!("{}_{}_fkey", table.name, column.name),
+                        format!(
+                            "FOREIGN KEY ({}) {}",
+                            column_name,
+                            renderer.render_references(schema_name, Some(foreign_key))
+                        ),
+                    ));
+                }
+
+                if !column.is_required() || column.auto_increment {
+                    changes.push(change.clone());
+                    continue;
+                }
+
+                // Like in a regular migration, foreign key columns stay nullable.
+                if !alter_table.table.is_part_of_foreign_key(&column.name) {
+                    // The rows written before the column is required are backfilled with its
+                    // default, which the nullable column of the expand phase does not have.
+                    let default = render_default_value(column).ok_or_else(|| {
+                        SqlError::Generic(format!(
+                            "The required column `{}` can not be added to the existing table `{}` in an online migration without a default. Give it a default, or backfill it with a data migration.",
+                            column.name, table.name
+                        ))
+                    })?;
+                    contract.push(SqlMigrationStep::RawSql {
+                        raw: format!(
+                            "ALTER TABLE {} ALTER COLUMN {} SET DEFAULT {}",
+                            table_name, column_name, default
+                        ),
+                    });
+                    contract.push(SqlMigrationStep::DataMigration(DataMigration {
+                        hook: DataMigrationHook::AfterAddColumn {
+                            table: table.name.clone(),
+                            column: column.name.clone(),
+                        },
+                        action: DataMigrationAction::Backfill {
+                            table: table.name.clone(),
+                            column: column.name.clone(),
+                            expression: default,
+                            condition: Some(format!("{} IS NULL", column_name)),
+                        },
+                    }));
+
+                    let constraint_name = format!("{}_{}_not_null", table.name, column.name);
+                    contract.extend(add_constraint(
+                        &constraint_name,
+                        format!("CHECK ({} IS NOT NULL)", column_name),
+                    ));
+                    contract.push(SqlMigrationStep::RawSql {
+                        raw: format!("ALTER TABLE {} ALTER COLUMN {} SET NOT NULL", table_name, column_name),
+                    });
+                    contract.push(SqlMigrationStep::RawSql {
+                        raw: format!(
+                            "ALTER TABLE {} DROP CONSTRAINT {}",
+                            table_name,
+                            renderer.quote(&constraint_name)
+                        ),
+                    });
+                }
+
+                for table_column in table.columns.iter_mut().filter(|c| c.name == column.name) {
+                    table_column.arity = ColumnArity::Nullable;
+                }
+                changes.push(TableChange::AddColumn(AddColumn {
+                    column: Column {
+                        arity: ColumnArity::Nullable,
+                        ..column.clone()
+                    },
+                }));
+            }
+            TableChange::AddCheckConstraint(AddCheckConstraint { check_constraint }) => {
+                contract.extend(add_constraint(
+                    &check_constraint.name,
+                    format!("CHECK ({})", check_constraint.expression),
+                ));
+            }
+            _ => changes.push(change.clone()),
+        }
+    }
+
+    let expanded = if changes.is_empty() {
+        None
+    } else {
+        Some(AlterTable { table, changes })
+    };
+
+    Ok((expanded, contract))
+}
+
+/// On MySQL, the statements that support it are run with `ALGORITHM=INPLACE, LOCK=NONE`, which
+/// makes MySQL fail the statement rather than block writes to the table while it runs.
+fn mysql_online_steps(migration: &SqlMigration, schema_name: &str) -> SqlResult<Vec<SqlMigrationStep>> {
+    migration
+        .corrected_steps
+        .iter()
+        .map(|step| {
+            let supports_inplace = match step {
+                SqlMigrationStep::CreateIndex(CreateIndex { table, .. }) => migration.before.has_table(table),
+                SqlMigrationStep::DropIndex(_) | SqlMigrationStep::AlterIndex(_) => true,
+                SqlMigrationStep::AlterTable(AlterTable { table, changes }) => {
+                    migration.before.has_table(&table.name)
+                        && changes.iter().all(|change| mysql_supports_inplace(change, table))
+                }
+                _ => false,
+            };
+
+            if !supports_inplace {
+                return Ok(step.clone());
+            }
+
+            let sql = render_raw_sql(step, SqlFamily::Mysql, schema_name)?;
+            let sql = sql.trim_end().trim_end_matches(';');
+            let raw = match step {
+                SqlMigrationStep::CreateIndex(_) | SqlMigrationStep::DropIndex(_) => {
+                    format!("{} ALGORITHM=INPLACE LOCK=NONE", sql)
+                }
+                _ => format!("{}, ALGORITHM=INPLACE, LOCK=NONE", sql),
+            };
+
+            Ok(SqlMigrationStep::RawSql { raw })
+        })
+        .collect()
+}
+
+/// Adding foreign keys or auto increment columns, changing column types and adding check
+/// constraints all copy the table.
+fn mysql_supports_inplace(change: &TableChange, table: &Table) -> bool {
+    match change {
+        TableChange::AddColumn(AddColumn { column }) => {
+            !column.auto_increment && table.foreign_key_for_column(&column.name).is_none()
+        }
+        TableChange::AlterColumn(AlterColumn {
+            in_place: Some(alteration),
+            ..
+        }) => alteration.type_change.is_none(),
+        TableChange::DropColumn(_)
+        | TableChange::RenameColumn(_)
+        | TableChange::DropForeignKey(_)
+        | TableChange::DropCheckConstraint(_) => true,
+        TableChange::AlterColumn(_) | TableChange::AddCheckConstraint(_) => false,
+    }
+}
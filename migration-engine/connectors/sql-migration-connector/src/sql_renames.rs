@@ -0,0 +1,249 @@
+use crate::sql_schema_calculator::{FieldExtensions, ModelExtensions};
+use crate::*;
+use datamodel::{Datamodel, FieldArity, FieldType, Model};
+use migration_connector::steps::{MigrationStep, UpdateField, UpdateModel};
+use sql_schema_describer::*;
+
+/// The tables and columns that are renamed by a migration, in terms of database names.
+///
+/// The datamodel steps are the only place where renames are expressed. Without them the schema
+/// differ can only compare tables and columns by name, and a rename would be migrated as a drop
+/// followed by a creation.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct SqlRenames {
+    tables: Vec<TableRename>,
+    columns: Vec<ColumnRename>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct TableRename {
+    name: String,
+    new_name: String,
+}
+
+/// `table` is the name of the table before any table renames were applied.
+#[derive(Debug, Clone, PartialEq)]
+struct ColumnRename {
+    table: String,
+    name: String,
+    new_name: String,
+}
+
+impl SqlRenames {
+    pub fn from_steps(previous: &Datamodel, next: &Datamodel, steps: &[MigrationStep]) -> SqlRenames {
+        let model_renames: Vec<&UpdateModel> = steps
+            .iter()
+            .filter_map(|step| match step {
+                MigrationStep::UpdateModel(update_model) if update_model.new_name.is_some() => Some(update_model),
+                _ => None,
+            })
+            .collect();
+        let field_renames: Vec<&UpdateField> = steps
+            .iter()
+            .filter_map(|step| match step {
+                MigrationStep::UpdateField(update_field) if update_field.new_name.is_some() => Some(update_field),
+                _ => None,
+            })
+            .collect();
+
+        let mut renames = SqlRenames::default();
+
+        for previous_model in previous.models() {
+            let model_rename = model_renames
+                .iter()
+                .find(|update_model| update_model.model == previous_model.name);
+            let next_model_name = model_rename
+                .and_then(|update_model| update_model.new_name.as_ref())
+                .unwrap_or(&previous_model.name);
+            let next_model = match next.find_model(next_model_name) {
+                Some(next_model) => next_model,
+                None => continue,
+            };
+
+            if model_rename.is_some() {
+                renames.push_table(previous_model.db_name(), next_model.db_name());
+            }
+
+            for previous_field in previous_model.fields() {
+                // The step refers to the model by the name it has at the time the step is applied.
+                let field_rename = field_renames.iter().find(|update_field| {
+                    (update_field.model == previous_model.name || update_field.model == next_model.name)
+                        && update_field.field == previous_field.name
+                });
+                let next_field_name = field_rename
+                    .and_then(|update_field| update_field.new_name.as_ref())
+                    .unwrap_or(&previous_field.name);
+                let next_field = match next_model.find_field(next_field_name) {
+                    Some(next_field) => next_field,
+                    None => continue,
+                };
+
+                match (&previous_field.field_type, previous_field.arity) {
+                    (FieldType::Relation(_), FieldArity::List) => (),
+                    // Scalar lists live in a table of their own.
+                    (_, FieldArity::List) if model_rename.is_some() || field_rename.is_some() => renames.push_table(
+                        scalar_list_table_name(previous_model, previous_field),
+                        scalar_list_table_name(next_model, next_field),
+                    ),
+                    (_, FieldArity::List) => (),
+                    _ if field_rename.is_some() => renames.columns.push(ColumnRename {
+                        table: previous_model.db_name(),
+                        name: previous_field.db_name(),
+                        new_name: next_field.db_name(),
+                    }),
+                    _ => (),
+                }
+            }
+        }
+
+        renames.columns.retain(|column| column.name != column.new_name);
+
+        renames
+    }
+
+    fn push_table(&mut self, name: String, new_name: String) {
+        if name != new_name {
+            self.tables.push(TableRename { name, new_name });
+        }
+    }
+
+    /// The renames that undo these renames, for computing the rollback of a migration.
+    pub fn inverse(&self) -> SqlRenames {
+        let tables = self
+            .tables
+            .iter()
+            .map(|table| TableRename {
+                name: table.new_name.clone(),
+                new_name: table.name.clone(),
+            })
+            .collect();
+        let columns = self
+            .columns
+            .iter()
+            .map(|column| ColumnRename {
+                table: self.renamed_table_name(&column.table).to_string(),
+                name: column.new_name.clone(),
+                new_name: column.name.clone(),
+            })
+            .collect();
+
+        SqlRenames { tables, columns }
+    }
+
+    fn renamed_table_name<'a>(&'a self, table: &'a str) -> &'a str {
+        self.tables
+            .iter()
+            .find(|rename| rename.name == table)
+            .map(|rename| rename.new_name.as_str())
+            .unwrap_or(table)
+    }
+
+    /// Applies the renames to `schema`. Returns the renamed schema and the steps performing the
+    /// renames in the database. Renames that do not apply to `schema` are skipped, which is
+    /// notably the case when the database has already been migrated by other means.
+    pub fn apply(&self, schema: &SqlSchema) -> (SqlSchema, Vec<SqlMigrationStep>) {
+        let mut renamed_schema = schema.clone();
+        let mut steps = Vec::new();
+
+        for rename in &self.tables {
+            if renamed_schema.has_table(&rename.name) && !renamed_schema.has_table(&rename.new_name) {
+                rename_table(&mut renamed_schema, &rename.name, &rename.new_name);
+                steps.push(SqlMigrationStep::RenameTable {
+                    name: rename.name.clone(),
+                    new_name: rename.new_name.clone(),
+                    schema: renamed_schema
+                        .table(&rename.new_name)
+                        .ok()
+                        .and_then(|t| t.schema.clone()),
+                });
+            }
+        }
+
+        let mut renamed_columns: Vec<(&str, &ColumnRename)> = Vec::new();
+
+        for rename in &self.columns {
+            let table_name = self.renamed_table_name(&rename.table);
+            let applies = match renamed_schema.table(table_name) {
+                Ok(table) => table.has_column(&rename.name) && !table.has_column(&rename.new_name),
+                Err(_) => false,
+            };
+
+            if applies {
+                rename_column(&mut renamed_schema, table_name, &rename.name, &rename.new_name);
+                renamed_columns.push((table_name, rename));
+            }
+        }
+
+        // Postgres and SQLite only accept a single `RENAME COLUMN` per statement. The renderers
+        // need the columns as they are after all renames.
+        for (table_name, rename) in renamed_columns {
+            steps.push(SqlMigrationStep::AlterTable(AlterTable {
+                table: renamed_schema.table_bang(table_name).clone(),
+                changes: vec![TableChange::RenameColumn(RenameColumn {
+                    name: rename.name.clone(),
+                    new_name: rename.new_name.clone(),
+                })],
+            }));
+        }
+
+        (renamed_schema, steps)
+    }
+}
+
+fn scalar_list_table_name(model: &Model, field: &datamodel::Field) -> String {
+    format!("{}_{}", model.db_name(), field.db_name())
+}
+
+/// Renames a table and updates the foreign keys referencing it.
+pub fn rename_table(schema: &mut SqlSchema, name: &str, new_name: &str) {
+    for table in schema.tables.iter_mut() {
+        if table.name == name {
+            table.name = new_name.to_string();
+        }
+
+        for foreign_key in table.foreign_keys.iter_mut() {
+            if foreign_key.referenced_table == name {
+                foreign_key.referenced_table = new_name.to_string();
+            }
+        }
+    }
+}
+
+/// Renames a column and updates the indexes, keys and foreign keys involving it.
+pub fn rename_column(schema: &mut SqlSchema, table_name: &str, name: &str, new_name: &str) {
+    let rename = |columns: &mut Vec<String>| {
+        for column in columns.iter_mut() {
+            if column == name {
+                *column = new_name.to_string();
+            }
+        }
+    };
+
+    for table in schema.tables.iter_mut() {
+        if table.name == table_name {
+            for column in table.columns.iter_mut() {
+                if column.name == name {
+                    column.name = new_name.to_string();
+                }
+            }
+
+            for index in table.indices.iter_mut() {
+                rename(&mut index.columns);
+            }
+
+            if let Some(primary_key) = table.primary_key.as_mut() {
+                rename(&mut primary_key.columns);
+            }
+
+            for foreign_key in table.foreign_keys.iter_mut() {
+                rename(&mut foreign_key.columns);
+            }
+        }
+
+        for foreign_key in table.foreign_keys.iter_mut() {
+            if foreign_key.referenced_table == table_name {
+                rename(&mut foreign_key.referenced_columns);
+            }
+        }
+    }
+}
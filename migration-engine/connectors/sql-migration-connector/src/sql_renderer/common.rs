@@ -10,28 +10,45 @@ pub fn render_nullability(table: &Table, column: &Column) -> &'static str {
 }
 
 pub fn render_default(column: &Column) -> String {
-    match &column.default {
-        Some(value) => {
-            let default = match column.tpe.family {
-                ColumnTypeFamily::String | ColumnTypeFamily::DateTime => {
-                    // TODO: find a better solution for this amazing hack. the default value must not be a String
-                    if value.starts_with("'") {
-                        format!("DEFAULT {}", value)
-                    } else {
-                        format!("DEFAULT '{}'", value)
-                    }
-                }
-                _ => format!("DEFAULT {}", value),
-            };
-            // we use the default value right now only to smoothen migrations. So we only use it when absolutely needed.
-            if column.is_required() {
-                default
+    match render_default_value(column) {
+        // Database expressions are the actual default of the column, so they are always rendered, verbatim.
+        Some(value) if column.default_is_expression => format!("DEFAULT {}", value),
+        // we use the default value right now only to smoothen migrations. So we only use it when absolutely needed.
+        Some(value) if column.is_required() => format!("DEFAULT {}", value),
+        _ => "".to_string(),
+    }
+}
+
+/// Renders the default of a column as an SQL expression, whatever the arity of the column.
+pub fn render_default_value(column: &Column) -> Option<String> {
+    let value = column.default.as_ref()?;
+
+    if column.default_is_expression {
+        return Some(value.clone());
+    }
+
+    let rendered = match column.tpe.family {
+        ColumnTypeFamily::String
+        | ColumnTypeFamily::DateTime
+        | ColumnTypeFamily::Uuid
+        | ColumnTypeFamily::Json
+        | ColumnTypeFamily::Enum(_) => {
+            // TODO: find a better solution for this amazing hack. the default value must not be a String
+            if value.starts_with("'") {
+                value.clone()
             } else {
-                "".to_string()
+                format!("'{}'", value)
             }
         }
-        None => "".to_string(),
-    }
+        _ => value.clone(),
+    };
+
+    Some(rendered)
+}
+
+/// Renders a comment as a string literal.
+pub fn render_comment(comment: &str) -> String {
+    format!("'{}'", comment.replace('\'', "''"))
 }
 
 pub fn render_on_delete(on_delete: &ForeignKeyAction) -> &'static str {
@@ -44,6 +61,16 @@ pub fn render_on_delete(on_delete: &ForeignKeyAction) -> &'static str {
     }
 }
 
+pub fn render_on_update(on_update: &ForeignKeyAction) -> &'static str {
+    match on_update {
+        ForeignKeyAction::NoAction => "",
+        ForeignKeyAction::SetNull => "ON UPDATE SET NULL",
+        ForeignKeyAction::Cascade => "ON UPDATE CASCADE",
+        ForeignKeyAction::SetDefault => "ON UPDATE SET DEFAULT",
+        ForeignKeyAction::Restrict => "ON UPDATE RESTRICT",
+    }
+}
+
 // TODO: this returns None for expressions
 // TODO: bring back once values for columns are not untyped Strings anymore
 //fn render_value(value: &Value) -> Option<String> {
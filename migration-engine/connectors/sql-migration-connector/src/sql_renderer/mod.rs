@@ -1,4 +1,4 @@
-use crate::SqlFamily;
+use crate::{ColumnAlteration, SqlFamily, SqlResult};
 use sql_schema_describer::*;
 
 mod common;
@@ -6,6 +6,7 @@ mod mysql_renderer;
 mod postgres_renderer;
 mod sqlite_renderer;
 
+pub(crate) use common::{render_default, render_default_value, render_nullability};
 use mysql_renderer::MySqlRenderer;
 use postgres_renderer::PostgresRenderer;
 use sqlite_renderer::SqliteRenderer;
@@ -19,9 +20,41 @@ pub trait SqlRenderer {
 
     fn render_column(&self, schema_name: &str, table: &Table, column: &Column, add_fk_prefix: bool) -> String;
 
-    fn render_column_type(&self, t: &ColumnType) -> String;
+    fn render_column_type(&self, schema_name: &str, t: &ColumnType) -> String;
 
     fn render_references(&self, schema_name: &str, foreign_key: Option<&ForeignKey>) -> String;
+
+    /// Renders the `ALTER TABLE` clauses that alter `column` in place.
+    fn render_alter_column(
+        &self,
+        schema_name: &str,
+        table: &Table,
+        column: &Column,
+        alteration: &ColumnAlteration,
+    ) -> SqlResult<Vec<String>>;
+
+    /// Renders the `ALTER TABLE` clause renaming the column `name` to the name of `column`.
+    fn render_rename_column(&self, _schema_name: &str, _table: &Table, name: &str, column: &Column) -> String {
+        format!("RENAME COLUMN {} TO {}", self.quote(name), self.quote(&column.name))
+    }
+
+    /// Renders the statement setting the comment of the table, or of the given column. A missing
+    /// comment removes it.
+    fn render_set_comment(
+        &self,
+        schema_name: &str,
+        table: &str,
+        column: Option<&str>,
+        comment: Option<&str>,
+    ) -> SqlResult<String>;
+
+    fn render_check_constraint(&self, check_constraint: &CheckConstraint) -> String {
+        format!(
+            "CONSTRAINT {} CHECK ({})",
+            self.quote(&check_constraint.name),
+            check_constraint.expression
+        )
+    }
 }
 
 impl dyn SqlRenderer {
@@ -1,4 +1,6 @@
 use super::common::*;
+use crate::sql_native_types::{render_native_type, MYSQL_NATIVE_TYPES};
+use crate::{ColumnAlteration, SqlError, SqlResult};
 use sql_schema_describer::*;
 
 pub struct MySqlRenderer {}
@@ -9,35 +11,48 @@ impl super::SqlRenderer for MySqlRenderer {
 
     fn render_column(&self, schema_name: &str, table: &Table, column: &Column, add_fk_prefix: bool) -> String {
         let column_name = self.quote(&column.name);
-        let tpe_str = self.render_column_type(&column.tpe);
+        let tpe_str = self.render_column_type(schema_name, &column.tpe);
         let nullability_str = render_nullability(&table, &column);
-        let default_str = render_default(&column);
+        let default_str = render_mysql_default(&column);
         let foreign_key = table.foreign_key_for_column(&column.name);
         let references_str = self.render_references(&schema_name, foreign_key);
         let auto_increment_str = if column.auto_increment { "AUTO_INCREMENT" } else { "" };
+        let on_update_str = render_on_update_current_timestamp(&column, &tpe_str);
+        let comment_str = render_column_comment(&column);
 
         match foreign_key {
             Some(_) => {
                 let add = if add_fk_prefix { "ADD" } else { "" };
                 let fk_line = format!("{} FOREIGN KEY ({}) {}", add, column_name, references_str);
                 format!(
-                    "{} {} {} {},\n{}",
-                    column_name, tpe_str, nullability_str, default_str, fk_line
+                    "{} {} {} {}{}{},\n{}",
+                    column_name, tpe_str, nullability_str, default_str, on_update_str, comment_str, fk_line
                 )
             }
             None => format!(
-                "{} {} {} {} {}",
-                column_name, tpe_str, nullability_str, default_str, auto_increment_str
+                "{} {} {} {} {}{}{}",
+                column_name, tpe_str, nullability_str, default_str, auto_increment_str, on_update_str, comment_str
             ),
         }
     }
 
-    fn render_column_type(&self, t: &ColumnType) -> String {
+    fn render_column_type(&self, _schema_name: &str, t: &ColumnType) -> String {
+        // The raw type of an enum column is its inline definition, e.g. `enum('A','B')`.
+        if let ColumnTypeFamily::Enum(_) = &t.family {
+            return t.raw.clone();
+        }
+
+        if let Some(native_type) = render_native_type(&t.raw, MYSQL_NATIVE_TYPES) {
+            return native_type;
+        }
+
         match &t.family {
             ColumnTypeFamily::Boolean => format!("boolean"),
             ColumnTypeFamily::DateTime => format!("datetime(3)"),
-            ColumnTypeFamily::Float => format!("Decimal(65,30)"),
+            ColumnTypeFamily::Float => format!("Double"),
+            ColumnTypeFamily::Decimal => format!("Decimal(64,30)"),
             ColumnTypeFamily::Int => format!("int"),
+            ColumnTypeFamily::BigInt => format!("bigint"),
             // we use varchar right now as mediumtext doesn't allow default values
             // a bigger length would not allow to use such a column as primary key
             ColumnTypeFamily::String => format!("varchar(191)"),
@@ -48,13 +63,131 @@ impl super::SqlRenderer for MySqlRenderer {
     fn render_references(&self, schema_name: &str, foreign_key: Option<&ForeignKey>) -> String {
         match foreign_key {
             Some(fk) => format!(
-                "REFERENCES `{}`.`{}`(`{}`) {}",
-                schema_name,
+                "REFERENCES `{}`.`{}`(`{}`) {} {}",
+                fk.referenced_schema.as_ref().map(String::as_str).unwrap_or(schema_name),
                 fk.referenced_table,
                 fk.referenced_columns.first().unwrap(),
-                render_on_delete(&fk.on_delete_action)
+                render_on_delete(&fk.on_delete_action),
+                render_on_update(&fk.on_update_action)
             ),
             None => "".to_string(),
         }
     }
+
+    fn render_alter_column(
+        &self,
+        schema_name: &str,
+        table: &Table,
+        column: &Column,
+        _alteration: &ColumnAlteration,
+    ) -> SqlResult<Vec<String>> {
+        Ok(vec![format!(
+            "MODIFY {}",
+            self.render_column_definition(schema_name, table, column)
+        )])
+    }
+
+    // `RENAME COLUMN` is only available from MySQL 8 on, so we restate the column definition.
+    fn render_rename_column(&self, schema_name: &str, table: &Table, name: &str, column: &Column) -> String {
+        format!(
+            "CHANGE {} {}",
+            self.quote(name),
+            self.render_column_definition(schema_name, table, column)
+        )
+    }
+
+    // Column comments are part of the column definition, so they are set by altering the column.
+    // An empty table comment removes it.
+    fn render_set_comment(
+        &self,
+        schema_name: &str,
+        table: &str,
+        column: Option<&str>,
+        comment: Option<&str>,
+    ) -> SqlResult<String> {
+        match column {
+            Some(column) => Err(SqlError::Generic(format!(
+                "The comment of the column `{}` on the `{}` table can only be set by altering the column on MySQL.",
+                column, table
+            ))),
+            None => Ok(format!(
+                "ALTER TABLE {} COMMENT = {}",
+                self.quote_with_schema(schema_name, table),
+                render_mysql_comment(comment.unwrap_or(""))
+            )),
+        }
+    }
+}
+
+impl MySqlRenderer {
+    /// The column definition without the foreign key, as `MODIFY` and `CHANGE` expect it. The
+    /// comment is restated too, as it would be removed otherwise.
+    fn render_column_definition(&self, schema_name: &str, table: &Table, column: &Column) -> String {
+        let auto_increment_str = if column.auto_increment { "AUTO_INCREMENT" } else { "" };
+        let tpe_str = self.render_column_type(schema_name, &column.tpe);
+
+        format!(
+            "{} {} {} {} {}{}{}",
+            self.quote(&column.name),
+            tpe_str,
+            render_nullability(&table, &column),
+            render_mysql_default(&column),
+            auto_increment_str,
+            render_on_update_current_timestamp(&column, &tpe_str),
+            render_column_comment(&column)
+        )
+    }
+}
+
+/// The precision of `CURRENT_TIMESTAMP` must be the one of the column, e.g. `datetime(3)`.
+fn render_on_update_current_timestamp(column: &Column, tpe_str: &str) -> String {
+    if !column.on_update_current_timestamp {
+        return "".to_string();
+    }
+
+    let precision = tpe_str.find('(').map(|start| &tpe_str[start..]).unwrap_or("");
+
+    format!(" ON UPDATE CURRENT_TIMESTAMP{}", precision)
+}
+
+fn render_column_comment(column: &Column) -> String {
+    match &column.comment {
+        Some(comment) => format!(" COMMENT {}", render_mysql_comment(comment)),
+        None => "".to_string(),
+    }
+}
+
+/// MySQL 8 only accepts expressions as defaults in parentheses. `CURRENT_TIMESTAMP` and its
+/// synonyms are the exception, as MySQL 5.7 only accepts them bare.
+fn render_mysql_default(column: &Column) -> String {
+    match &column.default {
+        Some(expression) if column.default_is_expression && !is_current_timestamp(expression) => {
+            format!("DEFAULT ({})", expression)
+        }
+        _ => render_default(column),
+    }
+}
+
+/// Whether the expression is `CURRENT_TIMESTAMP` or a synonym, with an optional precision.
+fn is_current_timestamp(expression: &str) -> bool {
+    let expression = expression.trim().to_lowercase();
+    let name = match expression.find('(') {
+        Some(start)
+            if expression.ends_with(')')
+                && expression[start + 1..expression.len() - 1]
+                    .chars()
+                    .all(|c| c.is_ascii_digit()) =>
+        {
+            &expression[..start]
+        }
+        Some(_) => return false,
+        None => expression.as_str(),
+    };
+
+    ["current_timestamp", "localtime", "localtimestamp", "now"].contains(&name)
+}
+
+/// Backslashes are escape characters in MySQL string literals.
+fn render_mysql_comment(comment: &str) -> String {
+    render_comment(&comment.replace('\\', "\\\\"))
 }
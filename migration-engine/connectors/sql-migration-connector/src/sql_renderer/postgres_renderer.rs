@@ -1,4 +1,6 @@
 use super::common::*;
+use crate::sql_native_types::{render_native_type, POSTGRES_NATIVE_TYPES};
+use crate::ColumnAlteration;
 use sql_schema_describer::*;
 
 pub struct PostgresRenderer {}
@@ -9,16 +11,19 @@ impl super::SqlRenderer for PostgresRenderer {
 
     fn render_column(&self, schema_name: &str, table: &Table, column: &Column, _add_fk_prefix: bool) -> String {
         let column_name = self.quote(&column.name);
-        let tpe_str = self.render_column_type(&column.tpe);
+        let tpe_str = self.render_column_type(schema_name, &column.tpe);
         let nullability_str = render_nullability(&table, &column);
         let default_str = render_default(&column);
         let foreign_key = table.foreign_key_for_column(&column.name);
         let references_str = self.render_references(&schema_name, foreign_key);
 
-        let is_serial = column.auto_increment;
+        if column.auto_increment {
+            let serial = match column.tpe.family {
+                ColumnTypeFamily::BigInt => "BIGSERIAL",
+                _ => "SERIAL",
+            };
 
-        if is_serial {
-            format!("{} SERIAL", column_name)
+            format!("{} {}", column_name, serial)
         } else {
             format!(
                 "{} {} {} {} {}",
@@ -27,12 +32,23 @@ impl super::SqlRenderer for PostgresRenderer {
         }
     }
 
-    fn render_column_type(&self, t: &ColumnType) -> String {
+    fn render_column_type(&self, schema_name: &str, t: &ColumnType) -> String {
+        // Enums are types of their own, living in the schema next to the tables.
+        if let ColumnTypeFamily::Enum(name) = &t.family {
+            return self.quote_with_schema(schema_name, name);
+        }
+
+        if let Some(native_type) = render_native_type(&t.raw, POSTGRES_NATIVE_TYPES) {
+            return native_type;
+        }
+
         match &t.family {
             ColumnTypeFamily::Boolean => format!("boolean"),
             ColumnTypeFamily::DateTime => format!("timestamp(3)"),
-            ColumnTypeFamily::Float => format!("Decimal(65,30)"),
+            ColumnTypeFamily::Float => format!("double precision"),
+            ColumnTypeFamily::Decimal => format!("numeric"),
             ColumnTypeFamily::Int => format!("integer"),
+            ColumnTypeFamily::BigInt => format!("bigint"),
             ColumnTypeFamily::String => format!("text"),
             x => unimplemented!("{:?} not handled yet", x),
         }
@@ -41,13 +57,83 @@ impl super::SqlRenderer for PostgresRenderer {
     fn render_references(&self, schema_name: &str, foreign_key: Option<&ForeignKey>) -> String {
         match foreign_key {
             Some(fk) => format!(
-                "REFERENCES \"{}\".\"{}\"(\"{}\") {}",
-                schema_name,
+                "REFERENCES \"{}\".\"{}\"(\"{}\") {} {}",
+                fk.referenced_schema.as_ref().map(String::as_str).unwrap_or(schema_name),
                 fk.referenced_table,
                 fk.referenced_columns.first().unwrap(),
-                render_on_delete(&fk.on_delete_action)
+                render_on_delete(&fk.on_delete_action),
+                render_on_update(&fk.on_update_action)
             ),
             None => "".to_string(),
         }
     }
+
+    fn render_alter_column(
+        &self,
+        schema_name: &str,
+        table: &Table,
+        column: &Column,
+        alteration: &ColumnAlteration,
+    ) -> SqlResult<Vec<String>> {
+        let column_name = self.quote(&column.name);
+        let mut clauses = Vec::new();
+
+        if alteration.type_change.is_some() {
+            let tpe_str = self.render_column_type(schema_name, &column.tpe);
+            match &column.tpe.family {
+                // There is no cast to an enum from most types, but there is one from text. The old
+                // default can not be cast automatically, so it is dropped first and set again below.
+                ColumnTypeFamily::Enum(_) => {
+                    if !column.auto_increment {
+                        clauses.push(format!("ALTER COLUMN {} DROP DEFAULT", column_name));
+                    }
+                    clauses.push(format!(
+                        "ALTER COLUMN {0} TYPE {1} USING {0}::text::{1}",
+                        column_name, tpe_str
+                    ));
+                }
+                _ => clauses.push(format!(
+                    "ALTER COLUMN {0} TYPE {1} USING {0}::{1}",
+                    column_name, tpe_str
+                )),
+            }
+        }
+
+        if alteration.arity_changed {
+            match render_nullability(&table, &column) {
+                "" => clauses.push(format!("ALTER COLUMN {} DROP NOT NULL", column_name)),
+                not_null => clauses.push(format!("ALTER COLUMN {} SET {}", column_name, not_null)),
+            }
+        }
+
+        // The default of a serial column is its sequence.
+        if !column.auto_increment {
+            match render_default(&column).as_str() {
+                "" => clauses.push(format!("ALTER COLUMN {} DROP DEFAULT", column_name)),
+                default => clauses.push(format!("ALTER COLUMN {} SET {}", column_name, default)),
+            }
+        }
+
+        Ok(clauses)
+    }
+
+    fn render_set_comment(
+        &self,
+        schema_name: &str,
+        table: &str,
+        column: Option<&str>,
+        comment: Option<&str>,
+    ) -> SqlResult<String> {
+        let table_name = self.quote_with_schema(schema_name, table);
+        let target = match column {
+            Some(column) => format!("COLUMN {}.{}", table_name, self.quote(column)),
+            None => format!("TABLE {}", table_name),
+        };
+        let comment = match comment {
+            Some(comment) => render_comment(comment),
+            None => "NULL".to_string(),
+        };
+
+        Ok(format!("COMMENT ON {} IS {}", target, comment))
+    }
 }
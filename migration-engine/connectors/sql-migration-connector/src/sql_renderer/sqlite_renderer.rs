@@ -1,4 +1,6 @@
 use super::common::*;
+use crate::sql_native_types::{render_native_type, SQLITE_NATIVE_TYPES};
+use crate::{ColumnAlteration, SqlError, SqlResult};
 use sql_schema_describer::*;
 
 pub struct SqliteRenderer {}
@@ -9,7 +11,7 @@ impl super::SqlRenderer for SqliteRenderer {
 
     fn render_column(&self, schema_name: &str, table: &Table, column: &Column, _add_fk_prefix: bool) -> String {
         let column_name = self.quote(&column.name);
-        let tpe_str = self.render_column_type(&column.tpe);
+        let tpe_str = self.render_column_type(schema_name, &column.tpe);
         let nullability_str = render_nullability(&table, &column);
         let default_str = render_default(&column);
         let foreign_key = table.foreign_key_for_column(&column.name);
@@ -26,12 +28,19 @@ impl super::SqlRenderer for SqliteRenderer {
         )
     }
 
-    fn render_column_type(&self, t: &ColumnType) -> String {
+    fn render_column_type(&self, _schema_name: &str, t: &ColumnType) -> String {
+        if let Some(native_type) = render_native_type(&t.raw, SQLITE_NATIVE_TYPES) {
+            return native_type;
+        }
+
         match &t.family {
             ColumnTypeFamily::Boolean => format!("BOOLEAN"),
             ColumnTypeFamily::DateTime => format!("DATE"),
             ColumnTypeFamily::Float => format!("REAL"),
+            // The TEXT affinity keeps SQLite from converting the values to floating point numbers.
+            ColumnTypeFamily::Decimal => format!("DECIMAL TEXT"),
             ColumnTypeFamily::Int => format!("INTEGER"),
+            ColumnTypeFamily::BigInt => format!("BIGINT"),
             ColumnTypeFamily::String => format!("TEXT"),
             x => unimplemented!("{:?} not handled yet", x),
         }
@@ -40,12 +49,41 @@ impl super::SqlRenderer for SqliteRenderer {
     fn render_references(&self, _schema_name: &str, foreign_key: Option<&ForeignKey>) -> String {
         match foreign_key {
             Some(fk) => format!(
-                "REFERENCES \"{}\"({}) {}",
+                "REFERENCES \"{}\"({}) {} {}",
                 fk.referenced_table,
                 fk.referenced_columns.first().unwrap(),
-                render_on_delete(&fk.on_delete_action)
+                render_on_delete(&fk.on_delete_action),
+                render_on_update(&fk.on_update_action)
             ),
             None => "".to_string(),
         }
     }
+
+    // The migrations we apply recreate the table instead.
+    fn render_alter_column(
+        &self,
+        _schema_name: &str,
+        table: &Table,
+        column: &Column,
+        _alteration: &ColumnAlteration,
+    ) -> SqlResult<Vec<String>> {
+        Err(SqlError::Generic(format!(
+            "The column `{}` on the `{}` table can not be altered in place on SQLite.",
+            column.name, table.name
+        )))
+    }
+
+    // Comments are left out of SQLite migrations.
+    fn render_set_comment(
+        &self,
+        _schema_name: &str,
+        table: &str,
+        _column: Option<&str>,
+        _comment: Option<&str>,
+    ) -> SqlResult<String> {
+        Err(SqlError::Generic(format!(
+            "The comment of the `{}` table can not be set, SQLite does not support comments.",
+            table
+        )))
+    }
 }
@@ -1,21 +1,46 @@
-use crate::SqlResult;
+use crate::sql_enums::render_inline_enum;
+use crate::sql_renderer::SqlRenderer;
+use crate::{SqlFamily, SqlResult};
 use chrono::*;
 use datamodel::common::*;
 use datamodel::*;
 use itertools::Itertools;
+use migration_connector::sha256_hex;
 use prisma_models::{DatamodelConverter, TempManifestationHolder, TempRelationHolder};
 use sql_schema_describer as sql;
 
 pub struct SqlSchemaCalculator<'a> {
     data_model: &'a Datamodel,
+    sql_family: SqlFamily,
+    default_schema: &'a str,
 }
 
 impl<'a> SqlSchemaCalculator<'a> {
-    pub fn calculate(data_model: &Datamodel) -> SqlResult<sql::SqlSchema> {
-        let calculator = SqlSchemaCalculator { data_model };
+    pub fn calculate(data_model: &Datamodel, sql_family: SqlFamily, default_schema: &str) -> SqlResult<sql::SqlSchema> {
+        let calculator = SqlSchemaCalculator {
+            data_model,
+            sql_family,
+            default_schema,
+        };
         calculator.calculate_internal()
     }
 
+    /// The `///` documentation of the datamodel is kept as comments, which SQLite does not support.
+    fn comment(&self, documentation: &Option<String>) -> Option<String> {
+        match self.sql_family {
+            SqlFamily::Sqlite => None,
+            _ => documentation.clone(),
+        }
+    }
+
+    /// The schema of the tables of a model, `None` when they live in the default schema.
+    fn model_schema(&self, model: &Model) -> Option<String> {
+        model
+            .schema
+            .clone()
+            .filter(|schema| schema.as_str() != self.default_schema)
+    }
+
     fn calculate_internal(&self) -> SqlResult<sql::SqlSchema> {
         let mut tables = Vec::new();
         let model_tables_without_inline_relations = self.calculate_model_tables()?;
@@ -32,36 +57,91 @@ impl<'a> SqlSchemaCalculator<'a> {
             table.columns.sort_unstable_by_key(|col| col.name.clone());
         }
 
-        let enums = Vec::new();
+        let enums = self.calculate_enums();
         let sequences = Vec::new();
 
         Ok(sql::SqlSchema {
             tables,
             enums,
             sequences,
+            views: Vec::new(),
         })
     }
 
+    /// Only Postgres has enums as types of their own. MySQL declares them inline on the columns.
+    fn calculate_enums(&self) -> Vec<sql::Enum> {
+        match self.sql_family {
+            SqlFamily::Postgres => self
+                .data_model
+                .enums()
+                .map(|dml_enum| sql::Enum {
+                    name: enum_db_name(dml_enum),
+                    values: dml_enum.values.clone(),
+                })
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// The column type of a scalar field, with enums mapped to the native enum types.
+    fn field_column_type(&self, table_name: &str, column_name: &str, field: &Field) -> sql::ColumnType {
+        let dml_enum = match &field.field_type {
+            FieldType::Enum(enum_name) => self.data_model.find_enum(enum_name),
+            _ => None,
+        };
+
+        match (dml_enum, self.sql_family) {
+            (Some(dml_enum), SqlFamily::Postgres) => sql::ColumnType {
+                raw: enum_db_name(dml_enum),
+                family: sql::ColumnTypeFamily::Enum(enum_db_name(dml_enum)),
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+            },
+            (Some(dml_enum), SqlFamily::Mysql) => sql::ColumnType {
+                raw: render_inline_enum(&dml_enum.values),
+                family: sql::ColumnTypeFamily::Enum(format!("{}_{}", table_name, column_name)),
+                character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+            },
+            // SQLite only auto-increments `INTEGER PRIMARY KEY` columns, which hold 64 bit integers.
+            (None, SqlFamily::Sqlite)
+                if field.is_id() && column_type(field).family == sql::ColumnTypeFamily::BigInt =>
+            {
+                sql::ColumnType::pure(sql::ColumnTypeFamily::Int)
+            }
+            _ => column_type(field),
+        }
+    }
+
     fn calculate_model_tables(&self) -> SqlResult<Vec<ModelTable>> {
         self.data_model
             .models()
+            // Views are managed by the user, the migration engine neither creates nor drops them.
+            .filter(|model| !model.is_view)
             .map(|model| {
                 let columns = model
                     .fields()
                     .flat_map(|f| match (&f.field_type, &f.arity) {
-                        (FieldType::Base(_), arity) | (FieldType::Enum(_), arity) if arity != &FieldArity::List => {
+                        (FieldType::Base(_), arity)
+                        | (FieldType::ConnectorSpecific { .. }, arity)
+                        | (FieldType::Enum(_), arity)
+                            if arity != &FieldArity::List =>
+                        {
                             Some(sql::Column {
                                 name: f.db_name(),
-                                tpe: column_type(f),
+                                tpe: self.field_column_type(&model.db_name(), &f.db_name(), f),
                                 arity: column_arity(&f),
                                 default: f.migration_value_new(&self.data_model),
-                                auto_increment: {
-                                    if column_type(f).family == sql::ColumnTypeFamily::Int {
-                                        f.is_id()
-                                    } else {
-                                        false
-                                    }
+                                default_is_expression: f.db_generated_default().is_some(),
+                                auto_increment: match column_type(f).family {
+                                    sql::ColumnTypeFamily::Int | sql::ColumnTypeFamily::BigInt => f.is_id(),
+                                    _ => false,
                                 },
+                                // MySQL keeps `@updatedAt` columns up to date on its own.
+                                on_update_current_timestamp: f.is_updated_at && self.sql_family == SqlFamily::Mysql,
+                                comment: self.comment(&f.documentation),
                             })
                         }
                         _ => None,
@@ -113,10 +193,13 @@ impl<'a> SqlSchemaCalculator<'a> {
 
                 let table = sql::Table {
                     name: model.db_name(),
+                    schema: self.model_schema(model),
                     columns,
                     indices: single_field_indexes.chain(multiple_field_indexes).collect(),
                     primary_key: Some(primary_key),
                     foreign_keys: Vec::new(),
+                    check_constraints: self.calculate_check_constraints(model),
+                    comment: self.comment(&model.documentation),
                 };
 
                 Ok(ModelTable {
@@ -130,7 +213,7 @@ impl<'a> SqlSchemaCalculator<'a> {
     fn calculate_scalar_list_tables(&self) -> SqlResult<Vec<sql::Table>> {
         let mut result = Vec::new();
 
-        for model in self.data_model.models() {
+        for model in self.data_model.models().filter(|model| !model.is_view) {
             let list_fields: Vec<&Field> = model
                 .fields()
                 .filter(|f| f.arity == FieldArity::List && is_scalar(f))
@@ -145,37 +228,52 @@ impl<'a> SqlSchemaCalculator<'a> {
                     constraint_name: None,
                     columns: vec!["nodeId".to_string()],
                     referenced_table: model.db_name(),
+                    referenced_schema: self.model_schema(model),
                     referenced_columns: vec![model.id_field()?.db_name()],
                     on_delete_action: sql::ForeignKeyAction::Cascade,
+                    on_update_action: sql::ForeignKeyAction::NoAction,
                 }];
+                let table_name = format!("{}_{}", model.db_name(), field.db_name());
                 let table = sql::Table {
-                    name: format!("{}_{}", model.db_name(), field.db_name()),
+                    name: table_name.clone(),
+                    schema: self.model_schema(model),
                     columns: vec![
                         sql::Column {
                             name: "nodeId".to_string(),
                             tpe: column_type(&id_field),
                             arity: sql::ColumnArity::Required,
                             default: None,
+                            default_is_expression: false,
                             auto_increment: false,
+                            on_update_current_timestamp: false,
+                            comment: None,
                         },
                         sql::Column {
                             name: "position".to_string(),
                             tpe: sql::ColumnType::pure(sql::ColumnTypeFamily::Int),
                             arity: sql::ColumnArity::Required,
                             default: None,
+                            default_is_expression: false,
                             auto_increment: false,
+                            on_update_current_timestamp: false,
+                            comment: None,
                         },
                         sql::Column {
                             name: "value".to_string(),
-                            tpe: column_type(&field),
+                            tpe: self.field_column_type(&table_name, "value", &field),
                             arity: sql::ColumnArity::Required,
                             default: None,
+                            default_is_expression: false,
                             auto_increment: false,
+                            on_update_current_timestamp: false,
+                            comment: None,
                         },
                     ],
                     indices: Vec::new(),
                     primary_key: Some(primary_key),
                     foreign_keys,
+                    check_constraints: Vec::new(),
+                    comment: None,
                 };
                 result.push(table);
             }
@@ -200,19 +298,40 @@ impl<'a> SqlSchemaCalculator<'a> {
                             (&relation.model_b, &relation.model_a)
                         };
                         let field = model.fields().find(|f| &f.db_name() == column).unwrap();
+                        let related_field = if field == &relation.field_a {
+                            &relation.field_b
+                        } else {
+                            &relation.field_a
+                        };
                         let foreign_key = sql::ForeignKey {
                             constraint_name: None,
                             columns: vec![column.to_string()],
                             referenced_table: related_model.db_name(),
+                            referenced_schema: self.model_schema(related_model),
                             referenced_columns: vec![related_model.id_field()?.db_name()],
-                            on_delete_action: sql::ForeignKeyAction::SetNull,
+                            on_delete_action: foreign_key_action(
+                                field,
+                                related_field,
+                                |info| info.on_delete,
+                                sql::ForeignKeyAction::SetNull,
+                            ),
+                            on_update_action: foreign_key_action(
+                                field,
+                                related_field,
+                                |info| info.on_update,
+                                // What databases report for foreign keys without an `ON UPDATE` clause.
+                                sql::ForeignKeyAction::NoAction,
+                            ),
                         };
                         let column = sql::Column {
                             name: column.to_string(),
                             tpe: column_type(related_model.id_field()?),
                             arity: column_arity(&field),
                             default: None,
+                            default_is_expression: false,
                             auto_increment: false,
+                            on_update_current_timestamp: false,
+                            comment: self.comment(&field.documentation),
                         };
                         model_table.table.columns.push(column);
                         model_table.table.foreign_keys.push(foreign_key)
@@ -235,33 +354,45 @@ impl<'a> SqlSchemaCalculator<'a> {
                             constraint_name: None,
                             columns: vec![relation.model_a_column()],
                             referenced_table: relation.model_a.db_name(),
+                            referenced_schema: self.model_schema(&relation.model_a),
                             referenced_columns: vec![relation.model_a.id_field()?.db_name()],
                             on_delete_action: sql::ForeignKeyAction::Cascade,
+                            on_update_action: sql::ForeignKeyAction::NoAction,
                         },
                         sql::ForeignKey {
                             constraint_name: None,
                             columns: vec![relation.model_b_column()],
                             referenced_table: relation.model_b.db_name(),
+                            referenced_schema: self.model_schema(&relation.model_b),
                             referenced_columns: vec![relation.model_b.id_field()?.db_name()],
                             on_delete_action: sql::ForeignKeyAction::Cascade,
+                            on_update_action: sql::ForeignKeyAction::NoAction,
                         },
                     ];
                     let table = sql::Table {
                         name: relation.table_name(),
+                        // Relation tables live next to the first model of the relation.
+                        schema: self.model_schema(&relation.model_a),
                         columns: vec![
                             sql::Column {
                                 name: relation.model_a_column(),
                                 tpe: column_type(relation.model_a.id_field()?),
                                 arity: sql::ColumnArity::Required,
                                 default: None,
+                                default_is_expression: false,
                                 auto_increment: false,
+                                on_update_current_timestamp: false,
+                                comment: None,
                             },
                             sql::Column {
                                 name: relation.model_b_column(),
                                 tpe: column_type(relation.model_b.id_field()?),
                                 arity: sql::ColumnArity::Required,
                                 default: None,
+                                default_is_expression: false,
                                 auto_increment: false,
+                                on_update_current_timestamp: false,
+                                comment: None,
                             },
                         ],
                         indices: vec![sql::Index {
@@ -271,6 +402,8 @@ impl<'a> SqlSchemaCalculator<'a> {
                         }],
                         primary_key: None,
                         foreign_keys,
+                        check_constraints: Vec::new(),
+                        comment: None,
                     };
                     result.push(table);
                 }
@@ -280,6 +413,50 @@ impl<'a> SqlSchemaCalculator<'a> {
         Ok(result)
     }
 
+    /// The `@@check` constraints of a model, followed by one constraint per field validator.
+    fn calculate_check_constraints(&self, model: &Model) -> Vec<sql::CheckConstraint> {
+        let renderer = SqlRenderer::for_family(&self.sql_family);
+        let table_name = &model.db_name();
+
+        let model_checks = model.check_constraints.iter().map(|check| sql::CheckConstraint {
+            // Derived from the expression, so the name stays the same when other checks of the
+            // model are added or removed.
+            name: check.name.clone().unwrap_or_else(|| {
+                constraint_name(format!(
+                    "{}_check_{}",
+                    table_name,
+                    &sha256_hex(check.expression.as_bytes())[..8]
+                ))
+            }),
+            expression: check.expression.clone(),
+        });
+
+        let field_checks = model.fields().flat_map(|field| {
+            let column = renderer.quote(&field.db_name());
+
+            field.validators.iter().map(move |validator| match validator {
+                FieldValidator::Length { min, max } => sql::CheckConstraint {
+                    name: constraint_name(format!("{}_{}_length", table_name, field.db_name())),
+                    expression: bounds_expression(
+                        &format!("{}({})", length_function(self.sql_family), column),
+                        min.map(|min| min.to_string()),
+                        max.map(|max| max.to_string()),
+                    ),
+                },
+                FieldValidator::Range { min, max } => sql::CheckConstraint {
+                    name: constraint_name(format!("{}_{}_range", table_name, field.db_name())),
+                    expression: bounds_expression(
+                        &column,
+                        min.map(|min| min.to_string()),
+                        max.map(|max| max.to_string()),
+                    ),
+                },
+            })
+        });
+
+        model_checks.chain(field_checks).collect()
+    }
+
     fn calculate_relations(&self) -> Vec<TempRelationHolder> {
         DatamodelConverter::calculate_relations(&self.data_model)
     }
@@ -322,6 +499,9 @@ pub trait FieldExtensions {
     fn migration_value(&self, datamodel: &Datamodel) -> Value;
 
     fn migration_value_new(&self, datamodel: &Datamodel) -> Option<String>;
+
+    /// The verbatim database expression of a `dbgenerated("...")` default.
+    fn db_generated_default(&self) -> Option<&str>;
 }
 
 impl FieldExtensions for Field {
@@ -348,6 +528,10 @@ impl FieldExtensions for Field {
     }
 
     fn migration_value_new(&self, datamodel: &Datamodel) -> Option<String> {
+        if let Some(expression) = self.db_generated_default() {
+            return Some(expression.to_owned());
+        }
+
         let value = match &self.default_value {
             Some(x) => match x {
                 PrismaValue::Expression(_, _, _) => default_migration_value(&self.field_type, datamodel),
@@ -364,6 +548,7 @@ impl FieldExtensions for Field {
                 }
             }
             Value::Int(x) => format!("{}", x),
+            Value::BigInt(x) => format!("{}", x),
             Value::Float(x) => format!("{}", x),
             Value::Decimal(x) => format!("{}", x),
             Value::String(x) => format!("{}", x),
@@ -384,15 +569,29 @@ impl FieldExtensions for Field {
             Some(result)
         }
     }
+
+    fn db_generated_default(&self) -> Option<&str> {
+        match &self.default_value {
+            Some(PrismaValue::Expression(name, _, args)) if name == "dbgenerated" => match args.first() {
+                Some(PrismaValue::String(expression)) => Some(expression),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
 }
 
 fn default_migration_value(field_type: &FieldType, datamodel: &Datamodel) -> Value {
     match field_type {
+        FieldType::ConnectorSpecific { base_type, .. } => {
+            default_migration_value(&FieldType::Base(*base_type), datamodel)
+        }
         FieldType::Base(PrismaType::Boolean) => Value::Boolean(false),
         FieldType::Base(PrismaType::Int) => Value::Int(0),
+        FieldType::Base(PrismaType::BigInt) => Value::BigInt(0),
         FieldType::Base(PrismaType::Float) => Value::Float(0.0),
         FieldType::Base(PrismaType::String) => Value::String("".to_string()),
-        FieldType::Base(PrismaType::Decimal) => Value::Decimal(0.0),
+        FieldType::Base(PrismaType::Decimal) => Value::Decimal(Decimal::new(0, 0)),
         FieldType::Base(PrismaType::DateTime) => {
             let naive = NaiveDateTime::from_timestamp(0, 0);
             let datetime: DateTime<Utc> = DateTime::from_utc(naive, Utc);
@@ -415,14 +614,24 @@ fn default_migration_value(field_type: &FieldType, datamodel: &Datamodel) -> Val
 fn is_scalar(field: &Field) -> bool {
     match field.field_type {
         FieldType::Base(_) => true,
+        FieldType::ConnectorSpecific { .. } => true,
         FieldType::Enum(_) => true,
         _ => false,
     }
 }
 
+fn enum_db_name(dml_enum: &Enum) -> String {
+    dml_enum.database_name.clone().unwrap_or_else(|| dml_enum.name.clone())
+}
+
 fn column_type(field: &Field) -> sql::ColumnType {
     match &field.field_type {
         FieldType::Base(ref scalar) => column_type_for_scalar_type(&scalar),
+        FieldType::ConnectorSpecific {
+            base_type,
+            connector_type: Some(connector_type),
+        } => column_type_for_native_type(base_type, connector_type),
+        FieldType::ConnectorSpecific { base_type, .. } => column_type_for_scalar_type(base_type),
         FieldType::Enum(_) => column_type_for_scalar_type(&ScalarType::String),
         x => panic!(format!(
             "This field type is not suported here. Field type is {:?} on field {}",
@@ -431,6 +640,66 @@ fn column_type(field: &Field) -> sql::ColumnType {
     }
 }
 
+/// The referential action of an inline relation can be given on either side of the relation.
+/// The side holding the foreign key takes precedence.
+fn foreign_key_action(
+    field: &Field,
+    related_field: &Field,
+    action: impl Fn(&RelationInfo) -> ReferentialAction,
+    default: sql::ForeignKeyAction,
+) -> sql::ForeignKeyAction {
+    let action = vec![field, related_field]
+        .into_iter()
+        .filter_map(|field| match &field.field_type {
+            FieldType::Relation(relation_info) => Some(action(relation_info)),
+            _ => None,
+        })
+        .find(|action| *action != ReferentialAction::None);
+
+    match action {
+        Some(ReferentialAction::Cascade) => sql::ForeignKeyAction::Cascade,
+        Some(ReferentialAction::Restrict) => sql::ForeignKeyAction::Restrict,
+        Some(ReferentialAction::SetNull) => sql::ForeignKeyAction::SetNull,
+        Some(ReferentialAction::SetDefault) => sql::ForeignKeyAction::SetDefault,
+        Some(ReferentialAction::None) | None => default,
+    }
+}
+
+/// Renders the inclusive bounds of a field validator, e.g. `"price" >= 0 AND "price" <= 100`.
+fn bounds_expression(subject: &str, min: Option<String>, max: Option<String>) -> String {
+    let lower = min.map(|min| format!("{} >= {}", subject, min));
+    let upper = max.map(|max| format!("{} <= {}", subject, max));
+
+    lower.into_iter().chain(upper).join(" AND ")
+}
+
+/// Postgres truncates identifiers to 63 bytes, MySQL rejects identifiers longer than 64 bytes.
+const MAX_CONSTRAINT_NAME_LENGTH: usize = 63;
+
+/// Shortens generated constraint names that are too long for the database. The end of the name is
+/// replaced by a hash of the whole name, so names sharing a long prefix stay distinct.
+fn constraint_name(name: String) -> String {
+    if name.len() <= MAX_CONSTRAINT_NAME_LENGTH {
+        return name;
+    }
+
+    let hash = &sha256_hex(name.as_bytes())[..8];
+    let mut prefix_length = MAX_CONSTRAINT_NAME_LENGTH - hash.len() - 1;
+    while !name.is_char_boundary(prefix_length) {
+        prefix_length -= 1;
+    }
+
+    format!("{}_{}", &name[..prefix_length], hash)
+}
+
+/// The function counting the characters of a string.
+fn length_function(sql_family: SqlFamily) -> &'static str {
+    match sql_family {
+        SqlFamily::Sqlite => "length",
+        SqlFamily::Postgres | SqlFamily::Mysql => "char_length",
+    }
+}
+
 fn column_arity(field: &Field) -> sql::ColumnArity {
     match &field.arity {
         FieldArity::Required => sql::ColumnArity::Required,
@@ -439,13 +708,37 @@ fn column_arity(field: &Field) -> sql::ColumnArity {
     }
 }
 
+/// The native type is kept as the raw type for the renderers. The family has to match what the
+/// describer reports for that type, otherwise the differ would see a change on every migration.
+fn column_type_for_native_type(base_type: &ScalarType, connector_type: &str) -> sql::ColumnType {
+    let (native_name, args) = configuration::builtin::parse_connector_type(connector_type);
+    let family = match native_name {
+        "Uuid" => sql::ColumnTypeFamily::Uuid,
+        "Json" | "JsonB" => sql::ColumnTypeFamily::Json,
+        "Decimal" | "Numeric" => sql::ColumnTypeFamily::Decimal,
+        _ => column_type_for_scalar_type(base_type).family,
+    };
+    let args: Vec<u32> = args.into_iter().filter_map(|arg| arg.parse().ok()).collect();
+    let is_character_type = native_name == "VarChar" || native_name == "Char";
+    let is_exact_numeric_type = native_name == "Decimal" || native_name == "Numeric";
+
+    sql::ColumnType {
+        raw: connector_type.to_string(),
+        family,
+        character_maximum_length: args.first().cloned().filter(|_| is_character_type),
+        numeric_precision: args.first().cloned().filter(|_| is_exact_numeric_type),
+        numeric_scale: args.get(1).cloned().filter(|_| is_exact_numeric_type),
+    }
+}
+
 fn column_type_for_scalar_type(scalar_type: &ScalarType) -> sql::ColumnType {
     match scalar_type {
         ScalarType::Int => sql::ColumnType::pure(sql::ColumnTypeFamily::Int),
+        ScalarType::BigInt => sql::ColumnType::pure(sql::ColumnTypeFamily::BigInt),
         ScalarType::Float => sql::ColumnType::pure(sql::ColumnTypeFamily::Float),
         ScalarType::Boolean => sql::ColumnType::pure(sql::ColumnTypeFamily::Boolean),
         ScalarType::String => sql::ColumnType::pure(sql::ColumnTypeFamily::String),
         ScalarType::DateTime => sql::ColumnType::pure(sql::ColumnTypeFamily::DateTime),
-        ScalarType::Decimal => unimplemented!(),
+        ScalarType::Decimal => sql::ColumnType::pure(sql::ColumnTypeFamily::Decimal),
     }
 }
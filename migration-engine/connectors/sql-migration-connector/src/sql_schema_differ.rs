@@ -1,5 +1,10 @@
+use crate::sql_enums::{is_inline_enum, parse_inline_enum};
+use crate::sql_native_types::all_native_types;
 use crate::*;
+use datamodel::configuration::builtin::parse_connector_type;
+use lazy_static::lazy_static;
 use log::debug;
+use regex::Regex;
 use sql_schema_describer::*;
 
 const MIGRATION_TABLE_NAME: &str = "_Migration";
@@ -18,11 +23,57 @@ pub struct SqlSchemaDiff {
     pub create_indexes: Vec<CreateIndex>,
     pub drop_indexes: Vec<DropIndex>,
     pub alter_indexes: Vec<AlterIndex>,
+    pub set_comments: Vec<SetComment>,
+    pub create_enums: Vec<CreateEnum>,
+    pub alter_enums: Vec<AlterEnum>,
+    pub drop_enums: Vec<DropEnum>,
+}
+
+/// A change to the values of a Postgres enum that exists in both schemas.
+#[derive(Debug, Clone)]
+pub struct AlterEnum {
+    pub name: String,
+    pub created_values: Vec<String>,
+    pub dropped_values: Vec<String>,
+    /// All the values of the enum after the change.
+    pub values: Vec<String>,
+    /// The columns of existing tables to convert when the enum has to be recreated.
+    pub conversions: Vec<AlterTable>,
 }
 
 impl SqlSchemaDiff {
     pub fn into_steps(self) -> Vec<SqlMigrationStep> {
         let mut steps = Vec::new();
+        let mut drop_enums = Vec::new();
+        steps.append(&mut wrap_as_step(self.create_enums, |x| {
+            SqlMigrationStep::CreateEnum(x)
+        }));
+        for alter_enum in self.alter_enums {
+            // Postgres can add values to an enum, but not remove them. Then the enum is replaced
+            // by a new one, and the columns using it are converted through text.
+            if alter_enum.dropped_values.is_empty() {
+                steps.extend(alter_enum.created_values.into_iter().map(|value| {
+                    SqlMigrationStep::AddEnumValue(AddEnumValue {
+                        name: alter_enum.name.clone(),
+                        value,
+                    })
+                }));
+            } else {
+                let old_name = format!("{}_old", alter_enum.name);
+                steps.push(SqlMigrationStep::RenameEnum {
+                    name: alter_enum.name.clone(),
+                    new_name: old_name.clone(),
+                });
+                steps.push(SqlMigrationStep::CreateEnum(CreateEnum {
+                    name: alter_enum.name,
+                    values: alter_enum.values,
+                }));
+                steps.append(&mut wrap_as_step(alter_enum.conversions, |x| {
+                    SqlMigrationStep::AlterTable(x)
+                }));
+                drop_enums.push(DropEnum { name: old_name });
+            }
+        }
         steps.append(&mut wrap_as_step(self.drop_indexes, |x| SqlMigrationStep::DropIndex(x)));
         steps.append(&mut wrap_as_step(self.drop_tables, |x| SqlMigrationStep::DropTable(x)));
         steps.append(&mut wrap_as_step(self.create_tables, |x| {
@@ -37,6 +88,12 @@ impl SqlSchemaDiff {
         steps.append(&mut wrap_as_step(self.alter_indexes, |x| {
             SqlMigrationStep::AlterIndex(x)
         }));
+        steps.append(&mut wrap_as_step(self.set_comments, |x| {
+            SqlMigrationStep::SetComment(x)
+        }));
+        // Enums go last, when no column uses them anymore.
+        drop_enums.extend(self.drop_enums);
+        steps.append(&mut wrap_as_step(drop_enums, |x| SqlMigrationStep::DropEnum(x)));
         steps
     }
 }
@@ -57,13 +114,114 @@ impl<'a> SqlSchemaDiffer<'a> {
             create_indexes: self.create_indexes(&alter_indexes),
             drop_indexes: self.drop_indexes(&alter_indexes),
             alter_indexes,
+            set_comments: self.set_comments(),
+            create_enums: self.create_enums(),
+            alter_enums: self.alter_enums(),
+            drop_enums: self.drop_enums(),
         }
     }
 
+    fn create_enums(&self) -> Vec<CreateEnum> {
+        self.next
+            .enums
+            .iter()
+            .filter(|next_enum| self.previous.get_enum(&next_enum.name).is_none())
+            .map(|next_enum| CreateEnum {
+                name: next_enum.name.clone(),
+                values: next_enum.values.clone(),
+            })
+            .collect()
+    }
+
+    fn drop_enums(&self) -> Vec<DropEnum> {
+        self.previous
+            .enums
+            .iter()
+            .filter(|previous_enum| self.next.get_enum(&previous_enum.name).is_none())
+            .map(|previous_enum| DropEnum {
+                name: previous_enum.name.clone(),
+            })
+            .collect()
+    }
+
+    fn alter_enums(&self) -> Vec<AlterEnum> {
+        self.previous
+            .enums
+            .iter()
+            .filter_map(|previous_enum| {
+                let next_enum = self.next.get_enum(&previous_enum.name)?;
+                let created_values: Vec<String> = next_enum
+                    .values
+                    .iter()
+                    .filter(|value| !previous_enum.values.contains(value))
+                    .cloned()
+                    .collect();
+                let dropped_values: Vec<String> = previous_enum
+                    .values
+                    .iter()
+                    .filter(|value| !next_enum.values.contains(value))
+                    .cloned()
+                    .collect();
+
+                if created_values.is_empty() && dropped_values.is_empty() {
+                    return None;
+                }
+
+                Some(AlterEnum {
+                    name: next_enum.name.clone(),
+                    created_values,
+                    dropped_values,
+                    values: next_enum.values.clone(),
+                    conversions: self.enum_conversions(&next_enum.name),
+                })
+            })
+            .collect()
+    }
+
+    /// Converts the columns of the existing tables that use the enum to the recreated enum.
+    fn enum_conversions(&self, enum_name: &str) -> Vec<AlterTable> {
+        let enum_family = ColumnTypeFamily::Enum(enum_name.to_owned());
+
+        self.table_pairs()
+            .filter_map(|(previous_table, next_table)| {
+                let changes: Vec<TableChange> = next_table
+                    .columns
+                    .iter()
+                    .filter(|next_column| {
+                        next_column.tpe.family == enum_family
+                            && previous_table
+                                .column(&next_column.name)
+                                .map(|previous_column| previous_column.tpe.family == enum_family)
+                                .unwrap_or(false)
+                    })
+                    .map(|next_column| {
+                        TableChange::AlterColumn(AlterColumn {
+                            name: next_column.name.clone(),
+                            column: next_column.clone(),
+                            in_place: Some(ColumnAlteration {
+                                type_change: Some(TypeChange::Lossy),
+                                arity_changed: false,
+                            }),
+                        })
+                    })
+                    .collect();
+
+                if changes.is_empty() {
+                    None
+                } else {
+                    Some(AlterTable {
+                        table: next_table.clone(),
+                        changes,
+                    })
+                }
+            })
+            .collect()
+    }
+
     fn create_tables(&self) -> Vec<CreateTable> {
         let mut result = Vec::new();
         for next_table in &self.next.tables {
-            if !self.previous.has_table(&next_table.name) && next_table.name != MIGRATION_TABLE_NAME {
+            if matching_table(self.previous, next_table).is_none() && next_table.name != MIGRATION_TABLE_NAME {
                 let create = CreateTable {
                     table: next_table.clone(),
                 };
@@ -76,9 +234,10 @@ impl<'a> SqlSchemaDiffer<'a> {
     fn drop_tables(&self) -> Vec<DropTable> {
         let mut result = Vec::new();
         for previous_table in &self.previous.tables {
-            if !self.next.has_table(&previous_table.name) && previous_table.name != MIGRATION_TABLE_NAME {
+            if matching_table(self.next, previous_table).is_none() && previous_table.name != MIGRATION_TABLE_NAME {
                 let drop = DropTable {
                     name: previous_table.name.clone(),
+                    schema: previous_table.schema.clone(),
                 };
                 result.push(drop);
             }
@@ -89,21 +248,21 @@ impl<'a> SqlSchemaDiffer<'a> {
     fn alter_tables(&self) -> Vec<AlterTable> {
         // TODO: this does not diff primary key columns yet
         let mut result = Vec::new();
-        for previous_table in &self.previous.tables {
-            if let Ok(next_table) = self.next.table(&previous_table.name) {
-                let mut changes = Vec::new();
-                changes.extend(Self::drop_foreign_keys(&previous_table, &next_table));
-                changes.append(&mut Self::drop_columns(&previous_table, &next_table));
-                changes.append(&mut Self::add_columns(&previous_table, &next_table));
-                changes.append(&mut Self::alter_columns(&previous_table, &next_table));
-
-                if !changes.is_empty() {
-                    let update = AlterTable {
-                        table: next_table.clone(),
-                        changes,
-                    };
-                    result.push(update);
-                }
+        for (previous_table, next_table) in self.table_pairs() {
+            let mut changes = Vec::new();
+            changes.extend(Self::drop_foreign_keys(&previous_table, &next_table));
+            changes.append(&mut Self::drop_columns(&previous_table, &next_table));
+            changes.append(&mut Self::add_columns(&previous_table, &next_table));
+            changes.append(&mut Self::alter_columns(&previous_table, &next_table));
+            changes.extend(Self::drop_check_constraints(&previous_table, &next_table));
+            changes.extend(Self::add_check_constraints(&previous_table, &next_table));
+
+            if !changes.is_empty() {
+                let update = AlterTable {
+                    table: next_table.clone(),
+                    changes,
+                };
+                result.push(update);
             }
         }
         result
@@ -144,14 +303,30 @@ impl<'a> SqlSchemaDiffer<'a> {
 
                 // TODO: use differs function again
                 let is_fk_case = previous_fk.is_some() && next_fk.is_some(); // to cater for the temporary ignorance of NOT NULL constraint
+                let type_change = type_change(&previous_column.tpe, &next_column.tpe);
+                let arity_changed = previous_column.arity != next_column.arity && !is_fk_case;
                 let differs_in_something = previous_column.name != next_column.name
-                    || previous_column.tpe.family != next_column.tpe.family
-                    || (previous_column.arity != next_column.arity && !is_fk_case);
+                    || type_change.is_some()
+                    || arity_changed
+                    || default_changed(previous_column, next_column);
+                let foreign_key_changed = foreign_key_changed(previous_fk, next_fk);
 
-                if differs_in_something || foreign_key_changed(previous_fk, next_fk) {
+                if differs_in_something || foreign_key_changed {
+                    // Foreign keys and auto increments are part of the column definition we render
+                    // when adding a column, so we only alter columns in place when they stay the same.
+                    let can_alter_in_place =
+                        !foreign_key_changed && previous_column.auto_increment == next_column.auto_increment;
                     let change = AlterColumn {
                         name: previous_column.name.clone(),
                         column: next_column.clone(),
+                        in_place: if can_alter_in_place {
+                            Some(ColumnAlteration {
+                                type_change,
+                                arity_changed,
+                            })
+                        } else {
+                            None
+                        },
                     };
                     result.push(TableChange::AlterColumn(change));
                 }
@@ -183,21 +358,53 @@ impl<'a> SqlSchemaDiffer<'a> {
             })
     }
 
+    fn drop_check_constraints(previous: &'a Table, next: &'a Table) -> impl Iterator<Item = TableChange> + 'a {
+        previous
+            .check_constraints
+            .iter()
+            .filter(move |previous_check| {
+                next.check_constraints
+                    .iter()
+                    .find(|next_check| check_constraints_match(previous_check, next_check))
+                    .is_none()
+            })
+            .map(|dropped_check| {
+                TableChange::DropCheckConstraint(DropCheckConstraint {
+                    name: dropped_check.name.clone(),
+                })
+            })
+    }
+
+    fn add_check_constraints(previous: &'a Table, next: &'a Table) -> impl Iterator<Item = TableChange> + 'a {
+        next.check_constraints
+            .iter()
+            .filter(move |next_check| {
+                previous
+                    .check_constraints
+                    .iter()
+                    .find(|previous_check| check_constraints_match(previous_check, next_check))
+                    .is_none()
+            })
+            .map(|added_check| {
+                TableChange::AddCheckConstraint(AddCheckConstraint {
+                    check_constraint: added_check.clone(),
+                })
+            })
+    }
+
     fn create_indexes(&self, alter_indexes: &[AlterIndex]) -> Vec<CreateIndex> {
         let mut result = Vec::new();
         for next_table in &self.next.tables {
             for index in &next_table.indices {
                 // TODO: must diff index settings
-                let previous_index_opt = self
-                    .previous
-                    .table(&next_table.name)
-                    .ok()
+                let previous_index_opt = matching_table(self.previous, next_table)
                     .and_then(|t| t.indices.iter().find(|i| i.name == index.name));
                 let index_was_altered = alter_indexes.iter().any(|altered| altered.index_new_name == index.name);
                 if previous_index_opt.is_none() && !index_was_altered {
                     let create = CreateIndex {
                         table: next_table.name.clone(),
                         index: index.clone(),
+                        schema: next_table.schema.clone(),
                     };
                     result.push(create);
                 }
@@ -211,10 +418,7 @@ impl<'a> SqlSchemaDiffer<'a> {
         for previous_table in &self.previous.tables {
             for index in &previous_table.indices {
                 // TODO: must diff index settings
-                let next_index_opt = self
-                    .next
-                    .table(&previous_table.name)
-                    .ok()
+                let next_index_opt = matching_table(self.next, previous_table)
                     .and_then(|t| t.indices.iter().find(|i| i.name == index.name));
                 let index_was_altered = alter_indexes.iter().any(|altered| altered.index_name == index.name);
                 if next_index_opt.is_none() && !index_was_altered {
@@ -228,6 +432,7 @@ impl<'a> SqlSchemaDiffer<'a> {
                         let drop = DropIndex {
                             table: previous_table.name.clone(),
                             name: index.name.clone(),
+                            schema: previous_table.schema.clone(),
                         };
                         result.push(drop);
                     } else {
@@ -242,14 +447,47 @@ impl<'a> SqlSchemaDiffer<'a> {
         result
     }
 
+    /// The comments are set once the tables and columns exist, as Postgres only sets them in
+    /// statements of their own.
+    fn set_comments(&self) -> Vec<SetComment> {
+        let mut result = Vec::new();
+        for next_table in &self.next.tables {
+            if next_table.name == MIGRATION_TABLE_NAME {
+                continue;
+            }
+
+            let previous_table = matching_table(self.previous, next_table);
+            let previous_comment = previous_table.and_then(|table| table.comment.as_ref());
+            if previous_comment != next_table.comment.as_ref() {
+                result.push(SetComment {
+                    table: next_table.name.clone(),
+                    schema: next_table.schema.clone(),
+                    column: None,
+                    comment: next_table.comment.clone(),
+                });
+            }
+
+            for next_column in &next_table.columns {
+                let previous_comment = previous_table
+                    .and_then(|table| table.column(&next_column.name))
+                    .and_then(|column| column.comment.as_ref());
+                if previous_comment != next_column.comment.as_ref() {
+                    result.push(SetComment {
+                        table: next_table.name.clone(),
+                        schema: next_table.schema.clone(),
+                        column: Some(next_column.name.clone()),
+                        comment: next_column.comment.clone(),
+                    });
+                }
+            }
+        }
+        result
+    }
+
     /// An iterator over the tables that are present in both schemas. The yielded tuples should be interpreted as `(previous_table, next_table)`.
     fn table_pairs(&self) -> impl Iterator<Item = (&Table, &Table)> {
         self.previous.tables.iter().filter_map(move |previous_table| {
-            self.next
-                .tables
-                .iter()
-                .find(|next_table| next_table.name == previous_table.name)
-                .map(|next_table| (previous_table, next_table))
+            matching_table(self.next, previous_table).map(|next_table| (previous_table, next_table))
         })
     }
 
@@ -273,17 +511,219 @@ impl<'a> SqlSchemaDiffer<'a> {
                         index_name: previous_index.name.clone(),
                         index_new_name: renamed_index.name.clone(),
                         table: next_table.name.clone(),
+                        schema: next_table.schema.clone(),
                     })
             })
             .collect()
     }
 }
 
+/// The table with the same schema and name as `table` in `schema`.
+fn matching_table<'a>(schema: &'a SqlSchema, table: &Table) -> Option<&'a Table> {
+    schema.get_table_in_schema(table.schema.as_ref().map(String::as_str), &table.name)
+}
+
 /// Compare two SQL indexes and return whether they only differ by name or type.
-fn indexes_are_equivalent(first: &Index, second: &Index) -> bool {
+pub(crate) fn indexes_are_equivalent(first: &Index, second: &Index) -> bool {
     first.columns == second.columns && first.tpe == second.tpe
 }
 
+/// Only `dbgenerated` defaults are compared. Literal defaults merely smoothen migrations, and each
+/// database reports them its own way. An expression in the database without one in the new column
+/// stands for a datamodel function like `now()`, which has no expression in the calculated schema.
+pub(crate) fn default_changed(previous: &Column, next: &Column) -> bool {
+    // The default of an auto-increment column is its sequence.
+    if !next.default_is_expression || previous.auto_increment || next.auto_increment {
+        return false;
+    }
+
+    !previous.default_is_expression
+        || previous.default.as_ref().map(|default| normalize_expression(default))
+            != next.default.as_ref().map(|default| normalize_expression(default))
+}
+
+lazy_static! {
+    /// Postgres casts, e.g. `'ABC'::text` or `'{}'::character varying[]`.
+    static ref RE_CAST: Regex = Regex::new(
+        r#"::("[^"]+"|(character varying|double precision|time(stamp)?( with(out)? time zone)?)\b|[a-z_][a-z0-9_]*)(\[\])?"#
+    )
+    .expect("compile regex");
+    /// MySQL charset introducers, e.g. `_utf8mb4'ABC'`.
+    static ref RE_INTRODUCER: Regex = Regex::new(r"_[a-z0-9]+'").expect("compile regex");
+    static ref RE_WHITESPACE: Regex = Regex::new(r"\s+").expect("compile regex");
+}
+
+/// Databases report expressions in their own words: Postgres adds casts, MySQL 8 adds charset
+/// introducers and escapes quotes, and the parentheses around the whole expression come and go.
+fn normalize_expression(expression: &str) -> String {
+    let expression = expression.to_lowercase().replace("\\'", "'");
+    let expression = RE_CAST.replace_all(&expression, "");
+    let expression = RE_INTRODUCER.replace_all(&expression, "'");
+    let expression = RE_WHITESPACE.replace_all(&expression, "").into_owned();
+    let mut expression = expression.as_str();
+
+    while expression.starts_with('(') && expression.ends_with(')') && wraps_whole(expression) {
+        expression = &expression[1..expression.len() - 1];
+    }
+
+    expression.to_owned()
+}
+
+/// Whether the opening parenthesis at the start is closed by the one at the end.
+fn wraps_whole(expression: &str) -> bool {
+    let mut depth = 0;
+
+    for (i, c) in expression.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            _ => (),
+        }
+
+        if depth == 0 {
+            return i == expression.len() - 1;
+        }
+    }
+
+    false
+}
+
+/// Classify the change between two column types. Returns `None` when the type does not change.
+pub(crate) fn type_change(previous: &ColumnType, next: &ColumnType) -> Option<TypeChange> {
+    use ColumnTypeFamily::*;
+
+    if let (Enum(previous_enum), Enum(next_enum)) = (&previous.family, &next.family) {
+        // The names of inline enums are made up from their table and column, so they are compared
+        // by their values only.
+        if previous_enum == next_enum || (is_inline_enum(&previous.raw) && is_inline_enum(&next.raw)) {
+            return inline_enum_change(&previous.raw, &next.raw);
+        }
+    }
+
+    if previous.family == next.family {
+        return native_type_change(previous, next);
+    }
+
+    if is_legacy_float_column(previous, next) {
+        return None;
+    }
+
+    let is_widening = match (&previous.family, &next.family) {
+        (Int, BigInt) | (Int, Float) | (BigInt, Float) | (Boolean, Int) | (Boolean, BigInt) => true,
+        // Without a native type, decimals have at least 34 integral digits.
+        (Int, Decimal) | (BigInt, Decimal) | (Boolean, Decimal) => next.numeric_precision.is_none(),
+        // All our string types are wide enough for the textual representation of other scalars.
+        (Int, String) | (BigInt, String) | (Float, String) | (Decimal, String) | (Boolean, String) => true,
+        (DateTime, String) => true,
+        (Enum(_), String) => true,
+        _ => false,
+    };
+
+    Some(if is_widening {
+        TypeChange::Widening
+    } else {
+        TypeChange::Lossy
+    })
+}
+
+/// Float fields used to be stored as `Decimal(65,30)` on MySQL and Postgres. These columns are
+/// kept as they are instead of being migrated to floating point types.
+fn is_legacy_float_column(previous: &ColumnType, next: &ColumnType) -> bool {
+    previous.family == ColumnTypeFamily::Decimal
+        && previous.numeric_precision == Some(65)
+        && previous.numeric_scale == Some(30)
+        && next.family == ColumnTypeFamily::Float
+        && next.raw.is_empty()
+}
+
+/// Compare the values of two inline MySQL enums. Postgres enums are diffed as types of their own,
+/// so their columns keep the same raw type, the name of the enum.
+fn inline_enum_change(previous: &str, next: &str) -> Option<TypeChange> {
+    let previous_values = parse_inline_enum(previous);
+    let next_values = parse_inline_enum(next);
+
+    if previous_values == next_values {
+        None
+    } else if previous_values.iter().all(|value| next_values.contains(value)) {
+        Some(TypeChange::Widening)
+    } else {
+        Some(TypeChange::Lossy)
+    }
+}
+
+/// Compare two column types of the same family, e.g. `SmallInt` and `int4` or `VarChar(100)` and
+/// `VarChar(200)`. Types without a native type in the datamodel come without a native name,
+/// lengths and precisions, and they are only compared when the next type has them.
+fn native_type_change(previous: &ColumnType, next: &ColumnType) -> Option<TypeChange> {
+    let name_change = native_type_name_change(previous, next);
+
+    let length_change = next.character_maximum_length.and_then(|next_length| {
+        match previous.character_maximum_length {
+            Some(previous_length) if previous_length == next_length => None,
+            Some(previous_length) if previous_length < next_length => Some(TypeChange::Widening),
+            // Limiting the length of an unbounded type can truncate values as well.
+            _ => Some(TypeChange::Lossy),
+        }
+    });
+
+    let precision_change = next.numeric_precision.and_then(|next_precision| {
+        let next_scale = i64::from(next.numeric_scale.unwrap_or(0));
+        let next_precision = i64::from(next_precision);
+
+        match previous.numeric_precision {
+            Some(previous_precision) => {
+                let previous_scale = i64::from(previous.numeric_scale.unwrap_or(0));
+                let previous_precision = i64::from(previous_precision);
+
+                if previous_precision == next_precision && previous_scale == next_scale {
+                    None
+                } else if next_scale >= previous_scale
+                    && next_precision - next_scale >= previous_precision - previous_scale
+                {
+                    // Neither the integral nor the fractional digits shrink.
+                    Some(TypeChange::Widening)
+                } else {
+                    Some(TypeChange::Lossy)
+                }
+            }
+            None => Some(TypeChange::Lossy),
+        }
+    });
+
+    match (name_change, length_change, precision_change) {
+        (Some(TypeChange::Lossy), _, _) | (_, Some(TypeChange::Lossy), _) | (_, _, Some(TypeChange::Lossy)) => {
+            Some(TypeChange::Lossy)
+        }
+        (name_change, length_change, precision_change) => name_change.or(length_change).or(precision_change),
+    }
+}
+
+/// Compare the native type declared in the datamodel with the previous type, either a described
+/// raw type like `int4` or another native type. Types the describers report but that are not in
+/// the list of native types can only be changed into the declared type with a loss.
+fn native_type_name_change(previous: &ColumnType, next: &ColumnType) -> Option<TypeChange> {
+    let (next_name, _) = parse_connector_type(&next.raw);
+    let next_type = all_native_types().find(|native_type| native_type.name == next_name)?;
+
+    let (previous_name, _) = parse_connector_type(&previous.raw);
+
+    if previous_name == next_name
+        || all_native_types().any(|native_type| native_type.name == next_name && native_type.matches_raw(&previous.raw))
+    {
+        return None;
+    }
+
+    let previous_type = all_native_types()
+        .find(|native_type| native_type.name == previous_name || native_type.matches_raw(&previous.raw));
+
+    match previous_type {
+        Some(previous_type) if previous_type.kind == next_type.kind && previous_type.rank <= next_type.rank => {
+            Some(TypeChange::Widening)
+        }
+        _ => Some(TypeChange::Lossy),
+    }
+}
+
 /// Compare two [ForeignKey](/sql-schema-describer/struct.ForeignKey.html)s and return whether a
 /// migration needs to be applied.
 fn foreign_key_changed(previous: Option<&ForeignKey>, next: Option<&ForeignKey>) -> bool {
@@ -296,9 +736,43 @@ fn foreign_key_changed(previous: Option<&ForeignKey>, next: Option<&ForeignKey>)
 
 /// Compare two [ForeignKey](/sql-schema-describer/struct.ForeignKey.html)s and return whether they
 /// should be considered equivalent for schema diffing purposes.
-fn foreign_keys_match(previous: &ForeignKey, next: &ForeignKey) -> bool {
+pub(crate) fn foreign_keys_match(previous: &ForeignKey, next: &ForeignKey) -> bool {
+    foreign_keys_match_except_on_update(previous, next) && previous.on_update_action == next.on_update_action
+}
+
+/// Like [foreign_keys_match](fn.foreign_keys_match.html), without comparing the `ON UPDATE` actions.
+pub(crate) fn foreign_keys_match_except_on_update(previous: &ForeignKey, next: &ForeignKey) -> bool {
     previous.referenced_table == next.referenced_table
+        && previous.referenced_schema == next.referenced_schema
         && previous.referenced_columns == next.referenced_columns
         && previous.columns == next.columns
         && previous.on_delete_action == next.on_delete_action
 }
+
+/// Compare two [CheckConstraint](/sql-schema-describer/struct.CheckConstraint.html)s and return
+/// whether they should be considered equivalent for schema diffing purposes.
+pub(crate) fn check_constraints_match(previous: &CheckConstraint, next: &CheckConstraint) -> bool {
+    previous.name == next.name
+        && normalize_check_expression(&previous.expression) == normalize_check_expression(&next.expression)
+}
+
+/// Databases store check expressions in their own canonical form, e.g. Postgres turns `price > 0`
+/// into `((price > (0)::numeric))`. This strips what they typically add: parentheses, identifier
+/// quotes, casts, whitespace and casing.
+fn normalize_check_expression(expression: &str) -> String {
+    let compact: String = expression
+        .to_lowercase()
+        .chars()
+        .filter(|c| !c.is_whitespace() && !['(', ')', '"', '`'].contains(c))
+        .collect();
+    let mut normalized = String::with_capacity(compact.len());
+    let mut rest = compact.as_str();
+
+    while let Some(idx) = rest.find("::") {
+        normalized.push_str(&rest[..idx]);
+        rest = rest[idx + 2..].trim_start_matches(|c: char| c.is_alphanumeric() || c == '_');
+    }
+    normalized.push_str(rest);
+
+    normalized
+}
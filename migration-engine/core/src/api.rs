@@ -37,17 +37,24 @@ where
 // liking them in the exported class.
 pub trait GenericApi: Send + Sync + 'static {
     fn apply_migration(&self, input: &ApplyMigrationInput) -> crate::Result<MigrationStepsResultOutput>;
+    fn baseline(&self, input: &BaselineInput) -> crate::Result<BaselineOutput>;
     fn calculate_database_steps(
         &self,
         input: &CalculateDatabaseStepsInput,
     ) -> crate::Result<MigrationStepsResultOutput>;
     fn calculate_datamodel(&self, input: &CalculateDatamodelInput) -> crate::Result<CalculateDatamodelOutput>;
+    fn check_drift(&self, input: &serde_json::Value) -> crate::Result<CheckDriftOutput>;
+    fn diff(&self, input: &DiffInput) -> crate::Result<DiffOutput>;
+    fn dry_run(&self, input: &DryRunInput) -> crate::Result<DryRunOutput>;
+    fn export_migrations(&self, input: &ExportMigrationsInput) -> crate::Result<ExportMigrationsOutput>;
     fn infer_migration_steps(&self, input: &InferMigrationStepsInput) -> crate::Result<MigrationStepsResultOutput>;
-    fn list_migrations(&self, input: &serde_json::Value) -> crate::Result<Vec<ListMigrationStepsOutput>>;
+    fn list_migrations(&self, input: &ListMigrationsInput) -> crate::Result<Vec<ListMigrationStepsOutput>>;
     fn migration_progress(&self, input: &MigrationProgressInput) -> crate::Result<MigrationProgressOutput>;
     fn reset(&self, input: &serde_json::Value) -> crate::Result<serde_json::Value>;
+    fn squash_migrations(&self, input: &SquashMigrationsInput) -> crate::Result<SquashMigrationsOutput>;
     fn unapply_migration(&self, input: &UnapplyMigrationInput) -> crate::Result<UnapplyMigrationOutput>;
     fn migration_persistence(&self) -> Arc<dyn MigrationPersistence>;
+    fn migration_lock(&self) -> Arc<dyn MigrationLock>;
     fn connector_type(&self) -> &'static str;
 }
 
@@ -60,6 +67,10 @@ where
         self.handle_command::<ApplyMigrationCommand>(input)
     }
 
+    fn baseline(&self, input: &BaselineInput) -> crate::Result<BaselineOutput> {
+        self.handle_command::<BaselineCommand>(input)
+    }
+
     fn calculate_database_steps(
         &self,
         input: &CalculateDatabaseStepsInput,
@@ -71,11 +82,27 @@ where
         self.handle_command::<CalculateDatamodelCommand>(input)
     }
 
+    fn check_drift(&self, input: &serde_json::Value) -> crate::Result<CheckDriftOutput> {
+        self.handle_command::<CheckDriftCommand>(input)
+    }
+
+    fn diff(&self, input: &DiffInput) -> crate::Result<DiffOutput> {
+        self.handle_command::<DiffCommand>(input)
+    }
+
+    fn dry_run(&self, input: &DryRunInput) -> crate::Result<DryRunOutput> {
+        self.handle_command::<DryRunCommand>(input)
+    }
+
+    fn export_migrations(&self, input: &ExportMigrationsInput) -> crate::Result<ExportMigrationsOutput> {
+        self.handle_command::<ExportMigrationsCommand>(input)
+    }
+
     fn infer_migration_steps(&self, input: &InferMigrationStepsInput) -> crate::Result<MigrationStepsResultOutput> {
         self.handle_command::<InferMigrationStepsCommand>(input)
     }
 
-    fn list_migrations(&self, input: &serde_json::Value) -> crate::Result<Vec<ListMigrationStepsOutput>> {
+    fn list_migrations(&self, input: &ListMigrationsInput) -> crate::Result<Vec<ListMigrationStepsOutput>> {
         self.handle_command::<ListMigrationStepsCommand>(input)
     }
 
@@ -87,6 +114,10 @@ where
         self.handle_command::<ResetCommand>(input)
     }
 
+    fn squash_migrations(&self, input: &SquashMigrationsInput) -> crate::Result<SquashMigrationsOutput> {
+        self.handle_command::<SquashMigrationsCommand>(input)
+    }
+
     fn unapply_migration(&self, input: &UnapplyMigrationInput) -> crate::Result<UnapplyMigrationOutput> {
         self.handle_command::<UnapplyMigrationCommand>(input)
     }
@@ -95,6 +126,10 @@ where
         self.engine.connector().migration_persistence()
     }
 
+    fn migration_lock(&self) -> Arc<dyn MigrationLock> {
+        self.engine.connector().migration_lock()
+    }
+
     fn connector_type(&self) -> &'static str {
         self.engine.connector().connector_type()
     }
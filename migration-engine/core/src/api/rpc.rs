@@ -28,6 +28,12 @@ enum RpcCommand {
     Reset,
     CalculateDatamodel,
     CalculateDatabaseSteps,
+    CheckDrift,
+    Diff,
+    DryRun,
+    ExportMigrations,
+    SquashMigrations,
+    Baseline,
 }
 
 impl RpcCommand {
@@ -41,6 +47,12 @@ impl RpcCommand {
             RpcCommand::Reset => "reset",
             RpcCommand::CalculateDatamodel => "calculateDatamodel",
             RpcCommand::CalculateDatabaseSteps => "calculateDatabaseSteps",
+            RpcCommand::CheckDrift => "checkDrift",
+            RpcCommand::Diff => "diff",
+            RpcCommand::DryRun => "dryRun",
+            RpcCommand::ExportMigrations => "exportMigrations",
+            RpcCommand::SquashMigrations => "squashMigrations",
+            RpcCommand::Baseline => "baseline",
         }
     }
 }
@@ -54,6 +66,12 @@ static AVAILABLE_COMMANDS: &[RpcCommand] = &[
     RpcCommand::Reset,
     RpcCommand::CalculateDatamodel,
     RpcCommand::CalculateDatabaseSteps,
+    RpcCommand::CheckDrift,
+    RpcCommand::Diff,
+    RpcCommand::DryRun,
+    RpcCommand::ExportMigrations,
+    RpcCommand::SquashMigrations,
+    RpcCommand::Baseline,
 ];
 
 impl RpcApi {
@@ -150,7 +168,12 @@ impl RpcApi {
                 serde_json::to_value(result).expect("Rendering of RPC response failed")
             }
             RpcCommand::ListMigrations => {
-                let result = executor.list_migrations(&serde_json::Value::Null)?;
+                // The command used to take no params, so they are optional.
+                let input: ListMigrationsInput = match params {
+                    Params::None => ListMigrationsInput::default(),
+                    params => params.clone().parse()?,
+                };
+                let result = executor.list_migrations(&input)?;
 
                 serde_json::to_value(result).expect("Rendering of RPC response failed")
             }
@@ -187,6 +210,41 @@ impl RpcApi {
                 let input: CalculateDatabaseStepsInput = params.clone().parse()?;
                 let result = executor.calculate_database_steps(&input)?;
 
+                serde_json::to_value(result).expect("Rendering of RPC response failed")
+            }
+            RpcCommand::CheckDrift => {
+                let result = executor.check_drift(&serde_json::Value::Null)?;
+
+                serde_json::to_value(result).expect("Rendering of RPC response failed")
+            }
+            RpcCommand::Diff => {
+                let input: DiffInput = params.clone().parse()?;
+                let result = executor.diff(&input)?;
+
+                serde_json::to_value(result).expect("Rendering of RPC response failed")
+            }
+            RpcCommand::DryRun => {
+                let input: DryRunInput = params.clone().parse()?;
+                let result = executor.dry_run(&input)?;
+
+                serde_json::to_value(result).expect("Rendering of RPC response failed")
+            }
+            RpcCommand::ExportMigrations => {
+                let input: ExportMigrationsInput = params.clone().parse()?;
+                let result = executor.export_migrations(&input)?;
+
+                serde_json::to_value(result).expect("Rendering of RPC response failed")
+            }
+            RpcCommand::SquashMigrations => {
+                let input: SquashMigrationsInput = params.clone().parse()?;
+                let result = executor.squash_migrations(&input)?;
+
+                serde_json::to_value(result).expect("Rendering of RPC response failed")
+            }
+            RpcCommand::Baseline => {
+                let input: BaselineInput = params.clone().parse()?;
+                let result = executor.baseline(&input)?;
+
                 serde_json::to_value(result).expect("Rendering of RPC response failed")
             }
         };
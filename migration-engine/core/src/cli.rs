@@ -1,10 +1,11 @@
+use crate::commands::{DiffInput, DiffOutputFormat, DryRunInput};
 use clap::ArgMatches;
 use failure::Fail;
 use itertools::Itertools;
 use migration_connector::*;
 use sql_connection::SqlFamily;
-use sql_migration_connector::SqlMigrationConnector;
-use std::collections::HashMap;
+use sql_migration_connector::{DiffTarget, SqlMigrationConnector};
+use std::{collections::HashMap, fs};
 use url::Url;
 
 #[derive(Debug, Fail, PartialEq)]
@@ -82,6 +83,73 @@ pub fn run(matches: &ArgMatches, datasource: &str) -> std::result::Result<String
     }
 }
 
+/// Diffs the two schemas given on the command line and renders the result in the requested format.
+pub fn diff(matches: &ArgMatches) -> std::result::Result<String, CliError> {
+    let output = match matches.value_of("output").unwrap_or("summary") {
+        "steps" => DiffOutputFormat::Steps,
+        "sql" => DiffOutputFormat::Sql,
+        _ => DiffOutputFormat::Summary,
+    };
+
+    let input = DiffInput {
+        from: diff_target(matches, "from")?,
+        to: diff_target(matches, "to")?,
+        provider: matches.value_of("provider").map(String::from),
+        schema_name: matches.value_of("schema_name").map(String::from),
+        output,
+    };
+
+    let result = crate::commands::diff(&input).map_err(crate::Error::from)?.result;
+
+    match result {
+        serde_json::Value::String(rendered) => Ok(rendered),
+        steps => Ok(serde_json::to_string_pretty(&steps).expect("Rendering the steps failed")),
+    }
+}
+
+/// Renders the migration between two datamodel files as a SQL script. The warnings about potential
+/// data loss are rendered as comments at the top of the script.
+pub fn dry_run(matches: &ArgMatches) -> std::result::Result<String, CliError> {
+    let read = |path: &str| fs::read_to_string(path).map_err(|err| CliError::Other(format!("{}: {}", path, err)));
+
+    let input = DryRunInput {
+        previous_datamodel: matches.value_of("previous").map(read).transpose()?,
+        next_datamodel: read(matches.value_of("next").unwrap())?,
+        provider: matches.value_of("provider").map(String::from),
+        schema_name: matches.value_of("schema_name").map(String::from),
+    };
+
+    let output = crate::commands::dry_run(&input).map_err(crate::Error::from)?;
+    let warnings: String = output
+        .warnings
+        .iter()
+        .map(|warning| format!("-- Warning: {}\n", warning.description))
+        .collect();
+
+    if warnings.is_empty() {
+        Ok(output.script)
+    } else {
+        Ok(format!("{}\n{}", warnings, output.script))
+    }
+}
+
+fn diff_target(matches: &ArgMatches, side: &str) -> std::result::Result<DiffTarget, CliError> {
+    let read = |path: &str| fs::read_to_string(path).map_err(|err| CliError::Other(format!("{}: {}", path, err)));
+
+    if let Some(path) = matches.value_of(format!("{}-datamodel", side)) {
+        Ok(DiffTarget::Datamodel { datamodel: read(path)? })
+    } else if let Some(url) = matches.value_of(format!("{}-url", side)) {
+        Ok(DiffTarget::Database { url: url.to_owned() })
+    } else if let Some(path) = matches.value_of(format!("{}-schema", side)) {
+        let schema = serde_json::from_str(&read(path)?)
+            .map_err(|err| CliError::Other(format!("{} is not a valid schema: {}", path, err)))?;
+
+        Ok(DiffTarget::SqlSchema { schema })
+    } else {
+        Ok(DiffTarget::Empty)
+    }
+}
+
 fn fetch_db_name(url: &Url, default: &str) -> String {
     let result = match url.path_segments() {
         Some(mut segments) => segments.next().unwrap_or(default),
@@ -217,6 +285,107 @@ pub fn clap_app() -> clap::App<'static, 'static> {
                         .required(false),
                 ),
         )
+        .subcommand(diff_subcommand())
+        .subcommand(
+            SubCommand::with_name("dry-run")
+                .about("Prints the SQL migrating from one datamodel to another, without connecting to a database.")
+                .arg(
+                    Arg::with_name("previous")
+                        .long("previous")
+                        .value_name("FILE")
+                        .help("The datamodel to migrate from. Defaults to an empty database.")
+                        .takes_value(true)
+                        .required(false),
+                )
+                .arg(
+                    Arg::with_name("next")
+                        .long("next")
+                        .value_name("FILE")
+                        .help("The datamodel to migrate to.")
+                        .takes_value(true)
+                        .required(true),
+                )
+                .arg(
+                    Arg::with_name("provider")
+                        .long("provider")
+                        .help("The database family, when it can not be determined from the datamodels.")
+                        .takes_value(true)
+                        .required(false),
+                )
+                .arg(
+                    Arg::with_name("schema_name")
+                        .long("schema-name")
+                        .help("The database schema referred to in the rendered SQL.")
+                        .takes_value(true)
+                        .required(false),
+                ),
+        )
+}
+
+fn diff_subcommand() -> clap::App<'static, 'static> {
+    use clap::{Arg, ArgGroup, SubCommand};
+
+    let mut subcommand = SubCommand::with_name("diff")
+        .about("Prints the migration between two schemas. Each side defaults to an empty database.")
+        .arg(
+            Arg::with_name("output")
+                .long("output")
+                .help("What to print: the migration steps, the SQL script or a summary.")
+                .takes_value(true)
+                .possible_values(&["steps", "sql", "summary"])
+                .default_value("summary"),
+        )
+        .arg(
+            Arg::with_name("provider")
+                .long("provider")
+                .help("The database family, when it can not be determined from the schemas.")
+                .takes_value(true)
+                .required(false),
+        )
+        .arg(
+            Arg::with_name("schema_name")
+                .long("schema-name")
+                .help("The database schema referred to in the rendered SQL.")
+                .takes_value(true)
+                .required(false),
+        );
+
+    for &(side, datamodel, url, schema, empty) in &[
+        ("from", "from-datamodel", "from-url", "from-schema", "from-empty"),
+        ("to", "to-datamodel", "to-url", "to-schema", "to-empty"),
+    ] {
+        subcommand = subcommand
+            .arg(
+                Arg::with_name(datamodel)
+                    .long(datamodel)
+                    .value_name("FILE")
+                    .help("A datamodel file.")
+                    .takes_value(true),
+            )
+            .arg(
+                Arg::with_name(url)
+                    .long(url)
+                    .value_name("URL")
+                    .help("The connection string of a database to introspect.")
+                    .takes_value(true),
+            )
+            .arg(
+                Arg::with_name(schema)
+                    .long(schema)
+                    .value_name("FILE")
+                    .help("A JSON file with a schema as returned by the schema describer.")
+                    .takes_value(true),
+            )
+            .arg(
+                Arg::with_name(empty)
+                    .long(empty)
+                    .help("An empty database.")
+                    .takes_value(false),
+            )
+            .group(ArgGroup::with_name(side).args(&[datamodel, url, schema, empty]));
+    }
+
+    subcommand
 }
 
 #[cfg(test)]
@@ -1,10 +1,11 @@
-use super::MigrationStepsResultOutput;
+use super::{verify_migration_history, LocalMigration, MigrationStepsResultOutput};
 use crate::commands::command::*;
 use crate::migration_engine::MigrationEngine;
 use datamodel::{ast::SchemaAst, Datamodel};
 use log::*;
 use migration_connector::*;
 use serde::Deserialize;
+use std::time::Duration;
 
 pub struct ApplyMigrationCommand<'a> {
     input: &'a ApplyMigrationInput,
@@ -27,13 +28,24 @@ impl<'a> MigrationCommand<'a> for ApplyMigrationCommand<'a> {
 
         let connector = engine.connector();
         let migration_persistence = connector.migration_persistence();
-
-        match migration_persistence.last() {
-            Some(ref last_migration) if last_migration.is_watch_migration() && !self.input.is_watch_migration() => {
-                self.handle_transition_out_of_watch_mode(&engine)
+        let lock_timeout = self
+            .input
+            .lock_timeout
+            .map(Duration::from_secs)
+            .unwrap_or(DEFAULT_MIGRATION_LOCK_TIMEOUT);
+
+        // The history is read under the lock too, so that concurrent engines can't both infer the
+        // next migration from the same last migration.
+        with_migration_lock(&*connector.migration_lock(), lock_timeout, || {
+            verify_migration_history(&migration_persistence.load_all(), &self.input.local_migrations)?;
+
+            match migration_persistence.last() {
+                Some(ref last_migration) if last_migration.is_watch_migration() && !self.input.is_watch_migration() => {
+                    self.handle_transition_out_of_watch_mode(&engine)
+                }
+                _ => self.handle_normal_migration(&engine),
             }
-            _ => self.handle_normal_migration(&engine),
-        }
+        })?
     }
 }
 
@@ -57,7 +69,7 @@ impl<'a> ApplyMigrationCommand<'a> {
         let next_datamodel_ast = engine
             .datamodel_calculator()
             .infer(&last_non_watch_datamodel, self.input.steps.as_slice())?;
-        let next_datamodel = datamodel::lift_ast(&next_datamodel_ast)?;
+        let next_datamodel = datamodel::lift_ast_with_connector_type(&next_datamodel_ast, connector.connector_type())?;
 
         self.handle_migration(&engine, current_datamodel, next_datamodel)
     }
@@ -75,7 +87,7 @@ impl<'a> ApplyMigrationCommand<'a> {
         let next_datamodel_ast = engine
             .datamodel_calculator()
             .infer(&current_datamodel_ast, self.input.steps.as_slice())?;
-        let next_datamodel = datamodel::lift_ast(&next_datamodel_ast)?;
+        let next_datamodel = datamodel::lift_ast_with_connector_type(&next_datamodel_ast, connector.connector_type())?;
 
         self.handle_migration(&engine, current_datamodel, next_datamodel)
     }
@@ -93,10 +105,18 @@ impl<'a> ApplyMigrationCommand<'a> {
         let connector = engine.connector();
         let migration_persistence = connector.migration_persistence();
 
-        let database_migration =
-            connector
-                .database_migration_inferrer()
-                .infer(&current_datamodel, &next_datamodel, &self.input.steps)?; // TODO: those steps are a lie right now. Does not matter because we don't use them at the moment.
+        // The steps are used to detect renames. When transitioning out of watch mode they are relative
+        // to the last non-watch datamodel, and renames that do not apply to the database are skipped.
+        let inferrer = connector.database_migration_inferrer();
+        let mut database_migration = inferrer.infer(&current_datamodel, &next_datamodel, &self.input.steps)?;
+
+        if !self.input.data_migrations.is_empty() {
+            database_migration = inferrer.add_data_migrations(database_migration, &self.input.data_migrations)?;
+        }
+
+        if self.input.online.unwrap_or(false) {
+            database_migration = inferrer.make_online(database_migration)?;
+        }
 
         let database_steps_json_pretty = connector
             .database_migration_step_applier()
@@ -143,6 +163,20 @@ pub struct ApplyMigrationInput {
     pub migration_id: String,
     pub steps: Vec<MigrationStep>,
     pub force: Option<bool>,
+    /// Apply the migration in a way that keeps the database usable while it runs, on the
+    /// databases that support it.
+    #[serde(default)]
+    pub online: Option<bool>,
+    /// User-authored changes to the data, run at defined points of the migration.
+    #[serde(default)]
+    pub data_migrations: Vec<DataMigration>,
+    /// How many seconds to wait for another migration engine to release the migration lock.
+    #[serde(default)]
+    pub lock_timeout: Option<u64>,
+    /// The migrations the user keeps locally. Their steps are checked against the steps of the
+    /// applied migrations with the same ids before anything is applied.
+    #[serde(default)]
+    pub local_migrations: Vec<LocalMigration>,
 }
 
 impl IsWatchMigration for ApplyMigrationInput {
@@ -0,0 +1,136 @@
+use crate::commands::command::*;
+use crate::migration_engine::MigrationEngine;
+use crate::*;
+use datamodel::ast::{parser::parse, SchemaAst};
+use migration_connector::*;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Starts the migration history of a database that was not migrated by the engine.
+///
+/// The live schema must match the schema the datamodel describes. Then a migration creating that
+/// schema from an empty database is recorded as applied, without running any SQL, and the next
+/// migrations are inferred from the datamodel. Otherwise the differences are returned and nothing
+/// is recorded.
+pub struct BaselineCommand<'a> {
+    input: &'a BaselineInput,
+}
+
+impl<'a> MigrationCommand<'a> for BaselineCommand<'a> {
+    type Input = BaselineInput;
+    type Output = BaselineOutput;
+
+    fn new(input: &'a Self::Input) -> Box<Self> {
+        Box::new(BaselineCommand { input })
+    }
+
+    fn execute<C, D>(&self, engine: &MigrationEngine<C, D>) -> CommandResult<Self::Output>
+    where
+        C: MigrationConnector<DatabaseMigration = D>,
+        D: DatabaseMigrationMarker + Send + Sync + 'static,
+    {
+        let connector = engine.connector();
+        let lock_timeout = self
+            .input
+            .lock_timeout
+            .map(Duration::from_secs)
+            .unwrap_or(DEFAULT_MIGRATION_LOCK_TIMEOUT);
+
+        // The history must still be empty when the baseline is recorded, so no other engine may
+        // apply a migration in between.
+        with_migration_lock(&*connector.migration_lock(), lock_timeout, || self.baseline(engine))?
+    }
+}
+
+impl BaselineCommand<'_> {
+    fn baseline<C, D>(&self, engine: &MigrationEngine<C, D>) -> CommandResult<BaselineOutput>
+    where
+        C: MigrationConnector<DatabaseMigration = D>,
+        D: DatabaseMigrationMarker + Send + Sync + 'static,
+    {
+        let connector = engine.connector();
+        let migration_persistence = connector.migration_persistence();
+
+        // Failed and unfinished migrations count too, their changes may be partly applied.
+        if !migration_persistence.load_all().is_empty() {
+            return Err(CommandError::Input {
+                code: 1,
+                error: "The database already has a migration history. Only databases without one can be baselined."
+                    .to_owned(),
+            });
+        }
+
+        if Migration::new(self.input.migration_id.clone()).is_watch_migration() {
+            return Err(CommandError::Input {
+                code: 1,
+                error: "The baseline migration can not be a watch migration.".to_owned(),
+            });
+        }
+
+        let datamodel = parse_datamodel(&self.input.datamodel)?;
+        let datamodel_ast = parse(&self.input.datamodel)?;
+        let datamodel_steps = engine
+            .datamodel_migration_steps_inferrer()
+            .infer(&SchemaAst::empty(), &datamodel_ast);
+        let database_migration = connector.database_migration_inferrer().infer_from_datamodels(
+            &Datamodel::empty(),
+            &datamodel,
+            &datamodel_steps,
+        )?;
+
+        // The drift from the schema the baseline migration would create is what differs between
+        // the datamodel and the database.
+        let differences = connector.drift_detector().detect_drift(Some(&database_migration))?;
+
+        if !differences.is_empty() {
+            return Ok(BaselineOutput {
+                migration_id: self.input.migration_id.clone(),
+                baselined: false,
+                differences,
+            });
+        }
+
+        let database_steps = connector
+            .database_migration_step_applier()
+            .render_steps_pretty(&database_migration)?;
+
+        let mut baseline = Migration::new(self.input.migration_id.clone());
+        baseline.status = MigrationStatus::MigrationSuccess;
+        baseline.applied = database_steps.len();
+        baseline.datamodel_string = self.input.datamodel.clone();
+        baseline.datamodel = datamodel;
+        baseline.datamodel_steps = datamodel_steps;
+        baseline.database_migration = database_migration.serialize();
+        baseline.finished_at = Some(Migration::timestamp_without_nanos());
+
+        let baseline = migration_persistence.create(baseline);
+
+        Ok(BaselineOutput {
+            migration_id: baseline.name,
+            baselined: true,
+            differences,
+        })
+    }
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct BaselineInput {
+    /// The id of the migration recorded as applied.
+    pub migration_id: String,
+    /// The datamodel describing the current schema of the database.
+    pub datamodel: String,
+    /// How many seconds to wait for another migration engine to release the migration lock.
+    #[serde(default)]
+    pub lock_timeout: Option<u64>,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct BaselineOutput {
+    pub migration_id: String,
+    /// Whether the migration was recorded. It is not when the database and the datamodel differ.
+    pub baselined: bool,
+    /// How the database differs from the datamodel.
+    pub differences: Vec<SchemaDrift>,
+}
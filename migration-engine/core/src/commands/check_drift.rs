@@ -0,0 +1,44 @@
+use crate::commands::command::*;
+use crate::migration_engine::MigrationEngine;
+use migration_connector::*;
+use serde::Serialize;
+
+/// Compares the live database with the schema the migration history expects.
+pub struct CheckDriftCommand;
+
+impl<'a> MigrationCommand<'a> for CheckDriftCommand {
+    type Input = serde_json::Value;
+    type Output = CheckDriftOutput;
+
+    fn new(_: &'a Self::Input) -> Box<Self> {
+        Box::new(CheckDriftCommand {})
+    }
+
+    fn execute<C, D>(&self, engine: &MigrationEngine<C, D>) -> CommandResult<Self::Output>
+    where
+        C: MigrationConnector<DatabaseMigration = D>,
+        D: DatabaseMigrationMarker + Send + Sync + 'static,
+    {
+        let connector = engine.connector();
+        let last_migration = connector.migration_persistence().last();
+        let database_migration = last_migration
+            .as_ref()
+            .map(|migration| connector.deserialize_database_migration(migration.database_migration.clone()));
+        let drift = connector.drift_detector().detect_drift(database_migration.as_ref())?;
+
+        Ok(CheckDriftOutput {
+            migration_id: last_migration.map(|migration| migration.name),
+            has_drift: !drift.is_empty(),
+            drift,
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckDriftOutput {
+    /// The last applied migration, which the database was compared with.
+    pub migration_id: Option<String>,
+    pub has_drift: bool,
+    pub drift: Vec<SchemaDrift>,
+}
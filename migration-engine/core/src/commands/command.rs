@@ -34,6 +34,16 @@ pub enum CommandError {
 
     #[fail(display = "Error in command input. (code: {}, error: {})", code, error)]
     Input { code: i64, error: String },
+
+    #[fail(
+        display = "Migration history mismatch at `{}`. (code: {}, error: {})",
+        migration_id, code, error
+    )]
+    MigrationChecksumMismatch {
+        code: i64,
+        migration_id: String,
+        error: String,
+    },
 }
 
 impl From<datamodel::error::ErrorCollection> for CommandError {
@@ -0,0 +1,97 @@
+use crate::commands::command::*;
+use crate::migration_engine::MigrationEngine;
+use migration_connector::*;
+use serde::{Deserialize, Serialize};
+use sql_connection::SqlFamily;
+use sql_migration_connector::{DiffTarget, SqlDiff};
+
+/// Diffs two schemas, independently of the database the engine is connected to.
+pub struct DiffCommand<'a> {
+    input: &'a DiffInput,
+}
+
+impl<'a> MigrationCommand<'a> for DiffCommand<'a> {
+    type Input = DiffInput;
+    type Output = DiffOutput;
+
+    fn new(input: &'a Self::Input) -> Box<Self> {
+        Box::new(DiffCommand { input })
+    }
+
+    fn execute<C, D>(&self, _engine: &MigrationEngine<C, D>) -> CommandResult<Self::Output>
+    where
+        C: MigrationConnector<DatabaseMigration = D>,
+        D: DatabaseMigrationMarker + Send + Sync + 'static,
+    {
+        diff(self.input)
+    }
+}
+
+/// Runs a diff. This is also what the `diff` CLI command uses, as it does not need an engine.
+pub fn diff(input: &DiffInput) -> CommandResult<DiffOutput> {
+    let sql_family = parse_provider(input.provider.as_ref())?;
+    let diff = SqlDiff::new(&input.from, &input.to, sql_family, input.schema_name.clone())?;
+
+    let result = match input.output {
+        DiffOutputFormat::Steps => serde_json::to_value(diff.steps()).expect("Serializing the steps failed."),
+        DiffOutputFormat::Sql => serde_json::Value::String(diff.render_sql()?),
+        DiffOutputFormat::Summary => serde_json::Value::String(diff.summary()),
+    };
+
+    Ok(DiffOutput {
+        provider: diff.sql_family.connector_type_string().to_owned(),
+        result,
+    })
+}
+
+pub(crate) fn parse_provider(provider: Option<&String>) -> CommandResult<Option<SqlFamily>> {
+    match provider {
+        Some(provider) => Ok(Some(SqlFamily::from_scheme(provider).ok_or_else(|| {
+            CommandError::Input {
+                code: 1,
+                error: format!("Unknown provider `{}`.", provider),
+            }
+        })?)),
+        None => Ok(None),
+    }
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DiffInput {
+    pub from: DiffTarget,
+    pub to: DiffTarget,
+    /// The database family to diff for, e.g. `postgresql`. Taken from the targets when missing.
+    #[serde(default)]
+    pub provider: Option<String>,
+    /// The schema the rendered SQL refers to. Taken from the targets when missing.
+    #[serde(default)]
+    pub schema_name: Option<String>,
+    #[serde(default)]
+    pub output: DiffOutputFormat,
+}
+
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum DiffOutputFormat {
+    /// The list of SQL migration steps.
+    Steps,
+    /// The migration as a SQL script.
+    Sql,
+    /// A human readable summary of the changes.
+    Summary,
+}
+
+impl Default for DiffOutputFormat {
+    fn default() -> Self {
+        DiffOutputFormat::Steps
+    }
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DiffOutput {
+    pub provider: String,
+    /// The steps as JSON, or the SQL script or summary as a string.
+    pub result: serde_json::Value,
+}
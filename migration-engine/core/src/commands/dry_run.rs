@@ -0,0 +1,75 @@
+use crate::commands::command::*;
+use crate::commands::diff::parse_provider;
+use crate::migration_engine::MigrationEngine;
+use migration_connector::*;
+use serde::{Deserialize, Serialize};
+use sql_migration_connector::{DiffTarget, SqlDiff};
+
+/// Renders the migration between two datamodels without connecting to a database. The warnings
+/// are about the changes that could lose data, as there is no data to check.
+pub struct DryRunCommand<'a> {
+    input: &'a DryRunInput,
+}
+
+impl<'a> MigrationCommand<'a> for DryRunCommand<'a> {
+    type Input = DryRunInput;
+    type Output = DryRunOutput;
+
+    fn new(input: &'a Self::Input) -> Box<Self> {
+        Box::new(DryRunCommand { input })
+    }
+
+    fn execute<C, D>(&self, _engine: &MigrationEngine<C, D>) -> CommandResult<Self::Output>
+    where
+        C: MigrationConnector<DatabaseMigration = D>,
+        D: DatabaseMigrationMarker + Send + Sync + 'static,
+    {
+        dry_run(self.input)
+    }
+}
+
+/// Runs a dry run. This is also what the `dry-run` CLI command uses, as it does not need an engine.
+pub fn dry_run(input: &DryRunInput) -> CommandResult<DryRunOutput> {
+    let sql_family = parse_provider(input.provider.as_ref())?;
+    let previous = match &input.previous_datamodel {
+        Some(datamodel) => DiffTarget::Datamodel {
+            datamodel: datamodel.clone(),
+        },
+        None => DiffTarget::Empty,
+    };
+    let next = DiffTarget::Datamodel {
+        datamodel: input.next_datamodel.clone(),
+    };
+
+    let diff = SqlDiff::new(&previous, &next, sql_family, input.schema_name.clone())?;
+
+    Ok(DryRunOutput {
+        provider: diff.sql_family.connector_type_string().to_owned(),
+        database_steps: serde_json::to_value(diff.steps()).expect("Serializing the steps failed."),
+        script: diff.render_sql()?,
+        warnings: diff.warnings()?,
+    })
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DryRunInput {
+    /// The datamodel the database is migrated from. An empty database when missing.
+    #[serde(default)]
+    pub previous_datamodel: Option<String>,
+    pub next_datamodel: String,
+    /// The database family to render the migration for. Taken from the datamodels when missing.
+    #[serde(default)]
+    pub provider: Option<String>,
+    #[serde(default)]
+    pub schema_name: Option<String>,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DryRunOutput {
+    pub provider: String,
+    pub database_steps: serde_json::Value,
+    pub script: String,
+    pub warnings: Vec<MigrationWarning>,
+}
@@ -0,0 +1,160 @@
+use crate::commands::command::*;
+use crate::migration_engine::MigrationEngine;
+use migration_connector::*;
+use serde::{Deserialize, Serialize};
+use std::{fs, path::Path};
+
+/// Writes applied migrations as SQL scripts, so they can be reviewed and run by other tools.
+///
+/// Every migration gets its own directory in the output directory, named after its position in the
+/// migration history and its id, with an `up.sql` and a `down.sql` script. Watch migrations are
+/// folded into the scripts of the migration that ends watch mode, since that migration only holds the
+/// changes from the last watch migration on. Watch migrations that no migration ended yet are not
+/// exported.
+pub struct ExportMigrationsCommand<'a> {
+    input: &'a ExportMigrationsInput,
+}
+
+impl<'a> MigrationCommand<'a> for ExportMigrationsCommand<'a> {
+    type Input = ExportMigrationsInput;
+    type Output = ExportMigrationsOutput;
+
+    fn new(input: &'a Self::Input) -> Box<Self> {
+        Box::new(ExportMigrationsCommand { input })
+    }
+
+    fn execute<C, D>(&self, engine: &MigrationEngine<C, D>) -> CommandResult<Self::Output>
+    where
+        C: MigrationConnector<DatabaseMigration = D>,
+        D: DatabaseMigrationMarker + Send + Sync + 'static,
+    {
+        let connector = engine.connector();
+        let step_applier = connector.database_migration_step_applier();
+        let mut migrations: Vec<Migration> = Vec::new();
+        // The watch migrations applied before each migration.
+        let mut watch_migrations: Vec<Vec<Migration>> = Vec::new();
+        let mut pending_watch_migrations = Vec::new();
+
+        for migration in connector.migration_persistence().load_all() {
+            if migration.status != MigrationStatus::MigrationSuccess {
+                continue;
+            }
+
+            if migration.is_watch_migration() {
+                pending_watch_migrations.push(migration);
+            } else {
+                migrations.push(migration);
+                watch_migrations.push(std::mem::replace(&mut pending_watch_migrations, Vec::new()));
+            }
+        }
+
+        if migrations.is_empty() {
+            return Ok(ExportMigrationsOutput { migrations: Vec::new() });
+        }
+
+        let position = |migration_id: &Option<String>, default: usize| match migration_id {
+            Some(migration_id) => migrations
+                .iter()
+                .position(|migration| &migration.name == migration_id)
+                .ok_or_else(|| CommandError::Input {
+                    code: 1,
+                    error: format!("There is no applied migration with the id `{}`.", migration_id),
+                }),
+            None => Ok(default),
+        };
+        let from = position(&self.input.from_migration_id, 0)?;
+        let to = position(&self.input.to_migration_id, migrations.len() - 1)?;
+
+        if from > to {
+            return Err(CommandError::Input {
+                code: 1,
+                error: "The first migration to export comes after the last one.".to_owned(),
+            });
+        }
+
+        let mut exported = Vec::new();
+
+        for (index, (migration, watch_migrations)) in migrations
+            .iter()
+            .zip(watch_migrations.iter())
+            .enumerate()
+            .take(to + 1)
+            .skip(from)
+        {
+            let datamodel_hash = sha256_hex(migration.datamodel_string.as_bytes());
+            let header = |script: &str| {
+                format!(
+                    "-- Migration: {}\n-- Script: {}\n-- Datamodel hash (SHA-256): {}\n\n",
+                    migration.name, script, datamodel_hash
+                )
+            };
+
+            let mut up = header("up");
+            let mut down = header("down");
+
+            for watch_migration in watch_migrations {
+                let database_migration =
+                    connector.deserialize_database_migration(watch_migration.database_migration.clone());
+                up.push_str(&step_applier.render_script(&database_migration)?);
+            }
+
+            let database_migration = connector.deserialize_database_migration(migration.database_migration.clone());
+            up.push_str(&step_applier.render_script(&database_migration)?);
+            down.push_str(&step_applier.render_rollback_script(&database_migration)?);
+
+            for watch_migration in watch_migrations.iter().rev() {
+                let database_migration =
+                    connector.deserialize_database_migration(watch_migration.database_migration.clone());
+                down.push_str(&step_applier.render_rollback_script(&database_migration)?);
+            }
+
+            let directory =
+                Path::new(&self.input.output_directory).join(format!("{:04}_{}", index + 1, migration.name));
+            write_script(&directory, "up.sql", &up)?;
+            write_script(&directory, "down.sql", &down)?;
+
+            exported.push(ExportedMigration {
+                id: migration.name.clone(),
+                directory: directory.to_string_lossy().into_owned(),
+                datamodel_hash,
+            });
+        }
+
+        Ok(ExportMigrationsOutput { migrations: exported })
+    }
+}
+
+fn write_script(directory: &Path, file_name: &str, script: &str) -> CommandResult<()> {
+    fs::create_dir_all(directory)
+        .and_then(|_| fs::write(directory.join(file_name), script))
+        .map_err(|err| CommandError::Generic {
+            code: 1,
+            error: format!("Writing `{}` to `{}` failed: {}", file_name, directory.display(), err),
+        })
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportMigrationsInput {
+    pub output_directory: String,
+    /// The first migration to export. Defaults to the first applied migration.
+    #[serde(default)]
+    pub from_migration_id: Option<String>,
+    /// The last migration to export. Defaults to the last applied migration.
+    #[serde(default)]
+    pub to_migration_id: Option<String>,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportMigrationsOutput {
+    pub migrations: Vec<ExportedMigration>,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportedMigration {
+    pub id: String,
+    pub directory: String,
+    pub datamodel_hash: String,
+}
@@ -34,7 +34,8 @@ impl<'a> MigrationCommand<'a> for InferMigrationStepsCommand<'a> {
         let assumed_datamodel_ast = engine
             .datamodel_calculator()
             .infer(&current_datamodel_ast, self.input.assume_to_be_applied.as_slice())?;
-        let assumed_datamodel = datamodel::lift_ast(&assumed_datamodel_ast)?;
+        let assumed_datamodel =
+            datamodel::lift_ast_with_connector_type(&assumed_datamodel_ast, connector.connector_type())?;
 
         let next_datamodel = parse_datamodel(&self.input.datamodel)?;
         let next_datamodel_ast = parse(&self.input.datamodel)?;
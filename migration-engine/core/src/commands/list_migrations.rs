@@ -1,17 +1,20 @@
+use super::{verify_migration_history, LocalMigration};
 use crate::commands::command::*;
 use crate::migration_engine::MigrationEngine;
 use migration_connector::steps::*;
 use migration_connector::*;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
-pub struct ListMigrationStepsCommand;
+pub struct ListMigrationStepsCommand<'a> {
+    input: &'a ListMigrationsInput,
+}
 
-impl<'a> MigrationCommand<'a> for ListMigrationStepsCommand {
-    type Input = serde_json::Value;
+impl<'a> MigrationCommand<'a> for ListMigrationStepsCommand<'a> {
+    type Input = ListMigrationsInput;
     type Output = Vec<ListMigrationStepsOutput>;
 
-    fn new(_: &'a Self::Input) -> Box<Self> {
-        Box::new(ListMigrationStepsCommand {})
+    fn new(input: &'a Self::Input) -> Box<Self> {
+        Box::new(ListMigrationStepsCommand { input })
     }
 
     fn execute<C, D>(&self, engine: &MigrationEngine<C, D>) -> CommandResult<Self::Output>
@@ -20,9 +23,12 @@ impl<'a> MigrationCommand<'a> for ListMigrationStepsCommand {
         D: DatabaseMigrationMarker + 'static,
     {
         let migration_persistence = engine.connector().migration_persistence();
+        let migrations = migration_persistence.load_all();
         let mut result = Vec::new();
 
-        for migration in migration_persistence.load_all().into_iter() {
+        verify_migration_history(&migrations, &self.input.local_migrations)?;
+
+        for migration in migrations.into_iter() {
             result.push(convert_migration_to_list_migration_steps_output(&engine, migration)?);
         }
 
@@ -50,9 +56,18 @@ where
         database_steps: serde_json::Value::Array(database_steps_json),
         status: migration.status,
         datamodel: engine.render_datamodel(&migration.datamodel),
+        checksum: migration.checksum,
     })
 }
 
+#[derive(Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ListMigrationsInput {
+    /// The migrations the user keeps locally, checked against the steps of the applied ones.
+    #[serde(default)]
+    pub local_migrations: Vec<LocalMigration>,
+}
+
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ListMigrationStepsOutput {
@@ -61,4 +76,5 @@ pub struct ListMigrationStepsOutput {
     pub database_steps: serde_json::Value,
     pub status: MigrationStatus,
     pub datamodel: String,
+    pub checksum: Option<String>,
 }
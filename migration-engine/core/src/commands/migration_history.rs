@@ -0,0 +1,57 @@
+use crate::commands::command::*;
+use migration_connector::*;
+use serde::Deserialize;
+
+/// A migration as the user keeps it locally, e.g. in the migrations folder of the project.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LocalMigration {
+    pub id: String,
+    pub steps: Vec<MigrationStep>,
+}
+
+/// Checks that the persisted migrations were not changed since they were written, and that the
+/// applied ones still have the steps of the local migrations with the same id. Migrations written
+/// before checksums were introduced can't be checked for edits. The error names the first
+/// migration in the history that diverges.
+pub fn verify_migration_history(persisted: &[Migration], local_migrations: &[LocalMigration]) -> CommandResult<()> {
+    for migration in persisted {
+        let was_edited = match &migration.checksum {
+            Some(checksum) => &migration_checksum(migration) != checksum,
+            None => false,
+        };
+
+        if was_edited {
+            return Err(CommandError::MigrationChecksumMismatch {
+                code: 1002,
+                migration_id: migration.name.clone(),
+                error: format!(
+                    "The migration `{}` in the database does not match its checksum. The migration history was edited after the migration was written.",
+                    migration.name
+                ),
+            });
+        }
+
+        // Migrations that were not applied, or rolled back, can still be changed locally.
+        if migration.status != MigrationStatus::MigrationSuccess {
+            continue;
+        }
+
+        let local_migration = local_migrations.iter().find(|local| local.id == migration.name);
+
+        if let Some(local_migration) = local_migration {
+            if local_migration.steps != migration.datamodel_steps {
+                return Err(CommandError::MigrationChecksumMismatch {
+                    code: 1002,
+                    migration_id: migration.name.clone(),
+                    error: format!(
+                        "The local steps of the migration `{}` differ from the steps it was applied with.",
+                        migration.name
+                    ),
+                });
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -1,21 +1,35 @@
 mod apply_migration;
+mod baseline;
 mod calculate_database_steps;
 mod calculate_datamodel;
+mod check_drift;
 mod command;
+mod diff;
+mod dry_run;
+mod export_migrations;
 mod infer_migration_steps;
 mod list_migrations;
+mod migration_history;
 mod migration_progress;
 mod reset;
+mod squash_migrations;
 mod unapply_migration;
 
 pub use apply_migration::*;
+pub use baseline::*;
 pub use calculate_database_steps::*;
 pub use calculate_datamodel::*;
+pub use check_drift::*;
 pub use command::*;
+pub use diff::*;
+pub use dry_run::*;
+pub use export_migrations::*;
 pub use infer_migration_steps::*;
 pub use list_migrations::*;
+pub use migration_history::*;
 pub use migration_progress::*;
 pub use reset::*;
+pub use squash_migrations::*;
 pub use unapply_migration::*;
 
 use migration_connector::{MigrationError, MigrationStep, MigrationWarning};
@@ -0,0 +1,137 @@
+use crate::commands::command::*;
+use crate::migration_engine::MigrationEngine;
+use datamodel::{ast::SchemaAst, Datamodel};
+use migration_connector::*;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Collapses the history up to an applied migration into a single baseline migration.
+///
+/// The baseline creates the whole schema of the last squashed migration from an empty database, so
+/// new environments get one clean migration. The squashed migrations are the first ones of the
+/// history, including the watch migrations between them: a baseline can't replace migrations that
+/// other migrations were applied before.
+pub struct SquashMigrationsCommand<'a> {
+    input: &'a SquashMigrationsInput,
+}
+
+impl<'a> MigrationCommand<'a> for SquashMigrationsCommand<'a> {
+    type Input = SquashMigrationsInput;
+    type Output = SquashMigrationsOutput;
+
+    fn new(input: &'a Self::Input) -> Box<Self> {
+        Box::new(SquashMigrationsCommand { input })
+    }
+
+    fn execute<C, D>(&self, engine: &MigrationEngine<C, D>) -> CommandResult<Self::Output>
+    where
+        C: MigrationConnector<DatabaseMigration = D>,
+        D: DatabaseMigrationMarker + Send + Sync + 'static,
+    {
+        let connector = engine.connector();
+        let lock_timeout = self
+            .input
+            .lock_timeout
+            .map(Duration::from_secs)
+            .unwrap_or(DEFAULT_MIGRATION_LOCK_TIMEOUT);
+
+        // Squashing rewrites the history, so it must not run while another engine applies a migration.
+        with_migration_lock(&*connector.migration_lock(), lock_timeout, || self.squash(engine))?
+    }
+}
+
+impl SquashMigrationsCommand<'_> {
+    fn squash<C, D>(&self, engine: &MigrationEngine<C, D>) -> CommandResult<SquashMigrationsOutput>
+    where
+        C: MigrationConnector<DatabaseMigration = D>,
+        D: DatabaseMigrationMarker + Send + Sync + 'static,
+    {
+        let connector = engine.connector();
+        let migration_persistence = connector.migration_persistence();
+
+        if Migration::new(self.input.baseline_migration_id.clone()).is_watch_migration() {
+            return Err(CommandError::Input {
+                code: 1,
+                error: "The baseline migration can not be a watch migration.".to_owned(),
+            });
+        }
+
+        let last_squashed = match &self.input.to_migration_id {
+            Some(migration_id) => migration_persistence
+                .by_name(migration_id)
+                .filter(|migration| {
+                    !migration.is_watch_migration() && migration.status == MigrationStatus::MigrationSuccess
+                })
+                .ok_or_else(|| CommandError::Input {
+                    code: 1,
+                    error: format!("There is no applied migration with the id `{}`.", migration_id),
+                })?,
+            None => migration_persistence
+                .last_non_watch_applied_migration()
+                .ok_or_else(|| CommandError::Input {
+                    code: 1,
+                    error: "There are no applied migrations to squash.".to_owned(),
+                })?,
+        };
+
+        let squashed_migration_ids: Vec<String> = migration_persistence
+            .load_all()
+            .into_iter()
+            .take_while(|migration| migration.revision <= last_squashed.revision)
+            .map(|migration| migration.name)
+            .collect();
+
+        let datamodel_steps = engine
+            .datamodel_migration_steps_inferrer()
+            .infer(&SchemaAst::empty(), &last_squashed.datamodel_ast());
+        let database_migration = connector.database_migration_inferrer().infer_from_datamodels(
+            &Datamodel::empty(),
+            &last_squashed.datamodel,
+            &datamodel_steps,
+        )?;
+        let database_steps = connector
+            .database_migration_step_applier()
+            .render_steps_pretty(&database_migration)?;
+
+        let mut baseline = Migration::new(self.input.baseline_migration_id.clone());
+        baseline.status = MigrationStatus::MigrationSuccess;
+        baseline.applied = database_steps.len();
+        baseline.datamodel_string = last_squashed.datamodel_string.clone();
+        baseline.datamodel = last_squashed.datamodel.clone();
+        baseline.datamodel_steps = datamodel_steps.clone();
+        baseline.database_migration = database_migration.serialize();
+        baseline.finished_at = Some(Migration::timestamp_without_nanos());
+
+        let baseline = migration_persistence.squash(&last_squashed, baseline)?;
+
+        Ok(SquashMigrationsOutput {
+            baseline_migration_id: baseline.name,
+            squashed_migration_ids,
+            datamodel_steps,
+            database_steps: serde_json::Value::Array(database_steps),
+        })
+    }
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SquashMigrationsInput {
+    /// The id of the migration replacing the squashed ones.
+    pub baseline_migration_id: String,
+    /// The last migration to squash. Defaults to the last applied migration.
+    #[serde(default)]
+    pub to_migration_id: Option<String>,
+    /// How many seconds to wait for another migration engine to release the migration lock.
+    #[serde(default)]
+    pub lock_timeout: Option<u64>,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SquashMigrationsOutput {
+    pub baseline_migration_id: String,
+    /// The ids of the migrations the baseline replaced, in the order they were applied.
+    pub squashed_migration_ids: Vec<String>,
+    pub datamodel_steps: Vec<MigrationStep>,
+    pub database_steps: serde_json::Value,
+}
@@ -3,6 +3,7 @@ use crate::migration_engine::MigrationEngine;
 use log::*;
 use migration_connector::*;
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
 
 pub struct UnapplyMigrationCommand<'a> {
     input: &'a UnapplyMigrationInput,
@@ -23,6 +24,25 @@ impl<'a> MigrationCommand<'a> for UnapplyMigrationCommand<'a> {
     {
         debug!("{:?}", self.input);
         let connector = engine.connector();
+        let lock_timeout = self
+            .input
+            .lock_timeout
+            .map(Duration::from_secs)
+            .unwrap_or(DEFAULT_MIGRATION_LOCK_TIMEOUT);
+
+        with_migration_lock(&*connector.migration_lock(), lock_timeout, || {
+            self.rollback_last_migration(engine)
+        })?
+    }
+}
+
+impl UnapplyMigrationCommand<'_> {
+    fn rollback_last_migration<C, D>(&self, engine: &MigrationEngine<C, D>) -> CommandResult<UnapplyMigrationOutput>
+    where
+        C: MigrationConnector<DatabaseMigration = D>,
+        D: DatabaseMigrationMarker + 'static,
+    {
+        let connector = engine.connector();
 
         let result = match connector.migration_persistence().last() {
             None => UnapplyMigrationOutput {
@@ -54,7 +74,11 @@ impl<'a> MigrationCommand<'a> for UnapplyMigrationCommand<'a> {
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct UnapplyMigrationInput {}
+pub struct UnapplyMigrationInput {
+    /// How many seconds to wait for another migration engine to release the migration lock.
+    #[serde(default)]
+    pub lock_timeout: Option<u64>,
+}
 
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
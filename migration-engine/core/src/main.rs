@@ -57,6 +57,24 @@ fn main() {
             Err(error) => {
                 error!("{}", error);
 
+                std::process::exit(error.exit_code());
+            }
+        }
+    } else if let Some(matches) = matches.subcommand_matches("diff") {
+        match cli::diff(&matches) {
+            Ok(output) => print!("{}", output),
+            Err(error) => {
+                error!("{}", error);
+
+                std::process::exit(error.exit_code());
+            }
+        }
+    } else if let Some(matches) = matches.subcommand_matches("dry-run") {
+        match cli::dry_run(&matches) {
+            Ok(output) => print!("{}", output),
+            Err(error) => {
+                error!("{}", error);
+
                 std::process::exit(error.exit_code());
             }
         }
@@ -124,7 +124,7 @@ fn fields_match(previous: &ast::Field, next: &ast::Field) -> bool {
 }
 
 /// Model directives that can appear multiple times on the same model. Unlike others, they cannot be matched based only on the directive name.
-const REPEATED_MODEL_DIRECTIVES: &[&str] = &["unique", "index"];
+const REPEATED_MODEL_DIRECTIVES: &[&str] = &["unique", "index", "check"];
 
 /// See ModelDiffer docs.
 fn is_regular(directive: &&ast::Directive) -> bool {
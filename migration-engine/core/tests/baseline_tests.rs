@@ -0,0 +1,168 @@
+mod test_harness;
+
+use migration_connector::{DriftKind, DriftObject, Migration, MigrationStatus};
+use migration_core::commands::BaselineInput;
+use pretty_assertions::assert_eq;
+use sql_migration_connector::SqlFamily;
+use test_harness::*;
+
+fn create_cat_table_manually(api: &TestApi) {
+    let sql = match api.sql_family() {
+        SqlFamily::Mysql => format!(
+            "CREATE TABLE `{}`.`Cat` (`id` varchar(191) NOT NULL PRIMARY KEY, `name` varchar(191) NOT NULL)",
+            SCHEMA_NAME
+        ),
+        _ => format!(
+            "CREATE TABLE \"{}\".\"Cat\" (\"id\" TEXT NOT NULL PRIMARY KEY, \"name\" TEXT NOT NULL)",
+            SCHEMA_NAME
+        ),
+    };
+
+    api.database().query_raw(&sql, &[]).unwrap();
+}
+
+#[test_each_connector]
+fn baselining_a_database_matching_the_datamodel_must_record_an_applied_migration(api: &TestApi) {
+    create_cat_table_manually(api);
+
+    let dm = r#"
+        model Cat {
+            id   String @id
+            name String
+        }
+    "#;
+    let output = api.baseline(&BaselineInput {
+        migration_id: "the-baseline".to_owned(),
+        datamodel: dm.to_owned(),
+        lock_timeout: None,
+    });
+
+    assert!(output.baselined);
+    assert!(output.differences.is_empty());
+
+    let migration = api.migration_persistence().last().unwrap();
+    assert_eq!(migration.name, "the-baseline");
+    assert_eq!(migration.status, MigrationStatus::MigrationSuccess);
+
+    // The next migration starts from the baselined schema.
+    let dm2 = r#"
+        model Cat {
+            id   String @id
+            name String
+            age  Int?
+        }
+    "#;
+    let schema = api
+        .infer_and_apply_with_migration_id(&dm2, "the-next-migration")
+        .sql_schema;
+    assert!(schema.table_bang("Cat").column("age").is_some());
+}
+
+#[test_each_connector]
+fn baselining_a_database_that_differs_from_the_datamodel_must_report_the_differences(api: &TestApi) {
+    create_cat_table_manually(api);
+
+    let dm = r#"
+        model Cat {
+            id   String @id
+            name String
+        }
+
+        model Dog {
+            id String @id
+        }
+    "#;
+    let output = api.baseline(&BaselineInput {
+        migration_id: "the-baseline".to_owned(),
+        datamodel: dm.to_owned(),
+        lock_timeout: None,
+    });
+
+    assert!(!output.baselined);
+    assert_eq!(output.differences.len(), 1);
+    assert_eq!(output.differences[0].kind, DriftKind::Removed);
+    assert_eq!(output.differences[0].object, DriftObject::Table);
+    assert_eq!(output.differences[0].table, "Dog");
+    assert!(api.migration_persistence().last().is_none());
+}
+
+#[test_each_connector]
+fn baselining_must_match_indexes_by_their_columns_and_not_their_names(api: &TestApi) {
+    create_cat_table_manually(api);
+
+    let sql = match api.sql_family() {
+        SqlFamily::Mysql => format!("CREATE UNIQUE INDEX `cat_names` ON `{}`.`Cat` (`name`)", SCHEMA_NAME),
+        SqlFamily::Sqlite => format!(
+            "CREATE UNIQUE INDEX \"{}\".\"cat_names\" ON \"Cat\" (\"name\")",
+            SCHEMA_NAME
+        ),
+        SqlFamily::Postgres => format!(
+            "CREATE UNIQUE INDEX \"cat_names\" ON \"{}\".\"Cat\" (\"name\")",
+            SCHEMA_NAME
+        ),
+    };
+    api.database().query_raw(&sql, &[]).unwrap();
+
+    let dm = r#"
+        model Cat {
+            id   String @id
+            name String @unique
+        }
+    "#;
+    let output = api.baseline(&BaselineInput {
+        migration_id: "the-baseline".to_owned(),
+        datamodel: dm.to_owned(),
+        lock_timeout: None,
+    });
+
+    assert!(output.differences.is_empty(), "{:?}", output.differences);
+    assert!(output.baselined);
+}
+
+#[test_each_connector]
+fn baselining_a_database_with_an_unfinished_migration_must_fail(api: &TestApi) {
+    create_cat_table_manually(api);
+    api.migration_persistence()
+        .create(Migration::new("the-unfinished-migration".to_owned()));
+
+    let dm = r#"
+        model Cat {
+            id   String @id
+            name String
+        }
+    "#;
+    let result = api.try_baseline(&BaselineInput {
+        migration_id: "the-baseline".to_owned(),
+        datamodel: dm.to_owned(),
+        lock_timeout: None,
+    });
+
+    assert!(result.is_err());
+    assert_eq!(api.migration_persistence().load_all().len(), 1);
+}
+
+#[test_each_connector(ignore = "mysql")]
+fn baselining_must_report_missing_check_constraints(api: &TestApi) {
+    create_cat_table_manually(api);
+
+    let dm = r#"
+        model Cat {
+            id   String @id
+            name String
+
+            @@check("length(name) < 20", name: "short_name")
+        }
+    "#;
+    let output = api.baseline(&BaselineInput {
+        migration_id: "the-baseline".to_owned(),
+        datamodel: dm.to_owned(),
+        lock_timeout: None,
+    });
+
+    assert!(!output.baselined);
+    assert_eq!(output.differences.len(), 1);
+    assert_eq!(output.differences[0].kind, DriftKind::Removed);
+    assert_eq!(output.differences[0].object, DriftObject::CheckConstraint);
+    assert_eq!(output.differences[0].table, "Cat");
+    assert_eq!(output.differences[0].name.as_deref(), Some("short_name"));
+}
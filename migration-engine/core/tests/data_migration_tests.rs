@@ -0,0 +1,133 @@
+mod test_harness;
+
+use migration_connector::{DataMigration, DataMigrationAction, DataMigrationHook};
+use pretty_assertions::assert_eq;
+use quaint::ast::*;
+use sql_migration_connector::{PrettySqlMigrationStep, SqlFamily, SqlMigrationStep};
+use sql_schema_describer::ColumnArity;
+use test_harness::*;
+
+fn split_full_name(api: &TestApi) {
+    let dm1 = r#"
+        model User {
+            id       String @id
+            fullName String
+        }
+    "#;
+    api.infer_and_apply(&dm1);
+
+    let insert = Insert::single_into((SCHEMA_NAME, "User"))
+        .value("id", "ada")
+        .value("fullName", "Ada Lovelace");
+    api.database().execute(insert.into()).unwrap();
+
+    let (first_name, last_name) = match api.sql_family() {
+        SqlFamily::Postgres => (r#"split_part("fullName", ' ', 1)"#, r#"split_part("fullName", ' ', 2)"#),
+        _ => (
+            "SUBSTRING_INDEX(`fullName`, ' ', 1)",
+            "SUBSTRING_INDEX(`fullName`, ' ', -1)",
+        ),
+    };
+    let backfill = |column: &str, expression: &str| DataMigration {
+        hook: DataMigrationHook::AfterAddColumn {
+            table: "User".to_owned(),
+            column: column.to_owned(),
+        },
+        action: DataMigrationAction::Backfill {
+            table: "User".to_owned(),
+            column: column.to_owned(),
+            expression: expression.to_owned(),
+            condition: None,
+        },
+    };
+
+    let dm2 = r#"
+        model User {
+            id        String @id
+            firstName String
+            lastName  String
+        }
+    "#;
+    let output = api.infer_and_apply_with_data_migrations(
+        &dm2,
+        vec![backfill("firstName", first_name), backfill("lastName", last_name)],
+    );
+
+    let table = output.sql_schema.table_bang("User");
+    assert!(table.column("fullName").is_none());
+    assert_eq!(table.column_bang("firstName").arity, ColumnArity::Required);
+    assert_eq!(table.column_bang("lastName").arity, ColumnArity::Required);
+
+    let steps: Vec<PrettySqlMigrationStep> = serde_json::from_value(output.migration_output.database_steps).unwrap();
+    let data_migrations = steps
+        .iter()
+        .filter(|step| match step.step {
+            SqlMigrationStep::DataMigration(_) => true,
+            _ => false,
+        })
+        .count();
+    assert_eq!(data_migrations, 2);
+
+    let query = Select::from_table((SCHEMA_NAME, "User")).so_that("id".equals("ada"));
+    let result_set = api.database().query(query.into()).unwrap();
+    let row = result_set.into_iter().next().expect("query returned no results");
+    assert_eq!(row["firstName"].as_str().unwrap(), "Ada");
+    assert_eq!(row["lastName"].as_str().unwrap(), "Lovelace");
+}
+
+#[test_one_connector(connector = "postgres")]
+fn backfilling_new_columns_must_work_on_postgres(api: &TestApi) {
+    split_full_name(api)
+}
+
+#[test_one_connector(connector = "mysql")]
+fn backfilling_new_columns_must_work_on_mysql(api: &TestApi) {
+    split_full_name(api)
+}
+
+#[test_each_connector]
+fn sql_data_migrations_must_run_before_and_after_the_migration(api: &TestApi) {
+    let dm1 = r#"
+        model Counter {
+            id    String @id
+            count Int
+        }
+    "#;
+    api.infer_and_apply(&dm1);
+
+    let insert = Insert::single_into((SCHEMA_NAME, "Counter"))
+        .value("id", "the-counter")
+        .value("count", 1);
+    api.database().execute(insert.into()).unwrap();
+
+    let table = match api.sql_family() {
+        SqlFamily::Mysql => format!("`{}`.`Counter`", SCHEMA_NAME),
+        _ => format!(r#""{}"."Counter""#, SCHEMA_NAME),
+    };
+    let double = |hook: DataMigrationHook| DataMigration {
+        hook,
+        action: DataMigrationAction::Sql {
+            sql: format!("UPDATE {} SET count = count * 2", table),
+        },
+    };
+
+    let dm2 = r#"
+        model Counter {
+            id    String  @id
+            count Int
+            label String?
+        }
+    "#;
+    api.infer_and_apply_with_data_migrations(
+        &dm2,
+        vec![
+            double(DataMigrationHook::BeforeMigration),
+            double(DataMigrationHook::AfterMigration),
+        ],
+    );
+
+    let query = Select::from_table((SCHEMA_NAME, "Counter")).so_that("id".equals("the-counter"));
+    let result_set = api.database().query(query.into()).unwrap();
+    let row = result_set.into_iter().next().expect("query returned no results");
+    assert_eq!(row["count"].as_i64().unwrap(), 4);
+}
@@ -0,0 +1,124 @@
+use migration_core::commands::{diff, DiffInput, DiffOutputFormat};
+use pretty_assertions::assert_eq;
+use sql_migration_connector::DiffTarget;
+
+const DATAMODEL: &str = r#"
+    model Cat {
+        id   Int    @id
+        name String
+    }
+"#;
+
+fn diff_input(from: DiffTarget, to: DiffTarget, output: DiffOutputFormat) -> DiffInput {
+    DiffInput {
+        from,
+        to,
+        provider: Some("postgresql".to_owned()),
+        schema_name: None,
+        output,
+    }
+}
+
+fn datamodel() -> DiffTarget {
+    DiffTarget::Datamodel {
+        datamodel: DATAMODEL.to_owned(),
+    }
+}
+
+#[test]
+fn diffing_an_empty_database_against_a_datamodel_must_summarize_the_changes() {
+    let output = diff(&diff_input(DiffTarget::Empty, datamodel(), DiffOutputFormat::Summary)).unwrap();
+
+    assert_eq!(output.provider, "postgresql");
+    assert_eq!(output.result, serde_json::json!("Create table `Cat`\n"));
+}
+
+#[test]
+fn diffing_a_datamodel_against_an_empty_database_must_render_sql() {
+    let output = diff(&diff_input(datamodel(), DiffTarget::Empty, DiffOutputFormat::Sql)).unwrap();
+
+    assert_eq!(output.result, serde_json::json!("DROP TABLE \"lift\".\"Cat\";\n"));
+}
+
+#[test]
+fn diffing_a_datamodel_against_itself_must_detect_no_difference() {
+    let output = diff(&diff_input(datamodel(), datamodel(), DiffOutputFormat::Summary)).unwrap();
+
+    assert_eq!(output.result, serde_json::json!("No difference detected.\n"));
+
+    let output = diff(&diff_input(datamodel(), datamodel(), DiffOutputFormat::Steps)).unwrap();
+
+    assert_eq!(output.result, serde_json::json!([]));
+}
+
+#[test]
+fn diffing_without_a_provider_must_fail_when_the_targets_do_not_determine_it() {
+    let input = DiffInput {
+        provider: None,
+        ..diff_input(DiffTarget::Empty, datamodel(), DiffOutputFormat::Summary)
+    };
+
+    assert!(diff(&input).is_err());
+}
+
+#[test]
+fn diffing_a_schema_with_comments_must_leave_them_out_on_sqlite() {
+    let schema = serde_json::from_value(serde_json::json!({
+        "tables": [{
+            "name": "Cat",
+            "columns": [{
+                "name": "id",
+                "tpe": { "raw": "INTEGER", "family": "int" },
+                "arity": "required",
+                "default": null,
+                "autoIncrement": false,
+                "comment": "The id of the cat"
+            }],
+            "indices": [],
+            "primaryKey": { "columns": ["id"], "sequence": null },
+            "foreignKeys": [],
+            "comment": "All the cats"
+        }],
+        "enums": [],
+        "sequences": []
+    }))
+    .unwrap();
+    let input = DiffInput {
+        provider: Some("sqlite".to_owned()),
+        ..diff_input(
+            DiffTarget::Empty,
+            DiffTarget::SqlSchema { schema },
+            DiffOutputFormat::Sql,
+        )
+    };
+
+    let output = diff(&input).unwrap();
+    let sql = output.result.as_str().unwrap();
+
+    assert!(sql.contains("CREATE TABLE"));
+    assert!(!sql.contains("COMMENT"));
+}
+
+#[test]
+fn diffing_altered_and_commented_columns_must_recreate_the_table_on_sqlite() {
+    let next = DiffTarget::Datamodel {
+        datamodel: r#"
+            model Cat {
+                id   Int @id
+                /// The name of the cat
+                name Int
+            }
+        "#
+        .to_owned(),
+    };
+    let input = DiffInput {
+        provider: Some("sqlite".to_owned()),
+        ..diff_input(datamodel(), next, DiffOutputFormat::Sql)
+    };
+
+    let output = diff(&input).unwrap();
+    let sql = output.result.as_str().unwrap();
+
+    assert!(sql.contains("CREATE TABLE \"lift\".\"new_Cat\""));
+    assert!(!sql.contains("COMMENT"));
+}
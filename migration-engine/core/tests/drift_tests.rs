@@ -0,0 +1,92 @@
+mod test_harness;
+
+use migration_connector::{DriftKind, DriftObject};
+use pretty_assertions::assert_eq;
+use sql_migration_connector::SqlFamily;
+use test_harness::*;
+
+fn add_column_manually(api: &TestApi, table: &str, column: &str) {
+    let sql = match api.sql_family() {
+        SqlFamily::Mysql => format!("ALTER TABLE `{}`.`{}` ADD COLUMN `{}` TEXT", SCHEMA_NAME, table, column),
+        _ => format!(
+            "ALTER TABLE \"{}\".\"{}\" ADD COLUMN \"{}\" TEXT",
+            SCHEMA_NAME, table, column
+        ),
+    };
+
+    api.database().query_raw(&sql, &[]).unwrap();
+}
+
+#[test_each_connector]
+fn a_freshly_migrated_database_must_not_drift(api: &TestApi) {
+    let dm = r#"
+        model Blog {
+            id    Int    @id
+            title String @unique
+            posts Post[]
+        }
+
+        model Post {
+            id   Int    @id
+            blog Blog
+            tags String[]
+        }
+    "#;
+    api.infer_and_apply(&dm);
+
+    let output = api.check_drift();
+
+    assert_eq!(
+        output.migration_id.as_ref().map(String::as_str),
+        Some("the-migration-id")
+    );
+    assert_eq!(output.drift, vec![]);
+    assert!(!output.has_drift);
+}
+
+#[test_each_connector]
+fn manually_added_columns_must_be_reported_as_drift(api: &TestApi) {
+    let dm = r#"
+        model Test {
+            id   String @id @default(cuid())
+            name String
+        }
+    "#;
+    api.infer_and_apply(&dm);
+
+    add_column_manually(api, "Test", "hotfix");
+
+    let output = api.check_drift();
+
+    assert!(output.has_drift);
+    assert_eq!(output.drift.len(), 1);
+
+    let drift = &output.drift[0];
+    assert_eq!(drift.kind, DriftKind::Added);
+    assert_eq!(drift.object, DriftObject::Column);
+    assert_eq!(drift.table, "Test");
+    assert_eq!(drift.name.as_ref().map(String::as_str), Some("hotfix"));
+}
+
+#[test_each_connector]
+fn tables_missing_from_the_database_must_be_reported_as_drift(api: &TestApi) {
+    let dm = r#"
+        model Test {
+            id String @id @default(cuid())
+        }
+    "#;
+    api.infer_and_apply(&dm);
+
+    let sql = match api.sql_family() {
+        SqlFamily::Mysql => format!("DROP TABLE `{}`.`Test`", SCHEMA_NAME),
+        _ => format!("DROP TABLE \"{}\".\"Test\"", SCHEMA_NAME),
+    };
+    api.database().query_raw(&sql, &[]).unwrap();
+
+    let output = api.check_drift();
+
+    assert_eq!(output.drift.len(), 1);
+    assert_eq!(output.drift[0].kind, DriftKind::Removed);
+    assert_eq!(output.drift[0].object, DriftObject::Table);
+    assert_eq!(output.drift[0].table, "Test");
+}
@@ -0,0 +1,86 @@
+use migration_core::commands::{dry_run, DryRunInput};
+use pretty_assertions::assert_eq;
+
+fn dry_run_input(previous: Option<&str>, next: &str) -> DryRunInput {
+    DryRunInput {
+        previous_datamodel: previous.map(String::from),
+        next_datamodel: next.to_owned(),
+        provider: Some("postgresql".to_owned()),
+        schema_name: None,
+    }
+}
+
+#[test]
+fn a_dry_run_from_an_empty_database_must_render_the_script_without_warnings() {
+    let dm = r#"
+        model Cat {
+            id   Int    @id
+            name String
+        }
+    "#;
+
+    let output = dry_run(&dry_run_input(None, dm)).unwrap();
+
+    assert_eq!(output.provider, "postgresql");
+    assert!(output.script.starts_with("CREATE TABLE \"lift\".\"Cat\""));
+    assert!(output.warnings.is_empty());
+}
+
+#[test]
+fn a_dry_run_must_warn_about_potential_data_loss() {
+    let dm1 = r#"
+        model Cat {
+            id   Int    @id
+            name String
+            age  Int
+        }
+
+        model Dog {
+            id Int @id
+        }
+    "#;
+    let dm2 = r#"
+        model Cat {
+            id   Int    @id
+            name Int
+        }
+    "#;
+
+    let output = dry_run(&dry_run_input(Some(dm1), dm2)).unwrap();
+    let warnings: Vec<&str> = output
+        .warnings
+        .iter()
+        .map(|warning| warning.description.as_str())
+        .collect();
+
+    assert_eq!(
+        warnings,
+        vec![
+            "You are about to drop the table `Dog`. All the data in it will be lost.",
+            "You are about to drop the column `age` on the `Cat` table. All the data in the column will be lost.",
+            "You are about to alter the column `name` on the `Cat` table. Values that are not compatible with the new column definition may fail to convert or lose data.",
+        ]
+    );
+    assert!(output.script.contains("DROP TABLE \"lift\".\"Dog\";"));
+}
+
+#[test]
+fn a_dry_run_must_not_warn_about_widening_columns() {
+    let dm1 = r#"
+        model Cat {
+            id  Int @id
+            age Int
+        }
+    "#;
+    let dm2 = r#"
+        model Cat {
+            id  Int    @id
+            age BigInt
+        }
+    "#;
+
+    let output = dry_run(&dry_run_input(Some(dm1), dm2)).unwrap();
+
+    assert!(output.warnings.is_empty());
+    assert!(output.script.contains("ALTER COLUMN"));
+}
@@ -199,26 +199,26 @@ fn altering_a_column_without_non_null_values_should_not_warn(api: &TestApi) {
 }
 
 #[test_each_connector]
-fn altering_a_column_with_non_null_values_should_warn(api: &TestApi) {
+fn altering_a_column_with_incompatible_values_should_warn(api: &TestApi) {
     let dm = r#"
         model Test {
             id String @id @default(cuid())
-            age Int?
+            age String?
         }
     "#;
 
     let original_database_schema = api.infer_and_apply(&dm).sql_schema;
 
     let insert = Insert::multi_into((SCHEMA_NAME, "Test"), vec!["id", "age"])
-        .values(("a", 12))
-        .values(("b", 22));
+        .values(("a", "12"))
+        .values(("b", "twenty-two"));
 
     api.database().execute(insert.into()).unwrap();
 
     let dm2 = r#"
         model Test {
             id String @id @default(cuid())
-            age Float?
+            age Int?
         }
     "#;
 
@@ -229,13 +229,97 @@ fn altering_a_column_with_non_null_values_should_warn(api: &TestApi) {
     // and the force flag isn't passed.
     assert_eq!(original_database_schema, final_database_schema);
 
+    // We can't tell which values SQLite will convert, so all of them count.
+    let values_count = if api.is_sqlite() { 2 } else { 1 };
+
     assert_eq!(
         result.migration_output.warnings,
         &[MigrationWarning {
-            description:
-                "You are about to alter the column `age` on the `Test` table, which still contains 2 non-null values. \
-                 The data in that column will be lost."
-                    .to_owned()
+            description: format!(
+                "You are about to alter the column `age` on the `Test` table, which contains {} values that are not \
+                 compatible with the new column definition. Converting them may fail or lose data.",
+                values_count
+            )
         }]
     );
 }
+
+#[test_each_connector]
+fn widening_a_column_with_non_null_values_should_not_warn_and_preserve_the_values(api: &TestApi) {
+    let dm = r#"
+        model Test {
+            id String @id @default(cuid())
+            age Int?
+        }
+    "#;
+
+    api.infer_and_apply(&dm);
+
+    let conn = api.database();
+    let insert = Insert::multi_into((SCHEMA_NAME, "Test"), vec!["id", "age"])
+        .values(("a", 12))
+        .values(("b", 22));
+
+    conn.execute(insert.into()).unwrap();
+
+    let dm2 = r#"
+        model Test {
+            id String @id @default(cuid())
+            age BigInt?
+        }
+    "#;
+
+    let result = api.infer_and_apply(&dm2);
+
+    assert!(result.migration_output.warnings.is_empty());
+
+    // TODO: same issue as above, SQLite reports the column as not found after the table got recreated.
+    if !api.is_sqlite() {
+        let conditions = "id".equals("a");
+        let table_for_select: Table = (SCHEMA_NAME, "Test").into();
+        let query = Select::from_table(table_for_select).so_that(conditions);
+        let result_set = conn.query(query.into()).unwrap();
+        let row = result_set.into_iter().next().expect("query returned no results");
+        assert_eq!(row["age"].as_i64().unwrap(), 12);
+    }
+}
+
+#[test_each_connector]
+fn renaming_a_model_and_a_field_must_preserve_the_data(api: &TestApi) {
+    let dm = r#"
+            model Test {
+                id String @id @default(cuid())
+                name String
+            }
+        "#;
+    api.infer_and_apply(&dm);
+
+    let conn = api.database();
+    let insert = Insert::single_into((SCHEMA_NAME, "Test"))
+        .value("id", "test")
+        .value("name", "Rex");
+    conn.execute(insert.into()).unwrap();
+
+    let steps = vec![
+        rename_model_step("Test", "Pet"),
+        rename_field_step("Pet", "name", "nickname"),
+    ];
+    let InferAndApplyOutput {
+        migration_output,
+        sql_schema,
+    } = api.apply_migration(steps, "the-rename-migration");
+
+    assert!(migration_output.warnings.is_empty());
+    assert!(sql_schema.get_table("Test").is_none());
+
+    let table = sql_schema.table_bang("Pet");
+    assert!(table.column("name").is_none());
+    assert!(table.column("nickname").is_some());
+
+    let conditions = "id".equals("test");
+    let table_for_select: Table = (SCHEMA_NAME, "Pet").into();
+    let query = Select::from_table(table_for_select).so_that(conditions);
+    let result_set = conn.query(query.into()).unwrap();
+    let row = result_set.into_iter().next().expect("query returned no results");
+    assert_eq!(row["nickname"].as_str().unwrap(), "Rex");
+}
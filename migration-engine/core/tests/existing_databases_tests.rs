@@ -331,6 +331,31 @@ fn renaming_a_field_where_the_column_was_already_renamed_must_work() {
     })
 }
 
+#[test]
+fn float_columns_stored_as_decimals_must_not_be_migrated() {
+    test_each_backend_with_ignores(vec![SqlFamily::Sqlite], |test_setup, api, barrel| {
+        let initial_result = barrel.execute(|migration| {
+            migration.create_table("Product", |t| {
+                t.add_column("id", types::primary());
+                t.add_column("price", types::custom("Decimal(65,30)"));
+            });
+        });
+        assert_eq!(
+            initial_result.table_bang("Product").column_bang("price").tpe.family,
+            ColumnTypeFamily::Decimal
+        );
+
+        let dm = r#"
+            model Product {
+                id Int @id
+                price Float
+            }
+        "#;
+        let result = infer_and_apply(test_setup, api, &dm).sql_schema;
+        assert_eq!(initial_result, result);
+    })
+}
+
 fn test_each_backend<F>(test_fn: F)
 where
     F: Fn(&TestSetup, &dyn GenericApi, &BarrelMigrationExecutor) -> () + std::panic::RefUnwindSafe,
@@ -0,0 +1,120 @@
+mod test_harness;
+
+use migration_connector::sha256_hex;
+use migration_core::commands::ExportMigrationsInput;
+use pretty_assertions::assert_eq;
+use std::{fs, path::PathBuf};
+use test_harness::*;
+
+fn output_directory(api: &TestApi, test_name: &str) -> PathBuf {
+    let directory = std::env::temp_dir().join(format!("{}_{:?}", test_name, api.sql_family()));
+    let _ = fs::remove_dir_all(&directory);
+
+    directory
+}
+
+#[test_each_connector]
+fn exporting_a_migration_must_write_up_and_down_scripts(api: &TestApi) {
+    let dm = r#"
+        model Cat {
+            id   Int    @id
+            name String
+        }
+    "#;
+    api.infer_and_apply(&dm);
+
+    let directory = output_directory(api, "exporting_a_migration_must_write_up_and_down_scripts");
+    let output = api.export_migrations(&ExportMigrationsInput {
+        output_directory: directory.to_string_lossy().into_owned(),
+        from_migration_id: None,
+        to_migration_id: None,
+    });
+
+    assert_eq!(output.migrations.len(), 1);
+
+    let exported = &output.migrations[0];
+    assert_eq!(exported.id, "the-migration-id");
+    assert_eq!(
+        PathBuf::from(&exported.directory),
+        directory.join("0001_the-migration-id")
+    );
+
+    let datamodel_string = api.migration_persistence().last().unwrap().datamodel_string;
+    assert_eq!(exported.datamodel_hash, sha256_hex(datamodel_string.as_bytes()));
+
+    let up = fs::read_to_string(directory.join("0001_the-migration-id").join("up.sql")).unwrap();
+    let down = fs::read_to_string(directory.join("0001_the-migration-id").join("down.sql")).unwrap();
+
+    assert!(up.starts_with("-- Migration: the-migration-id\n-- Script: up\n"));
+    assert!(up.contains(&format!("-- Datamodel hash (SHA-256): {}\n", exported.datamodel_hash)));
+    assert!(up.contains("CREATE TABLE"));
+    assert!(down.starts_with("-- Migration: the-migration-id\n-- Script: down\n"));
+    assert!(down.contains("DROP TABLE"));
+
+    fs::remove_dir_all(&directory).unwrap();
+}
+
+#[test_each_connector]
+fn replaying_exported_migrations_must_recreate_the_schema(api: &TestApi) {
+    let dm1 = r#"
+        model Cat {
+            id   Int    @id
+            name String
+        }
+    "#;
+    api.infer_and_apply_with_migration_id(&dm1, "first-migration");
+
+    let dm2 = r#"
+        model Cat {
+            id   Int    @id
+            name String
+            age  Int
+        }
+    "#;
+    api.infer_and_apply_with_migration_id(&dm2, "watch-0001");
+
+    let dm3 = r#"
+        model Cat {
+            id   Int    @id
+            name String
+            age  Int
+        }
+
+        model Dog {
+            id Int @id
+        }
+    "#;
+    api.infer_and_apply_with_migration_id(&dm3, "watch-0002");
+    api.infer_and_apply_with_migration_id(&dm3, "second-migration");
+
+    let expected_schema = api.introspect_database();
+
+    let directory = output_directory(api, "replaying_exported_migrations_must_recreate_the_schema");
+    let output = api.export_migrations(&ExportMigrationsInput {
+        output_directory: directory.to_string_lossy().into_owned(),
+        from_migration_id: None,
+        to_migration_id: None,
+    });
+
+    let ids: Vec<&str> = output.migrations.iter().map(|migration| migration.id.as_str()).collect();
+    assert_eq!(ids, vec!["first-migration", "second-migration"]);
+
+    api.reset();
+
+    for migration in &output.migrations {
+        let up = fs::read_to_string(PathBuf::from(&migration.directory).join("up.sql")).unwrap();
+        let script: String = up
+            .lines()
+            .filter(|line| !line.starts_with("--"))
+            .map(|line| format!("{}\n", line))
+            .collect();
+
+        for statement in script.split(";\n").map(str::trim).filter(|s| !s.is_empty()) {
+            api.database().query_raw(statement, &[]).unwrap();
+        }
+    }
+
+    assert_eq!(api.introspect_database(), expected_schema);
+
+    fs::remove_dir_all(&directory).unwrap();
+}
@@ -0,0 +1,161 @@
+mod test_harness;
+
+use migration_connector::{migration_checksum, MigrationStep};
+use migration_core::commands::{ApplyMigrationInput, CommandError, ListMigrationsInput, LocalMigration};
+use pretty_assertions::assert_eq;
+use sql_migration_connector::SqlFamily;
+use test_harness::*;
+
+const DM_1: &str = r#"
+    model Cat {
+        id   Int    @id
+        name String
+    }
+"#;
+
+const DM_2: &str = r#"
+    model Cat {
+        id   Int    @id
+        name String
+        age  Int?
+    }
+"#;
+
+const DM_3: &str = r#"
+    model Cat {
+        id   Int    @id
+        name String
+        age  Int?
+    }
+
+    model Dog {
+        id Int @id
+    }
+"#;
+
+fn local_migration(id: &str, steps: Vec<MigrationStep>) -> LocalMigration {
+    LocalMigration {
+        id: id.to_owned(),
+        steps,
+    }
+}
+
+fn mismatching_migration_id(error: migration_core::Error) -> String {
+    match error {
+        migration_core::Error::CommandError(CommandError::MigrationChecksumMismatch { migration_id, .. }) => {
+            migration_id
+        }
+        error => panic!("Expected a checksum mismatch, got {:?}", error),
+    }
+}
+
+#[test_each_connector]
+fn listing_migrations_must_succeed_when_the_local_steps_match(api: &TestApi) {
+    let first_steps = api.infer_steps(DM_1, "first");
+    api.apply_migration(first_steps.clone(), "first");
+    let second_steps = api.infer_steps(DM_2, "second");
+    api.apply_migration(second_steps.clone(), "second");
+
+    let migrations = api
+        .list_migrations(&ListMigrationsInput {
+            local_migrations: vec![
+                local_migration("first", first_steps.clone()),
+                local_migration("second", second_steps.clone()),
+            ],
+        })
+        .unwrap();
+
+    let checksums: Vec<Option<String>> = migrations.into_iter().map(|migration| migration.checksum).collect();
+    let expected_checksums: Vec<Option<String>> = api
+        .migration_persistence()
+        .load_all()
+        .iter()
+        .map(|migration| Some(migration_checksum(migration)))
+        .collect();
+    assert_eq!(checksums, expected_checksums);
+}
+
+#[test_each_connector]
+fn applying_a_migration_must_fail_when_local_steps_were_edited(api: &TestApi) {
+    api.infer_and_apply_with_migration_id(DM_1, "first");
+    api.infer_and_apply_with_migration_id(DM_2, "second");
+
+    // Both local migrations were edited, the error must name the first one.
+    let input = ApplyMigrationInput {
+        migration_id: "third".to_owned(),
+        steps: api.infer_steps(DM_3, "third"),
+        force: None,
+        online: None,
+        data_migrations: Vec::new(),
+        lock_timeout: None,
+        local_migrations: vec![
+            local_migration("first", Vec::new()),
+            local_migration("second", Vec::new()),
+        ],
+    };
+
+    let error = api.try_apply_migration(&input).unwrap_err();
+
+    assert_eq!(mismatching_migration_id(error), "first");
+    assert!(api.migration_persistence().by_name("third").is_none());
+    assert!(!api.introspect_database().has_table("Dog"));
+}
+
+#[test_each_connector]
+fn listing_migrations_must_fail_when_the_persisted_steps_were_edited(api: &TestApi) {
+    api.infer_and_apply_with_migration_id(DM_1, "first");
+    api.infer_and_apply_with_migration_id(DM_2, "second");
+
+    let sql = match api.sql_family() {
+        SqlFamily::Mysql => format!(
+            "UPDATE `{}`.`_Migration` SET `datamodel_steps` = '[]' WHERE `name` = 'second'",
+            SCHEMA_NAME
+        ),
+        _ => format!(
+            "UPDATE \"{}\".\"_Migration\" SET \"datamodel_steps\" = '[]' WHERE \"name\" = 'second'",
+            SCHEMA_NAME
+        ),
+    };
+    api.database().query_raw(&sql, &[]).unwrap();
+
+    let error = api.list_migrations(&ListMigrationsInput::default()).unwrap_err();
+
+    assert_eq!(mismatching_migration_id(error), "second");
+}
+
+#[test_each_connector]
+fn listing_migrations_must_fail_when_the_persisted_database_migration_was_edited(api: &TestApi) {
+    api.infer_and_apply_with_migration_id(DM_1, "first");
+
+    let sql = match api.sql_family() {
+        SqlFamily::Mysql => format!(
+            "UPDATE `{}`.`_Migration` SET `database_migration` = '{{}}' WHERE `name` = 'first'",
+            SCHEMA_NAME
+        ),
+        _ => format!(
+            "UPDATE \"{}\".\"_Migration\" SET \"database_migration\" = '{{}}' WHERE \"name\" = 'first'",
+            SCHEMA_NAME
+        ),
+    };
+    api.database().query_raw(&sql, &[]).unwrap();
+
+    let error = api.list_migrations(&ListMigrationsInput::default()).unwrap_err();
+
+    assert_eq!(mismatching_migration_id(error), "first");
+}
+
+#[test_each_connector]
+fn migrations_written_without_a_checksum_must_still_be_listed(api: &TestApi) {
+    api.infer_and_apply_with_migration_id(DM_1, "first");
+
+    let sql = match api.sql_family() {
+        SqlFamily::Mysql => format!("UPDATE `{}`.`_Migration` SET `checksum` = NULL", SCHEMA_NAME),
+        _ => format!("UPDATE \"{}\".\"_Migration\" SET \"checksum\" = NULL", SCHEMA_NAME),
+    };
+    api.database().query_raw(&sql, &[]).unwrap();
+
+    let migrations = api.list_migrations(&ListMigrationsInput::default()).unwrap();
+
+    assert_eq!(migrations.len(), 1);
+    assert_eq!(migrations[0].checksum, None);
+}
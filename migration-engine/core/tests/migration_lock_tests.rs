@@ -0,0 +1,126 @@
+mod test_harness;
+
+use migration_connector::with_migration_lock;
+use migration_core::commands::{ApplyMigrationInput, BaselineInput, SquashMigrationsInput};
+use std::time::Duration;
+use test_harness::*;
+
+#[test_each_connector]
+fn applying_a_migration_must_fail_while_another_engine_holds_the_migration_lock(api: &TestApi) {
+    let dm = r#"
+        model Cat {
+            id Int @id
+        }
+    "#;
+    let input = ApplyMigrationInput {
+        migration_id: "the-migration-id".to_owned(),
+        steps: api.infer_steps(dm, "the-migration-id"),
+        force: None,
+        online: None,
+        data_migrations: Vec::new(),
+        lock_timeout: Some(1),
+        local_migrations: Vec::new(),
+    };
+
+    let result = with_migration_lock(&*api.migration_lock(), Duration::from_secs(0), || {
+        api.try_apply_migration(&input)
+    })
+    .unwrap();
+
+    let error = format!("{:?}", result.unwrap_err());
+    assert!(
+        error.contains("another migration engine is migrating the database"),
+        "unexpected error: {}",
+        error
+    );
+    assert!(api.migration_persistence().last().is_none());
+
+    // The lock is released with the closure, so the migration can be applied now.
+    api.try_apply_migration(&input).unwrap();
+    assert!(api.introspect_database().has_table("Cat"));
+}
+
+#[test_each_connector]
+fn squashing_migrations_must_fail_while_another_engine_holds_the_migration_lock(api: &TestApi) {
+    let dm = r#"
+        model Cat {
+            id Int @id
+        }
+    "#;
+    api.infer_and_apply_with_migration_id(dm, "first");
+
+    let input = SquashMigrationsInput {
+        baseline_migration_id: "baseline".to_owned(),
+        to_migration_id: None,
+        lock_timeout: Some(1),
+    };
+
+    let result = with_migration_lock(&*api.migration_lock(), Duration::from_secs(0), || {
+        api.try_squash_migrations(&input)
+    })
+    .unwrap();
+
+    let error = format!("{:?}", result.unwrap_err());
+    assert!(
+        error.contains("another migration engine is migrating the database"),
+        "unexpected error: {}",
+        error
+    );
+    assert_eq!(api.migration_persistence().last().unwrap().name, "first");
+
+    api.try_squash_migrations(&input).unwrap();
+    assert_eq!(api.migration_persistence().last().unwrap().name, "baseline");
+}
+
+#[test_each_connector]
+fn baselining_must_fail_while_another_engine_holds_the_migration_lock(api: &TestApi) {
+    let dm = r#"
+        model Cat {
+            id Int @id
+        }
+    "#;
+    let input = BaselineInput {
+        migration_id: "the-baseline".to_owned(),
+        datamodel: dm.to_owned(),
+        lock_timeout: Some(1),
+    };
+
+    let result = with_migration_lock(&*api.migration_lock(), Duration::from_secs(0), || {
+        api.try_baseline(&input)
+    })
+    .unwrap();
+
+    let error = format!("{:?}", result.unwrap_err());
+    assert!(
+        error.contains("another migration engine is migrating the database"),
+        "unexpected error: {}",
+        error
+    );
+    assert!(api.migration_persistence().last().is_none());
+}
+
+#[test_each_connector]
+fn the_migration_lock_must_be_released_when_the_migration_panics(api: &TestApi) {
+    let lock = api.migration_lock();
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        with_migration_lock(&*lock, Duration::from_secs(0), || panic!("the migration failed"))
+    }));
+    assert!(result.is_err());
+
+    with_migration_lock(&*lock, Duration::from_secs(1), || ()).unwrap();
+}
+
+#[test]
+fn migrations_must_be_applied_under_the_lock_with_a_single_pooled_connection() {
+    let api = postgres_test_api_with_single_connection();
+    let dm = r#"
+        model Cat {
+            id Int @id
+        }
+    "#;
+
+    api.infer_and_apply(dm);
+
+    assert!(api.introspect_database().has_table("Cat"));
+}
@@ -77,13 +77,12 @@ fn create_should_allow_to_create_a_new_migration() {
 
         let result = persistence.create(migration.clone());
         migration.revision = result.revision; // copy over the generated revision so that the assertion can work.`
+        migration.datamodel_string = datamodel::render_datamodel_to_string(&migration.datamodel).unwrap();
+        migration.checksum = Some(migration_checksum(&migration));
 
         assert_eq!(result, migration);
         let mut loaded = persistence.last().unwrap();
 
-        // TODO: fix this
-        loaded.datamodel_string = "".into();
-
         if test_setup.sql_family == SqlFamily::Mysql {
             // TODO: mysql currently looses milli seconds on loading
             loaded.started_at = migration.started_at;
@@ -130,3 +129,18 @@ fn update_must_work() {
         assert_eq!(loaded.name, params.new_name);
     });
 }
+
+#[test]
+fn init_must_be_idempotent() {
+    test_each_connector(|_, api| {
+        let persistence = api.migration_persistence();
+        persistence.create(Migration::new("my_migration".to_string()));
+
+        persistence.init().unwrap();
+        persistence.init().unwrap();
+
+        let loaded = persistence.load_all();
+        assert_eq!(loaded.len(), 1);
+        assert_eq!(loaded[0].name, "my_migration");
+    });
+}
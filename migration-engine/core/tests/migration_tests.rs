@@ -14,6 +14,7 @@ fn adding_a_scalar_field_must_work() {
                 id String @id @default(cuid())
                 int Int
                 float Float
+                decimal Decimal
                 boolean Boolean
                 string String
                 dateTime DateTime
@@ -31,10 +32,298 @@ fn adding_a_scalar_field_must_work() {
 
         assert_eq!(table.column_bang("int").tpe.family, ColumnTypeFamily::Int);
         assert_eq!(table.column_bang("float").tpe.family, ColumnTypeFamily::Float);
+        assert_eq!(table.column_bang("decimal").tpe.family, ColumnTypeFamily::Decimal);
         assert_eq!(table.column_bang("boolean").tpe.family, ColumnTypeFamily::Boolean);
         assert_eq!(table.column_bang("string").tpe.family, ColumnTypeFamily::String);
         assert_eq!(table.column_bang("dateTime").tpe.family, ColumnTypeFamily::DateTime);
-        assert_eq!(table.column_bang("enum").tpe.family, ColumnTypeFamily::String);
+        assert_eq!(
+            table.column_bang("enum").tpe.family,
+            match test_setup.sql_family {
+                SqlFamily::Postgres => ColumnTypeFamily::Enum("MyEnum".to_owned()),
+                SqlFamily::Mysql => ColumnTypeFamily::Enum("Test_enum".to_owned()),
+                SqlFamily::Sqlite => ColumnTypeFamily::String,
+            }
+        );
+    });
+}
+
+#[test]
+fn native_types_must_be_used_for_the_columns() {
+    test_each_connector_with_ignores(vec![SqlFamily::Mysql, SqlFamily::Sqlite], |test_setup, api| {
+        let dm = format!(
+            r#"
+            {}
+
+            model Test {{
+                id Int @id
+                small Int @my_db.SmallInt
+                name String @my_db.VarChar(100)
+                price Decimal @my_db.Numeric(10, scale: 2)
+                createdAt DateTime @my_db.Timestamptz(3)
+            }}
+        "#,
+            postgres_test_config()
+        );
+        let result = infer_and_apply(test_setup, api, &dm).sql_schema;
+        let table = result.table_bang("Test");
+
+        assert_eq!(table.column_bang("small").tpe.raw, "int2");
+        assert_eq!(table.column_bang("small").tpe.family, ColumnTypeFamily::Int);
+        assert_eq!(table.column_bang("name").tpe.raw, "varchar");
+        assert_eq!(table.column_bang("name").tpe.character_maximum_length, Some(100));
+        assert_eq!(table.column_bang("price").tpe.raw, "numeric");
+        assert_eq!(table.column_bang("price").tpe.numeric_precision, Some(10));
+        assert_eq!(table.column_bang("price").tpe.numeric_scale, Some(2));
+        assert_eq!(table.column_bang("createdAt").tpe.raw, "timestamptz");
+
+        // The families match the described columns, so nothing changes on the next migration.
+        let result = infer_and_apply(test_setup, api, &dm);
+        assert!(result.sql_migration().is_empty());
+    });
+}
+
+#[test]
+fn changing_the_arguments_of_native_types_must_migrate_the_columns() {
+    test_each_connector_with_ignores(vec![SqlFamily::Mysql, SqlFamily::Sqlite], |test_setup, api| {
+        let dm1 = format!(
+            r#"
+            {}
+
+            model Test {{
+                id Int @id
+                name String @my_db.VarChar(100)
+                price Decimal @my_db.Numeric(10, scale: 2)
+            }}
+        "#,
+            postgres_test_config()
+        );
+        infer_and_apply(test_setup, api, &dm1);
+
+        let dm2 = format!(
+            r#"
+            {}
+
+            model Test {{
+                id Int @id
+                name String @my_db.VarChar(200)
+                price Decimal @my_db.Numeric(12, scale: 4)
+            }}
+        "#,
+            postgres_test_config()
+        );
+        let result = infer_and_apply(test_setup, api, &dm2).sql_schema;
+        let table = result.table_bang("Test");
+
+        assert_eq!(table.column_bang("name").tpe.character_maximum_length, Some(200));
+        assert_eq!(table.column_bang("price").tpe.numeric_precision, Some(12));
+        assert_eq!(table.column_bang("price").tpe.numeric_scale, Some(4));
+    });
+}
+
+#[test]
+fn adding_native_types_to_existing_fields_must_migrate_the_columns() {
+    test_each_connector_with_ignores(vec![SqlFamily::Mysql, SqlFamily::Sqlite], |test_setup, api| {
+        let dm1 = r#"
+            model Test {
+                id Int @id
+                small Int
+                createdAt DateTime
+            }
+        "#;
+        infer_and_apply(test_setup, api, &dm1);
+
+        let dm2 = format!(
+            r#"
+            {}
+
+            model Test {{
+                id Int @id
+                small Int @my_db.SmallInt
+                createdAt DateTime @my_db.Timestamptz
+            }}
+        "#,
+            postgres_test_config()
+        );
+        let result = infer_and_apply(test_setup, api, &dm2).sql_schema;
+        let table = result.table_bang("Test");
+
+        assert_eq!(table.column_bang("small").tpe.raw, "int2");
+        assert_eq!(table.column_bang("createdAt").tpe.raw, "timestamptz");
+
+        let result = infer_and_apply(test_setup, api, &dm2);
+        assert!(result.sql_migration().is_empty());
+    });
+}
+
+#[test]
+fn adding_a_native_type_to_an_existing_string_field_must_migrate_the_column_on_mysql() {
+    test_each_connector_with_ignores(vec![SqlFamily::Postgres, SqlFamily::Sqlite], |test_setup, api| {
+        let dm1 = r#"
+            model Test {
+                id Int @id
+                description String
+            }
+        "#;
+        infer_and_apply(test_setup, api, &dm1);
+
+        let dm2 = format!(
+            r#"
+            {}
+
+            model Test {{
+                id Int @id
+                description String @my_db.Text
+            }}
+        "#,
+            mysql_test_config()
+        );
+        let result = infer_and_apply(test_setup, api, &dm2).sql_schema;
+
+        assert_eq!(result.table_bang("Test").column_bang("description").tpe.raw, "text");
+    });
+}
+
+#[test]
+fn check_constraints_must_be_created_and_dropped() {
+    test_each_connector_with_ignores(vec![SqlFamily::Mysql], |test_setup, api| {
+        let dm1 = r#"
+            model Test {
+                id Int @id
+                price Float @range(min: 0)
+                name String @length(max: 10)
+
+                @@check("price < 1000", name: "cheap")
+            }
+        "#;
+        let result = infer_and_apply(test_setup, api, &dm1).sql_schema;
+        let mut check_names: Vec<&str> = result
+            .table_bang("Test")
+            .check_constraints
+            .iter()
+            .map(|check| check.name.as_str())
+            .collect();
+        check_names.sort();
+
+        assert_eq!(check_names, vec!["Test_name_length", "Test_price_range", "cheap"]);
+
+        // The expressions are normalized by the database, which must not lead to a new migration.
+        let result = infer_and_apply(test_setup, api, &dm1);
+        assert!(result.sql_migration().is_empty());
+
+        let dm2 = r#"
+            model Test {
+                id Int @id
+                price Float @range(min: 0)
+                name String @length(max: 10)
+            }
+        "#;
+        let result = infer_and_apply(test_setup, api, &dm2).sql_schema;
+        let table = result.table_bang("Test");
+
+        assert!(table.check_constraints.iter().all(|check| check.name != "cheap"));
+        assert_eq!(table.check_constraints.len(), 2);
+    });
+}
+
+#[test]
+fn check_constraint_names_must_not_depend_on_the_other_checks() {
+    test_each_connector_with_ignores(vec![SqlFamily::Mysql], |test_setup, api| {
+        let dm1 = r#"
+            model Test {
+                id Int @id
+                price Float
+                quantity Int
+
+                @@check("price < 1000")
+                @@check("quantity > 0")
+            }
+        "#;
+        let result = infer_and_apply(test_setup, api, &dm1).sql_schema;
+        let quantity_check = result
+            .table_bang("Test")
+            .check_constraints
+            .iter()
+            .find(|check| check.expression.contains("quantity"))
+            .expect("quantity check")
+            .clone();
+
+        let dm2 = r#"
+            model Test {
+                id Int @id
+                price Float
+                quantity Int
+
+                @@check("quantity > 0")
+            }
+        "#;
+        let result = infer_and_apply(test_setup, api, &dm2).sql_schema;
+
+        assert_eq!(result.table_bang("Test").check_constraints, vec![quantity_check]);
+    });
+}
+
+#[test]
+fn long_check_constraint_names_must_be_shortened() {
+    test_each_connector_with_ignores(vec![SqlFamily::Mysql], |test_setup, api| {
+        let dm = r#"
+            model Test {
+                id Int @id
+                aVeryLongFieldNameThatMakesTheNameOfTheConstraintTooLong String @length(max: 10)
+                aVeryLongFieldNameThatMakesTheNameOfTheConstraintTooLongToo String @length(max: 10)
+            }
+        "#;
+        let result = infer_and_apply(test_setup, api, &dm).sql_schema;
+        let check_names: Vec<&str> = result
+            .table_bang("Test")
+            .check_constraints
+            .iter()
+            .map(|check| check.name.as_str())
+            .collect();
+
+        assert_eq!(check_names.len(), 2);
+        assert!(check_names.iter().all(|name| name.len() <= 63));
+        assert_ne!(check_names[0], check_names[1]);
+
+        let result = infer_and_apply(test_setup, api, &dm);
+        assert!(result.sql_migration().is_empty());
+    });
+}
+
+#[test]
+fn referential_actions_must_be_rendered_into_foreign_keys() {
+    test_each_connector(|test_setup, api| {
+        let dm1 = r#"
+            model A {
+                id Int @id
+                b  B   @relation(references: [id], onDelete: CASCADE, onUpdate: RESTRICT)
+            }
+            model B {
+                id Int @id
+            }
+        "#;
+        let result = infer_and_apply(test_setup, api, &dm1).sql_schema;
+        let foreign_key = &result.table_bang("A").foreign_keys[0];
+
+        assert_eq!(foreign_key.on_delete_action, ForeignKeyAction::Cascade);
+        assert_eq!(foreign_key.on_update_action, ForeignKeyAction::Restrict);
+
+        let result = infer_and_apply(test_setup, api, &dm1);
+        assert!(result.sql_migration().is_empty());
+
+        let dm2 = r#"
+            model A {
+                id Int @id
+                b  B?  @relation(references: [id], onDelete: SET_NULL)
+            }
+            model B {
+                id Int @id
+            }
+        "#;
+        let result = infer_and_apply(test_setup, api, &dm2).sql_schema;
+        let foreign_key = &result.table_bang("A").foreign_keys[0];
+
+        assert_eq!(foreign_key.on_delete_action, ForeignKeyAction::SetNull);
+        assert_eq!(foreign_key.on_update_action, ForeignKeyAction::NoAction);
     });
 }
 
@@ -107,10 +396,231 @@ fn adding_an_id_field_of_type_int_must_work() {
                 let sequence = result.get_sequence("Test_myId_seq").expect("sequence must exist");
                 let default = column.default.as_ref().expect("Must have nextval default");
                 assert_eq!(default.contains(&sequence.name), true);
-                assert_eq!(default, &format!("nextval(\"{}\"::regclass)", sequence.name))
+                assert_eq!(default, &format!("nextval('\"{}\"'::regclass)", sequence.name))
+            }
+            _ => assert_eq!(column.auto_increment, true),
+        }
+    });
+}
+
+#[test]
+fn adding_a_big_int_field_must_work() {
+    test_each_connector(|test_setup, api| {
+        let dm = r#"
+            model Test {
+                id Int @id
+                bigInt BigInt
+                optionalBigInt BigInt?
+            }
+        "#;
+
+        let result = infer_and_apply(test_setup, api, &dm).sql_schema;
+        let table = result.table_bang("Test");
+        assert_eq!(table.column_bang("bigInt").tpe.family, ColumnTypeFamily::BigInt);
+        assert_eq!(table.column_bang("bigInt").is_required(), true);
+        assert_eq!(table.column_bang("optionalBigInt").tpe.family, ColumnTypeFamily::BigInt);
+        assert_eq!(table.column_bang("optionalBigInt").is_required(), false);
+    });
+}
+
+#[test]
+fn adding_an_id_field_of_type_big_int_must_work() {
+    test_each_connector(|test_setup, api| {
+        let dm = r#"
+            model Test {
+                myId BigInt @id
+            }
+        "#;
+
+        let result = infer_and_apply(test_setup, api, &dm).sql_schema;
+        let column = result.table_bang("Test").column_bang("myId");
+        match test_setup.sql_family {
+            // An `INTEGER PRIMARY KEY` holds 64 bit integers on SQLite.
+            SqlFamily::Sqlite => assert_eq!(column.tpe.family, ColumnTypeFamily::Int),
+            _ => assert_eq!(column.tpe.family, ColumnTypeFamily::BigInt),
+        }
+        match test_setup.sql_family {
+            SqlFamily::Postgres => {
+                let sequence = result.get_sequence("Test_myId_seq").expect("sequence must exist");
+                let default = column.default.as_ref().expect("Must have nextval default");
+                assert_eq!(default, &format!("nextval('\"{}\"'::regclass)", sequence.name))
             }
             _ => assert_eq!(column.auto_increment, true),
         }
+
+        // Migrating again must not alter the column.
+        let result = infer_and_apply(test_setup, api, &dm);
+        assert!(result.sql_migration().is_empty());
+    });
+}
+
+#[test]
+fn adding_a_field_with_a_dbgenerated_default_must_render_the_expression_verbatim() {
+    test_each_connector_with_ignores(vec![SqlFamily::Mysql], |test_setup, api| {
+        let dm = r#"
+            model Test {
+                id Int @id
+                name String @default(dbgenerated("(lower('ABC'))"))
+            }
+        "#;
+
+        let result = infer_and_apply(test_setup, api, &dm).sql_schema;
+        let column = result.table_bang("Test").column_bang("name");
+        let expected_default = match test_setup.sql_family {
+            SqlFamily::Postgres => "lower('ABC'::text)",
+            _ => "(lower('ABC'))",
+        };
+        assert_eq!(column.default.as_ref().map(String::as_str), Some(expected_default));
+        assert_eq!(column.default_is_expression, true);
+    });
+}
+
+#[test]
+fn changing_a_dbgenerated_default_must_alter_the_column() {
+    test_each_connector_with_ignores(vec![SqlFamily::Mysql], |test_setup, api| {
+        let dm1 = r#"
+            model Test {
+                id Int @id
+                name String @default(dbgenerated("(lower('ABC'))"))
+            }
+        "#;
+        infer_and_apply(test_setup, api, &dm1);
+
+        // The database reports the expression in its own words, which must not count as a change.
+        let result = infer_and_apply(test_setup, api, &dm1);
+        assert!(result.sql_migration().is_empty());
+
+        let dm2 = r#"
+            model Test {
+                id Int @id
+                name String @default(dbgenerated("(upper('ABC'))"))
+            }
+        "#;
+        let result = infer_and_apply(test_setup, api, &dm2);
+        assert!(!result.sql_migration().is_empty());
+        let column = result.sql_schema.table_bang("Test").column_bang("name");
+        let expected_default = match test_setup.sql_family {
+            SqlFamily::Postgres => "upper('ABC'::text)",
+            _ => "(upper('ABC'))",
+        };
+        assert_eq!(column.default.as_ref().map(String::as_str), Some(expected_default));
+    });
+}
+
+#[test]
+fn adding_a_field_with_a_dbgenerated_default_must_work_on_mysql_8() {
+    let api = mysql_8_test_api();
+
+    // MySQL 8 only accepts the expression in parentheses, which it doesn't report back.
+    let dm = r#"
+        model Test {
+            id Int @id
+            name String @default(dbgenerated("lower('ABC')"))
+        }
+    "#;
+
+    let result = api.infer_and_apply(&dm).sql_schema;
+    let column = result.table_bang("Test").column_bang("name");
+    assert!(column.default.as_ref().unwrap().to_lowercase().starts_with("lower("));
+    assert_eq!(column.default_is_expression, true);
+
+    // Migrating again must not alter the column.
+    let result = api.infer_and_apply(&dm);
+    assert!(result.sql_migration().is_empty());
+}
+
+#[test]
+fn documentation_must_be_applied_as_database_comments() {
+    test_each_connector_with_ignores(vec![SqlFamily::Sqlite], |test_setup, api| {
+        let dm1 = r#"
+            /// All the tests
+            model Test {
+                id Int @id
+                /// The name of the test
+                name String
+            }
+        "#;
+
+        let result = infer_and_apply(test_setup, api, &dm1).sql_schema;
+        let table = result.table_bang("Test");
+        assert_eq!(table.comment, Some("All the tests".to_owned()));
+        assert_eq!(
+            table.column_bang("name").comment,
+            Some("The name of the test".to_owned())
+        );
+        assert_eq!(table.column_bang("id").comment, None);
+
+        let dm2 = r#"
+            model Test {
+                id Int @id
+                /// The test's name
+                name String
+            }
+        "#;
+
+        let result = infer_and_apply(test_setup, api, &dm2).sql_schema;
+        let table = result.table_bang("Test");
+        assert_eq!(table.comment, None);
+        assert_eq!(table.column_bang("name").comment, Some("The test's name".to_owned()));
+    });
+}
+
+#[test]
+fn changing_the_documentation_of_a_field_must_keep_the_rest_of_the_column() {
+    test_each_connector_with_ignores(vec![SqlFamily::Sqlite], |test_setup, api| {
+        let dm1 = r#"
+            model Test {
+                id Int @id
+                /// The name of the test
+                name String @default("test")
+            }
+        "#;
+
+        infer_and_apply(test_setup, api, &dm1);
+
+        let dm2 = r#"
+            model Test {
+                id Int @id
+                /// The test's name
+                name String @default("test")
+            }
+        "#;
+
+        let result = infer_and_apply(test_setup, api, &dm2).sql_schema;
+        let column = result.table_bang("Test").column_bang("name");
+        assert_eq!(column.comment, Some("The test's name".to_owned()));
+        assert_eq!(column.is_required(), true);
+        assert!(column.default.is_some());
+    });
+}
+
+#[test]
+fn changing_the_documentation_of_an_updated_at_field_must_keep_its_on_update() {
+    test_each_connector_with_ignores(vec![SqlFamily::Sqlite, SqlFamily::Postgres], |test_setup, api| {
+        let dm1 = r#"
+            model Test {
+                id Int @id
+                /// When the test changed
+                updatedAt DateTime @updatedAt
+            }
+        "#;
+
+        let result = infer_and_apply(test_setup, api, &dm1).sql_schema;
+        let column = result.table_bang("Test").column_bang("updatedAt");
+        assert_eq!(column.on_update_current_timestamp, true);
+
+        let dm2 = r#"
+            model Test {
+                id Int @id
+                /// When the test was last changed
+                updatedAt DateTime @updatedAt
+            }
+        "#;
+
+        let result = infer_and_apply(test_setup, api, &dm2).sql_schema;
+        let column = result.table_bang("Test").column_bang("updatedAt");
+        assert_eq!(column.comment, Some("When the test was last changed".to_owned()));
+        assert_eq!(column.on_update_current_timestamp, true);
     });
 }
 
@@ -239,8 +749,10 @@ fn changing_the_type_of_an_id_field_must_work() {
                 },
                 columns: vec![column.name.clone()],
                 referenced_table: "B".to_string(),
+                referenced_schema: None,
                 referenced_columns: vec!["id".to_string()],
                 on_delete_action: ForeignKeyAction::SetNull,
+                on_update_action: ForeignKeyAction::NoAction,
             }]
         );
 
@@ -267,8 +779,10 @@ fn changing_the_type_of_an_id_field_must_work() {
                 },
                 columns: vec![column.name.clone()],
                 referenced_table: "B".to_string(),
+                referenced_schema: None,
                 referenced_columns: vec!["id".to_string()],
                 on_delete_action: ForeignKeyAction::SetNull,
+                on_update_action: ForeignKeyAction::NoAction,
             }]
         );
     });
@@ -326,8 +840,10 @@ fn changing_a_relation_field_to_a_scalar_field_must_work() {
                 },
                 columns: vec![column.name.clone()],
                 referenced_table: "B".to_string(),
+                referenced_schema: None,
                 referenced_columns: vec!["id".to_string()],
                 on_delete_action: ForeignKeyAction::SetNull,
+                on_update_action: ForeignKeyAction::NoAction,
             }]
         );
 
@@ -390,8 +906,10 @@ fn changing_a_scalar_field_to_a_relation_field_must_work() {
                 },
                 columns: vec![column.name.clone()],
                 referenced_table: "B".to_string(),
+                referenced_schema: None,
                 referenced_columns: vec!["id".to_string()],
                 on_delete_action: ForeignKeyAction::SetNull,
+                on_update_action: ForeignKeyAction::NoAction,
             }]
         );
     });
@@ -432,8 +950,10 @@ fn adding_a_many_to_many_relation_must_result_in_a_prisma_style_relation_table()
                     },
                     columns: vec![aColumn.name.clone()],
                     referenced_table: "A".to_string(),
+                    referenced_schema: None,
                     referenced_columns: vec!["id".to_string()],
                     on_delete_action: ForeignKeyAction::Cascade,
+                    on_update_action: ForeignKeyAction::NoAction,
                 },
                 ForeignKey {
                     constraint_name: match test_setup.sql_family {
@@ -443,8 +963,10 @@ fn adding_a_many_to_many_relation_must_result_in_a_prisma_style_relation_table()
                     },
                     columns: vec![bColumn.name.clone()],
                     referenced_table: "B".to_string(),
+                    referenced_schema: None,
                     referenced_columns: vec!["id".to_string()],
                     on_delete_action: ForeignKeyAction::Cascade,
+                    on_update_action: ForeignKeyAction::NoAction,
                 },
             ]
         );
@@ -485,8 +1007,10 @@ fn adding_a_many_to_many_relation_with_custom_name_must_work() {
                     },
                     columns: vec![aColumn.name.clone()],
                     referenced_table: "A".to_string(),
+                    referenced_schema: None,
                     referenced_columns: vec!["id".to_string()],
                     on_delete_action: ForeignKeyAction::Cascade,
+                    on_update_action: ForeignKeyAction::NoAction,
                 },
                 ForeignKey {
                     constraint_name: match test_setup.sql_family {
@@ -496,8 +1020,10 @@ fn adding_a_many_to_many_relation_with_custom_name_must_work() {
                     },
                     columns: vec![bColumn.name.clone()],
                     referenced_table: "B".to_string(),
+                    referenced_schema: None,
                     referenced_columns: vec!["id".to_string()],
                     on_delete_action: ForeignKeyAction::Cascade,
+                    on_update_action: ForeignKeyAction::NoAction,
                 }
             ]
         );
@@ -559,8 +1085,10 @@ fn adding_an_inline_relation_must_result_in_a_foreign_key_in_the_model_table() {
                 },
                 columns: vec![column.name.clone()],
                 referenced_table: "B".to_string(),
+                referenced_schema: None,
                 referenced_columns: vec!["id".to_string()],
                 on_delete_action: ForeignKeyAction::SetNull,
+                on_update_action: ForeignKeyAction::NoAction,
             }]
         );
     });
@@ -593,8 +1121,10 @@ fn specifying_a_db_name_for_an_inline_relation_must_work() {
                 },
                 columns: vec![column.name.clone()],
                 referenced_table: "B".to_string(),
+                referenced_schema: None,
                 referenced_columns: vec!["id".to_string()],
                 on_delete_action: ForeignKeyAction::SetNull,
+                on_update_action: ForeignKeyAction::NoAction,
             }]
         );
     });
@@ -627,8 +1157,10 @@ fn adding_an_inline_relation_to_a_model_with_an_exotic_id_type() {
                 },
                 columns: vec![column.name.clone()],
                 referenced_table: "B".to_string(),
+                referenced_schema: None,
                 referenced_columns: vec!["id".to_string()],
                 on_delete_action: ForeignKeyAction::SetNull,
+                on_update_action: ForeignKeyAction::NoAction,
             }]
         );
     });
@@ -691,8 +1223,10 @@ fn moving_an_inline_relation_to_the_other_side_must_work() {
                 },
                 columns: vec!["b".to_string()],
                 referenced_table: "B".to_string(),
+                referenced_schema: None,
                 referenced_columns: vec!["id".to_string()],
                 on_delete_action: ForeignKeyAction::SetNull,
+                on_update_action: ForeignKeyAction::NoAction,
             }]
         );
 
@@ -718,8 +1252,10 @@ fn moving_an_inline_relation_to_the_other_side_must_work() {
                 },
                 columns: vec!["a".to_string()],
                 referenced_table: "A".to_string(),
+                referenced_schema: None,
                 referenced_columns: vec!["id".to_string()],
                 on_delete_action: ForeignKeyAction::SetNull,
+                on_update_action: ForeignKeyAction::NoAction,
             }]
         );
     });
@@ -1065,6 +1601,7 @@ fn index_renaming_must_work() {
                 table: "A".into(),
                 index_new_name: "customNameA".into(),
                 index_name: "customName".into(),
+                schema: None,
             })];
             let actual_steps = result.sql_migration();
             assert_eq!(actual_steps, expected_steps);
@@ -1118,6 +1655,7 @@ fn index_renaming_must_work_when_renaming_to_default() {
                 table: "A".into(),
                 index_new_name: "A.field_secondField".into(),
                 index_name: "customName".into(),
+                schema: None,
             })];
             let actual_steps = result.sql_migration();
             assert_eq!(actual_steps, expected_steps);
@@ -1171,6 +1709,7 @@ fn index_renaming_must_work_when_renaming_to_custom() {
                 table: "A".into(),
                 index_name: "A.field_secondField".into(),
                 index_new_name: "somethingCustom".into(),
+                schema: None,
             })];
             let actual_steps = result.sql_migration();
             assert_eq!(actual_steps, expected_steps);
@@ -1223,6 +1762,7 @@ fn index_updates_with_rename_must_work() {
                 SqlMigrationStep::DropIndex(DropIndex {
                     table: "A".into(),
                     name: "customName".into(),
+                    schema: None,
                 }),
                 SqlMigrationStep::CreateIndex(CreateIndex {
                     table: "A".into(),
@@ -1231,6 +1771,7 @@ fn index_updates_with_rename_must_work() {
                         columns: vec!["field".into(), "id".into()],
                         tpe: IndexType::Unique,
                     },
+                    schema: None,
                 }),
             ];
             let actual_steps = result.sql_migration();
@@ -1347,8 +1888,10 @@ fn reserved_sql_key_words_must_work() {
                 },
                 columns: vec!["parent".to_string()],
                 referenced_table: "Group".to_string(),
+                referenced_schema: None,
                 referenced_columns: vec!["id".to_string()],
                 on_delete_action: ForeignKeyAction::SetNull,
+                on_update_action: ForeignKeyAction::NoAction,
             }]
         );
     });
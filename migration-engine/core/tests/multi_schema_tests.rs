@@ -0,0 +1,93 @@
+mod test_harness;
+
+use pretty_assertions::assert_eq;
+use test_harness::*;
+
+const DATAMODEL: &str = r#"
+    model User {
+        id       Int       @id
+        invoices Invoice[]
+        posts    Post[]
+
+        @@schema("auth")
+    }
+
+    model Invoice {
+        id     Int  @id
+        amount Int
+        user   User
+
+        @@schema("billing")
+    }
+
+    model Post {
+        id     Int  @id
+        author User
+    }
+"#;
+
+#[test]
+fn models_must_be_migrated_into_their_schemas() {
+    let api = postgres_test_api_with_schemas(&["auth", "billing"]);
+
+    api.infer_and_apply(DATAMODEL);
+
+    let schema = api.introspect_database_schemas(&["auth", "billing"]);
+
+    let user = schema.get_table_in_schema(Some("auth"), "User").expect("User table");
+    assert!(schema.get_table_in_schema(None, "User").is_none());
+
+    let invoice = schema
+        .get_table_in_schema(Some("billing"), "Invoice")
+        .expect("Invoice table");
+    assert_eq!(invoice.foreign_keys.len(), 1);
+    assert_eq!(invoice.foreign_keys[0].referenced_table, user.name);
+    assert_eq!(
+        invoice.foreign_keys[0].referenced_schema.as_ref().map(String::as_str),
+        Some("auth")
+    );
+
+    let post = schema.get_table_in_schema(None, "Post").expect("Post table");
+    assert_eq!(post.foreign_keys.len(), 1);
+    assert_eq!(
+        post.foreign_keys[0].referenced_schema.as_ref().map(String::as_str),
+        Some("auth")
+    );
+}
+
+#[test]
+fn migrating_the_same_multi_schema_datamodel_twice_must_be_a_noop() {
+    let api = postgres_test_api_with_schemas(&["auth", "billing"]);
+
+    api.infer_and_apply(DATAMODEL);
+    let output = api.infer_and_apply(DATAMODEL);
+
+    assert!(output.sql_migration().is_empty());
+}
+
+#[test]
+fn moving_a_model_between_schemas_must_recreate_its_table() {
+    let api = postgres_test_api_with_schemas(&["auth", "billing"]);
+
+    api.infer_and_apply(DATAMODEL);
+
+    let dm2 = DATAMODEL.replace(r#"@@schema("billing")"#, r#"@@schema("auth")"#);
+    api.infer_and_apply(&dm2);
+
+    let schema = api.introspect_database_schemas(&["auth", "billing"]);
+
+    assert!(schema.get_table_in_schema(Some("billing"), "Invoice").is_none());
+    assert!(schema.get_table_in_schema(Some("auth"), "Invoice").is_some());
+}
+
+#[test]
+fn a_freshly_migrated_multi_schema_database_must_not_drift() {
+    let api = postgres_test_api_with_schemas(&["auth", "billing"]);
+
+    api.infer_and_apply(DATAMODEL);
+
+    let output = api.check_drift();
+
+    assert_eq!(output.drift, vec![]);
+    assert!(!output.has_drift);
+}
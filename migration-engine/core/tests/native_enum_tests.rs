@@ -0,0 +1,230 @@
+mod test_harness;
+
+use migration_connector::MigrationWarning;
+use pretty_assertions::assert_eq;
+use sql_migration_connector::{PrettySqlMigrationStep, SqlFamily};
+use sql_schema_describer::ColumnTypeFamily;
+use test_harness::*;
+
+fn rendered_steps(output: &InferAndApplyOutput) -> Vec<PrettySqlMigrationStep> {
+    serde_json::from_value(output.migration_output.database_steps.clone()).unwrap()
+}
+
+fn insert_cat(api: &TestApi, id: &str, mood: &str) {
+    let sql = match api.sql_family() {
+        SqlFamily::Mysql => format!(
+            "INSERT INTO `{}`.`Cat` (`id`, `mood`) VALUES ('{}', '{}')",
+            SCHEMA_NAME, id, mood
+        ),
+        _ => format!(
+            "INSERT INTO \"{}\".\"Cat\" (\"id\", \"mood\") VALUES ('{}', '{}')",
+            SCHEMA_NAME, id, mood
+        ),
+    };
+
+    api.database().query_raw(&sql, &[]).unwrap();
+}
+
+#[test_one_connector(connector = "postgres")]
+fn enums_must_be_created_as_native_types_on_postgres(api: &TestApi) {
+    let dm = r#"
+        model Cat {
+            id   String @id
+            mood Mood
+        }
+
+        enum Mood {
+            HAPPY
+            HUNGRY
+        }
+    "#;
+    let output = api.infer_and_apply(&dm);
+
+    let steps = rendered_steps(&output);
+    assert_eq!(
+        steps[0].raw,
+        format!("CREATE TYPE \"{}\".\"Mood\" AS ENUM ('HAPPY', 'HUNGRY')", SCHEMA_NAME)
+    );
+
+    let schema = output.sql_schema;
+    assert_eq!(schema.get_enum("Mood").unwrap().values, &["HAPPY", "HUNGRY"]);
+    assert_eq!(
+        schema.table_bang("Cat").column_bang("mood").tpe.family,
+        ColumnTypeFamily::Enum("Mood".to_owned())
+    );
+
+    // Migrating to the same datamodel again must not produce any steps.
+    let output = api.infer_and_apply_with_migration_id(&dm, "the-second-migration");
+    assert!(output.sql_migration().is_empty());
+}
+
+#[test_one_connector(connector = "postgres")]
+fn adding_an_enum_value_must_alter_the_type_in_place_on_postgres(api: &TestApi) {
+    let dm1 = r#"
+        model Cat {
+            id   String @id
+            mood Mood
+        }
+
+        enum Mood {
+            HAPPY
+        }
+    "#;
+    api.infer_and_apply(&dm1);
+    insert_cat(api, "felix", "HAPPY");
+
+    let dm2 = r#"
+        model Cat {
+            id   String @id
+            mood Mood
+        }
+
+        enum Mood {
+            HAPPY
+            HUNGRY
+        }
+    "#;
+    let output = api.infer_and_apply_with_migration_id(&dm2, "the-second-migration");
+
+    let steps = rendered_steps(&output);
+    assert_eq!(steps.len(), 1);
+    assert_eq!(
+        steps[0].raw,
+        format!("ALTER TYPE \"{}\".\"Mood\" ADD VALUE 'HUNGRY'", SCHEMA_NAME)
+    );
+    // Postgres does not allow adding enum values in a transaction block.
+    assert!(!steps[0].step.can_run_in_transaction());
+    assert!(output.migration_output.warnings.is_empty());
+    assert_eq!(output.sql_schema.get_enum("Mood").unwrap().values, &["HAPPY", "HUNGRY"]);
+}
+
+#[test_one_connector(connector = "postgres")]
+fn removing_an_unused_enum_value_must_recreate_the_type_on_postgres(api: &TestApi) {
+    let dm1 = r#"
+        model Cat {
+            id   String @id
+            mood Mood
+        }
+
+        enum Mood {
+            HAPPY
+            HUNGRY
+        }
+    "#;
+    api.infer_and_apply(&dm1);
+    insert_cat(api, "felix", "HAPPY");
+
+    let dm2 = r#"
+        model Cat {
+            id   String @id
+            mood Mood
+        }
+
+        enum Mood {
+            HAPPY
+        }
+    "#;
+    let output = api.infer_and_apply_with_migration_id(&dm2, "the-second-migration");
+
+    assert!(output.migration_output.warnings.is_empty());
+    let schema = output.sql_schema;
+    assert_eq!(schema.get_enum("Mood").unwrap().values, &["HAPPY"]);
+    assert!(schema.get_enum("Mood_old").is_none());
+    assert_eq!(
+        schema.table_bang("Cat").column_bang("mood").tpe.family,
+        ColumnTypeFamily::Enum("Mood".to_owned())
+    );
+}
+
+#[test_one_connector(connector = "mysql")]
+fn enums_must_be_inline_column_types_on_mysql(api: &TestApi) {
+    let dm = r#"
+        model Cat {
+            id   String @id
+            mood Mood
+        }
+
+        enum Mood {
+            HAPPY
+            HUNGRY
+        }
+    "#;
+    let schema = api.infer_and_apply(&dm).sql_schema;
+
+    let column = schema.table_bang("Cat").column_bang("mood");
+    assert_eq!(column.tpe.raw, "enum('HAPPY','HUNGRY')");
+    assert_eq!(column.tpe.family, ColumnTypeFamily::Enum("Cat_mood".to_owned()));
+    assert!(schema.enums.is_empty());
+}
+
+#[test_each_connector(ignore = "sqlite")]
+fn removing_an_enum_value_that_is_still_used_must_warn(api: &TestApi) {
+    let dm1 = r#"
+        model Cat {
+            id   String @id
+            mood Mood
+        }
+
+        enum Mood {
+            HAPPY
+            HUNGRY
+        }
+    "#;
+    let original_database_schema = api.infer_and_apply(&dm1).sql_schema;
+    insert_cat(api, "felix", "HAPPY");
+    insert_cat(api, "garfield", "HUNGRY");
+
+    let dm2 = r#"
+        model Cat {
+            id   String @id
+            mood Mood
+        }
+
+        enum Mood {
+            HAPPY
+        }
+    "#;
+    let InferAndApplyOutput {
+        migration_output,
+        sql_schema: final_database_schema,
+    } = api.infer_and_apply_with_migration_id(&dm2, "the-second-migration");
+
+    assert_eq!(original_database_schema, final_database_schema);
+    assert_eq!(
+        migration_output.warnings,
+        &[MigrationWarning {
+            description: "You are about to alter the column `mood` on the `Cat` table, which contains 1 values that are not compatible with the new column definition. Converting them may fail or lose data.".into()
+        }]
+    );
+}
+
+#[test_one_connector(connector = "mysql")]
+fn inline_enums_with_the_same_values_must_not_be_altered_on_mysql(api: &TestApi) {
+    let dm1 = r#"
+        model Cat {
+            id   String @id
+            mood Mood
+        }
+
+        enum Mood {
+            HAPPY
+            HUNGRY
+        }
+    "#;
+    api.infer_and_apply(&dm1);
+
+    let dm2 = r#"
+        model Cat {
+            id   String  @id
+            mood Feeling
+        }
+
+        enum Feeling {
+            HAPPY
+            HUNGRY
+        }
+    "#;
+    let output = api.infer_and_apply_with_migration_id(&dm2, "the-second-migration");
+
+    assert!(rendered_steps(&output).is_empty());
+}
@@ -0,0 +1,188 @@
+mod test_harness;
+
+use migration_connector::{DataMigration, DataMigrationAction};
+use migration_core::commands::ApplyMigrationInput;
+use pretty_assertions::assert_eq;
+use quaint::ast::*;
+use sql_migration_connector::{PrettySqlMigrationStep, SqlMigrationStep};
+use sql_schema_describer::{ColumnArity, IndexType};
+use test_harness::*;
+
+fn rendered_steps(output: &InferAndApplyOutput) -> Vec<PrettySqlMigrationStep> {
+    serde_json::from_value(output.migration_output.database_steps.clone()).unwrap()
+}
+
+#[test_each_connector]
+fn online_migrations_must_end_in_the_same_schema(api: &TestApi) {
+    let dm1 = r#"
+        model Test {
+            id   String @id
+            name String
+        }
+    "#;
+    api.infer_and_apply(&dm1);
+
+    let dm2 = r#"
+        model Test {
+            id       String @id
+            name     String @unique
+            category String @default("none")
+        }
+    "#;
+    let result = api.infer_and_apply_online(&dm2).sql_schema;
+
+    let table = result.table_bang("Test");
+    assert_eq!(table.column_bang("category").arity, ColumnArity::Required);
+    let index = table.indices.iter().find(|index| index.columns == &["name"]).unwrap();
+    assert_eq!(index.tpe, IndexType::Unique);
+}
+
+#[test_one_connector(connector = "postgres")]
+fn online_migrations_on_postgres_must_create_indexes_concurrently_and_add_columns_in_two_phases(api: &TestApi) {
+    let dm1 = r#"
+        model Test {
+            id   String @id
+            name String
+        }
+    "#;
+    api.infer_and_apply(&dm1);
+
+    let dm2 = r#"
+        model Test {
+            id       String @id
+            name     String @unique
+            category String @default("none")
+        }
+    "#;
+    let output = api.infer_and_apply_online(&dm2);
+    let steps = rendered_steps(&output);
+
+    let create_index = steps
+        .iter()
+        .find(|step| step.raw.starts_with("CREATE UNIQUE INDEX CONCURRENTLY"))
+        .expect("The index must be created concurrently.");
+    assert!(!create_index.step.can_run_in_transaction());
+
+    let raw_steps: Vec<&str> = steps.iter().map(|step| step.raw.as_str()).collect();
+    let add_constraint = raw_steps
+        .iter()
+        .position(|raw| raw.contains("ADD CONSTRAINT \"Test_category_not_null\"") && raw.ends_with("NOT VALID"))
+        .unwrap();
+    let set_not_null = raw_steps
+        .iter()
+        .position(|raw| raw.contains("ALTER COLUMN \"category\" SET NOT NULL"))
+        .unwrap();
+    assert!(add_constraint < set_not_null);
+
+    assert_eq!(
+        output.sql_schema.table_bang("Test").column_bang("category").arity,
+        ColumnArity::Required
+    );
+}
+
+#[test_one_connector(connector = "postgres")]
+fn online_migrations_on_postgres_must_backfill_new_required_columns_with_their_default(api: &TestApi) {
+    let dm1 = r#"
+        model Test {
+            id   String @id
+            name String
+        }
+    "#;
+    api.infer_and_apply(&dm1);
+
+    let insert = Insert::single_into((SCHEMA_NAME, "Test"))
+        .value("id", "a")
+        .value("name", "Ada");
+    api.database().execute(insert.into()).unwrap();
+
+    let dm2 = r#"
+        model Test {
+            id       String @id
+            name     String
+            category String @default("none")
+        }
+    "#;
+    let output = api.infer_and_apply_online(&dm2);
+
+    let backfill = rendered_steps(&output)
+        .into_iter()
+        .find_map(|step| match step.step {
+            SqlMigrationStep::DataMigration(DataMigration {
+                action: DataMigrationAction::Backfill { condition, .. },
+                ..
+            }) => Some(condition),
+            _ => None,
+        })
+        .expect("The new column must be backfilled.");
+    assert_eq!(backfill.as_ref().map(String::as_str), Some("\"category\" IS NULL"));
+
+    let query = Select::from_table((SCHEMA_NAME, "Test")).so_that("id".equals("a"));
+    let result_set = api.database().query(query.into()).unwrap();
+    let row = result_set.into_iter().next().expect("query returned no results");
+    assert_eq!(row["category"].as_str().unwrap(), "none");
+}
+
+#[test_one_connector(connector = "postgres")]
+fn online_migrations_on_postgres_must_reject_new_required_columns_without_default(api: &TestApi) {
+    let dm1 = r#"
+        model Test {
+            id   String @id
+            name String
+        }
+    "#;
+    api.infer_and_apply(&dm1);
+
+    let dm2 = r#"
+        model Test {
+            id       String @id
+            name     String
+            category String
+        }
+    "#;
+    let steps = api.infer_steps(&dm2, "the-online-migration-id");
+    let input = ApplyMigrationInput {
+        migration_id: "the-online-migration-id".to_owned(),
+        steps,
+        force: None,
+        online: Some(true),
+        data_migrations: Vec::new(),
+        lock_timeout: None,
+        local_migrations: Vec::new(),
+    };
+    let error = api.try_apply_migration(&input).unwrap_err();
+
+    assert!(format!("{}", error).contains("without a default"), "{}", error);
+    assert!(api
+        .introspect_database()
+        .table_bang("Test")
+        .column("category")
+        .is_none());
+}
+
+#[test_one_connector(connector = "mysql")]
+fn online_migrations_on_mysql_must_alter_tables_in_place(api: &TestApi) {
+    let dm1 = r#"
+        model Test {
+            id   String @id
+            name String
+        }
+    "#;
+    api.infer_and_apply(&dm1);
+
+    let dm2 = r#"
+        model Test {
+            id       String @id
+            name     String
+            category String?
+        }
+    "#;
+    let steps = rendered_steps(&api.infer_and_apply_online(&dm2));
+
+    match &steps[..] {
+        [PrettySqlMigrationStep {
+            step: SqlMigrationStep::RawSql { raw },
+            ..
+        }] => assert!(raw.ends_with(", ALGORITHM=INPLACE, LOCK=NONE"), raw.clone()),
+        steps => panic!("Unexpected steps: {:?}", steps),
+    }
+}
@@ -0,0 +1,120 @@
+mod test_harness;
+
+use migration_connector::{MigrationStatus, MigrationStep};
+use migration_core::commands::SquashMigrationsInput;
+use pretty_assertions::assert_eq;
+use quaint::ast::*;
+use test_harness::*;
+
+const DM_1: &str = r#"
+    model Cat {
+        id   Int    @id
+        name String
+    }
+"#;
+
+const DM_2: &str = r#"
+    model Cat {
+        id   Int    @id
+        name String
+        age  Int?
+    }
+"#;
+
+const DM_3: &str = r#"
+    model Cat {
+        id   Int    @id
+        name String
+        age  Int?
+    }
+
+    model Dog {
+        id Int @id
+    }
+"#;
+
+fn migration_names(api: &TestApi) -> Vec<String> {
+    api.migration_persistence()
+        .load_all()
+        .into_iter()
+        .map(|migration| migration.name)
+        .collect()
+}
+
+#[test_each_connector]
+fn squashing_must_replace_the_first_migrations_with_a_baseline(api: &TestApi) {
+    api.infer_and_apply_with_migration_id(DM_1, "first");
+    api.infer_and_apply_with_migration_id(DM_2, "second");
+    api.infer_and_apply_with_migration_id(DM_3, "third");
+    let second = api.migration_persistence().by_name("second").unwrap();
+
+    let output = api.squash_migrations(&SquashMigrationsInput {
+        baseline_migration_id: "baseline".to_owned(),
+        to_migration_id: Some("second".to_owned()),
+        lock_timeout: None,
+    });
+
+    assert_eq!(output.squashed_migration_ids, &["first", "second"]);
+    assert_eq!(migration_names(api), &["baseline", "third"]);
+
+    let baseline = api.migration_persistence().by_name("baseline").unwrap();
+    assert_eq!(baseline.status, MigrationStatus::MigrationSuccess);
+    assert_eq!(baseline.datamodel, second.datamodel);
+    assert_eq!(baseline.revision, second.revision);
+    assert_eq!(baseline.datamodel_steps, output.datamodel_steps);
+    assert!(output.datamodel_steps.iter().any(|step| match step {
+        MigrationStep::CreateModel(create_model) => create_model.model == "Cat",
+        _ => false,
+    }));
+}
+
+#[test_each_connector]
+fn squashing_all_migrations_must_keep_the_history_usable(api: &TestApi) {
+    api.infer_and_apply_with_migration_id(DM_1, "first");
+    api.infer_and_apply_with_migration_id(DM_2, "second");
+
+    let output = api.squash_migrations(&SquashMigrationsInput {
+        baseline_migration_id: "baseline".to_owned(),
+        to_migration_id: None,
+        lock_timeout: None,
+    });
+
+    assert_eq!(output.squashed_migration_ids, &["first", "second"]);
+    assert_eq!(migration_names(api), &["baseline"]);
+
+    // The next migration is inferred from the datamodel of the baseline.
+    let result = api.infer_and_apply_with_migration_id(DM_3, "third");
+
+    assert!(result.sql_schema.has_table("Dog"));
+    assert_eq!(migration_names(api), &["baseline", "third"]);
+    assert!(api.check_drift().drift.is_empty());
+}
+
+#[test_each_connector]
+fn squashed_migrations_must_be_kept_and_marked_as_squashed(api: &TestApi) {
+    api.infer_and_apply_with_migration_id(DM_1, "first");
+    api.infer_and_apply_with_migration_id(DM_2, "second");
+    api.infer_and_apply_with_migration_id(DM_3, "third");
+
+    api.squash_migrations(&SquashMigrationsInput {
+        baseline_migration_id: "baseline".to_owned(),
+        to_migration_id: Some("second".to_owned()),
+        lock_timeout: None,
+    });
+
+    let query = Select::from_table((SCHEMA_NAME, "_Migration"))
+        .column("name")
+        .so_that("squashed_into".equals("baseline"))
+        .order_by("name".ascend());
+    let squashed_names: Vec<String> = api
+        .database()
+        .query(query.into())
+        .unwrap()
+        .into_iter()
+        .map(|row| row["name"].to_string().unwrap())
+        .collect();
+
+    assert_eq!(squashed_names, &["first", "second"]);
+    assert!(api.migration_persistence().by_name("first").is_none());
+    assert_eq!(migration_names(api), &["baseline", "third"]);
+}
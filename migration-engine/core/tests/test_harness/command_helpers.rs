@@ -70,6 +70,10 @@ pub fn apply_migration(
         migration_id: migration_id.to_string(),
         steps: steps,
         force: None,
+        online: None,
+        data_migrations: Vec::new(),
+        lock_timeout: None,
+        local_migrations: Vec::new(),
     };
 
     let migration_output = dbg!(api.apply_migration(&input)).expect("ApplyMigration failed");
@@ -95,7 +99,7 @@ pub struct UnapplyOutput {
 }
 
 pub fn unapply_migration(test_setup: &TestSetup, api: &dyn GenericApi) -> UnapplyOutput {
-    let input = UnapplyMigrationInput {};
+    let input = UnapplyMigrationInput { lock_timeout: None };
     let output = api.unapply_migration(&input).unwrap();
 
     let sql_schema = introspect_database(test_setup, api);
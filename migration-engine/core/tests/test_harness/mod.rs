@@ -6,7 +6,7 @@ mod step_helpers;
 mod test_api;
 
 pub use command_helpers::*;
-pub use migration_engine_macros::test_each_connector;
+pub use migration_engine_macros::{test_each_connector, test_one_connector};
 pub use misc_helpers::*;
 pub use step_helpers::*;
 pub use test_api::*;
@@ -35,3 +35,20 @@ pub fn create_model_step(model: &str) -> MigrationStep {
         model: model.to_string(),
     })
 }
+
+pub fn rename_model_step(model: &str, new_name: &str) -> MigrationStep {
+    MigrationStep::UpdateModel(UpdateModel {
+        model: model.to_string(),
+        new_name: Some(new_name.to_string()),
+    })
+}
+
+pub fn rename_field_step(model: &str, field: &str, new_name: &str) -> MigrationStep {
+    MigrationStep::UpdateField(UpdateField {
+        model: model.to_string(),
+        field: field.to_string(),
+        new_name: Some(new_name.to_string()),
+        tpe: None,
+        arity: None,
+    })
+}
@@ -6,13 +6,17 @@ use super::{
     },
     InferAndApplyOutput, SCHEMA_NAME,
 };
-use migration_connector::{MigrationPersistence, MigrationStep};
+use migration_connector::{DataMigration, MigrationLock, MigrationPersistence, MigrationStep};
 use migration_core::{
     api::GenericApi,
-    commands::{ApplyMigrationInput, InferMigrationStepsInput},
+    commands::{
+        ApplyMigrationInput, BaselineInput, BaselineOutput, CheckDriftOutput, ExportMigrationsInput,
+        ExportMigrationsOutput, InferMigrationStepsInput, ListMigrationStepsOutput, ListMigrationsInput,
+        MigrationStepsResultOutput, SquashMigrationsInput, SquashMigrationsOutput,
+    },
 };
 use sql_connection::SyncSqlConnection;
-use sql_migration_connector::SqlFamily;
+use sql_migration_connector::{SqlFamily, SqlMigrationConnector};
 use sql_schema_describer::*;
 use std::sync::Arc;
 
@@ -29,6 +33,10 @@ impl TestApi {
         &self.database
     }
 
+    pub fn sql_family(&self) -> SqlFamily {
+        self.sql_family
+    }
+
     pub fn is_sqlite(&self) -> bool {
         self.sql_family == SqlFamily::Sqlite
     }
@@ -37,14 +45,40 @@ impl TestApi {
         self.api.migration_persistence()
     }
 
+    pub fn migration_lock(&self) -> Arc<dyn MigrationLock> {
+        self.api.migration_lock()
+    }
+
     pub fn apply_migration(&self, steps: Vec<MigrationStep>, migration_id: &str) -> InferAndApplyOutput {
         let input = ApplyMigrationInput {
             migration_id: migration_id.to_string(),
             steps,
             force: None,
+            online: None,
+            data_migrations: Vec::new(),
+            lock_timeout: None,
+            local_migrations: Vec::new(),
+        };
+
+        self.run_apply_migration(&input)
+    }
+
+    pub fn apply_migration_online(&self, steps: Vec<MigrationStep>, migration_id: &str) -> InferAndApplyOutput {
+        let input = ApplyMigrationInput {
+            migration_id: migration_id.to_string(),
+            steps,
+            force: None,
+            online: Some(true),
+            data_migrations: Vec::new(),
+            lock_timeout: None,
+            local_migrations: Vec::new(),
         };
 
-        let migration_output = self.api.apply_migration(&input).expect("ApplyMigration failed");
+        self.run_apply_migration(&input)
+    }
+
+    fn run_apply_migration(&self, input: &ApplyMigrationInput) -> InferAndApplyOutput {
+        let migration_output = self.api.apply_migration(input).expect("ApplyMigration failed");
 
         assert!(
             migration_output.general_errors.is_empty(),
@@ -61,8 +95,10 @@ impl TestApi {
     }
 
     pub fn infer_and_apply(&self, datamodel: &str) -> InferAndApplyOutput {
-        let migration_id = "the-migration-id";
+        self.infer_and_apply_with_migration_id(datamodel, "the-migration-id")
+    }
 
+    pub fn infer_and_apply_with_migration_id(&self, datamodel: &str, migration_id: &str) -> InferAndApplyOutput {
         let input = InferMigrationStepsInput {
             migration_id: migration_id.to_string(),
             datamodel: datamodel.to_string(),
@@ -74,8 +110,160 @@ impl TestApi {
         self.apply_migration(steps, migration_id)
     }
 
-    fn introspect_database(&self) -> SqlSchema {
-        let inspector: Box<dyn SqlSchemaDescriberBackend> = match self.api.connector_type() {
+    /// Applies the migration with the data migrations. The migration is forced, because the data
+    /// usually gets migrated from fields the migration drops.
+    pub fn infer_and_apply_with_data_migrations(
+        &self,
+        datamodel: &str,
+        data_migrations: Vec<DataMigration>,
+    ) -> InferAndApplyOutput {
+        let migration_id = "the-migration-with-data-migrations";
+
+        let input = InferMigrationStepsInput {
+            migration_id: migration_id.to_string(),
+            datamodel: datamodel.to_string(),
+            assume_to_be_applied: Vec::new(),
+        };
+
+        let steps = run_infer_command(self.api.as_ref(), input).0.datamodel_steps;
+
+        self.run_apply_migration(&ApplyMigrationInput {
+            migration_id: migration_id.to_string(),
+            steps,
+            force: Some(true),
+            online: None,
+            data_migrations,
+            lock_timeout: None,
+            local_migrations: Vec::new(),
+        })
+    }
+
+    /// Infers the steps of the migration without applying them.
+    pub fn infer_steps(&self, datamodel: &str, migration_id: &str) -> Vec<MigrationStep> {
+        let input = InferMigrationStepsInput {
+            migration_id: migration_id.to_string(),
+            datamodel: datamodel.to_string(),
+            assume_to_be_applied: Vec::new(),
+        };
+
+        run_infer_command(self.api.as_ref(), input).0.datamodel_steps
+    }
+
+    pub fn try_apply_migration(
+        &self,
+        input: &ApplyMigrationInput,
+    ) -> migration_core::Result<MigrationStepsResultOutput> {
+        self.api.apply_migration(input)
+    }
+
+    /// Like `infer_and_apply`, but returns the error of the migration instead of panicking.
+    pub fn try_infer_and_apply(
+        &self,
+        datamodel: &str,
+        migration_id: &str,
+    ) -> migration_core::Result<MigrationStepsResultOutput> {
+        let input = InferMigrationStepsInput {
+            migration_id: migration_id.to_string(),
+            datamodel: datamodel.to_string(),
+            assume_to_be_applied: Vec::new(),
+        };
+
+        let steps = run_infer_command(self.api.as_ref(), input).0.datamodel_steps;
+
+        self.api.apply_migration(&ApplyMigrationInput {
+            migration_id: migration_id.to_string(),
+            steps,
+            force: None,
+            online: None,
+            data_migrations: Vec::new(),
+            lock_timeout: None,
+            local_migrations: Vec::new(),
+        })
+    }
+
+    pub fn infer_and_apply_online(&self, datamodel: &str) -> InferAndApplyOutput {
+        let migration_id = "the-online-migration-id";
+
+        let input = InferMigrationStepsInput {
+            migration_id: migration_id.to_string(),
+            datamodel: datamodel.to_string(),
+            assume_to_be_applied: Vec::new(),
+        };
+
+        let steps = run_infer_command(self.api.as_ref(), input).0.datamodel_steps;
+
+        self.apply_migration_online(steps, migration_id)
+    }
+
+    pub fn list_migrations(
+        &self,
+        input: &ListMigrationsInput,
+    ) -> migration_core::Result<Vec<ListMigrationStepsOutput>> {
+        self.api.list_migrations(input)
+    }
+
+    pub fn check_drift(&self) -> CheckDriftOutput {
+        self.api
+            .check_drift(&serde_json::Value::Null)
+            .expect("CheckDrift failed")
+    }
+
+    /// Empties the database, the migration history included.
+    pub fn reset(&self) {
+        self.api.reset(&serde_json::Value::Null).expect("Reset failed");
+    }
+
+    pub fn export_migrations(&self, input: &ExportMigrationsInput) -> ExportMigrationsOutput {
+        self.api.export_migrations(input).expect("ExportMigrations failed")
+    }
+
+    pub fn squash_migrations(&self, input: &SquashMigrationsInput) -> SquashMigrationsOutput {
+        self.api.squash_migrations(input).expect("SquashMigrations failed")
+    }
+
+    pub fn try_squash_migrations(
+        &self,
+        input: &SquashMigrationsInput,
+    ) -> migration_core::Result<SquashMigrationsOutput> {
+        self.api.squash_migrations(input)
+    }
+
+    pub fn baseline(&self, input: &BaselineInput) -> BaselineOutput {
+        self.api.baseline(input).expect("Baseline failed")
+    }
+
+    pub fn try_baseline(&self, input: &BaselineInput) -> migration_core::Result<BaselineOutput> {
+        self.api.baseline(input)
+    }
+
+    pub fn introspect_database(&self) -> SqlSchema {
+        let mut result = self
+            .describer()
+            .describe(&SCHEMA_NAME.to_string())
+            .expect("Introspection failed");
+
+        // the presence of the _Migration table makes assertions harder. Therefore remove it from the result.
+        result.tables = result.tables.into_iter().filter(|t| t.name != "_Migration").collect();
+
+        result
+    }
+
+    /// Describes the test schema together with the given other schemas.
+    pub fn introspect_database_schemas(&self, schemas: &[&str]) -> SqlSchema {
+        let schemas: Vec<String> = schemas.iter().map(|schema| schema.to_string()).collect();
+
+        let mut result = self
+            .describer()
+            .describe_schemas(SCHEMA_NAME, &schemas)
+            .expect("Introspection failed");
+
+        result.tables = result.tables.into_iter().filter(|t| t.name != "_Migration").collect();
+
+        result
+    }
+
+    fn describer(&self) -> Box<dyn SqlSchemaDescriberBackend> {
+        match self.api.connector_type() {
             "postgresql" => Box::new(sql_schema_describer::postgres::SqlSchemaDescriber::new(Arc::clone(
                 &self.database,
             ))),
@@ -86,16 +274,7 @@ impl TestApi {
                 &self.database,
             ))),
             _ => unimplemented!(),
-        };
-
-        let mut result = inspector
-            .describe(&SCHEMA_NAME.to_string())
-            .expect("Introspection failed");
-
-        // the presence of the _Migration table makes assertions harder. Therefore remove it from the result.
-        result.tables = result.tables.into_iter().filter(|t| t.name != "_Migration").collect();
-
-        result
+        }
     }
 }
 
@@ -129,6 +308,57 @@ pub fn postgres_test_api() -> TestApi {
     }
 }
 
+/// A Postgres test API whose connection pool is limited to a single connection.
+pub fn postgres_test_api_with_single_connection() -> TestApi {
+    // Makes sure the test database exists.
+    postgres_migration_connector(&postgres_url());
+
+    let connector =
+        SqlMigrationConnector::new_from_database_str(&format!("{}&connection_limit=1", postgres_url())).unwrap();
+
+    TestApi {
+        sql_family: SqlFamily::Postgres,
+        database: Arc::clone(&connector.database),
+        api: Box::new(test_api(connector)),
+    }
+}
+
+/// A Postgres test API whose datasource lists the given schemas next to the test schema. The
+/// schemas are dropped and recreated empty.
+pub fn postgres_test_api_with_schemas(schemas: &[&str]) -> TestApi {
+    // Makes sure the test database exists.
+    postgres_migration_connector(&postgres_url());
+
+    let schemas_list: Vec<String> = schemas.iter().map(|schema| format!("\"{}\"", schema)).collect();
+    let config = datamodel::parse_configuration(&format!(
+        r#"
+        datasource my_db {{
+            provider = "postgresql"
+            url = "{}"
+            schemas = [{}]
+            default = true
+        }}
+    "#,
+        postgres_url(),
+        schemas_list.join(", ")
+    ))
+    .unwrap();
+    let connector = SqlMigrationConnector::new(config.datasources[0].as_ref()).unwrap();
+
+    for schema in schemas {
+        connector
+            .database
+            .query_raw(&format!("DROP SCHEMA IF EXISTS \"{}\" CASCADE", schema), &[])
+            .unwrap();
+    }
+
+    TestApi {
+        sql_family: SqlFamily::Postgres,
+        database: Arc::clone(&connector.database),
+        api: Box::new(test_api(connector)),
+    }
+}
+
 pub fn sqlite_test_api() -> TestApi {
     let connector = sqlite_migration_connector();
 
@@ -0,0 +1,76 @@
+mod test_harness;
+
+use migration_connector::MigrationStatus;
+use pretty_assertions::assert_eq;
+use quaint::ast::*;
+use test_harness::*;
+
+#[test_each_connector(ignore = "mysql")]
+fn a_failing_migration_must_be_rolled_back_entirely(api: &TestApi) {
+    let dm1 = r#"
+        model Test {
+            id   String @id
+            name String
+        }
+    "#;
+    api.infer_and_apply(&dm1);
+
+    for id in &["first", "second"] {
+        let insert = Insert::single_into((SCHEMA_NAME, "Test"))
+            .value("id", *id)
+            .value("name", "duplicate");
+        api.database().execute(insert.into()).unwrap();
+    }
+
+    // Adding the column succeeds, but the unique index can't be created.
+    let dm2 = r#"
+        model Test {
+            id       String  @id
+            name     String  @unique
+            category String?
+        }
+    "#;
+    let result = api.try_infer_and_apply(&dm2, "the-failing-migration");
+    assert!(result.is_err());
+
+    let table = api.introspect_database().table_bang("Test").clone();
+    assert!(table.column("category").is_none());
+    assert!(table.indices.is_empty());
+
+    let migration = api.migration_persistence().by_name("the-failing-migration").unwrap();
+    assert_eq!(migration.status, MigrationStatus::MigrationFailure);
+    assert_eq!(migration.applied, 0);
+    assert_eq!(migration.errors.len(), 1);
+}
+
+#[test_each_connector(ignore = "mysql")]
+fn a_successful_migration_must_record_all_its_steps_as_applied(api: &TestApi) {
+    let dm = r#"
+        model Test {
+            id   String @id
+            name String @unique
+        }
+    "#;
+    let output = api.infer_and_apply(&dm).migration_output;
+    let database_steps = output.database_steps.as_array().unwrap().len();
+
+    let migration = api.migration_persistence().by_name("the-migration-id").unwrap();
+    assert_eq!(migration.status, MigrationStatus::MigrationSuccess);
+    assert_eq!(migration.applied, database_steps);
+}
+
+#[test_one_connector(connector = "mysql")]
+fn a_migration_applied_step_by_step_must_record_all_its_steps_as_applied(api: &TestApi) {
+    let dm = r#"
+        model Test {
+            id   String @id
+            name String @unique
+        }
+    "#;
+    let output = api.infer_and_apply(&dm).migration_output;
+    let database_steps = output.database_steps.as_array().unwrap().len();
+
+    let migration = api.migration_persistence().by_name("the-migration-id").unwrap();
+    assert_eq!(migration.status, MigrationStatus::MigrationSuccess);
+    assert_eq!(migration.applied, database_steps);
+}
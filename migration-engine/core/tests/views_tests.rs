@@ -0,0 +1,80 @@
+mod test_harness;
+
+use pretty_assertions::assert_eq;
+use sql_migration_connector::SqlFamily;
+use test_harness::*;
+
+const DATAMODEL: &str = r#"
+    model User {
+        id   Int    @id
+        name String
+    }
+
+    model UserName {
+        id   Int    @id
+        name String
+
+        @@view
+    }
+"#;
+
+fn create_view_manually(api: &TestApi) {
+    let sql = match api.sql_family() {
+        SqlFamily::Mysql => format!(
+            "CREATE VIEW `{0}`.`UserName` AS SELECT `id`, `name` FROM `{0}`.`User`",
+            SCHEMA_NAME
+        ),
+        SqlFamily::Postgres => format!(
+            "CREATE VIEW \"{0}\".\"UserName\" AS SELECT \"id\", \"name\" FROM \"{0}\".\"User\"",
+            SCHEMA_NAME
+        ),
+        // Views in SQLite can only refer to the tables of their own database.
+        SqlFamily::Sqlite => format!(
+            "CREATE VIEW \"{}\".\"UserName\" AS SELECT \"id\", \"name\" FROM \"User\"",
+            SCHEMA_NAME
+        ),
+    };
+
+    api.database().query_raw(&sql, &[]).unwrap();
+}
+
+#[test_each_connector]
+fn view_models_must_not_be_migrated(api: &TestApi) {
+    api.infer_and_apply(DATAMODEL);
+
+    let schema = api.introspect_database();
+
+    assert!(schema.has_table("User"));
+    assert!(!schema.has_table("UserName"));
+}
+
+#[test_each_connector]
+fn views_in_the_database_must_be_left_alone(api: &TestApi) {
+    api.infer_and_apply(DATAMODEL);
+    create_view_manually(api);
+
+    let output = api.infer_and_apply(DATAMODEL);
+    assert!(output.sql_migration().is_empty());
+
+    // Removing the model of the view doesn't drop it either.
+    let dm2 = r#"
+        model User {
+            id   Int    @id
+            name String
+        }
+    "#;
+    let output = api.infer_and_apply(dm2);
+    assert!(output.sql_migration().is_empty());
+
+    let schema = api.introspect_database();
+    let view = schema.get_view("UserName").expect("UserName view");
+    assert_eq!(
+        view.columns
+            .iter()
+            .map(|column| column.name.as_str())
+            .collect::<Vec<_>>(),
+        vec!["id", "name"]
+    );
+
+    assert_eq!(api.check_drift().drift, vec![]);
+}
@@ -50,8 +50,10 @@ impl<'a> DatamodelConverter<'a> {
             .map(|model| ModelTemplate {
                 name: model.name.clone(),
                 is_embedded: model.is_embedded,
+                is_view: model.is_view,
                 fields: self.convert_fields(model),
                 manifestation: model.database_name.clone(),
+                schema: model.schema.clone(),
             })
             .collect()
     }
@@ -97,6 +99,7 @@ impl<'a> DatamodelConverter<'a> {
                     behaviour: field.behaviour(),
                     default_value: field.default_value(),
                     internal_enum: field.internal_enum(self.datamodel),
+                    validators: field.validators(),
                 }),
             })
             .collect()
@@ -105,13 +108,22 @@ impl<'a> DatamodelConverter<'a> {
     fn convert_relations(&self) -> Vec<RelationTemplate> {
         self.relations
             .iter()
-            .map(|r| RelationTemplate {
-                name: r.name(),
-                model_a_on_delete: OnDelete::SetNull,
-                model_b_on_delete: OnDelete::SetNull,
-                manifestation: Some(r.manifestation()),
-                model_a_name: r.model_a.name.clone(),
-                model_b_name: r.model_b.name.clone(),
+            .map(|r| {
+                let (model_a_on_delete, model_b_on_delete) =
+                    r.referential_actions(|info| info.on_delete, ReferentialAction::SetNull);
+                let (model_a_on_update, model_b_on_update) =
+                    r.referential_actions(|info| info.on_update, ReferentialAction::NoAction);
+
+                RelationTemplate {
+                    name: r.name(),
+                    model_a_on_delete,
+                    model_b_on_delete,
+                    model_a_on_update,
+                    model_b_on_update,
+                    manifestation: Some(r.manifestation()),
+                    model_a_name: r.model_a.name.clone(),
+                    model_b_name: r.model_b.name.clone(),
+                }
             })
             .collect()
     }
@@ -289,6 +301,56 @@ impl TempRelationHolder {
         }
     }
 
+    /// Returns the actions for model A and model B. The referential actions of the datamodel belong to the
+    /// foreign key of an inline relation: they apply to the records holding the foreign key when the referenced
+    /// record is deleted or updated. They can be declared on either relation field.
+    fn referential_actions(
+        &self,
+        action: impl Fn(&dml::RelationInfo) -> dml::ReferentialAction,
+        default: ReferentialAction,
+    ) -> (ReferentialAction, ReferentialAction) {
+        let referencing_side = match &self.manifestation {
+            TempManifestationHolder::Inline {
+                in_table_of_model,
+                column,
+            } => {
+                if in_table_of_model == &self.model_a.name && column == &self.field_a.final_db_name() {
+                    RelationSide::A
+                } else {
+                    RelationSide::B
+                }
+            }
+            TempManifestationHolder::Table => return (default, default),
+        };
+
+        let (referencing_field, referenced_field) = match referencing_side {
+            RelationSide::A => (&self.field_a, &self.field_b),
+            RelationSide::B => (&self.field_b, &self.field_a),
+        };
+
+        let declared_action = vec![referencing_field, referenced_field]
+            .into_iter()
+            .filter_map(|field| match &field.field_type {
+                dml::FieldType::Relation(info) => Some(action(info)),
+                _ => None,
+            })
+            .find(|action| *action != dml::ReferentialAction::None);
+
+        let referential_action = match declared_action {
+            Some(dml::ReferentialAction::Cascade) => ReferentialAction::Cascade,
+            Some(dml::ReferentialAction::Restrict) => ReferentialAction::Restrict,
+            Some(dml::ReferentialAction::SetNull) => ReferentialAction::SetNull,
+            Some(dml::ReferentialAction::SetDefault) => ReferentialAction::SetDefault,
+            Some(dml::ReferentialAction::None) | None => default,
+        };
+
+        // The action is applied when a record of the referenced side changes.
+        match referencing_side {
+            RelationSide::A => (default, referential_action),
+            RelationSide::B => (referential_action, default),
+        }
+    }
+
     fn manifestation(&self) -> RelationLinkManifestation {
         match &self.manifestation {
             // TODO: relation table columns must get renamed: lowercased type names instead of A and B
@@ -320,6 +382,7 @@ trait DatamodelFieldExtensions {
     fn final_db_name(&self) -> String;
     fn internal_enum(&self, datamodel: &dml::Datamodel) -> Option<InternalEnum>;
     fn default_value(&self) -> Option<PrismaValue>;
+    fn validators(&self) -> Vec<FieldValidator>;
 }
 
 impl DatamodelFieldExtensions for dml::Field {
@@ -328,24 +391,24 @@ impl DatamodelFieldExtensions for dml::Field {
         match self.field_type {
             dml::FieldType::Enum(_) => TypeIdentifier::Enum,
             dml::FieldType::Relation(_) => TypeIdentifier::Relation,
-            dml::FieldType::Base(scalar) => match scalar {
-                dml::ScalarType::Boolean => TypeIdentifier::Boolean,
-                dml::ScalarType::DateTime => TypeIdentifier::DateTime,
-                dml::ScalarType::Decimal => TypeIdentifier::Float,
-                dml::ScalarType::Float => TypeIdentifier::Float,
-                dml::ScalarType::Int => TypeIdentifier::Int,
-                dml::ScalarType::String => match self.default_value {
-                    Some(datamodel::common::PrismaValue::Expression(ref expr, _, _)) if expr == "cuid" => {
-                        TypeIdentifier::GraphQLID
-                    }
-                    Some(datamodel::common::PrismaValue::Expression(ref expr, _, _)) if expr == "uuid" => {
-                        TypeIdentifier::UUID
-                    }
-                    _ => TypeIdentifier::String,
-                },
-            },
-            dml::FieldType::ConnectorSpecific { .. } => {
-                unimplemented!("Connector Specific types are not supported here yet")
+            dml::FieldType::Base(scalar) | dml::FieldType::ConnectorSpecific { base_type: scalar, .. } => {
+                match scalar {
+                    dml::ScalarType::Boolean => TypeIdentifier::Boolean,
+                    dml::ScalarType::DateTime => TypeIdentifier::DateTime,
+                    dml::ScalarType::Decimal => TypeIdentifier::Decimal,
+                    dml::ScalarType::Float => TypeIdentifier::Float,
+                    dml::ScalarType::Int => TypeIdentifier::Int,
+                    dml::ScalarType::BigInt => TypeIdentifier::BigInt,
+                    dml::ScalarType::String => match self.default_value {
+                        Some(datamodel::common::PrismaValue::Expression(ref expr, _, _)) if expr == "cuid" => {
+                            TypeIdentifier::GraphQLID
+                        }
+                        Some(datamodel::common::PrismaValue::Expression(ref expr, _, _)) if expr == "uuid" => {
+                            TypeIdentifier::UUID
+                        }
+                        _ => TypeIdentifier::String,
+                    },
+                }
             }
         }
     }
@@ -392,6 +455,10 @@ impl DatamodelFieldExtensions for dml::Field {
                 Some(datamodel::common::PrismaValue::Expression(ref expr, _, _)) if expr == "now" => {
                     Some(FieldBehaviour::CreatedAt)
                 }
+                // case: @default(dbgenerated("..."))
+                Some(datamodel::common::PrismaValue::Expression(ref expr, _, _)) if expr == "dbgenerated" => {
+                    Some(FieldBehaviour::DbGenerated)
+                }
                 _ => None,
             })
             .or_else(|| {
@@ -436,14 +503,25 @@ impl DatamodelFieldExtensions for dml::Field {
         self.default_value.as_ref().and_then(|v| match v {
             datamodel::common::PrismaValue::Boolean(x) => Some(PrismaValue::Boolean(*x)),
             datamodel::common::PrismaValue::Int(x) => Some(PrismaValue::Int(i64::from(*x))),
+            datamodel::common::PrismaValue::BigInt(x) => Some(PrismaValue::Int(*x)),
             datamodel::common::PrismaValue::Float(x) => Some(PrismaValue::Float(f64::from(*x))),
             datamodel::common::PrismaValue::String(x) => Some(PrismaValue::String(x.clone())),
             datamodel::common::PrismaValue::DateTime(x) => Some(PrismaValue::DateTime(*x)),
-            datamodel::common::PrismaValue::Decimal(x) => Some(PrismaValue::Float(f64::from(*x))), // TODO: not sure if this mapping is correct
+            datamodel::common::PrismaValue::Decimal(x) => Some(PrismaValue::Decimal(*x)),
             datamodel::common::PrismaValue::ConstantLiteral(x) => {
                 Some(PrismaValue::Enum(EnumValue::string(x.clone(), x.clone())))
             }
             datamodel::common::PrismaValue::Expression(_, _, _) => None, // expressions are handled in the behaviour function right now
         })
     }
+
+    fn validators(&self) -> Vec<FieldValidator> {
+        self.validators
+            .iter()
+            .map(|validator| match validator {
+                dml::FieldValidator::Length { min, max } => FieldValidator::Length { min: *min, max: *max },
+                dml::FieldValidator::Range { min, max } => FieldValidator::Range { min: *min, max: *max },
+            })
+            .collect()
+    }
 }
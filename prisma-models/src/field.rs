@@ -32,6 +32,7 @@ pub struct FieldManifestation {
 pub enum TypeIdentifier {
     String,
     Float,
+    Decimal,
     Boolean,
     Enum,
     Json,
@@ -39,6 +40,7 @@ pub enum TypeIdentifier {
     GraphQLID,
     UUID,
     Int,
+    BigInt,
     Relation,
 }
 
@@ -126,6 +128,7 @@ impl FieldTemplate {
                     behaviour: st.behaviour,
                     model,
                     default_value: st.default_value,
+                    validators: st.validators,
                 };
 
                 Field::Scalar(Arc::new(scalar))
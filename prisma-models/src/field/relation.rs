@@ -154,12 +154,8 @@ impl RelationField {
 
     pub fn as_column(&self) -> Column<'static> {
         let model = self.model();
-        let internal_data_model = model.internal_data_model();
         let db_name = self.db_name();
-        let parts = (
-            (internal_data_model.db_name.clone(), model.db_name().to_string()),
-            db_name.clone(),
-        );
+        let parts = ((model.schema_name(), model.db_name().to_string()), db_name.clone());
 
         parts.into()
     }
@@ -178,6 +174,23 @@ impl RelationField {
         }
     }
 
+    /// The action applied to the records connected through this field when a record of its model is deleted.
+    pub fn on_delete(&self) -> ReferentialAction {
+        match self.relation_side {
+            RelationSide::A => self.relation().model_a_on_delete,
+            RelationSide::B => self.relation().model_b_on_delete,
+        }
+    }
+
+    /// The action applied to the records connected through this field when the referenced fields of a
+    /// record of its model are updated.
+    pub fn on_update(&self) -> ReferentialAction {
+        match self.relation_side {
+            RelationSide::A => self.relation().model_a_on_update,
+            RelationSide::B => self.relation().model_b_on_update,
+        }
+    }
+
     pub fn is_relation_with_name_and_side(&self, relation_name: &str, side: RelationSide) -> bool {
         self.relation().name == relation_name && self.relation_side == side
     }
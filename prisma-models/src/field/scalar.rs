@@ -3,6 +3,7 @@ use quaint::ast::*;
 use super::FieldManifestation;
 use crate::prelude::*;
 // use serde::Deserializer;
+use std::cmp::Ordering;
 use std::sync::{Arc, Weak};
 
 static ID_FIELD: &str = "id";
@@ -29,6 +30,9 @@ pub struct ScalarFieldTemplate {
 
     #[serde(rename = "enum")]
     pub internal_enum: Option<InternalEnum>,
+
+    #[serde(default)]
+    pub validators: Vec<FieldValidator>,
 }
 
 #[derive(DebugStub)]
@@ -45,6 +49,7 @@ pub struct ScalarField {
     #[debug_stub = "#ModelWeakRef#"]
     pub model: ModelWeakRef,
     pub default_value: Option<PrismaValue>,
+    pub validators: Vec<FieldValidator>,
 
     pub(crate) is_unique: bool,
 }
@@ -54,6 +59,8 @@ pub struct ScalarField {
 pub enum FieldBehaviour {
     CreatedAt,
     UpdatedAt,
+    /// The value is generated by the database, from a `dbgenerated("...")` default.
+    DbGenerated,
     Id {
         strategy: IdStrategy,
         sequence: Option<Sequence>, // TODO: this can be removed when we have switched fully to datamodel v2. This is not of interested for the query engine.
@@ -63,6 +70,68 @@ pub enum FieldBehaviour {
     },
 }
 
+/// Rules the values written to a field have to satisfy, from `@length` and `@range`.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase", tag = "type")]
+pub enum FieldValidator {
+    Length { min: Option<i32>, max: Option<i32> },
+    Range { min: Option<Decimal>, max: Option<Decimal> },
+}
+
+impl FieldValidator {
+    /// Checks a value that is about to be written and returns the reason if it is rejected.
+    /// Nulls are always accepted, whether they are allowed is up to the arity of the field.
+    pub fn validate(&self, value: &PrismaValue) -> Result<(), String> {
+        match (self, value) {
+            (FieldValidator::Length { min, max }, PrismaValue::String(s)) => {
+                let length = s.chars().count();
+
+                match (min, max) {
+                    (Some(min), _) if length < *min as usize => Err(format!(
+                        "The value must be at least {} characters long, but is {} characters long.",
+                        min, length
+                    )),
+                    (_, Some(max)) if length > *max as usize => Err(format!(
+                        "The value must be at most {} characters long, but is {} characters long.",
+                        max, length
+                    )),
+                    _ => Ok(()),
+                }
+            }
+            (FieldValidator::Range { min, max }, value) => {
+                let below_min = min.map_or(false, |min| compare_to_bound(value, &min) == Some(Ordering::Less));
+                let above_max = max.map_or(false, |max| compare_to_bound(value, &max) == Some(Ordering::Greater));
+
+                match (min, max) {
+                    (Some(min), _) if below_min => {
+                        Err(format!("The value {} is less than the minimum {}.", value, min))
+                    }
+                    (_, Some(max)) if above_max => {
+                        Err(format!("The value {} is greater than the maximum {}.", value, max))
+                    }
+                    _ => Ok(()),
+                }
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Compares a numeric value to an exact decimal bound. Floats are compared as floats, so that
+/// bounds like `0.1` behave the way they were written.
+fn compare_to_bound(value: &PrismaValue, bound: &Decimal) -> Option<Ordering> {
+    match value {
+        PrismaValue::Int(i) => Some(Decimal::from(*i).cmp(bound)),
+        PrismaValue::Decimal(d) => Some(d.cmp(bound)),
+        PrismaValue::Float(f) => bound
+            .to_string()
+            .parse::<f64>()
+            .ok()
+            .and_then(|bound| f.partial_cmp(&bound)),
+        _ => None,
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize, Clone, Copy, Eq, PartialEq)]
 pub enum IdStrategy {
     Auto,
@@ -119,6 +188,13 @@ impl ScalarField {
         }
     }
 
+    pub fn is_db_generated(&self) -> bool {
+        match self.behaviour {
+            Some(FieldBehaviour::DbGenerated) => true,
+            _ => false,
+        }
+    }
+
     pub fn is_updated_at(&self) -> bool {
         if self.model().is_legacy() {
             self.name == UPDATED_AT_FIELD
@@ -143,11 +219,10 @@ impl ScalarField {
     }
 
     pub fn as_column(&self) -> Column<'static> {
+        let model = self.model();
+
         (
-            (
-                self.internal_data_model().db_name.clone(),
-                self.model().db_name().to_string(),
-            ),
+            (model.schema_name(), model.db_name().to_string()),
             self.db_name().to_string(),
         )
             .into()
@@ -174,4 +249,11 @@ impl ScalarField {
     pub fn scalar_list_table(&self) -> ScalarListTable {
         ScalarListTable::new(self)
     }
+
+    /// Checks a value against all validators of this field.
+    pub fn validate(&self, value: &PrismaValue) -> Result<(), String> {
+        self.validators
+            .iter()
+            .try_for_each(|validator| validator.validate(value))
+    }
 }
@@ -97,11 +97,45 @@ impl InternalDataModel {
         self.version.is_none()
     }
 
+    /// Relation fields pointing to `model` whose records prevent the deletion of connected `model` records,
+    /// either because the relation is required or because its referential action restricts deletions.
     pub fn fields_requiring_model(&self, model: &ModelRef) -> Vec<RelationFieldRef> {
         self.relation_fields()
             .iter()
             .filter(|rf| &rf.related_model() == model)
-            .filter(|f| f.is_required && !f.is_list)
+            .filter(|f| !f.is_list)
+            .filter(|f| match f.related_field().on_delete() {
+                ReferentialAction::Restrict | ReferentialAction::NoAction => true,
+                ReferentialAction::Cascade => false,
+                // Relation columns have no default, so setting the default sets them to `NULL` as well.
+                ReferentialAction::SetNull | ReferentialAction::SetDefault => f.is_required,
+            })
+            .map(|f| Arc::clone(f))
+            .collect()
+    }
+
+    /// Relation fields pointing to `model` whose records are deleted together with connected `model` records.
+    pub fn fields_cascading_from_model(&self, model: &ModelRef) -> Vec<RelationFieldRef> {
+        self.relation_fields()
+            .iter()
+            .filter(|rf| &rf.related_model() == model)
+            .filter(|f| !f.is_list && f.related_field().on_delete().is_cascade())
+            .map(|f| Arc::clone(f))
+            .collect()
+    }
+
+    /// Relation fields pointing to `model` that are set to `NULL` when connected `model` records are deleted.
+    /// Only relations inlined in the table of the field's model are set, the others lose the link with the
+    /// deleted record.
+    pub fn fields_set_null_from_model(&self, model: &ModelRef) -> Vec<RelationFieldRef> {
+        self.relation_fields()
+            .iter()
+            .filter(|rf| &rf.related_model() == model)
+            .filter(|f| !f.is_list && !f.is_required && f.relation_is_inlined_in_parent())
+            .filter(|f| match f.related_field().on_delete() {
+                ReferentialAction::SetNull | ReferentialAction::SetDefault => true,
+                _ => false,
+            })
             .map(|f| Arc::clone(f))
             .collect()
     }
@@ -12,15 +12,22 @@ pub type ModelWeakRef = Weak<Model>;
 pub struct ModelTemplate {
     pub name: String,
     pub is_embedded: bool,
+    #[serde(default)]
+    pub is_view: bool,
     pub fields: Vec<FieldTemplate>,
     pub manifestation: Option<String>,
+    #[serde(default)]
+    pub schema: Option<String>,
 }
 
 #[derive(DebugStub)]
 pub struct Model {
     pub name: String,
     pub is_embedded: bool,
+    /// Views are read only, no mutations are generated for them.
+    pub is_view: bool,
     manifestation: Option<String>,
+    schema: Option<String>,
 
     fields: OnceCell<Fields>,
 
@@ -33,8 +40,10 @@ impl ModelTemplate {
         let model = Arc::new(Model {
             name: self.name,
             is_embedded: self.is_embedded,
+            is_view: self.is_view,
             fields: OnceCell::new(),
             manifestation: self.manifestation,
+            schema: self.schema,
             internal_data_model,
         });
 
@@ -77,7 +86,15 @@ impl Model {
     }
 
     pub fn table(&self) -> Table<'static> {
-        (self.internal_data_model().db_name.clone(), self.db_name().to_string()).into()
+        (self.schema_name(), self.db_name().to_string()).into()
+    }
+
+    /// The database schema of the model's table, which is the one of the connection unless the
+    /// model declares its own.
+    pub fn schema_name(&self) -> String {
+        self.schema
+            .clone()
+            .unwrap_or_else(|| self.internal_data_model().db_name.clone())
     }
 
     pub fn fields(&self) -> &Fields {
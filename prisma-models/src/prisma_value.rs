@@ -5,6 +5,8 @@ use serde_json::Value;
 use std::{convert::TryFrom, fmt, string::FromUtf8Error};
 use uuid::Uuid;
 
+pub use datamodel::common::Decimal;
+
 #[cfg(feature = "sql")]
 use quaint::ast::*;
 
@@ -37,6 +39,10 @@ pub enum PrismaValue {
     #[serde(rename = "float")]
     Float(f64),
 
+    /// Arbitrary precision number, serialized as a string to not lose any digits on the way.
+    #[serde(rename = "decimal")]
+    Decimal(Decimal),
+
     #[serde(rename = "bool")]
     Boolean(bool),
 
@@ -79,6 +85,7 @@ impl fmt::Display for PrismaValue {
         match self {
             PrismaValue::String(x) => x.fmt(f),
             PrismaValue::Float(x) => x.fmt(f),
+            PrismaValue::Decimal(x) => x.fmt(f),
             PrismaValue::Boolean(x) => x.fmt(f),
             PrismaValue::DateTime(x) => x.fmt(f),
             PrismaValue::Enum(x) => x.as_string().fmt(f),
@@ -123,6 +130,12 @@ impl From<f32> for PrismaValue {
     }
 }
 
+impl From<Decimal> for PrismaValue {
+    fn from(d: Decimal) -> Self {
+        PrismaValue::Decimal(d)
+    }
+}
+
 impl From<bool> for PrismaValue {
     fn from(b: bool) -> Self {
         PrismaValue::Boolean(b)
@@ -246,6 +259,8 @@ impl<'a> From<PrismaValue> for DatabaseValue<'a> {
         match pv {
             PrismaValue::String(s) => s.into(),
             PrismaValue::Float(f) => (f as f64).into(),
+            // Bound as a number, text parameters are rejected by `numeric` columns on Postgres.
+            PrismaValue::Decimal(d) => (d.to_string().parse::<f64>().unwrap()).into(),
             PrismaValue::Boolean(b) => b.into(),
             PrismaValue::DateTime(d) => d.into(),
             PrismaValue::Enum(e) => e.as_string().into(),
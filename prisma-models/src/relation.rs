@@ -6,27 +6,42 @@ use std::sync::{Arc, Weak};
 pub type RelationRef = Arc<Relation>;
 pub type RelationWeakRef = Weak<Relation>;
 
+/// What happens to connected records when a record on one side of a relation is deleted or
+/// its referenced fields are updated.
 #[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum OnDelete {
+pub enum ReferentialAction {
     SetNull,
     Cascade,
+    Restrict,
+    SetDefault,
+    NoAction,
 }
 
-impl OnDelete {
+impl ReferentialAction {
     pub fn is_cascade(self) -> bool {
-        match self {
-            OnDelete::Cascade => true,
-            OnDelete::SetNull => false,
-        }
+        self == ReferentialAction::Cascade
     }
 
     pub fn is_set_null(self) -> bool {
-        match self {
-            OnDelete::Cascade => false,
-            OnDelete::SetNull => true,
-        }
+        self == ReferentialAction::SetNull
+    }
+
+    pub fn is_restrict(self) -> bool {
+        self == ReferentialAction::Restrict
+    }
+
+    pub fn is_set_default(self) -> bool {
+        self == ReferentialAction::SetDefault
     }
+
+    pub fn is_no_action(self) -> bool {
+        self == ReferentialAction::NoAction
+    }
+}
+
+fn default_on_update() -> ReferentialAction {
+    ReferentialAction::NoAction
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
@@ -64,8 +79,12 @@ pub enum RelationLinkManifestation {
 #[serde(rename_all = "camelCase")]
 pub struct RelationTemplate {
     pub name: String,
-    pub model_a_on_delete: OnDelete,
-    pub model_b_on_delete: OnDelete,
+    pub model_a_on_delete: ReferentialAction,
+    pub model_b_on_delete: ReferentialAction,
+    #[serde(default = "default_on_update")]
+    pub model_a_on_update: ReferentialAction,
+    #[serde(default = "default_on_update")]
+    pub model_b_on_update: ReferentialAction,
     pub manifestation: Option<RelationLinkManifestation>, // TODO: remove the option after the switch to v2 is completed
 
     #[serde(rename = "modelAId")]
@@ -83,8 +102,10 @@ pub struct Relation {
     model_a_name: String,
     model_b_name: String,
 
-    pub model_a_on_delete: OnDelete,
-    pub model_b_on_delete: OnDelete,
+    pub model_a_on_delete: ReferentialAction,
+    pub model_b_on_delete: ReferentialAction,
+    pub model_a_on_update: ReferentialAction,
+    pub model_b_on_update: ReferentialAction,
 
     model_a: OnceCell<ModelWeakRef>,
     model_b: OnceCell<ModelWeakRef>,
@@ -107,6 +128,8 @@ impl RelationTemplate {
             model_b_name: self.model_b_name,
             model_a_on_delete: self.model_a_on_delete,
             model_b_on_delete: self.model_b_on_delete,
+            model_a_on_update: self.model_a_on_update,
+            model_b_on_update: self.model_b_on_update,
             model_a: OnceCell::new(),
             model_b: OnceCell::new(),
             field_a: OnceCell::new(),
@@ -270,19 +293,13 @@ impl Relation {
         use RelationLinkManifestation::*;
 
         match self.manifestation {
-            Some(RelationTable(ref m)) => {
-                let db = self.model_a().internal_data_model().db_name.clone();
-                (db, m.table.clone()).into()
-            }
+            Some(RelationTable(ref m)) => (self.model_a().schema_name(), m.table.clone()).into(),
             Some(Inline(ref m)) => self
                 .internal_data_model()
                 .find_model(&m.in_table_of_model_name)
                 .unwrap()
                 .table(),
-            None => {
-                let db = self.model_a().internal_data_model().db_name.clone();
-                (db, format!("_{}", self.name)).into()
-            }
+            None => (self.model_a().schema_name(), format!("_{}", self.name)).into(),
         }
     }
 
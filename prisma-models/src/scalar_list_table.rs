@@ -23,10 +23,9 @@ impl<'a> ScalarListTable<'a> {
     }
 
     pub fn table(&self) -> Table<'static> {
-        let internal_data_model = self.parent_field.internal_data_model();
-        let database_name = internal_data_model.db_name.clone();
+        let schema_name = self.parent_field.model().schema_name();
 
-        Table::from((database_name, self.table_name.clone()))
+        Table::from((schema_name, self.table_name.clone()))
     }
 
     pub fn node_id_column(&self) -> Column<'static> {
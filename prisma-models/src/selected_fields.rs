@@ -147,6 +147,22 @@ impl SelectedFields {
         result
     }
 
+    /// The names of the selected columns as they appear in the result set.
+    pub fn db_names(&self) -> Vec<String> {
+        let mut result: Vec<String> = self.scalar_non_list().iter().map(|f| f.db_name().to_string()).collect();
+
+        for rf in self.relation_inlined().iter() {
+            result.push(rf.db_name());
+        }
+
+        if self.from_field.is_some() {
+            result.push(Self::RELATED_MODEL_ALIAS.to_string());
+            result.push(Self::PARENT_MODEL_ALIAS.to_string());
+        };
+
+        result
+    }
+
     pub fn type_identifiers(&self) -> Vec<TypeIdentifier> {
         let mut result: Vec<TypeIdentifier> = self.scalar_non_list().iter().map(|sf| sf.type_identifier).collect();
 
@@ -48,7 +48,9 @@ fn models_with_only_scalar_fields() {
             model Test {
                 id Int @id
                 int Int
+                bigInt BigInt
                 float Float
+                decimal Decimal
                 boolean Boolean
                 dateTime DateTime
                 stringOpt String?
@@ -70,10 +72,18 @@ fn models_with_only_scalar_fields() {
         .assert_scalar_field("int")
         .assert_type_identifier(TypeIdentifier::Int)
         .assert_no_behaviour();
+    model
+        .assert_scalar_field("bigInt")
+        .assert_type_identifier(TypeIdentifier::BigInt)
+        .assert_no_behaviour();
     model
         .assert_scalar_field("float")
         .assert_type_identifier(TypeIdentifier::Float)
         .assert_no_behaviour();
+    model
+        .assert_scalar_field("decimal")
+        .assert_type_identifier(TypeIdentifier::Decimal)
+        .assert_no_behaviour();
     model
         .assert_scalar_field("boolean")
         .assert_type_identifier(TypeIdentifier::Boolean)
@@ -203,6 +213,24 @@ fn createdAt_works() {
         .assert_created_at();
 }
 
+#[test]
+fn dbgenerated_works() {
+    let datamodel = convert(
+        r#"
+            model Test {
+                id String @id @default(cuid())
+                token String @default(dbgenerated("md5(random()::text)"))
+            }
+        "#,
+    );
+
+    let model = datamodel.assert_model("Test");
+    model
+        .assert_scalar_field("token")
+        .assert_type_identifier(TypeIdentifier::String)
+        .assert_behaviour(FieldBehaviour::DbGenerated);
+}
+
 #[test]
 fn updatedAt_works() {
     let datamodel = convert(
@@ -415,6 +443,126 @@ fn ambiguous_relations() {
     post.assert_relation_field("blog2").assert_relation_name("Relation2");
 }
 
+#[test]
+fn field_validators() {
+    let datamodel = convert(
+        r#"
+            model Product {
+                id    Int    @id
+                name  String @length(min: 2, max: 5)
+                price Float  @range(min: 0.5)
+                stock Int    @range(max: 10)
+            }
+        "#,
+    );
+
+    let product = datamodel.assert_model("Product");
+
+    let name = product.assert_scalar_field("name");
+    assert_eq!(
+        name.validators,
+        vec![FieldValidator::Length {
+            min: Some(2),
+            max: Some(5)
+        }]
+    );
+    assert!(name.validate(&PrismaValue::String("abc".to_string())).is_ok());
+    assert!(name.validate(&PrismaValue::String("a".to_string())).is_err());
+    assert!(name.validate(&PrismaValue::String("abcdef".to_string())).is_err());
+    assert!(name.validate(&PrismaValue::Null).is_ok());
+
+    let price = product.assert_scalar_field("price");
+    assert!(price.validate(&PrismaValue::Float(0.5)).is_ok());
+    assert!(price.validate(&PrismaValue::Float(0.4)).is_err());
+
+    let stock = product.assert_scalar_field("stock");
+    assert!(stock.validate(&PrismaValue::Int(10)).is_ok());
+    assert!(stock.validate(&PrismaValue::Int(11)).is_err());
+}
+
+#[test]
+fn referential_actions() {
+    let datamodel = convert(
+        r#"
+            model Blog {
+                id Int @id
+                posts Post[]
+            }
+
+            model Post {
+                id Int @id
+                blog Blog @relation(onDelete: CASCADE, onUpdate: RESTRICT)
+            }
+        "#,
+    );
+
+    let relation = datamodel.assert_relation("BlogToPost");
+
+    // Deleting a blog cascades to its posts, deleting a post leaves the blog alone.
+    assert_eq!(relation.model_a_on_delete, ReferentialAction::Cascade);
+    assert_eq!(relation.model_b_on_delete, ReferentialAction::SetNull);
+    assert_eq!(relation.model_a_on_update, ReferentialAction::Restrict);
+    assert_eq!(relation.model_b_on_update, ReferentialAction::NoAction);
+
+    let blog = datamodel.assert_model("Blog");
+    let post = datamodel.assert_model("Post");
+
+    assert_eq!(
+        datamodel
+            .fields_cascading_from_model(&blog)
+            .iter()
+            .map(|rf| rf.name.as_str())
+            .collect::<Vec<_>>(),
+        vec!["blog"]
+    );
+    assert!(datamodel.fields_requiring_model(&blog).is_empty());
+    assert!(datamodel.fields_cascading_from_model(&post).is_empty());
+}
+
+#[test]
+fn referential_actions_setting_null() {
+    let datamodel = convert(
+        r#"
+            model Post {
+                id Int @id
+                comments Comment[]
+                likes Like[]
+                tags Tag[]
+            }
+
+            model Comment {
+                id Int @id
+                post Post? @relation(onDelete: SET_NULL)
+            }
+
+            model Tag {
+                id Int @id
+                post Post? @relation(onDelete: SET_DEFAULT)
+            }
+
+            model Like {
+                id Int @id
+                post Post @relation(onDelete: SET_NULL)
+            }
+        "#,
+    );
+
+    let post = datamodel.assert_model("Post");
+    let names = |fields: Vec<RelationFieldRef>| {
+        let mut names: Vec<String> = fields.iter().map(|rf| rf.model().name.clone()).collect();
+        names.sort();
+        names
+    };
+
+    // Optional relations are set to `NULL`, required ones can't be and keep restricting the deletion.
+    assert_eq!(
+        names(datamodel.fields_set_null_from_model(&post)),
+        vec!["Comment", "Tag"]
+    );
+    assert_eq!(names(datamodel.fields_requiring_model(&post)), vec!["Like"]);
+    assert!(datamodel.fields_cascading_from_model(&post).is_empty());
+}
+
 fn convert(datamodel: &str) -> Arc<InternalDataModel> {
     let datamodel = dbg!(datamodel::parse_datamodel(datamodel).unwrap());
     let template = DatamodelConverter::convert(&datamodel);
@@ -1,5 +1,9 @@
 use super::transaction::SqlConnectorTransaction;
-use crate::{database::operations::*, query_builder::read::ManyRelatedRecordsQueryBuilder, QueryExt, SqlError};
+use crate::{
+    database::{operations::*, SqlFamily},
+    query_builder::read::ManyRelatedRecordsQueryBuilder,
+    QueryExt, SqlError,
+};
 use connector_interface::{
     self as connector,
     filter::{Filter, RecordFinder},
@@ -10,6 +14,7 @@ use std::marker::PhantomData;
 
 pub struct SqlConnection<C, T> {
     inner: C,
+    family: SqlFamily,
     _p: PhantomData<T>,
 }
 
@@ -18,8 +23,12 @@ where
     C: QueryExt + Send + Sync + 'static,
     T: ManyRelatedRecordsQueryBuilder + Send + Sync + 'static,
 {
-    pub fn new(inner: C) -> Self {
-        Self { inner, _p: PhantomData }
+    pub(crate) fn new(inner: C, family: SqlFamily) -> Self {
+        Self {
+            inner,
+            family,
+            _p: PhantomData,
+        }
     }
 }
 
@@ -34,7 +43,7 @@ where
         IO::new(async move {
             let tx: quaint::connector::Transaction<'a> = fut_tx.await.map_err(SqlError::from)?;
 
-            Ok(Box::new(SqlConnectorTransaction::<T>::new(tx)) as Box<dyn Transaction<'a> + 'a>)
+            Ok(Box::new(SqlConnectorTransaction::<T>::new(tx, self.family)) as Box<dyn Transaction<'a> + 'a>)
         })
     }
 }
@@ -49,7 +58,7 @@ where
         record_finder: &'b RecordFinder,
         selected_fields: &'b SelectedFields,
     ) -> connector::IO<'b, Option<SingleRecord>> {
-        IO::new(async move { read::get_single_record(&self.inner, record_finder, selected_fields).await })
+        IO::new(async move { read::get_single_record(&self.inner, self.family, record_finder, selected_fields).await })
     }
 
     fn get_many_records<'b>(
@@ -58,7 +67,9 @@ where
         query_arguments: QueryArguments,
         selected_fields: &'b SelectedFields,
     ) -> connector::IO<'b, ManyRecords> {
-        IO::new(async move { read::get_many_records(&self.inner, model, query_arguments, selected_fields).await })
+        IO::new(async move {
+            read::get_many_records(&self.inner, self.family, model, query_arguments, selected_fields).await
+        })
     }
 
     fn get_related_records<'b>(
@@ -71,6 +82,7 @@ where
         IO::new(async move {
             read::get_related_records::<T>(
                 &self.inner,
+                self.family,
                 from_field,
                 from_record_ids,
                 query_arguments,
@@ -85,7 +97,7 @@ where
         list_field: &'b ScalarFieldRef,
         record_ids: Vec<GraphqlId>,
     ) -> connector::IO<'b, Vec<ScalarListValues>> {
-        IO::new(async move { read::get_scalar_list_values(&self.inner, list_field, record_ids).await })
+        IO::new(async move { read::get_scalar_list_values(&self.inner, self.family, list_field, record_ids).await })
     }
 
     fn count_by_model<'b>(&'b self, model: &'b ModelRef, query_arguments: QueryArguments) -> connector::IO<'b, usize> {
@@ -13,6 +13,23 @@ pub use mysql::*;
 pub use postgresql::*;
 pub use sqlite::*;
 
+/// The database family behind a connection, for the few queries that have to be rendered by hand.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum SqlFamily {
+    Postgres,
+    Mysql,
+    Sqlite,
+}
+
+impl SqlFamily {
+    pub(crate) fn quote(self, identifier: &str) -> String {
+        match self {
+            SqlFamily::Mysql => format!("`{}`", identifier),
+            SqlFamily::Postgres | SqlFamily::Sqlite => format!("\"{}\"", identifier),
+        }
+    }
+}
+
 pub trait FromSource {
     fn from_source(source: &dyn Source) -> crate::Result<Self>
     where
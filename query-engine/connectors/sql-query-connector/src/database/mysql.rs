@@ -1,4 +1,4 @@
-use super::connection::SqlConnection;
+use super::{connection::SqlConnection, SqlFamily};
 use crate::{query_builder::ManyRelatedRecordsWithUnionAll, FromSource, QueryExt, SqlError};
 use connector_interface::{Connection, Connector, IO};
 use datamodel::Source;
@@ -25,7 +25,7 @@ impl Connector for Mysql {
     fn get_connection<'a>(&'a self) -> IO<Box<dyn Connection + 'a>> {
         IO::new(async move {
             let conn = self.pool.check_out().await.map_err(SqlError::from)?;
-            let conn = SqlConnection::<_, ManyRelatedRecordsWithUnionAll>::new(conn);
+            let conn = SqlConnection::<_, ManyRelatedRecordsWithUnionAll>::new(conn, SqlFamily::Mysql);
 
             Ok(Box::new(conn) as Box<dyn Connection>)
         })
@@ -1,4 +1,5 @@
 use crate::{
+    database::SqlFamily,
     query_builder::read::{ManyRelatedRecordsBaseQuery, ManyRelatedRecordsQueryBuilder, ReadQueryBuilder},
     QueryExt, SqlError,
 };
@@ -14,15 +15,22 @@ struct ScalarListElement {
 
 pub async fn get_single_record(
     conn: &dyn QueryExt,
+    family: SqlFamily,
     record_finder: &RecordFinder,
     selected_fields: &SelectedFields,
 ) -> connector_interface::Result<Option<SingleRecord>> {
     let model = record_finder.field.model();
-    let query = ReadQueryBuilder::get_records(&model, selected_fields, record_finder);
+    let query = ReadQueryBuilder::get_records(&model, selected_fields, record_finder).limit(1);
     let field_names = selected_fields.names();
+    let columns = selected_fields.db_names();
     let idents = selected_fields.type_identifiers();
 
-    let record = (match conn.find(query, idents.as_slice()).await {
+    let row = conn
+        .filter_decimals(family, query.into(), columns.as_slice(), idents.as_slice())
+        .await
+        .and_then(|rows| rows.into_iter().next().ok_or(SqlError::RecordDoesNotExist));
+
+    let record = (match row {
         Ok(result) => Ok(Some(result)),
         Err(_e @ SqlError::RecordNotFoundForWhere(_)) => Ok(None),
         Err(e) => Err(e),
@@ -35,16 +43,18 @@ pub async fn get_single_record(
 
 pub async fn get_many_records(
     conn: &dyn QueryExt,
+    family: SqlFamily,
     model: &ModelRef,
     query_arguments: QueryArguments,
     selected_fields: &SelectedFields,
 ) -> connector_interface::Result<ManyRecords> {
     let field_names = selected_fields.names();
+    let columns = selected_fields.db_names();
     let idents = selected_fields.type_identifiers();
     let query = ReadQueryBuilder::get_records(model, selected_fields, query_arguments);
 
     let records = conn
-        .filter(query.into(), idents.as_slice())
+        .filter_decimals(family, query.into(), columns.as_slice(), idents.as_slice())
         .await?
         .into_iter()
         .map(Record::from)
@@ -55,6 +65,7 @@ pub async fn get_many_records(
 
 pub async fn get_related_records<T>(
     conn: &dyn QueryExt,
+    family: SqlFamily,
     from_field: &RelationFieldRef,
     from_record_ids: &[GraphqlId],
     query_arguments: QueryArguments,
@@ -65,6 +76,7 @@ where
 {
     let idents = selected_fields.type_identifiers();
     let field_names = selected_fields.names();
+    let columns = selected_fields.db_names();
 
     let query = {
         let is_with_pagination = query_arguments.is_with_pagination();
@@ -78,7 +90,7 @@ where
     };
 
     let records: Result<Vec<Record>> = conn
-        .filter(query, idents.as_slice())
+        .filter_decimals(family, query, columns.as_slice(), idents.as_slice())
         .await?
         .into_iter()
         .map(|mut row| {
@@ -103,13 +115,21 @@ where
 
 pub async fn get_scalar_list_values(
     conn: &dyn QueryExt,
+    family: SqlFamily,
     list_field: &ScalarFieldRef,
     record_ids: Vec<GraphqlId>,
 ) -> connector_interface::Result<Vec<ScalarListValues>> {
     let type_identifier = list_field.type_identifier;
     let query = ReadQueryBuilder::get_scalar_list_values_by_record_ids(list_field, record_ids);
+    let columns = ["nodeId".to_string(), "value".to_string()];
+
     let rows = conn
-        .filter(query.into(), &[TypeIdentifier::GraphQLID, type_identifier])
+        .filter_decimals(
+            family,
+            query.into(),
+            &columns,
+            &[TypeIdentifier::GraphQLID, type_identifier],
+        )
         .await?;
 
     let results: Vec<ScalarListElement> = rows
@@ -1,4 +1,4 @@
-use super::connection::SqlConnection;
+use super::{connection::SqlConnection, SqlFamily};
 use crate::{query_builder::ManyRelatedRecordsWithRowNumber, FromSource, QueryExt, SqlError};
 use connector_interface::{Connection, Connector, IO};
 use datamodel::Source;
@@ -24,7 +24,7 @@ impl Connector for PostgreSql {
     fn get_connection<'a>(&'a self) -> IO<Box<dyn Connection + 'a>> {
         IO::new(async move {
             let conn = self.pool.check_out().await.map_err(SqlError::from)?;
-            let conn = SqlConnection::<_, ManyRelatedRecordsWithRowNumber>::new(conn);
+            let conn = SqlConnection::<_, ManyRelatedRecordsWithRowNumber>::new(conn, SqlFamily::Postgres);
 
             Ok(Box::new(conn) as Box<dyn Connection>)
         })
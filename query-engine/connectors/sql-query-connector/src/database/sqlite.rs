@@ -1,4 +1,4 @@
-use super::connection::SqlConnection;
+use super::{connection::SqlConnection, SqlFamily};
 use crate::{query_builder::ManyRelatedRecordsWithRowNumber, FromSource, QueryExt, SqlError};
 use connector_interface::{Connection, Connector, IO};
 use datamodel::Source;
@@ -37,7 +37,7 @@ impl Connector for Sqlite {
     fn get_connection<'a>(&'a self) -> IO<Box<dyn Connection + 'a>> {
         IO::new(async move {
             let conn = self.pool.check_out().await.map_err(SqlError::from)?;
-            let conn = SqlConnection::<_, ManyRelatedRecordsWithRowNumber>::new(conn);
+            let conn = SqlConnection::<_, ManyRelatedRecordsWithRowNumber>::new(conn, SqlFamily::Sqlite);
 
             Ok(Box::new(conn) as Box<dyn Connection>)
         })
@@ -1,4 +1,4 @@
-use crate::database::operations::*;
+use crate::database::{operations::*, SqlFamily};
 use crate::{query_builder::read::ManyRelatedRecordsQueryBuilder, SqlError};
 use connector_interface::{
     self as connector,
@@ -10,13 +10,15 @@ use std::marker::PhantomData;
 
 pub struct SqlConnectorTransaction<'a, T> {
     inner: quaint::connector::Transaction<'a>,
+    family: SqlFamily,
     _p: PhantomData<T>,
 }
 
 impl<'a, T> SqlConnectorTransaction<'a, T> {
-    pub fn new(tx: quaint::connector::Transaction<'a>) -> Self {
+    pub(crate) fn new(tx: quaint::connector::Transaction<'a>, family: SqlFamily) -> Self {
         Self {
             inner: tx,
+            family,
             _p: PhantomData,
         }
     }
@@ -44,7 +46,7 @@ where
         record_finder: &'b RecordFinder,
         selected_fields: &'b SelectedFields,
     ) -> connector::IO<'b, Option<SingleRecord>> {
-        IO::new(async move { read::get_single_record(&self.inner, record_finder, selected_fields).await })
+        IO::new(async move { read::get_single_record(&self.inner, self.family, record_finder, selected_fields).await })
     }
 
     fn get_many_records<'b>(
@@ -53,7 +55,9 @@ where
         query_arguments: QueryArguments,
         selected_fields: &'b SelectedFields,
     ) -> connector::IO<'b, ManyRecords> {
-        IO::new(async move { read::get_many_records(&self.inner, model, query_arguments, selected_fields).await })
+        IO::new(async move {
+            read::get_many_records(&self.inner, self.family, model, query_arguments, selected_fields).await
+        })
     }
 
     fn get_related_records<'b>(
@@ -66,6 +70,7 @@ where
         IO::new(async move {
             read::get_related_records::<T>(
                 &self.inner,
+                self.family,
                 from_field,
                 from_record_ids,
                 query_arguments,
@@ -80,7 +85,7 @@ where
         list_field: &'b ScalarFieldRef,
         record_ids: Vec<GraphqlId>,
     ) -> connector::IO<'b, Vec<ScalarListValues>> {
-        IO::new(async move { read::get_scalar_list_values(&self.inner, list_field, record_ids).await })
+        IO::new(async move { read::get_scalar_list_values(&self.inner, self.family, list_field, record_ids).await })
     }
 
     fn count_by_model<'b>(&'b self, model: &'b ModelRef, query_arguments: QueryArguments) -> connector::IO<'b, usize> {
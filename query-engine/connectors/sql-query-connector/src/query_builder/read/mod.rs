@@ -1,12 +1,18 @@
 mod many_related_records;
 
-use crate::{cursor_condition::CursorCondition, filter_conversion::AliasedCondition, ordering::Ordering};
+use crate::{
+    cursor_condition::CursorCondition, database::SqlFamily, filter_conversion::AliasedCondition, ordering::Ordering,
+};
 use connector_interface::{
     filter::{Filter, RecordFinder},
     QueryArguments,
 };
+use itertools::Itertools;
 use prisma_models::prelude::*;
-use quaint::ast::*;
+use quaint::{
+    ast::*,
+    visitor::{self, Visitor},
+};
 use std::sync::Arc;
 
 pub use many_related_records::*;
@@ -80,6 +86,8 @@ pub struct ReadQueryBuilder;
 
 #[allow(dead_code)]
 impl ReadQueryBuilder {
+    const DECIMALS_TABLE_ALIAS: &'static str = "decimals";
+
     pub fn get_records<T>(model: &ModelRef, selected_fields: &SelectedFields, query: T) -> Select<'static>
     where
         T: SelectDefinition,
@@ -118,6 +126,44 @@ impl ReadQueryBuilder {
         Select::from_table(table).value(count(column))
     }
 
+    /// Renders the query with its decimal columns read as text. Postgres hands out `numeric` values
+    /// as floats and SQLite reads `NUMERIC` columns with real affinity, both would lose digits.
+    pub fn decimals_as_text<'a>(
+        family: SqlFamily,
+        query: Query<'a>,
+        columns: &[String],
+        idents: &[TypeIdentifier],
+    ) -> (String, Vec<ParameterizedValue<'a>>) {
+        let (inner, params) = match family {
+            SqlFamily::Postgres => visitor::Postgres::build(query),
+            SqlFamily::Mysql => visitor::Mysql::build(query),
+            SqlFamily::Sqlite => visitor::Sqlite::build(query),
+        };
+
+        let selection = columns
+            .iter()
+            .zip(idents)
+            .map(|(column, ident)| {
+                let column = family.quote(column);
+
+                match (ident, family) {
+                    (TypeIdentifier::Decimal, SqlFamily::Mysql) => format!("CAST({} AS CHAR) AS {}", column, column),
+                    (TypeIdentifier::Decimal, _) => format!("CAST({} AS TEXT) AS {}", column, column),
+                    _ => column,
+                }
+            })
+            .join(", ");
+
+        let sql = format!(
+            "SELECT {} FROM ({}) AS {}",
+            selection,
+            inner,
+            family.quote(Self::DECIMALS_TABLE_ALIAS)
+        );
+
+        (sql, params)
+    }
+
     pub fn count_by_table(database: &str, table: &str) -> Select<'static> {
         Select::from_table((database.to_string(), table.to_string())).value(count(asterisk()))
     }
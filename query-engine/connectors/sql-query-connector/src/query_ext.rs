@@ -1,4 +1,6 @@
-use crate::{error::*, query_builder::ReadQueryBuilder, AliasedCondition, RawQuery, SqlRow, ToSqlRow};
+use crate::{
+    database::SqlFamily, error::*, query_builder::ReadQueryBuilder, AliasedCondition, RawQuery, SqlRow, ToSqlRow,
+};
 use connector_interface::{
     error::RecordFinderInfo,
     filter::{Filter, RecordFinder},
@@ -29,6 +31,30 @@ pub trait QueryExt: Queryable + Send + Sync {
         Ok(sql_rows)
     }
 
+    /// Like `filter`, but reads the decimal columns as text so none of their digits get lost.
+    /// `columns` are the names of the selected columns in the result set.
+    async fn filter_decimals(
+        &self,
+        family: SqlFamily,
+        q: Query<'_>,
+        columns: &[String],
+        idents: &[TypeIdentifier],
+    ) -> crate::Result<Vec<SqlRow>> {
+        if !idents.contains(&TypeIdentifier::Decimal) {
+            return self.filter(q, idents).await;
+        }
+
+        let (sql, params) = ReadQueryBuilder::decimals_as_text(family, q, columns, idents);
+        let result_set = self.query_raw(sql.as_str(), params.as_slice()).await?;
+        let mut sql_rows = Vec::new();
+
+        for row in result_set {
+            sql_rows.push(row.to_sql_row(idents)?);
+        }
+
+        Ok(sql_rows)
+    }
+
     async fn raw_json(&self, q: RawQuery) -> crate::Result<Value> {
         if q.is_select() {
             let result_set = self.query_raw(q.0.as_str(), &[]).await?;
@@ -1,6 +1,6 @@
 use crate::error::SqlError;
 use chrono::{DateTime, Utc};
-use prisma_models::{EnumValue, GraphqlId, PrismaValue, Record, TypeIdentifier};
+use prisma_models::{Decimal, EnumValue, GraphqlId, PrismaValue, Record, TypeIdentifier};
 use quaint::{
     ast::{DatabaseValue, ParameterizedValue},
     connector::ResultRow,
@@ -125,6 +125,38 @@ impl ToSqlRow for ResultRow {
                         return Err(SqlError::ConversionError(error.into()));
                     }
                 },
+                TypeIdentifier::BigInt => match p_value {
+                    ParameterizedValue::Null => PrismaValue::Null,
+                    ParameterizedValue::Integer(i) => PrismaValue::Int(i),
+                    ParameterizedValue::Text(s) => PrismaValue::Int(s.parse().map_err(|_| {
+                        let error = io::Error::new(
+                            io::ErrorKind::InvalidData,
+                            format!("Could not parse stored BigInt string: {}", s),
+                        );
+                        SqlError::ConversionError(error.into())
+                    })?),
+                    _ => {
+                        let error = io::Error::new(io::ErrorKind::InvalidData, "BigInt value not stored as int or text");
+                        return Err(SqlError::ConversionError(error.into()));
+                    }
+                },
+                TypeIdentifier::Decimal => match p_value {
+                    ParameterizedValue::Null => PrismaValue::Null,
+                    // Decimals are read as text, going through floating point numbers would lose digits.
+                    ParameterizedValue::Text(s) => PrismaValue::Decimal(s.parse().map_err(|_| {
+                        let error = io::Error::new(
+                            io::ErrorKind::InvalidData,
+                            format!("Could not parse stored Decimal string: {}", s),
+                        );
+                        SqlError::ConversionError(error.into())
+                    })?),
+                    ParameterizedValue::Integer(i) => PrismaValue::Decimal(Decimal::from(i)),
+                    _ => {
+                        let error =
+                            io::Error::new(io::ErrorKind::InvalidData, "Decimal value not stored as int or text");
+                        return Err(SqlError::ConversionError(error.into()));
+                    }
+                },
                 _ => PrismaValue::from(p_value),
             };
 
@@ -0,0 +1,218 @@
+use connector_interface::{Connector, Filter, QueryArguments, ReadOperations, WriteArgs, WriteOperations};
+use prisma_models::{DatamodelConverter, Decimal, PrismaArgs, PrismaValue, SelectedFields};
+use quaint::connector::Queryable;
+use sql_query_connector::{FromSource, Mysql, PostgreSql, Sqlite};
+use tokio::runtime::Runtime;
+
+const SCHEMA_NAME: &str = "sql_query_connector_tests";
+
+const DATAMODEL: &str = r#"
+    model Product {
+        id    Int @id
+        price Decimal
+    }
+"#;
+
+#[test]
+fn reading_decimals_from_postgres_must_keep_all_digits() {
+    let url = postgres_url();
+    let runtime = Runtime::new().unwrap();
+
+    runtime.block_on(async {
+        let pool = quaint::pool::postgres(url::Url::parse(&url).unwrap()).unwrap();
+        let conn = pool.check_out().await.unwrap();
+
+        let setup = format!(
+            r#"
+                DROP SCHEMA IF EXISTS "{schema}" CASCADE;
+                CREATE SCHEMA "{schema}";
+                CREATE TABLE "{schema}"."Product" (id INTEGER PRIMARY KEY, price NUMERIC);
+                INSERT INTO "{schema}"."Product" (id, price) VALUES (1, 12345678901234567.891);
+            "#,
+            schema = SCHEMA_NAME
+        );
+
+        for statement in setup.split(';').map(str::trim).filter(|s| !s.is_empty()) {
+            conn.execute_raw(statement, &[]).await.unwrap();
+        }
+    });
+
+    let connector = PostgreSql::from_source(datasource("postgresql", &url).as_ref()).unwrap();
+    let records = runtime.block_on(read_prices(&connector));
+
+    let expected: Decimal = "12345678901234567.891".parse().unwrap();
+    assert_eq!(records, vec![PrismaValue::Decimal(expected)]);
+}
+
+#[test]
+fn writing_and_reading_decimals_must_work_on_postgres() {
+    let url = postgres_url();
+    let runtime = Runtime::new().unwrap();
+
+    runtime.block_on(async {
+        let pool = quaint::pool::postgres(url::Url::parse(&url).unwrap()).unwrap();
+        let conn = pool.check_out().await.unwrap();
+
+        let setup = format!(
+            r#"
+                DROP SCHEMA IF EXISTS "{schema}" CASCADE;
+                CREATE SCHEMA "{schema}";
+                CREATE TABLE "{schema}"."Product" (id INTEGER PRIMARY KEY, price NUMERIC(10,4));
+            "#,
+            schema = SCHEMA_NAME
+        );
+
+        for statement in setup.split(';').map(str::trim).filter(|s| !s.is_empty()) {
+            conn.execute_raw(statement, &[]).await.unwrap();
+        }
+    });
+
+    let connector = PostgreSql::from_source(datasource("postgresql", &url).as_ref()).unwrap();
+
+    runtime.block_on(write_and_read_prices(&connector));
+}
+
+#[test]
+fn writing_and_reading_decimals_must_work_on_mysql() {
+    let url = mysql_url(SCHEMA_NAME);
+    let runtime = Runtime::new().unwrap();
+
+    runtime.block_on(async {
+        // The test database is recreated from a connection to a database that always exists.
+        let pool = quaint::pool::mysql(url::Url::parse(&mysql_url("mysql")).unwrap()).unwrap();
+        let conn = pool.check_out().await.unwrap();
+
+        let setup = format!(
+            r#"
+                DROP DATABASE IF EXISTS `{schema}`;
+                CREATE DATABASE `{schema}`;
+                CREATE TABLE `{schema}`.`Product` (id INTEGER PRIMARY KEY, price DECIMAL(10,4) NOT NULL);
+            "#,
+            schema = SCHEMA_NAME
+        );
+
+        for statement in setup.split(';').map(str::trim).filter(|s| !s.is_empty()) {
+            conn.execute_raw(statement, &[]).await.unwrap();
+        }
+    });
+
+    let connector = Mysql::from_source(datasource("mysql", &url).as_ref()).unwrap();
+
+    runtime.block_on(write_and_read_prices(&connector));
+}
+
+#[test]
+fn writing_and_reading_decimals_must_work_on_sqlite() {
+    let file_path = sqlite_test_file();
+    let runtime = Runtime::new().unwrap();
+
+    let _ = std::fs::remove_file(&file_path);
+
+    runtime.block_on(async {
+        let pool = quaint::pool::sqlite(&file_path, SCHEMA_NAME).unwrap();
+        let conn = pool.check_out().await.unwrap();
+
+        // The column is created the way the migration engine creates decimal columns.
+        let statement = format!(
+            r#"CREATE TABLE "{schema}"."Product" (id INTEGER PRIMARY KEY, price DECIMAL TEXT NOT NULL)"#,
+            schema = SCHEMA_NAME
+        );
+
+        conn.execute_raw(statement.as_str(), &[]).await.unwrap();
+    });
+
+    let url = format!("file:{}", file_path);
+    let connector = Sqlite::from_source(datasource("sqlite", &url).as_ref()).unwrap();
+
+    runtime.block_on(write_and_read_prices(&connector));
+}
+
+/// Creates a product, then updates its price, and checks that both prices are read back exactly.
+async fn write_and_read_prices(connector: &dyn Connector) {
+    let internal_data_model = DatamodelConverter::convert_string(DATAMODEL.to_string()).build(SCHEMA_NAME.to_string());
+    let model = internal_data_model.find_model("Product").unwrap();
+    let conn = connector.get_connection().await.unwrap();
+
+    let created: Decimal = "1234.5678".parse().unwrap();
+    let mut args = PrismaArgs::new();
+    args.insert("id", PrismaValue::Int(1));
+    args.insert("price", PrismaValue::Decimal(created));
+
+    conn.create_record(&model, WriteArgs::new(args, vec![])).await.unwrap();
+
+    assert_eq!(read_prices(connector).await, vec![PrismaValue::Decimal(created)]);
+
+    let updated: Decimal = "-0.0042".parse().unwrap();
+    let mut args = PrismaArgs::new();
+    args.insert("price", PrismaValue::Decimal(updated));
+
+    conn.update_records(&model, Filter::empty(), WriteArgs::new(args, vec![]))
+        .await
+        .unwrap();
+
+    assert_eq!(read_prices(connector).await, vec![PrismaValue::Decimal(updated)]);
+}
+
+async fn read_prices(connector: &dyn Connector) -> Vec<PrismaValue> {
+    let internal_data_model = DatamodelConverter::convert_string(DATAMODEL.to_string()).build(SCHEMA_NAME.to_string());
+    let model = internal_data_model.find_model("Product").unwrap();
+    let selected_fields = SelectedFields::from(&model);
+    let conn = connector.get_connection().await.unwrap();
+
+    let records = conn
+        .get_many_records(&model, QueryArguments::default(), &selected_fields)
+        .await
+        .unwrap();
+
+    records
+        .records
+        .into_iter()
+        .map(|record| record.values[1].clone())
+        .collect()
+}
+
+fn datasource(provider: &str, url: &str) -> Box<dyn datamodel::Source> {
+    let config = format!(
+        r#"
+            datasource db {{
+                provider = "{}"
+                url = "{}"
+            }}
+        "#,
+        provider, url
+    );
+
+    datamodel::parse_configuration(&config)
+        .unwrap()
+        .datasources
+        .into_iter()
+        .next()
+        .unwrap()
+}
+
+fn postgres_url() -> String {
+    let host = match std::env::var("IS_BUILDKITE") {
+        Ok(_) => "test-db-postgres",
+        Err(_) => "127.0.0.1",
+    };
+
+    format!(
+        "postgresql://postgres:prisma@{}:5432/test-db?schema={}",
+        host, SCHEMA_NAME
+    )
+}
+
+fn mysql_url(database: &str) -> String {
+    let host = match std::env::var("IS_BUILDKITE") {
+        Ok(_) => "test-db-mysql-5-7",
+        Err(_) => "127.0.0.1",
+    };
+
+    format!("mysql://root:prisma@{}:3306/{}", host, database)
+}
+
+fn sqlite_test_file() -> String {
+    let server_root = std::env::var("SERVER_ROOT").expect("Env var SERVER_ROOT required but not found.");
+
+    format!("{}/db/{}.db", server_root, SCHEMA_NAME)
+}
@@ -1,7 +1,7 @@
 use super::*;
 use crate::schema::*;
 use chrono::prelude::*;
-use prisma_models::{GraphqlId, PrismaValue};
+use prisma_models::{Decimal, GraphqlId, PrismaValue};
 use std::{
     collections::{BTreeMap, HashSet},
     sync::Arc,
@@ -180,6 +180,11 @@ impl QueryDocumentParser {
             (QueryValue::Int(i), ScalarType::Int)         => Ok(PrismaValue::Int(i)),
             (QueryValue::Float(f), ScalarType::Float)     => Ok(PrismaValue::Float(f)),
             (QueryValue::Float(f), ScalarType::Int)       => Ok(PrismaValue::Int(f as i64)),
+            (QueryValue::Int(i), ScalarType::BigInt)      => Ok(PrismaValue::Int(i)),
+            (QueryValue::String(s), ScalarType::BigInt)   => Self::parse_big_int(s.as_str()).map(PrismaValue::Int),
+            (QueryValue::String(s), ScalarType::Decimal)  => Self::parse_decimal(s.as_str()).map(PrismaValue::Decimal),
+            (QueryValue::Int(i), ScalarType::Decimal)     => Ok(PrismaValue::Decimal(Decimal::from(i))),
+            (QueryValue::Float(f), ScalarType::Decimal)   => Self::parse_decimal(&f.to_string()).map(PrismaValue::Decimal),
             (QueryValue::Boolean(b), ScalarType::Boolean) => Ok(PrismaValue::Boolean(b)),
             (QueryValue::Enum(e), ScalarType::Enum(et))   => match et.value_for(e.as_str()) {
                                                                 Some(val) => Ok(PrismaValue::Enum(val.clone())),
@@ -211,6 +216,16 @@ impl QueryDocumentParser {
         serde_json::from_str(s).map_err(|err| QueryParserError::ValueParseError(format!("Invalid json: {}", err)))
     }
 
+    pub fn parse_big_int(s: &str) -> QueryParserResult<i64> {
+        s.parse::<i64>()
+            .map_err(|err| QueryParserError::ValueParseError(format!("Invalid BigInt: {}", err)))
+    }
+
+    pub fn parse_decimal(s: &str) -> QueryParserResult<Decimal> {
+        s.parse::<Decimal>()
+            .map_err(|err| QueryParserError::ValueParseError(format!("Invalid Decimal: {}", err)))
+    }
+
     pub fn parse_uuid(s: &str) -> QueryParserResult<Uuid> {
         Uuid::parse_str(s).map_err(|err| QueryParserError::ValueParseError(format!("Invalid UUID: {}", err)))
     }
@@ -33,6 +33,14 @@ pub enum QueryGraphBuilderError {
 
     RecordNotFound(String),
 
+    /// A value that is about to be written violates a validator
+    /// of its field, e.g. `@length` or `@range`.
+    FieldValidationError {
+        model_name: String,
+        field_name: String,
+        reason: String,
+    },
+
     QueryGraphError(QueryGraphError),
 }
 
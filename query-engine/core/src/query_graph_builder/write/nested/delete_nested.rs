@@ -21,6 +21,8 @@ use std::{convert::TryInto, sync::Arc};
 /// - If the relation is inlined but not in the parent, we can directly generate a delete on the record with the parent ID.
 ///
 /// We always need to make sure that the records are connected before deletion.
+/// The referential actions of relations pointing to the child model are applied by the deletion checks:
+/// restricting relations fail the deletion, cascading relations delete their connected records first.
 pub fn connect_nested_delete(
     graph: &mut QueryGraph,
     parent_node: &NodeRef,
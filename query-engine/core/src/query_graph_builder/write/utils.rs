@@ -3,7 +3,7 @@ use crate::{
     query_graph::{Flow, Node, NodeRef, QueryGraph, QueryGraphDependency},
     ParsedInputValue, QueryGraphBuilderError, QueryGraphBuilderResult,
 };
-use connector::{filter::RecordFinder, Filter, QueryArguments};
+use connector::{filter::RecordFinder, Filter, QueryArguments, ScalarCompare};
 use itertools::Itertools;
 use prisma_models::{ModelRef, PrismaArgs, PrismaValue, RelationFieldRef, SelectedFields};
 use std::{convert::TryInto, sync::Arc};
@@ -230,8 +230,19 @@ pub fn insert_existing_1to1_related_model_checks(
 }
 
 /// Inserts checks into the graph that check all required, non-list relations pointing to
-/// the given `model`. Those checks fail at runtime (edges to the `Empty` node) if one or more
-/// records are found. Checks are inserted between `parent_node` and `child_node`.
+/// the given `model`, as well as all relations restricting the deletion of `model` records.
+/// Those checks fail at runtime (edges to the `Empty` node) if one or more records are found.
+/// Checks are inserted between `parent_node` and `child_node`.
+///
+/// Relations cascading the deletion of `model` records are not checked. Instead, the connected
+/// records are deleted before `child_node` runs. Emulating the cascade keeps required relations
+/// from failing the checks and also covers databases that do not enforce foreign keys, like SQLite
+/// without the `foreign_keys` pragma. The deletion of the connected records is checked and
+/// cascaded the same way, down the whole chain of cascading relations. A chain stops at a model it
+/// already went through, the records of cyclic cascades are left to the database.
+///
+/// Optional relations that set `NULL` or the default on deletion are emulated the same way: the
+/// connected records are updated before `child_node` runs, with their relation field set to `NULL`.
 ///
 /// This function is usually part of a delete (`deleteOne` or `deleteMany`).
 /// Expects `parent_node` to return one or more IDs (for records of `model`) to be checked.
@@ -274,6 +285,17 @@ pub fn insert_deletion_checks(
     model: &ModelRef,
     parent_node: &NodeRef,
     child_node: &NodeRef,
+) -> QueryGraphBuilderResult<()> {
+    insert_cascading_deletion_checks(graph, model, parent_node, child_node, &mut vec![])
+}
+
+/// `cascade_path` holds the models whose deletion cascaded to the deletion of `model`.
+fn insert_cascading_deletion_checks(
+    graph: &mut QueryGraph,
+    model: &ModelRef,
+    parent_node: &NodeRef,
+    child_node: &NodeRef,
+    cascade_path: &mut Vec<ModelRef>,
 ) -> QueryGraphBuilderResult<()> {
     let internal_model = model.internal_data_model();
     let relation_fields = internal_model.fields_requiring_model(model);
@@ -316,5 +338,71 @@ pub fn insert_deletion_checks(
         graph.create_edge(&noop_node, child_node, QueryGraphDependency::ExecutionOrder)?;
     }
 
+    cascade_path.push(Arc::clone(model));
+
+    for rf in internal_model.fields_cascading_from_model(model) {
+        let relation_field = rf.related_field();
+        let related_model = rf.model();
+        let id_field = related_model.fields().id();
+        let read_node = insert_find_children_by_parent_node(graph, parent_node, &relation_field, None)?;
+        let delete_many_node = graph.create_node(Query::Write(WriteQuery::DeleteManyRecords(DeleteManyRecords {
+            model: Arc::clone(&related_model),
+            filter: Filter::empty(),
+        })));
+
+        graph.create_edge(
+            &read_node,
+            &delete_many_node,
+            QueryGraphDependency::ParentIds(Box::new(move |mut node, parent_ids| {
+                if let Node::Query(Query::Write(WriteQuery::DeleteManyRecords(ref mut dmr))) = node {
+                    dmr.filter = id_field.is_in(Some(parent_ids));
+                }
+
+                Ok(node)
+            })),
+        )?;
+
+        if !cascade_path.contains(&related_model) {
+            insert_cascading_deletion_checks(graph, &related_model, &read_node, &delete_many_node, cascade_path)?;
+        }
+
+        // The connected records have to be gone before the child (delete) runs.
+        graph.create_edge(&delete_many_node, child_node, QueryGraphDependency::ExecutionOrder)?;
+    }
+
+    cascade_path.pop();
+
+    for rf in internal_model.fields_set_null_from_model(model) {
+        let relation_field = rf.related_field();
+        let related_model = rf.model();
+        let id_field = related_model.fields().id();
+        let read_node = insert_find_children_by_parent_node(graph, parent_node, &relation_field, None)?;
+
+        let mut non_list_args = PrismaArgs::new();
+        non_list_args.insert(rf.name.clone(), PrismaValue::Null);
+
+        let update_many_node = graph.create_node(Query::Write(WriteQuery::UpdateManyRecords(UpdateManyRecords {
+            model: Arc::clone(&related_model),
+            filter: Filter::empty(),
+            non_list_args,
+            list_args: vec![],
+        })));
+
+        graph.create_edge(
+            &read_node,
+            &update_many_node,
+            QueryGraphDependency::ParentIds(Box::new(move |mut node, parent_ids| {
+                if let Node::Query(Query::Write(WriteQuery::UpdateManyRecords(ref mut umr))) = node {
+                    umr.filter = id_field.is_in(Some(parent_ids));
+                }
+
+                Ok(node)
+            })),
+        )?;
+
+        // The connected records have to let go of the records before the child (delete) runs.
+        graph.create_edge(&update_many_node, child_node, QueryGraphDependency::ExecutionOrder)?;
+    }
+
     Ok(())
 }
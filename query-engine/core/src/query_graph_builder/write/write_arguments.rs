@@ -30,6 +30,14 @@ impl WriteArguments {
 
                     Field::Scalar(sf) => {
                         let value: PrismaValue = v.try_into()?;
+
+                        sf.validate(&value)
+                            .map_err(|reason| QueryGraphBuilderError::FieldValidationError {
+                                model_name: model.name.clone(),
+                                field_name: sf.name.clone(),
+                                reason,
+                            })?;
+
                         args.non_list.insert(sf.name.clone(), value)
                     }
 
@@ -5,7 +5,7 @@ use crate::{
 };
 use connector::ScalarListValues;
 use indexmap::IndexMap;
-use prisma_models::{GraphqlId, PrismaValue};
+use prisma_models::{Decimal, GraphqlId, PrismaValue};
 use std::{borrow::Borrow, collections::HashMap, convert::TryFrom};
 
 /// A grouping of items to their parent record.
@@ -340,9 +340,15 @@ fn serialize_scalar(value: PrismaValue, typ: &OutputTypeRef) -> CoreResult<Item>
                 (ScalarType::Int, PrismaValue::Float(f)) => PrismaValue::Int(f as i64),
                 (ScalarType::Int, PrismaValue::Int(i)) => PrismaValue::Int(i),
 
+                // BigInts leave the engine as strings, JSON numbers can't hold all 64 bits in JS.
+                (ScalarType::BigInt, PrismaValue::Int(i)) => PrismaValue::String(i.to_string()),
+
                 (ScalarType::Float, PrismaValue::Float(f)) => PrismaValue::Float(f),
                 (ScalarType::Float, PrismaValue::Int(i)) => PrismaValue::Float(i as f64),
 
+                (ScalarType::Decimal, PrismaValue::Decimal(d)) => PrismaValue::Decimal(d),
+                (ScalarType::Decimal, PrismaValue::Int(i)) => PrismaValue::Decimal(Decimal::from(i)),
+
                 (ScalarType::Enum(ref et), PrismaValue::Enum(ref ev)) => match et.value_for(&ev.name) {
                     Some(_) => PrismaValue::Enum(ev.clone()),
                     None => {
@@ -304,10 +304,18 @@ impl InputType {
         InputType::Scalar(ScalarType::Int)
     }
 
+    pub fn big_int() -> InputType {
+        InputType::Scalar(ScalarType::BigInt)
+    }
+
     pub fn float() -> InputType {
         InputType::Scalar(ScalarType::Float)
     }
 
+    pub fn decimal() -> InputType {
+        InputType::Scalar(ScalarType::Decimal)
+    }
+
     pub fn boolean() -> InputType {
         InputType::Scalar(ScalarType::Boolean)
     }
@@ -359,10 +367,18 @@ impl OutputType {
         OutputType::Scalar(ScalarType::Int)
     }
 
+    pub fn big_int() -> OutputType {
+        OutputType::Scalar(ScalarType::BigInt)
+    }
+
     pub fn float() -> OutputType {
         OutputType::Scalar(ScalarType::Float)
     }
 
+    pub fn decimal() -> OutputType {
+        OutputType::Scalar(ScalarType::Decimal)
+    }
+
     pub fn boolean() -> OutputType {
         OutputType::Scalar(ScalarType::Boolean)
     }
@@ -416,7 +432,9 @@ impl OutputType {
 pub enum ScalarType {
     String,
     Int,
+    BigInt,
     Float,
+    Decimal,
     Boolean,
     Enum(EnumTypeRef),
     DateTime,
@@ -43,7 +43,7 @@ pub trait CreateInputTypeBuilderExtension<'a>: InputTypeBuilderBase<'a> {
 
                         _ => unreachable!(),
                     }
-                } else if required_and_none && (f.is_created_at() || f.is_updated_at()) {
+                } else if required_and_none && (f.is_created_at() || f.is_updated_at() || f.is_db_generated()) {
                     self.map_optional_input_type(f)
                 } else if required_and_none {
                     self.map_required_input_type(f)
@@ -10,7 +10,9 @@ pub trait InputBuilderExtensions {
         let typ = match field.type_identifier {
             TypeIdentifier::String => InputType::string(),
             TypeIdentifier::Int => InputType::int(),
+            TypeIdentifier::BigInt => InputType::big_int(),
             TypeIdentifier::Float => InputType::float(),
+            TypeIdentifier::Decimal => InputType::decimal(),
             TypeIdentifier::Boolean => InputType::boolean(),
             TypeIdentifier::GraphQLID => InputType::id(),
             TypeIdentifier::UUID => InputType::uuid(),
@@ -106,6 +106,7 @@ impl<'a> ObjectTypeBuilder<'a> {
             ModelField::Scalar(sf) => match sf.type_identifier {
                 TypeIdentifier::String => OutputType::string(),
                 TypeIdentifier::Float => OutputType::float(),
+                TypeIdentifier::Decimal => OutputType::decimal(),
                 TypeIdentifier::Boolean => OutputType::boolean(),
                 TypeIdentifier::Enum => Self::map_enum_field(sf).into(),
                 TypeIdentifier::Json => OutputType::json(),
@@ -113,6 +114,7 @@ impl<'a> ObjectTypeBuilder<'a> {
                 TypeIdentifier::GraphQLID => OutputType::id(),
                 TypeIdentifier::UUID => OutputType::uuid(),
                 TypeIdentifier::Int => OutputType::int(),
+                TypeIdentifier::BigInt => OutputType::big_int(),
                 TypeIdentifier::Relation => unreachable!(), // Scalar fields can't have a Relation type identifier.
             },
         };
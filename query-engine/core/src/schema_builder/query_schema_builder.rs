@@ -148,11 +148,12 @@ impl<'a> QuerySchemaBuilder<'a> {
         (OutputType::Object(Arc::downgrade(&strong_ref)), strong_ref)
     }
 
-    /// Builds the root mutation type.
+    /// Builds the root mutation type. Views are read only and get no mutations.
     fn build_mutation_type(&self) -> (OutputType, ObjectTypeStrongRef) {
         let non_embedded_models = self.non_embedded_models();
         let fields = non_embedded_models
             .into_iter()
+            .filter(|model| !model.is_view)
             .map(|model| {
                 let mut vec = vec![self.create_item_field(Arc::clone(&model))];
 
@@ -67,8 +67,10 @@ impl<'a> DMMFTypeRenderer<'a> {
                 let stringified = match scalar {
                     ScalarType::String => "String",
                     ScalarType::Int => "Int",
+                    ScalarType::BigInt => "BigInt",
                     ScalarType::Boolean => "Boolean",
                     ScalarType::Float => "Float",
+                    ScalarType::Decimal => "Decimal",
                     ScalarType::DateTime => "DateTime",
                     ScalarType::Json => "DateTime",
                     ScalarType::ID => "ID",
@@ -140,8 +142,10 @@ impl<'a> DMMFTypeRenderer<'a> {
                 let stringified = match scalar {
                     ScalarType::String => "String",
                     ScalarType::Int => "Int",
+                    ScalarType::BigInt => "BigInt",
                     ScalarType::Boolean => "Boolean",
                     ScalarType::Float => "Float",
+                    ScalarType::Decimal => "Decimal",
                     ScalarType::DateTime => "DateTime",
                     ScalarType::Json => "DateTime",
                     ScalarType::ID => "ID",
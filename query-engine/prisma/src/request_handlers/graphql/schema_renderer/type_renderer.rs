@@ -49,8 +49,10 @@ impl<'a> GqlTypeRenderer<'a> {
                 let stringified = match scalar {
                     ScalarType::String => "String",
                     ScalarType::Int => "Int",
+                    ScalarType::BigInt => "BigInt",
                     ScalarType::Boolean => "Boolean",
                     ScalarType::Float => "Float",
+                    ScalarType::Decimal => "Decimal",
                     ScalarType::DateTime => "DateTime",
                     ScalarType::Json => "DateTime",
                     ScalarType::ID => "ID",
@@ -90,8 +92,10 @@ impl<'a> GqlTypeRenderer<'a> {
                 let stringified = match scalar {
                     ScalarType::String => "String",
                     ScalarType::Int => "Int",
+                    ScalarType::BigInt => "BigInt",
                     ScalarType::Boolean => "Boolean",
                     ScalarType::Float => "Float",
+                    ScalarType::Decimal => "Decimal",
                     ScalarType::DateTime => "DateTime",
                     ScalarType::Json => "DateTime",
                     ScalarType::ID => "ID",